@@ -0,0 +1,249 @@
+//! Coordination between concurrently running fix-agents.
+//!
+//! When parallel tool execution spawns multiple fix-agents, two of them
+//! could try to patch the same file at once. The `FixCoordinator` hands
+//! out exclusive per-file locks: an agent acquires the lock for every
+//! file it is about to modify, and the `FileLockGuard` releases the lock
+//! automatically when dropped. A contended lock is retried with a short
+//! backoff before the acquiring agent gives up.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use super::status::AgentId;
+
+/// Delay between lock acquisition retries.
+const RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// How many times a contended lock is retried before giving up.
+const MAX_RETRIES: usize = 5;
+
+/// Error from coordinating file access between fix-agents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CoordinationError {
+    /// The file is locked by another agent.
+    FileLocked { path: PathBuf, holder: AgentId },
+}
+
+impl std::fmt::Display for CoordinationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoordinationError::FileLocked { holder, .. } => {
+                write!(f, "File locked by agent {}", holder.0)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CoordinationError {}
+
+/// Tracks which fix-agent is currently modifying which file.
+///
+/// Cloning is cheap and shares the same lock table, so the `AgentManager`
+/// can hold one coordinator and hand a clone to each spawned agent.
+#[derive(Debug, Clone, Default)]
+pub struct FixCoordinator {
+    locks: Arc<Mutex<HashMap<PathBuf, AgentId>>>,
+}
+
+impl FixCoordinator {
+    /// Create a coordinator with an empty lock table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Acquire an exclusive lock on `path` for `agent_id`.
+    ///
+    /// If another agent holds the lock, backs off and retries every 100ms
+    /// (up to 5 times) before returning `CoordinationError::FileLocked`.
+    /// Re-acquiring a lock the agent already holds succeeds immediately.
+    pub fn acquire_file_lock(
+        &self,
+        agent_id: AgentId,
+        path: &Path,
+    ) -> Result<FileLockGuard, CoordinationError> {
+        let path = path.to_path_buf();
+        let mut holder = agent_id;
+
+        for attempt in 0..=MAX_RETRIES {
+            if attempt > 0 {
+                thread::sleep(RETRY_DELAY);
+            }
+
+            let mut locks = self.locks.lock().unwrap_or_else(|e| e.into_inner());
+            match locks.get(&path) {
+                Some(&owner) if owner != agent_id => holder = owner,
+                _ => {
+                    locks.insert(path.clone(), agent_id);
+                    return Ok(FileLockGuard {
+                        locks: Arc::clone(&self.locks),
+                        path,
+                        agent_id,
+                    });
+                }
+            }
+        }
+
+        Err(CoordinationError::FileLocked { path, holder })
+    }
+
+    /// Which agent currently holds the lock on `path`, if any.
+    pub fn lock_holder(&self, path: &Path) -> Option<AgentId> {
+        self.locks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(path)
+            .copied()
+    }
+}
+
+/// Exclusive hold on a file; the lock is released when the guard drops.
+#[derive(Debug)]
+pub struct FileLockGuard {
+    locks: Arc<Mutex<HashMap<PathBuf, AgentId>>>,
+    path: PathBuf,
+    agent_id: AgentId,
+}
+
+impl FileLockGuard {
+    /// The locked path.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for FileLockGuard {
+    fn drop(&mut self) {
+        let mut locks = self.locks.lock().unwrap_or_else(|e| e.into_inner());
+        // Only release a lock this guard's agent still owns
+        if locks.get(&self.path) == Some(&self.agent_id) {
+            locks.remove(&self.path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acquire_uncontended_lock() {
+        // Arrange
+        let coordinator = FixCoordinator::new();
+
+        // Act
+        let guard = coordinator.acquire_file_lock(AgentId(1), Path::new("src/main.rs"));
+
+        // Assert
+        assert!(guard.is_ok());
+        assert_eq!(
+            coordinator.lock_holder(Path::new("src/main.rs")),
+            Some(AgentId(1))
+        );
+    }
+
+    #[test]
+    fn test_guard_releases_lock_on_drop() {
+        // Arrange
+        let coordinator = FixCoordinator::new();
+        let guard = coordinator
+            .acquire_file_lock(AgentId(1), Path::new("Cargo.toml"))
+            .unwrap();
+
+        // Act
+        drop(guard);
+
+        // Assert
+        assert_eq!(coordinator.lock_holder(Path::new("Cargo.toml")), None);
+    }
+
+    #[test]
+    fn test_same_agent_can_reacquire() {
+        // Arrange
+        let coordinator = FixCoordinator::new();
+        let _first = coordinator
+            .acquire_file_lock(AgentId(1), Path::new("src/lib.rs"))
+            .unwrap();
+
+        // Act: the same agent asking again succeeds immediately
+        let second = coordinator.acquire_file_lock(AgentId(1), Path::new("src/lib.rs"));
+
+        // Assert
+        assert!(second.is_ok());
+    }
+
+    #[test]
+    fn test_contended_lock_fails_after_retries() {
+        // Arrange
+        let coordinator = FixCoordinator::new();
+        let _held = coordinator
+            .acquire_file_lock(AgentId(1), Path::new("src/machine.rs"))
+            .unwrap();
+
+        // Act: a different agent retries, then gives up
+        let result = coordinator.acquire_file_lock(AgentId(2), Path::new("src/machine.rs"));
+
+        // Assert
+        let error = result.unwrap_err();
+        assert_eq!(error.to_string(), "File locked by agent 1");
+        assert_eq!(
+            coordinator.lock_holder(Path::new("src/machine.rs")),
+            Some(AgentId(1))
+        );
+    }
+
+    #[test]
+    fn test_locks_on_different_files_are_independent() {
+        // Arrange
+        let coordinator = FixCoordinator::new();
+        let _first = coordinator
+            .acquire_file_lock(AgentId(1), Path::new("a.rs"))
+            .unwrap();
+
+        // Act
+        let second = coordinator.acquire_file_lock(AgentId(2), Path::new("b.rs"));
+
+        // Assert
+        assert!(second.is_ok());
+    }
+
+    #[test]
+    fn test_clones_share_the_lock_table() {
+        // Arrange
+        let coordinator = FixCoordinator::new();
+        let clone = coordinator.clone();
+        let _guard = coordinator
+            .acquire_file_lock(AgentId(1), Path::new("shared.rs"))
+            .unwrap();
+
+        // Act: the clone sees (and contends on) the same lock
+        let result = clone.acquire_file_lock(AgentId(2), Path::new("shared.rs"));
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_lock_reacquirable_after_release() {
+        // Arrange
+        let coordinator = FixCoordinator::new();
+        drop(
+            coordinator
+                .acquire_file_lock(AgentId(1), Path::new("free.rs"))
+                .unwrap(),
+        );
+
+        // Act
+        let result = coordinator.acquire_file_lock(AgentId(2), Path::new("free.rs"));
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(
+            coordinator.lock_holder(Path::new("free.rs")),
+            Some(AgentId(2))
+        );
+    }
+}
@@ -8,6 +8,7 @@ use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
+use super::coordinator::FixCoordinator;
 use super::status::{AgentId, AgentState, AgentStatus};
 
 /// Progress reporter that agents can use to update their progress.
@@ -45,6 +46,9 @@ pub struct AgentManager {
     next_id: Arc<Mutex<u64>>,
     progress_tx: mpsc::UnboundedSender<ProgressUpdate>,
     progress_rx: Arc<Mutex<mpsc::UnboundedReceiver<ProgressUpdate>>>,
+    /// Shared file-lock table handed to fix-agents so parallel fixes
+    /// never modify the same file at once
+    fix_coordinator: FixCoordinator,
 }
 
 /// Internal representation of a managed agent.
@@ -68,9 +72,18 @@ impl AgentManager {
             next_id: Arc::new(Mutex::new(0)),
             progress_tx,
             progress_rx: Arc::new(Mutex::new(progress_rx)),
+            fix_coordinator: FixCoordinator::new(),
         }
     }
 
+    /// Get a clone of the shared fix coordinator.
+    ///
+    /// Clones share the same lock table, so every agent spawned from this
+    /// manager contends on the same per-file locks.
+    pub fn fix_coordinator(&self) -> FixCoordinator {
+        self.fix_coordinator.clone()
+    }
+
     /// Spawns a new agent with the given name, description, and task.
     ///
     /// Returns the agent ID.
@@ -330,7 +343,6 @@ impl AgentManager {
         let result = agent.handle.await;
 
         // Update final state based on result
-        
 
         match result {
             Ok(Ok(value)) => {
@@ -349,6 +361,46 @@ impl AgentManager {
         }
     }
 
+    /// Waits for all non-cancelled agents to reach a terminal state.
+    ///
+    /// Blocks until every agent has either finished (success or failure) or
+    /// been cancelled, or until the timeout elapses. Unlike `wait`, this does
+    /// not consume results or remove agents from the manager.
+    ///
+    /// Returns the IDs of agents that were still running when the timeout
+    /// expired (empty if all agents completed in time).
+    pub async fn wait_for_all(&self, timeout: std::time::Duration) -> Vec<AgentId> {
+        // A deadline past the end of time means "wait forever"
+        let deadline = tokio::time::Instant::now().checked_add(timeout);
+
+        loop {
+            let pending = self.pending_agent_ids();
+            if pending.is_empty() {
+                return Vec::new();
+            }
+
+            if let Some(deadline) = deadline {
+                if tokio::time::Instant::now() >= deadline {
+                    return pending;
+                }
+            }
+
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+    }
+
+    /// Returns the IDs of agents that have neither finished nor been cancelled.
+    fn pending_agent_ids(&self) -> Vec<AgentId> {
+        let agents = self.agents.lock().unwrap();
+        agents
+            .iter()
+            .filter(|(_, agent)| {
+                agent.status.state != AgentState::Cancelled && !agent.handle.is_finished()
+            })
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
     /// Cancels all running agents.
     pub async fn cancel_all(&self) -> Result<(), String> {
         let agent_ids: Vec<AgentId> = {
@@ -1041,6 +1093,72 @@ mod tests {
         manager.wait(id2).await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_wait_for_all_completes_within_timeout() {
+        let manager = AgentManager::new();
+
+        manager.spawn("a1".to_string(), "desc".to_string(), || {
+            std::thread::sleep(Duration::from_millis(50));
+            Ok("1".to_string())
+        });
+        manager.spawn("a2".to_string(), "desc".to_string(), || {
+            std::thread::sleep(Duration::from_millis(50));
+            Ok("2".to_string())
+        });
+
+        let timed_out = manager.wait_for_all(Duration::from_secs(5)).await;
+
+        assert!(timed_out.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_all_returns_timed_out_agents() {
+        let manager = AgentManager::new();
+
+        let fast = manager.spawn("fast".to_string(), "desc".to_string(), || {
+            Ok("done".to_string())
+        });
+        let slow = manager.spawn("slow".to_string(), "desc".to_string(), || {
+            std::thread::sleep(Duration::from_secs(10));
+            Ok("never".to_string())
+        });
+
+        // Give the fast agent time to finish
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        let timed_out = manager.wait_for_all(Duration::from_millis(100)).await;
+
+        assert_eq!(timed_out, vec![slow]);
+        assert!(!timed_out.contains(&fast));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_all_ignores_cancelled_agents() {
+        let manager = AgentManager::new();
+
+        let id = manager.spawn("long".to_string(), "desc".to_string(), || {
+            std::thread::sleep(Duration::from_secs(10));
+            Ok("never".to_string())
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        manager.cancel(id).await.unwrap();
+
+        // A cancelled agent should not hold up the wait
+        let timed_out = manager.wait_for_all(Duration::from_secs(5)).await;
+
+        assert!(timed_out.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_all_no_agents() {
+        let manager = AgentManager::new();
+
+        let timed_out = manager.wait_for_all(Duration::from_millis(10)).await;
+
+        assert!(timed_out.is_empty());
+    }
+
     #[tokio::test]
     async fn test_cancel_all() {
         let manager = AgentManager::new();
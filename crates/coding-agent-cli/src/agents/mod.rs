@@ -3,13 +3,23 @@
 //! This module provides infrastructure for spawning and managing autonomous agents
 //! that can handle complex tasks like error recovery and code fixes.
 
+mod coordinator;
 mod fix_agent;
 pub mod manager;
 pub mod status;
+pub mod task_agent;
+mod telemetry;
 
+pub use coordinator::{CoordinationError, FileLockGuard, FixCoordinator};
 pub use fix_agent::{
-    categorize_deviation, should_auto_fix, DeviationCategory, DeviationRule, FixAgent,
-    FixAgentConfig, FixAttempt, FixResult, FixStatus,
+    categorize_deviation, categorize_deviation_with_context, should_auto_fix,
+    should_auto_fix_with_context, DeviationCategory, DeviationRule, FixAgent, FixAgentConfig,
+    FixAttempt, FixResult, FixStatus,
 };
 pub use manager::AgentManager;
 pub use status::AgentId;
+pub use task_agent::{run_task_agent, TaskAgentConfig, TaskAgentOutcome};
+pub use telemetry::{
+    aggregate_stats, load_telemetry, normalize_error_pattern, FixTelemetry, FixTelemetrySink,
+    LocalTelemetrySink, PatternStats,
+};
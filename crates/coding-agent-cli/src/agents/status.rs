@@ -2,6 +2,8 @@
 //!
 //! This module defines the state machine for agents and provides status tracking.
 
+use crate::ui::theme::{Color, Theme};
+
 /// Unique identifier for an agent.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AgentId(pub u64);
@@ -46,14 +48,14 @@ impl AgentState {
         }
     }
 
-    /// Returns a color code for the state (ANSI escape).
-    pub fn color(&self) -> &'static str {
+    /// Returns the theme color for the state.
+    pub fn color(&self) -> Color {
         match self {
-            AgentState::Queued => "\x1b[90m",    // Gray
-            AgentState::Running => "\x1b[33m",   // Yellow
-            AgentState::Complete => "\x1b[32m",  // Green
-            AgentState::Failed => "\x1b[31m",    // Red
-            AgentState::Cancelled => "\x1b[90m", // Gray
+            AgentState::Queued => Color::Muted,
+            AgentState::Running => Color::Tool,
+            AgentState::Complete => Color::Success,
+            AgentState::Failed => Color::Error,
+            AgentState::Cancelled => Color::Muted,
         }
     }
 }
@@ -113,20 +115,19 @@ impl AgentStatus {
         self.progress = progress.min(100);
     }
 
-    /// Returns a formatted status line for display.
+    /// Returns a formatted status line for display using the default theme.
     pub fn format_line(&self) -> String {
-        let reset = "\x1b[0m";
+        self.format_line_with_theme(&Theme::default())
+    }
+
+    /// Returns a formatted status line styled with the given theme.
+    pub fn format_line_with_theme(&self, theme: &Theme) -> String {
+        let symbol = theme.apply(self.state.color(), self.state.symbol());
         let progress_bar = self.format_progress_bar();
 
         format!(
-            "{}{}{} {}  {}  {}{}",
-            self.state.color(),
-            self.state.symbol(),
-            reset,
-            self.name,
-            self.description,
-            progress_bar,
-            reset
+            "{} {}  {}  {}",
+            symbol, self.name, self.description, progress_bar
         )
     }
 
@@ -0,0 +1,247 @@
+//! Child agent that runs a scoped task in its own conversation.
+//!
+//! The `spawn_task` tool creates one of these: a sub-agent seeded with a
+//! task description and a restricted tool set. It runs its own
+//! request/tool loop to completion (bounded by iterations) and returns a
+//! final summary that the parent conversation receives as the tool result.
+//!
+//! The agent itself is pure and synchronous — the REPL runs it under the
+//! `AgentManager` (via `spawn_with_progress`) so it shows up in the status
+//! bar and responds to `/agents` and `/cancel` like any other agent.
+
+use crate::tokens::TokenCounter;
+use crate::tools::{create_tool_definitions, execute_tool, tool_definitions_to_api};
+use coding_agent_core::{AnthropicClient, ContentBlock, Message, MessageBuilder, ToolDefinition};
+
+use super::manager::ProgressReporter;
+
+/// Default cap on the child's request/tool iterations.
+pub const DEFAULT_MAX_ITERATIONS: usize = 25;
+
+/// Tools a child agent may use when the caller gives no explicit allowlist.
+///
+/// `spawn_task` itself is deliberately absent so children cannot recurse,
+/// and `progress_file` is omitted because the child's progress is already
+/// surfaced through the agent status bar.
+pub const DEFAULT_CHILD_TOOLS: &[&str] = &[
+    "read_file",
+    "write_file",
+    "edit_file",
+    "list_files",
+    "bash",
+    "code_search",
+];
+
+/// System prompt for child agents: work the task, then summarize.
+const TASK_AGENT_SYSTEM_PROMPT: &str = "You are a sub-agent working on a single scoped task for a parent coding agent. Complete the task using the tools available to you, then finish with a concise summary of what you did: files changed, commands run, and anything left incomplete. The summary is all the parent sees, so make it self-contained.";
+
+/// Configuration for a child task agent.
+pub struct TaskAgentConfig {
+    /// API key for the Anthropic API.
+    pub api_key: String,
+    /// Model to use for the child conversation.
+    pub model: String,
+    /// Maximum request/tool iterations before the child is stopped.
+    pub max_iterations: usize,
+    /// Names of the tools the child is allowed to use.
+    pub allowed_tools: Vec<String>,
+}
+
+/// What a child task agent produced, including its token usage so the
+/// caller can roll it up into the parent's cost tracking.
+#[derive(Debug)]
+pub struct TaskAgentOutcome {
+    /// The child's final summary text.
+    pub summary: String,
+    /// How many request/tool iterations the child used.
+    pub iterations: usize,
+    /// Estimated input tokens consumed by the child conversation.
+    pub input_tokens: u64,
+    /// Estimated output tokens produced by the child conversation.
+    pub output_tokens: u64,
+}
+
+/// Build the child's tool set: the standard definitions filtered down to
+/// the allowed names, with `spawn_task` always excluded to prevent
+/// recursive spawning.
+pub(crate) fn restricted_tool_definitions(allowed: &[String]) -> Vec<ToolDefinition> {
+    create_tool_definitions()
+        .into_iter()
+        .filter(|def| def.name != "spawn_task")
+        .filter(|def| allowed.iter().any(|name| name == &def.name))
+        .collect()
+}
+
+/// Run a child task agent to completion.
+///
+/// Blocks the calling thread (API calls go through `ureq`), so run this
+/// under `AgentManager::spawn_with_progress`. Progress is reported as the
+/// fraction of the iteration budget used.
+pub fn run_task_agent(
+    description: &str,
+    config: &TaskAgentConfig,
+    reporter: Option<&ProgressReporter>,
+) -> Result<TaskAgentOutcome, String> {
+    if description.trim().is_empty() {
+        return Err("Task description cannot be empty".to_string());
+    }
+
+    let definitions = restricted_tool_definitions(&config.allowed_tools);
+    let tools_api = tool_definitions_to_api(&definitions);
+
+    // Token counts are estimated the same way the REPL estimates its own:
+    // by counting message text locally
+    let counter = TokenCounter::new().ok();
+    let mut input_tokens: u64 = 0;
+    let mut output_tokens: u64 = 0;
+    let count_tokens = |counter: Option<&TokenCounter>, role: &str, text: &str| -> u64 {
+        counter
+            .map(|c| c.count_message(role, text).tokens as u64)
+            .unwrap_or(0)
+    };
+
+    input_tokens += count_tokens(counter.as_ref(), "user", description);
+
+    // Everything but the conversation is fixed for the child's lifetime,
+    // so one client covers the whole loop
+    let client = AnthropicClient::builder(config.api_key.clone())
+        .model(config.model.clone())
+        .max_tokens(4096)
+        .system(TASK_AGENT_SYSTEM_PROMPT)
+        .tools(tools_api)
+        .base_url(crate::config::api_base_url())
+        .build();
+
+    let mut conversation = vec![Message::user(description)];
+    let mut iteration = 0;
+
+    loop {
+        iteration += 1;
+        if iteration > config.max_iterations {
+            return Err(format!(
+                "Task agent reached its iteration limit ({}) without finishing",
+                config.max_iterations
+            ));
+        }
+
+        if let Some(reporter) = reporter {
+            reporter.report(((iteration * 100) / config.max_iterations.max(1)).min(99) as u8);
+        }
+
+        let response = client.send(&conversation)?;
+
+        let mut response_text = String::new();
+        let mut tool_uses: Vec<(String, String, serde_json::Value)> = Vec::new();
+
+        for block in &response.content {
+            match block {
+                ContentBlock::Text { text } => response_text.push_str(text),
+                ContentBlock::ToolUse { id, name, input } => {
+                    tool_uses.push((id.clone(), name.clone(), input.clone()));
+                }
+                _ => {}
+            }
+        }
+
+        output_tokens += count_tokens(counter.as_ref(), "assistant", &response_text);
+        conversation.push(Message::assistant(response.content.clone()));
+
+        // No tool uses means the child is done; its final text is the summary
+        if tool_uses.is_empty() {
+            if response_text.trim().is_empty() {
+                return Err("Task agent finished without producing a summary".to_string());
+            }
+            return Ok(TaskAgentOutcome {
+                summary: response_text,
+                iterations: iteration,
+                input_tokens,
+                output_tokens,
+            });
+        }
+
+        let mut tool_results: Vec<ContentBlock> = Vec::new();
+        for (id, name, input) in tool_uses {
+            match execute_tool(&definitions, &name, input) {
+                Ok(output) => {
+                    input_tokens += count_tokens(counter.as_ref(), "user", &output);
+                    tool_results.push(ContentBlock::ToolResult {
+                        tool_use_id: id,
+                        content: output,
+                        is_error: None,
+                    });
+                }
+                Err(error) => {
+                    input_tokens += count_tokens(counter.as_ref(), "user", &error);
+                    tool_results.push(ContentBlock::ToolResult {
+                        tool_use_id: id,
+                        content: error,
+                        is_error: Some(true),
+                    });
+                }
+            }
+        }
+
+        conversation.push(MessageBuilder::user().blocks(tool_results).build());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restricted_tool_definitions_filters_by_allowlist() {
+        // Arrange
+        let allowed = vec!["read_file".to_string(), "code_search".to_string()];
+
+        // Act
+        let definitions = restricted_tool_definitions(&allowed);
+
+        // Assert
+        let names: Vec<&str> = definitions.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["read_file", "code_search"]);
+    }
+
+    #[test]
+    fn test_restricted_tool_definitions_never_includes_spawn_task() {
+        // Arrange: explicitly ask for spawn_task
+        let allowed = vec!["spawn_task".to_string(), "bash".to_string()];
+
+        // Act
+        let definitions = restricted_tool_definitions(&allowed);
+
+        // Assert: recursion is not allowed
+        let names: Vec<&str> = definitions.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["bash"]);
+    }
+
+    #[test]
+    fn test_default_child_tools_are_real_tools() {
+        // Arrange
+        let allowed: Vec<String> = DEFAULT_CHILD_TOOLS.iter().map(|s| s.to_string()).collect();
+
+        // Act
+        let definitions = restricted_tool_definitions(&allowed);
+
+        // Assert: every default name resolves to a registered tool
+        assert_eq!(definitions.len(), DEFAULT_CHILD_TOOLS.len());
+    }
+
+    #[test]
+    fn test_run_task_agent_rejects_empty_description() {
+        // Arrange
+        let config = TaskAgentConfig {
+            api_key: "test-key".to_string(),
+            model: "test-model".to_string(),
+            max_iterations: DEFAULT_MAX_ITERATIONS,
+            allowed_tools: vec!["read_file".to_string()],
+        };
+
+        // Act
+        let result = run_task_agent("   ", &config, None);
+
+        // Assert
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("empty"));
+    }
+}
@@ -0,0 +1,268 @@
+//! Telemetry for fix-agent outcomes.
+//!
+//! `categorize_deviation` and the fix diagnosis run on hard-coded
+//! heuristics. Recording what each fix-agent actually did — the error
+//! pattern it saw, how it categorized it, and whether the fix stuck —
+//! builds a local dataset those heuristics can be tuned against.
+//! Recording is best-effort and never blocks or fails a fix.
+
+use super::fix_agent::{DeviationCategory, FixStatus};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// One fix-agent outcome, as written to the telemetry log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FixTelemetry {
+    /// The error message with identifiers normalized out, so repeated
+    /// occurrences of the same failure mode group together
+    pub error_pattern: String,
+    /// How the deviation heuristics categorized the error
+    pub category: DeviationCategory,
+    /// Final status of the fix attempt
+    pub outcome: FixStatus,
+    /// Number of attempts the agent made
+    pub attempts: u32,
+    /// The diagnosed fix type (e.g. `missing_dependency`)
+    pub fix_type: String,
+}
+
+/// Destination for fix telemetry records.
+///
+/// Implementations must tolerate being called from any fix-agent;
+/// failures are the sink's to report and the agent's to ignore.
+pub trait FixTelemetrySink: Send + Sync {
+    fn record(&self, telemetry: &FixTelemetry) -> Result<(), String>;
+}
+
+/// Sink that appends JSONL records to a file under the user data dir.
+pub struct LocalTelemetrySink {
+    path: PathBuf,
+}
+
+impl LocalTelemetrySink {
+    /// Sink writing to the default location
+    /// (`~/.local/share/coding-agent/fix_telemetry.jsonl` on Linux).
+    pub fn new() -> Option<Self> {
+        Some(Self {
+            path: Self::default_path()?,
+        })
+    }
+
+    /// Sink writing to an explicit path (tests, custom setups).
+    pub fn with_path(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Default telemetry log location, `None` when no data dir exists.
+    pub fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("coding-agent").join("fix_telemetry.jsonl"))
+    }
+}
+
+impl FixTelemetrySink for LocalTelemetrySink {
+    fn record(&self, telemetry: &FixTelemetry) -> Result<(), String> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        let line = serde_json::to_string(telemetry)
+            .map_err(|e| format!("Failed to serialize telemetry: {}", e))?;
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| format!("Failed to open {}: {}", self.path.display(), e))?;
+        writeln!(file, "{}", line)
+            .map_err(|e| format!("Failed to write {}: {}", self.path.display(), e))
+    }
+}
+
+/// Normalize an error message into a grouping pattern.
+///
+/// Backtick- and single-quoted identifiers become `_` so "cannot find
+/// crate \`serde\`" and "cannot find crate \`tokio\`" share a pattern;
+/// the result is lowercased and capped at 120 characters.
+pub fn normalize_error_pattern(message: &str) -> String {
+    let mut pattern = String::new();
+    let mut inside_quote: Option<char> = None;
+
+    for c in message.chars() {
+        match inside_quote {
+            Some(quote) if c == quote => {
+                pattern.push('_');
+                inside_quote = None;
+            }
+            Some(_) => {}
+            None if c == '`' || c == '\'' => inside_quote = Some(c),
+            None => pattern.extend(c.to_lowercase()),
+        }
+    }
+
+    pattern.chars().take(120).collect()
+}
+
+/// Aggregated outcomes for one error pattern.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PatternStats {
+    pub error_pattern: String,
+    pub successes: u32,
+    pub failures: u32,
+    pub total_attempts: u32,
+}
+
+impl PatternStats {
+    /// Fraction of recorded fixes that succeeded, in [0, 1].
+    pub fn success_rate(&self) -> f64 {
+        let total = self.successes + self.failures;
+        if total == 0 {
+            return 0.0;
+        }
+        f64::from(self.successes) / f64::from(total)
+    }
+}
+
+/// Read all telemetry records from a JSONL file.
+///
+/// Malformed lines are skipped: the log is append-only and best-effort,
+/// so one bad line must not hide the rest of the data.
+pub fn load_telemetry(path: &Path) -> Result<Vec<FixTelemetry>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Ok(content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+/// Group records by error pattern, most-recorded patterns first.
+pub fn aggregate_stats(records: &[FixTelemetry]) -> Vec<PatternStats> {
+    let mut stats: Vec<PatternStats> = Vec::new();
+    for record in records {
+        let entry = match stats
+            .iter_mut()
+            .find(|s| s.error_pattern == record.error_pattern)
+        {
+            Some(entry) => entry,
+            None => {
+                stats.push(PatternStats {
+                    error_pattern: record.error_pattern.clone(),
+                    successes: 0,
+                    failures: 0,
+                    total_attempts: 0,
+                });
+                stats.last_mut().expect("just pushed")
+            }
+        };
+        if record.outcome == FixStatus::Success {
+            entry.successes += 1;
+        } else {
+            entry.failures += 1;
+        }
+        entry.total_attempts += record.attempts;
+    }
+    stats.sort_by(|a, b| {
+        (b.successes + b.failures)
+            .cmp(&(a.successes + a.failures))
+            .then_with(|| a.error_pattern.cmp(&b.error_pattern))
+    });
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(pattern: &str, outcome: FixStatus, attempts: u32) -> FixTelemetry {
+        FixTelemetry {
+            error_pattern: pattern.to_string(),
+            category: DeviationCategory::Dependency,
+            outcome,
+            attempts,
+            fix_type: "missing_dependency".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_normalize_error_pattern_collapses_identifiers() {
+        // Arrange & Act & Assert: different crates share one pattern
+        assert_eq!(
+            normalize_error_pattern("cannot find crate `serde`"),
+            normalize_error_pattern("cannot find crate `tokio`")
+        );
+        assert_eq!(
+            normalize_error_pattern("Cannot find crate `serde`"),
+            "cannot find crate _"
+        );
+        assert_eq!(
+            normalize_error_pattern("unknown action 'squish'"),
+            "unknown action _"
+        );
+    }
+
+    #[test]
+    fn test_normalize_error_pattern_caps_length() {
+        let long = "x".repeat(500);
+        assert_eq!(normalize_error_pattern(&long).chars().count(), 120);
+    }
+
+    #[test]
+    fn test_local_sink_appends_jsonl_lines() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("fix_telemetry.jsonl");
+        let sink = LocalTelemetrySink::with_path(path.clone());
+
+        // Act
+        sink.record(&record("cannot find crate _", FixStatus::Success, 1))
+            .unwrap();
+        sink.record(&record("mismatched types", FixStatus::Failed, 3))
+            .unwrap();
+
+        // Assert
+        let loaded = load_telemetry(&path).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0].outcome, FixStatus::Success);
+        assert_eq!(loaded[1].attempts, 3);
+    }
+
+    #[test]
+    fn test_load_telemetry_skips_malformed_lines() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fix_telemetry.jsonl");
+        let good = serde_json::to_string(&record("p", FixStatus::Success, 1)).unwrap();
+        std::fs::write(&path, format!("{}\nnot json\n\n{}\n", good, good)).unwrap();
+
+        // Act
+        let loaded = load_telemetry(&path).unwrap();
+
+        // Assert
+        assert_eq!(loaded.len(), 2);
+    }
+
+    #[test]
+    fn test_aggregate_stats_groups_by_pattern() {
+        // Arrange
+        let records = vec![
+            record("cannot find crate _", FixStatus::Success, 1),
+            record("cannot find crate _", FixStatus::Failed, 3),
+            record("cannot find crate _", FixStatus::Success, 2),
+            record("mismatched types", FixStatus::Failed, 3),
+        ];
+
+        // Act
+        let stats = aggregate_stats(&records);
+
+        // Assert: most-recorded pattern first, counts summed
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].error_pattern, "cannot find crate _");
+        assert_eq!(stats[0].successes, 2);
+        assert_eq!(stats[0].failures, 1);
+        assert_eq!(stats[0].total_attempts, 6);
+        assert!((stats[0].success_rate() - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(stats[1].failures, 1);
+        assert_eq!(stats[1].success_rate(), 0.0);
+    }
+}
@@ -0,0 +1,151 @@
+//! The `code auth` subcommands: manage the API key in the OS keychain
+//!
+//! `login` prompts for the key with masked input, validates it, and stores
+//! it in the platform keychain; `logout` removes it; `status` reports every
+//! place a key was found in resolution order (keychain → environment →
+//! `.env` → config). Keychain storage needs the optional `keyring` feature;
+//! without it these commands explain how to get it.
+
+use crate::config::Config;
+use crate::security::keychain;
+use std::io::{self, Write};
+
+/// Prompt for an API key and store it in the OS keychain
+pub fn login() -> Result<String, String> {
+    print!("Anthropic API key (input hidden): ");
+    io::stdout().flush().map_err(|e| e.to_string())?;
+
+    let Some(key) = super::setup::read_masked_line().map_err(|e| e.to_string())? else {
+        return Err("No key entered.".to_string());
+    };
+    let key = key.trim().to_string();
+    if key.is_empty() {
+        return Err("No key entered.".to_string());
+    }
+
+    print!("Validating key... ");
+    io::stdout().flush().map_err(|e| e.to_string())?;
+    super::setup::validate_api_key(&key)?;
+    println!("ok");
+
+    keychain::store_api_key(&key)?;
+    Ok("Key stored in the OS keychain.".to_string())
+}
+
+/// Remove the API key from the OS keychain
+pub fn logout() -> Result<String, String> {
+    if keychain::delete_api_key()? {
+        Ok("Key removed from the OS keychain.".to_string())
+    } else {
+        Ok("No key stored in the OS keychain.".to_string())
+    }
+}
+
+/// Report where the API key is coming from, in resolution order
+pub fn status() -> Result<String, String> {
+    let keychain_state = if keychain::is_available() {
+        match keychain::get_api_key() {
+            Ok(Some(_)) => "key stored".to_string(),
+            Ok(None) => "no key".to_string(),
+            Err(e) => format!("unavailable ({})", e),
+        }
+    } else {
+        "not built in (rebuild with --features keyring)".to_string()
+    };
+
+    // `.env` is reported separately from the inherited environment so the
+    // user can tell which file to edit
+    let dotenv_has_key = dotenv_has_key();
+    let env_has_key = std::env::var("ANTHROPIC_API_KEY").is_ok();
+    let config_has_key = Config::load()
+        .ok()
+        .and_then(|config| config.api_key)
+        .is_some_and(|key| !key.is_empty());
+
+    Ok(render_status(
+        &keychain_state,
+        env_has_key,
+        dotenv_has_key,
+        config_has_key,
+    ))
+}
+
+/// Whether the project `.env` defines the API key
+fn dotenv_has_key() -> bool {
+    std::fs::read_to_string(".env")
+        .map(|contents| {
+            contents
+                .lines()
+                .any(|line| line.trim_start().starts_with("ANTHROPIC_API_KEY="))
+        })
+        .unwrap_or(false)
+}
+
+/// Which source the API key resolves from, if any, for `/status`
+///
+/// Follows the same resolution order as `resolve_api_key`. Once dotenvy
+/// has run, a key from `.env` is indistinguishable from an inherited one,
+/// so both sources are named when the file defines it too.
+pub(crate) fn key_source() -> Option<String> {
+    if keychain::get_api_key().ok().flatten().is_some() {
+        return Some("OS keychain".to_string());
+    }
+    if std::env::var("ANTHROPIC_API_KEY").is_ok() {
+        return Some(if dotenv_has_key() {
+            "environment / .env".to_string()
+        } else {
+            "environment".to_string()
+        });
+    }
+    if Config::load()
+        .ok()
+        .and_then(|config| config.api_key)
+        .is_some_and(|key| !key.is_empty())
+    {
+        return Some("config.toml".to_string());
+    }
+    None
+}
+
+/// Format the status report for the four key sources
+fn render_status(keychain_state: &str, env: bool, dotenv: bool, config: bool) -> String {
+    let mark = |present: bool| if present { "✓" } else { "✗" };
+
+    let mut output = String::new();
+    output.push_str("API key sources (first match wins):\n");
+    output.push_str(&format!("  keychain:    {}\n", keychain_state));
+    output.push_str(&format!("  environment: {} ANTHROPIC_API_KEY\n", mark(env)));
+    output.push_str(&format!("  .env file:   {} project .env\n", mark(dotenv)));
+    output.push_str(&format!(
+        "  config:      {} api_key in config.toml\n",
+        mark(config)
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_status_lists_sources_in_order() {
+        let output = render_status("key stored", true, false, false);
+
+        let keychain_pos = output.find("keychain").unwrap();
+        let env_pos = output.find("environment").unwrap();
+        let dotenv_pos = output.find(".env file").unwrap();
+        let config_pos = output.find("config:").unwrap();
+        assert!(keychain_pos < env_pos);
+        assert!(env_pos < dotenv_pos);
+        assert!(dotenv_pos < config_pos);
+    }
+
+    #[test]
+    fn test_render_status_marks_presence() {
+        let output = render_status("no key", true, false, true);
+
+        assert!(output.contains("✓ ANTHROPIC_API_KEY"));
+        assert!(output.contains("✗ project .env"));
+        assert!(output.contains("✓ api_key in config.toml"));
+    }
+}
@@ -0,0 +1,39 @@
+//! The /auto command - toggles auto-accept mode
+//!
+//! The REPL intercepts `/auto` so it can flip the live auto-accept flag
+//! (also bound to Shift+Tab); the registered command only provides the
+//! name, usage, and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct AutoCommand;
+
+impl Command for AutoCommand {
+    fn name(&self) -> &'static str {
+        "auto"
+    }
+
+    fn description(&self) -> &'static str {
+        "Toggle auto-accept for edits inside the project root (also Shift+Tab)"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Auto-accept only makes sense with a live session; the REPL
+        // intercepts this command before it reaches the registry
+        CommandResult::Output(
+            "Auto-accept is only available in an interactive session.".to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_command_name() {
+        let cmd = AutoCommand;
+        assert_eq!(cmd.name(), "auto");
+        assert!(!cmd.description().is_empty());
+    }
+}
@@ -48,6 +48,15 @@ impl Command for CancelCommand {
             ));
         }
 
+        // Cancelling kills in-flight work, so ask before doing it
+        match ctx.confirm(&format!("Cancel agent {:?}?", agent_id)) {
+            Ok(true) => {}
+            Ok(false) => {
+                return CommandResult::Output(format!("Agent {:?} left running.", agent_id))
+            }
+            Err(e) => return CommandResult::Error(e),
+        }
+
         // Cancel the agent (this is synchronous, but the cancel operation uses async internally)
         // We need to handle the async operation
         let manager_clone = agent_manager.clone();
@@ -168,11 +177,14 @@ mod tests {
             },
         );
 
+        // Skip the confirmation prompt, which needs a terminal
+        let mut config = crate::config::Config::default();
+        config.behavior.skip_confirmations = true;
         let mut ctx = CommandContext {
             registry: registry.clone(),
             cost_tracker: CostTracker::with_default_model(),
             agent_manager: Some(manager.clone()),
-            config: std::sync::Arc::new(crate::config::Config::default()),
+            config: std::sync::Arc::new(config),
             collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
         };
 
@@ -0,0 +1,43 @@
+//! The /checkpoint command - lightweight mid-session snapshots
+//!
+//! The REPL intercepts `/checkpoint` so it can snapshot and restore the
+//! live session; the registered command only provides the name, usage,
+//! and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct CheckpointCommand;
+
+impl Command for CheckpointCommand {
+    fn name(&self) -> &'static str {
+        "checkpoint"
+    }
+
+    fn description(&self) -> &'static str {
+        "Save a lightweight session snapshot (/checkpoint list to browse, restore <n> to roll back)"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/checkpoint [list|restore <n>]"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Checkpoints snapshot the live session; the REPL intercepts this
+        // command before it reaches the registry
+        CommandResult::Output(
+            "Checkpoints are only available in an interactive session.".to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_command_name() {
+        let cmd = CheckpointCommand;
+        assert_eq!(cmd.name(), "checkpoint");
+        assert_eq!(cmd.usage(), "/checkpoint [list|restore <n>]");
+    }
+}
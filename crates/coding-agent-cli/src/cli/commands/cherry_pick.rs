@@ -0,0 +1,43 @@
+//! The /cherry-pick command - apply a commit from another branch
+//!
+//! The REPL intercepts `/cherry-pick` so that conflicts can be handed
+//! to the agent as a conversation message; the registered command only
+//! provides the name, usage, and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct CherryPickCommand;
+
+impl Command for CherryPickCommand {
+    fn name(&self) -> &'static str {
+        "cherry-pick"
+    }
+
+    fn description(&self) -> &'static str {
+        "Apply a commit onto the current branch (conflicts are handed to the agent)"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/cherry-pick <commit>|abort"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Conflict handling injects a conversation message; the REPL
+        // intercepts this command before it reaches the registry
+        CommandResult::Output(
+            "Cherry-pick is only available in an interactive session.".to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cherry_pick_command_name() {
+        let cmd = CherryPickCommand;
+        assert_eq!(cmd.name(), "cherry-pick");
+        assert_eq!(cmd.usage(), "/cherry-pick <commit>|abort");
+    }
+}
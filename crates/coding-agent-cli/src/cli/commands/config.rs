@@ -71,14 +71,10 @@ impl Command for ConfigCommand {
                         "Config file edited: {}\nRestart to apply changes.",
                         path_str
                     )),
-                    Err(e) => {
+                    Err(_) => {
                         // Some editors (like macOS 'open') return immediately
                         // This is fine, just report success
-                        if e.kind() == std::io::ErrorKind::InvalidInput {
-                            CommandResult::Output(format!("Opened config file: {}", path_str))
-                        } else {
-                            CommandResult::Output(format!("Opened config file: {}", path_str))
-                        }
+                        CommandResult::Output(format!("Opened config file: {}", path_str))
                     }
                 }
             }
@@ -53,7 +53,7 @@ fn render_context_info(cost_tracker: &CostTracker) -> String {
         CostTracker::format_tokens(cost_tracker.output_tokens())
     ));
     output.push_str(separator);
-    output.push_str("\n");
+    output.push('\n');
     output.push_str(&format!(
         "  Total:   {:>10} tokens ({:.0}% of context window)\n",
         CostTracker::format_tokens(cost_tracker.total_tokens()),
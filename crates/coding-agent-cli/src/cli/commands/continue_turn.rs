@@ -0,0 +1,36 @@
+//! The /continue command - resumes a turn stopped early
+//!
+//! Covers both a max_tokens truncation and the tool iteration cap. The
+//! REPL intercepts `/continue` so it can re-enter the conversation loop;
+//! the registered command only provides the name and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct ContinueCommand;
+
+impl Command for ContinueCommand {
+    fn name(&self) -> &'static str {
+        "continue"
+    }
+
+    fn description(&self) -> &'static str {
+        "Resume a turn cut off by the token or tool iteration limit"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Resuming needs the live conversation; the REPL intercepts this
+        // command before it reaches the registry
+        CommandResult::Output("Continue is only available in an interactive session.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_continue_command_name() {
+        let cmd = ContinueCommand;
+        assert_eq!(cmd.name(), "continue");
+    }
+}
@@ -0,0 +1,38 @@
+//! The /debug command - toggles the session debug log
+//!
+//! The REPL intercepts `/debug` so it can flip the live log flag;
+//! the registered command only provides the name, usage, and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct DebugCommand;
+
+impl Command for DebugCommand {
+    fn name(&self) -> &'static str {
+        "debug"
+    }
+
+    fn description(&self) -> &'static str {
+        "Toggle the session debug log of API requests, responses, and tool calls (/debug on|off)"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // The debug log only exists in a live session; the REPL
+        // intercepts this command before it reaches the registry
+        CommandResult::Output(
+            "The debug log is only available in an interactive session.".to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_command_name() {
+        let cmd = DebugCommand;
+        assert_eq!(cmd.name(), "debug");
+        assert!(!cmd.description().is_empty());
+    }
+}
@@ -4,8 +4,9 @@
 //! similar to running `git diff` and `git diff --cached` together.
 
 use super::{CollapsedResults, Command, CommandContext, CommandResult};
-use crate::integrations::git::{GitError, GitRepo};
-use git2::{DiffOptions, Repository};
+use crate::integrations::git::{DiffFileStat, GitError, GitRepo};
+use crate::ui::theme::{Color, Theme};
+use std::path::Path;
 
 pub struct DiffCommand;
 
@@ -19,12 +20,13 @@ impl Command for DiffCommand {
     }
 
     fn usage(&self) -> &'static str {
-        "/diff [--staged] [--unstaged] [file...]"
+        "/diff [--staged] [--unstaged] [--stat] [file...]"
     }
 
     fn execute(&self, args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
         // Parse arguments
         let options = parse_diff_args(args);
+        let theme = Theme::default();
 
         // Open the repository
         let git_repo = match GitRepo::open_cwd() {
@@ -39,18 +41,6 @@ impl Command for DiffCommand {
             }
         };
 
-        let repo_root = match git_repo.root() {
-            Some(r) => r,
-            None => {
-                return CommandResult::Error("Could not determine repository root.".to_string())
-            }
-        };
-
-        let repo = match Repository::open(repo_root) {
-            Ok(r) => r,
-            Err(e) => return CommandResult::Error(format!("Failed to open repository: {}", e)),
-        };
-
         // Get status to determine if there are changes
         let status = match git_repo.status() {
             Ok(s) => s,
@@ -66,11 +56,11 @@ impl Command for DiffCommand {
 
         // Show staged changes if requested (or if showing both)
         if options.show_staged {
-            match get_staged_diff(&repo, &options.files) {
-                Ok(diff) if !diff.is_empty() => {
+            match render_section(&git_repo, true, &options, &theme) {
+                Ok(section) if !section.is_empty() => {
                     output.push_str("Staged changes (to be committed):\n");
                     output.push_str("────────────────────────────────────────────────\n");
-                    output.push_str(&diff);
+                    output.push_str(&section);
                     output.push('\n');
                 }
                 Ok(_) => {
@@ -86,14 +76,14 @@ impl Command for DiffCommand {
 
         // Show unstaged changes if requested (or if showing both)
         if options.show_unstaged {
-            match get_unstaged_diff(&repo, &options.files) {
-                Ok(diff) if !diff.is_empty() => {
+            match render_section(&git_repo, false, &options, &theme) {
+                Ok(section) if !section.is_empty() => {
                     if !output.is_empty() {
                         output.push('\n');
                     }
                     output.push_str("Unstaged changes (not staged for commit):\n");
                     output.push_str("────────────────────────────────────────────────\n");
-                    output.push_str(&diff);
+                    output.push_str(&section);
                 }
                 Ok(_) => {
                     if options.unstaged_only {
@@ -106,8 +96,9 @@ impl Command for DiffCommand {
             }
         }
 
-        // Show untracked files if present and showing all
-        if !options.staged_only && !options.unstaged_only {
+        // Show untracked files if present and showing all (skipped in stat
+        // mode, where only tracked changes have line counts)
+        if !options.staged_only && !options.unstaged_only && !options.stat_only {
             let untracked = status.untracked_files();
             if !untracked.is_empty() {
                 if !output.is_empty() {
@@ -136,6 +127,8 @@ struct DiffOptions2 {
     staged_only: bool,
     /// Show only unstaged changes
     unstaged_only: bool,
+    /// Show only per-file statistics, no patch text
+    stat_only: bool,
     /// Show staged changes (true if not filtering to unstaged only)
     show_staged: bool,
     /// Show unstaged changes (true if not filtering to staged only)
@@ -157,6 +150,9 @@ fn parse_diff_args(args: &[&str]) -> DiffOptions2 {
             "--unstaged" | "-u" => {
                 options.unstaged_only = true;
             }
+            "--stat" => {
+                options.stat_only = true;
+            }
             _ => {
                 // Treat as file path
                 files.push(arg.to_string());
@@ -182,115 +178,122 @@ fn parse_diff_args(args: &[&str]) -> DiffOptions2 {
     options
 }
 
-/// Get the diff of staged changes (changes between HEAD and index)
-fn get_staged_diff(repo: &Repository, files: &[String]) -> Result<String, String> {
-    let head = match repo.head() {
-        Ok(h) => match h.peel_to_tree() {
-            Ok(t) => Some(t),
-            Err(_) => None,
-        },
-        Err(_) => None, // No HEAD yet (initial commit)
-    };
-
-    let mut opts = DiffOptions::new();
-    opts.include_untracked(false);
-
-    // Add file filters if specified
-    for file in files {
-        opts.pathspec(file);
+/// Render one section (staged or unstaged) of the diff output
+///
+/// Returns the colored patch followed by a stats summary, or just the
+/// per-file stats when `--stat` was given. Empty string when the diff
+/// has no changes.
+fn render_section(
+    repo: &GitRepo,
+    staged: bool,
+    options: &DiffOptions2,
+    theme: &Theme,
+) -> Result<String, GitError> {
+    let stats = section_stats(repo, staged, &options.files)?;
+    if stats.is_empty() {
+        return Ok(String::new());
     }
 
-    let diff = repo
-        .diff_tree_to_index(head.as_ref(), None, Some(&mut opts))
-        .map_err(|e| format!("Failed to get staged diff: {}", e))?;
-
-    format_diff(&diff)
+    let mut output = String::new();
+    if !options.stat_only {
+        output.push_str(&colorize_patch(
+            &section_patch(repo, staged, &options.files)?,
+            theme,
+        ));
+        output.push('\n');
+    }
+    output.push_str(&format_stats(&stats, theme));
+    Ok(output)
 }
 
-/// Get the diff of unstaged changes (changes between index and working directory)
-fn get_unstaged_diff(repo: &Repository, files: &[String]) -> Result<String, String> {
-    let mut opts = DiffOptions::new();
-    opts.include_untracked(false);
+/// Get the patch text for one section, honoring any file filters
+fn section_patch(repo: &GitRepo, staged: bool, files: &[String]) -> Result<String, GitError> {
+    let diff_one = |path: Option<&Path>| {
+        if staged {
+            repo.diff_staged(path)
+        } else {
+            repo.diff_unstaged(path)
+        }
+    };
 
-    // Add file filters if specified
+    if files.is_empty() {
+        return diff_one(None);
+    }
+    let mut output = String::new();
     for file in files {
-        opts.pathspec(file);
+        output.push_str(&diff_one(Some(Path::new(file)))?);
     }
-
-    let diff = repo
-        .diff_index_to_workdir(None, Some(&mut opts))
-        .map_err(|e| format!("Failed to get unstaged diff: {}", e))?;
-
-    format_diff(&diff)
+    Ok(output)
 }
 
-/// Format a git2 Diff into a readable string
-fn format_diff(diff: &git2::Diff) -> Result<String, String> {
-    let mut output = String::new();
-    let stats = diff
-        .stats()
-        .map_err(|e| format!("Failed to get diff stats: {}", e))?;
+/// Get the per-file stats for one section, honoring any file filters
+fn section_stats(
+    repo: &GitRepo,
+    staged: bool,
+    files: &[String],
+) -> Result<Vec<DiffFileStat>, GitError> {
+    let stats_one = |path: Option<&Path>| {
+        if staged {
+            repo.diff_staged_stats(path)
+        } else {
+            repo.diff_unstaged_stats(path)
+        }
+    };
 
-    // If no changes, return empty
-    if stats.files_changed() == 0 {
-        return Ok(String::new());
+    if files.is_empty() {
+        return stats_one(None);
     }
+    let mut stats = Vec::new();
+    for file in files {
+        stats.extend(stats_one(Some(Path::new(file)))?);
+    }
+    Ok(stats)
+}
 
-    // Format each delta (file change)
-    diff.print(git2::DiffFormat::Patch, |delta, _hunk, line| {
-        // Get file path
-        if let Some(new_file) = delta.new_file().path() {
-            // Only print file header once per file
-            if line.origin() == 'F' {
-                // File header
-                let old_path = delta
-                    .old_file()
-                    .path()
-                    .map(|p| p.display().to_string())
-                    .unwrap_or_else(|| "/dev/null".to_string());
-                let new_path = new_file.display().to_string();
-
-                if old_path != new_path {
-                    output.push_str(&format!("diff {} -> {}\n", old_path, new_path));
-                } else {
-                    output.push_str(&format!("diff {}\n", new_path));
-                }
-            }
-        }
-
-        // Format the line based on its origin
-        let prefix = match line.origin() {
-            '+' => "+",
-            '-' => "-",
-            ' ' => " ",
-            'H' => "", // Hunk header
-            'F' => "", // File header (handled above)
-            'B' => "", // Binary
-            _ => "",
+/// Color a patch: additions green, deletions red, headers and hunks muted
+fn colorize_patch(patch: &str, theme: &Theme) -> String {
+    let mut output = String::new();
+    for line in patch.lines() {
+        let colored = if line.starts_with("diff ")
+            || line.starts_with("index ")
+            || line.starts_with("--- ")
+            || line.starts_with("+++ ")
+            || line.starts_with("@@")
+        {
+            theme.apply(Color::Muted, line)
+        } else if line.starts_with('+') {
+            theme.apply(Color::Success, line)
+        } else if line.starts_with('-') {
+            theme.apply(Color::Error, line)
+        } else {
+            line.to_string()
         };
+        output.push_str(&colored);
+        output.push('\n');
+    }
+    output
+}
 
-        if !prefix.is_empty() || line.origin() == 'H' {
-            let content = std::str::from_utf8(line.content()).unwrap_or("<binary>");
-            if line.origin() == 'H' {
-                output.push_str(&format!("{}", content));
-            } else {
-                output.push_str(&format!("{}{}", prefix, content));
-            }
-        }
-
-        true
-    })
-    .map_err(|e| format!("Failed to format diff: {}", e))?;
-
-    // Add summary
+/// Format per-file stats as "+added -removed path" lines with a total
+fn format_stats(stats: &[DiffFileStat], theme: &Theme) -> String {
+    let mut output = String::new();
+    for stat in stats {
+        output.push_str(&format!(
+            "  {} {} {}\n",
+            theme.apply(Color::Success, &format!("+{}", stat.added)),
+            theme.apply(Color::Error, &format!("-{}", stat.removed)),
+            stat.path.display()
+        ));
+    }
+    let added: usize = stats.iter().map(|s| s.added).sum();
+    let removed: usize = stats.iter().map(|s| s.removed).sum();
     output.push_str(&format!(
-        "\n{} file(s) changed, {} insertion(s), {} deletion(s)\n",
-        stats.files_changed(),
-        stats.insertions(),
-        stats.deletions()
+        "{} file(s) changed, {} insertion(s), {} deletion(s)\n",
+        stats.len(),
+        added,
+        removed
     ));
-
-    Ok(output)
+    output
 }
 
 #[cfg(test)]
@@ -298,8 +301,8 @@ mod tests {
     use super::*;
     use crate::cli::commands::CommandRegistry;
     use crate::tokens::CostTracker;
+    use git2::Repository;
     use std::fs;
-    use std::path::Path;
     use std::sync::{Arc, Mutex};
     use tempfile::TempDir;
 
@@ -410,6 +413,19 @@ mod tests {
         assert_eq!(options.files[0], "src/main.rs");
     }
 
+    #[test]
+    fn test_parse_diff_args_stat() {
+        let options = parse_diff_args(&["--stat"]);
+        assert!(options.stat_only);
+        assert!(options.show_staged);
+        assert!(options.show_unstaged);
+
+        // Combines with section filters
+        let options = parse_diff_args(&["--staged", "--stat"]);
+        assert!(options.stat_only);
+        assert!(options.staged_only);
+    }
+
     #[test]
     fn test_diff_shows_changes() {
         let (temp_dir, repo) = init_test_repo();
@@ -419,19 +435,28 @@ mod tests {
         let file_path = temp_dir.path().join("README.md");
         fs::write(&file_path, "# Test\n\nNew content here.\n").expect("Failed to write");
 
-        // Test getting unstaged diff directly
-        let diff = get_unstaged_diff(&repo, &[]).expect("Failed to get unstaged diff");
+        // Test rendering the unstaged section directly
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        Theme::force_no_color();
+        let theme = Theme::default();
+        let section = render_section(&git_repo, false, &DiffOptions2::default(), &theme)
+            .expect("Failed to render unstaged section");
 
-        // Should contain the new content
+        // Should contain the new content and a stats summary
         assert!(
-            diff.contains("New content"),
+            section.contains("New content"),
             "Expected diff to contain 'New content', got: {}",
-            diff
+            section
         );
         assert!(
-            diff.contains("+"),
+            section.contains("+"),
             "Expected diff to contain additions, got: {}",
-            diff
+            section
+        );
+        assert!(
+            section.contains("1 file(s) changed"),
+            "Expected a stats summary, got: {}",
+            section
         );
     }
 
@@ -450,19 +475,58 @@ mod tests {
             .expect("Failed to stage");
         index.write().expect("Failed to write index");
 
-        // Test getting staged diff directly
-        let diff = get_staged_diff(&repo, &[]).expect("Failed to get staged diff");
+        // Test rendering the staged section directly
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        Theme::force_no_color();
+        let theme = Theme::default();
+        let section = render_section(&git_repo, true, &DiffOptions2::default(), &theme)
+            .expect("Failed to render staged section");
 
         // Should contain the staged content
         assert!(
-            diff.contains("Staged content"),
+            section.contains("Staged content"),
             "Expected diff to contain 'Staged content', got: {}",
-            diff
+            section
         );
         assert!(
-            diff.contains("+"),
+            section.contains("+"),
             "Expected diff to contain additions, got: {}",
-            diff
+            section
+        );
+    }
+
+    #[test]
+    fn test_render_section_stat_only() {
+        let (temp_dir, repo) = init_test_repo();
+        create_initial_commit(&temp_dir, &repo);
+
+        // Modify and stage the file
+        let file_path = temp_dir.path().join("README.md");
+        fs::write(&file_path, "# Test\n\nStaged content.\n").expect("Failed to write");
+
+        let mut index = repo.index().expect("Failed to get index");
+        index
+            .add_path(Path::new("README.md"))
+            .expect("Failed to stage");
+        index.write().expect("Failed to write index");
+
+        // Render with --stat: stats only, no patch text
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        Theme::force_no_color();
+        let theme = Theme::default();
+        let options = parse_diff_args(&["--stat"]);
+        let section = render_section(&git_repo, true, &options, &theme)
+            .expect("Failed to render staged section");
+
+        assert!(
+            section.contains("+2 -0 README.md"),
+            "Expected per-file stats, got: {}",
+            section
+        );
+        assert!(
+            !section.contains("Staged content"),
+            "Expected no patch text with --stat, got: {}",
+            section
         );
     }
 
@@ -471,20 +535,26 @@ mod tests {
         let (temp_dir, repo) = init_test_repo();
         create_initial_commit(&temp_dir, &repo);
 
-        // Test getting diffs on a clean repo
-        let staged_diff = get_staged_diff(&repo, &[]).expect("Failed to get staged diff");
-        let unstaged_diff = get_unstaged_diff(&repo, &[]).expect("Failed to get unstaged diff");
+        // Test rendering sections on a clean repo
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        Theme::force_no_color();
+        let theme = Theme::default();
+        let options = DiffOptions2::default();
+        let staged =
+            render_section(&git_repo, true, &options, &theme).expect("Failed to render staged");
+        let unstaged =
+            render_section(&git_repo, false, &options, &theme).expect("Failed to render unstaged");
 
         // Both should be empty for a clean repo
         assert!(
-            staged_diff.is_empty(),
-            "Expected empty staged diff for clean repo, got: {}",
-            staged_diff
+            staged.is_empty(),
+            "Expected empty staged section for clean repo, got: {}",
+            staged
         );
         assert!(
-            unstaged_diff.is_empty(),
-            "Expected empty unstaged diff for clean repo, got: {}",
-            unstaged_diff
+            unstaged.is_empty(),
+            "Expected empty unstaged section for clean repo, got: {}",
+            unstaged
         );
     }
 
@@ -0,0 +1,117 @@
+//! The /diff-sessions command - compare two saved sessions
+
+use super::{Command, CommandContext, CommandResult};
+use crate::integrations::{Session, SessionDiff, SessionManager};
+use std::path::PathBuf;
+
+pub struct DiffSessionsCommand;
+
+impl Command for DiffSessionsCommand {
+    fn name(&self) -> &'static str {
+        "diff-sessions"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show what changed between two saved sessions"
+    }
+
+    fn execute(&self, args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        let [id_a, id_b] = args else {
+            return CommandResult::Error("Usage: /diff-sessions <id-a> <id-b>".to_string());
+        };
+
+        let base_dir = std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join(".specstory/history");
+        let manager = SessionManager::new(base_dir);
+
+        let a = match load_session(&manager, id_a) {
+            Ok(session) => session,
+            Err(e) => return CommandResult::Error(e),
+        };
+        let b = match load_session(&manager, id_b) {
+            Ok(session) => session,
+            Err(e) => return CommandResult::Error(e),
+        };
+
+        let diff = SessionDiff::compute(&a, &b);
+        CommandResult::Output(format!(
+            "--- {} ({} messages)\n+++ {} ({} messages)\n{}",
+            a.metadata.title,
+            a.message_count(),
+            b.metadata.title,
+            b.message_count(),
+            diff
+        ))
+    }
+}
+
+/// Load a session by filename or session id prefix
+///
+/// Tries the argument as an exact filename first, then falls back to
+/// matching a saved session whose filename starts with the given id
+/// (session ids prefix their filenames).
+fn load_session(manager: &SessionManager, id: &str) -> Result<Session, String> {
+    if let Ok(session) = manager.load(id) {
+        return Ok(session);
+    }
+
+    let infos = manager
+        .list_sessions()
+        .map_err(|e| format!("Failed to list saved sessions: {}", e))?;
+    let matches: Vec<_> = infos
+        .iter()
+        .filter(|info| info.filename.starts_with(id))
+        .collect();
+
+    match matches.as_slice() {
+        [info] => manager
+            .load(&info.filename)
+            .map_err(|e| format!("Failed to load session \"{}\": {}", id, e)),
+        [] => Err(format!(
+            "No saved session matches \"{}\". Use /history to list sessions.",
+            id
+        )),
+        _ => Err(format!(
+            "\"{}\" matches {} sessions; use a longer prefix or the full filename.",
+            id,
+            matches.len()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_diff_sessions_command_name() {
+        let cmd = DiffSessionsCommand;
+        assert_eq!(cmd.name(), "diff-sessions");
+        assert!(!cmd.description().is_empty());
+    }
+
+    #[test]
+    fn test_load_session_by_prefix() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let manager = SessionManager::new(temp_dir.path().join("history"));
+
+        let mut session = Session::new();
+        session.add_user_message("hello");
+        manager.save(&mut session).expect("Should save");
+        let id = session.id();
+
+        let loaded = load_session(&manager, &id).expect("Should load by prefix");
+        assert_eq!(loaded.messages.len(), 1);
+    }
+
+    #[test]
+    fn test_load_session_unknown_id() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let manager = SessionManager::new(temp_dir.path().join("history"));
+
+        let result = load_session(&manager, "2099-01-01");
+        assert!(result.unwrap_err().contains("No saved session matches"));
+    }
+}
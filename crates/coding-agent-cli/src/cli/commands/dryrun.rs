@@ -0,0 +1,36 @@
+//! The /dryrun command - toggles dry-run simulation of mutating tools
+//!
+//! The REPL intercepts `/dryrun` so it can flip the live executor flag;
+//! the registered command only provides the name, usage, and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct DryRunCommand;
+
+impl Command for DryRunCommand {
+    fn name(&self) -> &'static str {
+        "dryrun"
+    }
+
+    fn description(&self) -> &'static str {
+        "Toggle dry-run mode: mutating tools report what they would do without doing it (/dryrun on|off)"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Dry-run only makes sense with a live session; the REPL
+        // intercepts this command before it reaches the registry
+        CommandResult::Output("Dry-run is only available in an interactive session.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dryrun_command_name() {
+        let cmd = DryRunCommand;
+        assert_eq!(cmd.name(), "dryrun");
+        assert!(!cmd.description().is_empty());
+    }
+}
@@ -0,0 +1,40 @@
+//! The /export command - writes the session to a file
+//!
+//! Supports the markdown session format and a standalone HTML page. The
+//! REPL intercepts `/export` so it can render the live session; the
+//! registered command only provides the name and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct ExportCommand;
+
+impl Command for ExportCommand {
+    fn name(&self) -> &'static str {
+        "export"
+    }
+
+    fn description(&self) -> &'static str {
+        "Export the session as markdown or a standalone HTML page"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/export [--format <markdown|html|replay>] [path]"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Exporting needs the live session; the REPL intercepts this
+        // command before it reaches the registry
+        CommandResult::Output("Export is only available in an interactive session.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_command_name() {
+        let cmd = ExportCommand;
+        assert_eq!(cmd.name(), "export");
+    }
+}
@@ -0,0 +1,42 @@
+//! Files command - open the fuzzy file finder
+//!
+//! The REPL intercepts `/files` because the picked path seeds the live
+//! input buffer; the registered command only provides the name and
+//! help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+/// Command to pick a project file and insert it as an `@path` reference
+pub struct FilesCommand;
+
+impl Command for FilesCommand {
+    fn name(&self) -> &'static str {
+        "files"
+    }
+
+    fn description(&self) -> &'static str {
+        "Fuzzy-find a project file and insert it as an @path reference"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/files"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        CommandResult::Output(
+            "The file finder is only available in an interactive session.".to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_files_command_name() {
+        let cmd = FilesCommand;
+        assert_eq!(cmd.name(), "files");
+        assert!(cmd.description().contains("@path"));
+    }
+}
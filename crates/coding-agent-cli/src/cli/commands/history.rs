@@ -15,7 +15,11 @@ impl Command for HistoryCommand {
         "Browse conversation history (SpecStory)"
     }
 
-    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+    fn usage(&self) -> &'static str {
+        "/history [--tag <tag>]"
+    }
+
+    fn execute(&self, args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
         // Get session manager for the current working directory
         let base_dir = std::env::current_dir()
             .unwrap_or_else(|_| PathBuf::from("."))
@@ -23,16 +27,33 @@ impl Command for HistoryCommand {
 
         let manager = SessionManager::new(base_dir);
 
-        match manager.list_sessions() {
+        // `--tag <tag>` narrows the listing; `recent` is a built-in
+        // virtual tag for the last 7 days
+        let (listing, tag) = match args {
+            [] => (manager.list_sessions(), None),
+            ["--tag", tag] => (manager.list_by_tag(tag), Some(*tag)),
+            _ => {
+                return CommandResult::Error("Usage: /history [--tag <tag>]".to_string());
+            }
+        };
+
+        match listing {
             Ok(sessions) => {
                 if sessions.is_empty() {
-                    return CommandResult::Output(
-                        "No conversation history found.\nStart a new session and your conversations will be saved automatically."
+                    return CommandResult::Output(match tag {
+                        Some(tag) => format!(
+                            "No sessions tagged '{}'.\nTag the current session with /tag add <tag>.",
+                            tag
+                        ),
+                        None => "No conversation history found.\nStart a new session and your conversations will be saved automatically."
                             .to_string(),
-                    );
+                    });
                 }
 
-                let mut output = String::from("Conversation History\n");
+                let mut output = match tag {
+                    Some(tag) => format!("Conversation History (tag: {})\n", tag),
+                    None => String::from("Conversation History\n"),
+                };
                 output.push_str(&"─".repeat(50));
                 output.push('\n');
                 output.push('\n');
@@ -45,10 +66,16 @@ impl Command for HistoryCommand {
                         i + 1,
                         truncate_title(&session.title, 40)
                     ));
+                    let tags = if session.tags.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" [{}]", session.tags.join(", "))
+                    };
                     output.push_str(&format!(
-                        "      └─ {} messages, {}\n",
+                        "      └─ {} messages, {}{}\n",
                         session.message_count,
-                        session.time_ago()
+                        session.time_ago(),
+                        tags
                     ));
                     output.push('\n');
                 }
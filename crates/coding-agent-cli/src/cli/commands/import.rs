@@ -0,0 +1,129 @@
+//! The /import command - bring conversations from other tools into the history
+
+use super::{Command, CommandContext, CommandResult};
+use crate::integrations::SessionManager;
+use std::path::{Path, PathBuf};
+
+pub struct ImportCommand;
+
+impl Command for ImportCommand {
+    fn name(&self) -> &'static str {
+        "import"
+    }
+
+    fn description(&self) -> &'static str {
+        "Import sessions from a ChatGPT export or a Markdown export"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/import --format <chatgpt|markdown> <file>"
+    }
+
+    fn execute(&self, args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        let ["--format", format, file] = args else {
+            return CommandResult::Error(format!("Usage: {}", self.usage()));
+        };
+
+        let base_dir = std::env::current_dir()
+            .unwrap_or_else(|_| PathBuf::from("."))
+            .join(".specstory/history");
+        let manager = SessionManager::new(base_dir);
+
+        match *format {
+            "chatgpt" => import_chatgpt(&manager, Path::new(file)),
+            "markdown" => import_markdown(&manager, Path::new(file)),
+            other => CommandResult::Error(format!(
+                "Unknown format \"{}\". Supported formats: chatgpt, markdown",
+                other
+            )),
+        }
+    }
+}
+
+/// Convert a ChatGPT export and save each conversation as a session
+fn import_chatgpt(manager: &SessionManager, path: &Path) -> CommandResult {
+    let sessions = match manager.import_from_chatgpt(path) {
+        Ok(sessions) => sessions,
+        Err(e) => return CommandResult::Error(e.to_string()),
+    };
+    if sessions.is_empty() {
+        return CommandResult::Error(format!(
+            "No importable conversations found in {}",
+            path.display()
+        ));
+    }
+
+    let mut output = format!(
+        "Imported {} conversation{}:\n",
+        sessions.len(),
+        if sessions.len() == 1 { "" } else { "s" }
+    );
+    for mut session in sessions {
+        match manager.save(&mut session) {
+            Ok(saved) => output.push_str(&format!(
+                "  {} — {}\n",
+                saved.display(),
+                session.metadata.title
+            )),
+            Err(e) => return CommandResult::Error(format!("Failed to save session: {}", e)),
+        }
+    }
+    output.pop();
+    CommandResult::Output(output)
+}
+
+/// Re-import a Markdown export (the format /export and session saves use)
+fn import_markdown(manager: &SessionManager, path: &Path) -> CommandResult {
+    let mut session = match manager.load_from_path(path) {
+        Ok(session) => session,
+        Err(e) => return CommandResult::Error(e.to_string()),
+    };
+
+    // Drop the source path so the save lands in the history directory
+    // under a fresh filename instead of overwriting the export
+    session.file_path = None;
+    match manager.save(&mut session) {
+        Ok(saved) => CommandResult::Output(format!(
+            "Imported \"{}\" to {}",
+            session.metadata.title,
+            saved.display()
+        )),
+        Err(e) => CommandResult::Error(format!("Failed to save session: {}", e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::create_test_context;
+
+    #[test]
+    fn test_import_command_name_and_usage() {
+        let cmd = ImportCommand;
+        assert_eq!(cmd.name(), "import");
+        assert_eq!(cmd.usage(), "/import --format <chatgpt|markdown> <file>");
+    }
+
+    #[test]
+    fn test_import_requires_format_and_file() {
+        let cmd = ImportCommand;
+        let mut ctx = create_test_context();
+
+        let result = cmd.execute(&["session.json"], &mut ctx);
+
+        assert!(matches!(result, CommandResult::Error(_)));
+    }
+
+    #[test]
+    fn test_import_rejects_unknown_format() {
+        let cmd = ImportCommand;
+        let mut ctx = create_test_context();
+
+        let result = cmd.execute(&["--format", "slack", "export.json"], &mut ctx);
+
+        match result {
+            CommandResult::Error(message) => assert!(message.contains("slack")),
+            _ => panic!("Expected Error result"),
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! The /keys command - show the active keybindings
+
+use super::{Command, CommandContext, CommandResult};
+use crate::cli::keybindings::KeyMap;
+
+pub struct KeysCommand;
+
+impl Command for KeysCommand {
+    fn name(&self) -> &'static str {
+        "keys"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show the active keybindings"
+    }
+
+    fn execute(&self, _args: &[&str], ctx: &mut CommandContext) -> CommandResult {
+        match KeyMap::from_config(&ctx.config.keybindings) {
+            Ok(keymap) => CommandResult::Output(render_keymap(&keymap)),
+            Err(e) => CommandResult::Error(format!("Invalid [keybindings] config: {}", e)),
+        }
+    }
+}
+
+/// Render the configurable bindings plus the built-in keys
+fn render_keymap(keymap: &KeyMap) -> String {
+    let separator = "──────────────────────────────────────────────";
+
+    let mut output = String::new();
+    output.push_str("Keybindings\n");
+    output.push_str(separator);
+    output.push_str("\n\n");
+
+    output.push_str("Configurable (via [keybindings] in config.toml):\n");
+    for (action, chord) in keymap.entries() {
+        output.push_str(&format!(
+            "  {:<12} {:<14} {}\n",
+            chord.to_string(),
+            action.name(),
+            action.description()
+        ));
+    }
+
+    output.push_str("\nBuilt-in:\n");
+    output.push_str("  Ctrl+R       Reverse search through input history\n");
+    output.push_str("  Ctrl+D       Exit the application\n");
+    output.push_str("  Up/Down      Move between draft lines, then history\n");
+    output.push_str("  Left/Right   Move the cursor within the draft\n");
+    output.push_str("  Home/End     Jump to the start/end of the line\n");
+    output.push_str("  Tab          Insert indentation\n");
+
+    output.push('\n');
+    output.push_str(separator);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::create_test_context;
+
+    #[test]
+    fn test_keys_command_name() {
+        let cmd = KeysCommand;
+        assert_eq!(cmd.name(), "keys");
+        assert!(!cmd.description().is_empty());
+    }
+
+    #[test]
+    fn test_keys_command_lists_defaults() {
+        let cmd = KeysCommand;
+        let mut ctx = create_test_context();
+
+        let result = cmd.execute(&[], &mut ctx);
+
+        if let CommandResult::Output(output) = result {
+            assert!(output.contains("Ctrl+W"));
+            assert!(output.contains("delete-word"));
+            assert!(output.contains("Built-in:"));
+        } else {
+            panic!("Expected CommandResult::Output");
+        }
+    }
+
+    #[test]
+    fn test_keys_command_reflects_overrides() {
+        let cmd = KeysCommand;
+        let mut ctx = create_test_context();
+
+        let mut config = crate::config::Config::default();
+        config
+            .keybindings
+            .bindings
+            .insert("clear".to_string(), "ctrl+l".to_string());
+        ctx.config = std::sync::Arc::new(config);
+
+        let result = cmd.execute(&[], &mut ctx);
+
+        if let CommandResult::Output(output) = result {
+            assert!(output.contains("Ctrl+L"));
+        } else {
+            panic!("Expected CommandResult::Output");
+        }
+    }
+
+    #[test]
+    fn test_keys_command_reports_invalid_config() {
+        let cmd = KeysCommand;
+        let mut ctx = create_test_context();
+
+        let mut config = crate::config::Config::default();
+        config
+            .keybindings
+            .bindings
+            .insert("warp".to_string(), "ctrl+w".to_string());
+        ctx.config = std::sync::Arc::new(config);
+
+        let result = cmd.execute(&[], &mut ctx);
+
+        if let CommandResult::Error(error) = result {
+            assert!(error.contains("Unknown keybinding action"));
+        } else {
+            panic!("Expected CommandResult::Error");
+        }
+    }
+}
@@ -80,7 +80,10 @@ impl Command for LandCommand {
         } else {
             match create_commit() {
                 Ok(commit_msg) => {
-                    output.push_str(&format!("  {} Committed: \"{}\"\n\n", CHECKMARK, commit_msg));
+                    output.push_str(&format!(
+                        "  {} Committed: \"{}\"\n\n",
+                        CHECKMARK, commit_msg
+                    ));
                 }
                 Err(e) => {
                     output.push_str(&format!("  {} {}\n", CROSS, e));
@@ -351,7 +354,10 @@ mod tests {
         };
 
         // Should start with /land
-        assert!(output.starts_with("/land"), "Output should start with /land");
+        assert!(
+            output.starts_with("/land"),
+            "Output should start with /land"
+        );
 
         // Should contain step markers
         assert!(output.contains("[1/5]"), "Should have step 1 marker");
@@ -0,0 +1,35 @@
+//! The /metrics command - prints the session's Prometheus counters
+//!
+//! The REPL intercepts `/metrics` so it can render its live collector;
+//! the registered command only provides the name, usage, and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct MetricsCommand;
+
+impl Command for MetricsCommand {
+    fn name(&self) -> &'static str {
+        "metrics"
+    }
+
+    fn description(&self) -> &'static str {
+        "Print the session's metrics in Prometheus text format"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Metrics are collected by the live session; the REPL intercepts
+        // this command before it reaches the registry
+        CommandResult::Output("Metrics are only available in an interactive session.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_command_name() {
+        let cmd = MetricsCommand;
+        assert_eq!(cmd.name(), "metrics");
+    }
+}
@@ -3,29 +3,65 @@
 //! This module provides a command registry pattern for slash commands.
 //! Commands are registered by name and can be looked up and executed.
 
+mod auto;
 mod cancel;
+mod checkpoint;
+mod cherry_pick;
 mod clear;
 mod commit;
 pub mod config;
 mod context;
+mod continue_turn;
 mod cost;
+mod debug;
 mod diff;
+mod diff_sessions;
 mod document;
+mod dryrun;
 mod exit;
+mod export;
+mod files;
 mod help;
 mod history;
+mod import;
+mod keys;
 mod land;
-mod model;
+mod metrics;
+pub(crate) mod model;
+mod plan;
+mod profile;
+mod progress;
+mod rename;
 mod results;
+mod review;
+pub(crate) mod search;
 mod spec;
-mod status;
+mod stats;
+pub(crate) mod status;
+mod tag;
+mod telemetry;
+mod theme;
+mod todos;
+pub(crate) mod tools;
+mod trim;
 mod undo;
 
 use crate::cli::Mode;
 use crate::tokens::CostTracker;
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex};
 
+/// Process-wide override that makes every confirmation answer yes,
+/// set by the `--yes` CLI flag for scripted usage.
+static SKIP_CONFIRMATIONS: AtomicBool = AtomicBool::new(false);
+
+/// Skip confirmation prompts for the rest of the process (from `--yes`
+/// or `behavior.skip_confirmations`).
+pub fn set_skip_confirmations(skip: bool) {
+    SKIP_CONFIRMATIONS.store(skip, Ordering::Relaxed);
+}
+
 /// Result of executing a command
 #[derive(Debug, Clone, PartialEq)]
 pub enum CommandResult {
@@ -69,6 +105,35 @@ pub struct CollapsedResults {
     pub tool_name: String,
     /// Number of items that were collapsed
     pub count: usize,
+    /// Full text of tool results trimmed out of the conversation,
+    /// retrievable via `/results <n>` (1-based)
+    pub elided: Vec<ElidedResult>,
+}
+
+impl CollapsedResults {
+    /// Store a tool result elided from the conversation by trimming
+    ///
+    /// Returns the 1-based index the conversation placeholder points at
+    /// ("see /results <n>").
+    pub fn store_elided(&mut self, tool_name: &str, content: String) -> usize {
+        self.elided.push(ElidedResult {
+            tool_name: tool_name.to_string(),
+            lines: content.lines().count(),
+            content,
+        });
+        self.elided.len()
+    }
+}
+
+/// A full tool result that was trimmed out of the conversation
+#[derive(Debug, Clone)]
+pub struct ElidedResult {
+    /// The tool that produced the output
+    pub tool_name: String,
+    /// Number of lines in the original output
+    pub lines: usize,
+    /// The full original output
+    pub content: String,
 }
 
 /// Context available to commands during execution
@@ -85,6 +150,49 @@ pub struct CommandContext {
     pub collapsed_results: Arc<Mutex<CollapsedResults>>,
 }
 
+impl CommandContext {
+    /// Ask a yes/no question, defaulting to no.
+    pub fn confirm(&self, prompt: &str) -> Result<bool, String> {
+        self.confirm_with_default(prompt, false)
+    }
+
+    /// Ask a yes/no question with the given default answer.
+    ///
+    /// Reads a single keypress: `y`/`n` answer directly, Enter takes the
+    /// default. Returns `Ok(true)` without prompting when confirmations
+    /// are skipped (`behavior.skip_confirmations` or `--yes`).
+    pub fn confirm_with_default(&self, prompt: &str, default: bool) -> Result<bool, String> {
+        if self.config.behavior.skip_confirmations || SKIP_CONFIRMATIONS.load(Ordering::Relaxed) {
+            return Ok(true);
+        }
+
+        use crossterm::event::{self, Event, KeyCode, KeyEvent};
+        use std::io::Write;
+
+        let hint = if default { "[Y/n]" } else { "[y/N]" };
+        print!("\r\n{} {} ", prompt, hint);
+        let _ = std::io::stdout().flush();
+
+        let answer = loop {
+            match event::read() {
+                Ok(Event::Key(KeyEvent { code, .. })) => match code {
+                    KeyCode::Char('y') | KeyCode::Char('Y') => break true,
+                    KeyCode::Char('n') | KeyCode::Char('N') => break false,
+                    KeyCode::Enter => break default,
+                    KeyCode::Esc => break false,
+                    _ => continue,
+                },
+                Ok(_) => continue,
+                Err(e) => return Err(format!("Failed to read confirmation: {}", e)),
+            }
+        };
+
+        print!("\r\n");
+        let _ = std::io::stdout().flush();
+        Ok(answer)
+    }
+}
+
 /// Registry of available commands
 #[derive(Clone)]
 pub struct CommandRegistry {
@@ -103,22 +211,47 @@ impl CommandRegistry {
     pub fn with_defaults() -> Self {
         let mut registry = Self::new();
         registry.register(&help::HelpCommand);
+        registry.register(&auto::AutoCommand);
         registry.register(&cancel::CancelCommand);
+        registry.register(&checkpoint::CheckpointCommand);
+        registry.register(&cherry_pick::CherryPickCommand);
         registry.register(&clear::ClearCommand);
         registry.register(&commit::CommitCommand);
         registry.register(&config::ConfigCommand);
         registry.register(&context::ContextCommand);
+        registry.register(&continue_turn::ContinueCommand);
         registry.register(&cost::CostCommand);
+        registry.register(&debug::DebugCommand);
         registry.register(&diff::DiffCommand);
+        registry.register(&diff_sessions::DiffSessionsCommand);
         registry.register(&document::DocumentCommand);
+        registry.register(&dryrun::DryRunCommand);
         registry.register(&exit::ExitCommand);
         registry.register(&exit::QuitCommand);
         registry.register(&exit::QCommand);
+        registry.register(&export::ExportCommand);
+        registry.register(&files::FilesCommand);
         registry.register(&history::HistoryCommand);
+        registry.register(&import::ImportCommand);
+        registry.register(&keys::KeysCommand);
         registry.register(&land::LandCommand);
+        registry.register(&metrics::MetricsCommand);
         registry.register(&model::ModelCommand);
+        registry.register(&plan::PlanCommand);
+        registry.register(&profile::ProfileCommand);
+        registry.register(&progress::ProgressCommand);
+        registry.register(&rename::RenameCommand);
+        registry.register(&review::ReviewCommand);
+        registry.register(&search::SearchCommand);
         registry.register(&spec::SpecCommand);
+        registry.register(&stats::StatsCommand);
         registry.register(&status::StatusCommand);
+        registry.register(&tag::TagCommand);
+        registry.register(&telemetry::TelemetryCommand);
+        registry.register(&theme::ThemeCommand);
+        registry.register(&todos::TodosCommand);
+        registry.register(&tools::ToolsCommand);
+        registry.register(&trim::TrimCommand);
         registry.register(&undo::UndoCommand);
         registry.register(&results::ResultsCommand);
         registry
@@ -189,6 +322,28 @@ pub fn create_test_context() -> CommandContext {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_confirm_skipped_by_config() {
+        // Arrange: a context whose config opts out of confirmation prompts
+        let mut config = crate::config::Config::default();
+        config.behavior.skip_confirmations = true;
+        let ctx = CommandContext {
+            registry: CommandRegistry::with_defaults(),
+            cost_tracker: CostTracker::with_default_model(),
+            agent_manager: None,
+            config: Arc::new(config),
+            collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
+        };
+
+        // Act: both helpers should answer yes without touching the terminal
+        let plain = ctx.confirm("Are you sure?");
+        let defaulted = ctx.confirm_with_default("Are you sure?", false);
+
+        // Assert
+        assert_eq!(plain, Ok(true));
+        assert_eq!(defaulted, Ok(true));
+    }
+
     #[test]
     fn test_slash_command_parsing() {
         // Basic command
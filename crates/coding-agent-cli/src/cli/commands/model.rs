@@ -1,6 +1,14 @@
 //! The /model command - switch AI model
+//!
+//! The REPL intercepts `/model` so `set` can change the live model; this
+//! registered command handles standalone contexts and provides the shared
+//! rendering/validation helpers.
 
 use super::{CollapsedResults, Command, CommandContext, CommandResult};
+use crate::tokens::ModelPricing;
+
+/// The model used until `/model set` changes it
+pub(crate) const DEFAULT_MODEL: &str = "claude-sonnet-4-20250514";
 
 pub struct ModelCommand;
 
@@ -14,45 +22,150 @@ impl Command for ModelCommand {
     }
 
     fn usage(&self) -> &'static str {
-        "/model [name]"
+        "/model [list|info|set <name>]"
     }
 
     fn execute(&self, args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
-        if args.is_empty() {
-            // Show current model
-            CommandResult::Output(format_current_model())
-        } else {
-            // Switch to specified model
-            let model_name = args[0];
-
-            // Validate model
-            if !is_valid_model(model_name) {
-                let suggestions = suggest_models(model_name);
-                let mut error_msg = format!("Unknown model: {}", model_name);
-                if !suggestions.is_empty() {
-                    error_msg.push_str("\n\nDid you mean:");
-                    for suggestion in suggestions {
-                        error_msg.push_str(&format!("\n  - {}", suggestion));
-                    }
-                }
-                error_msg.push_str("\n\nAvailable models:");
-                for model in available_models() {
-                    error_msg.push_str(&format!("\n  - {}", model));
+        match args {
+            [] => CommandResult::Output(format_current_model(DEFAULT_MODEL)),
+            ["list"] => match std::env::var("ANTHROPIC_API_KEY") {
+                Ok(api_key) => match fetch_models(&api_key) {
+                    Ok(models) => CommandResult::Output(render_model_list(&models, DEFAULT_MODEL)),
+                    Err(e) => CommandResult::Error(e),
+                },
+                Err(_) => CommandResult::Error("ANTHROPIC_API_KEY not set.".to_string()),
+            },
+            ["info"] => CommandResult::Output(render_model_info(DEFAULT_MODEL)),
+            // `/model set <name>` and the legacy `/model <name>` both switch
+            ["set", model_name] | [model_name] => {
+                if !is_valid_model(model_name) {
+                    return CommandResult::Error(unknown_model_error(model_name));
                 }
-                return CommandResult::Error(error_msg);
+                CommandResult::Output(format!("Switched to model: {}\n\nNote: Model switching will take effect on the next message.", model_name))
             }
+            _ => CommandResult::Error("Usage: /model [list|info|set <name>]".to_string()),
+        }
+    }
+}
+
+/// Build the error message for an unrecognized model name
+pub(crate) fn unknown_model_error(model_name: &str) -> String {
+    let suggestions = suggest_models(model_name);
+    let mut error_msg = format!("Unknown model: {}", model_name);
+    if !suggestions.is_empty() {
+        error_msg.push_str("\n\nDid you mean:");
+        for suggestion in suggestions {
+            error_msg.push_str(&format!("\n  - {}", suggestion));
+        }
+    }
+    error_msg.push_str("\n\nAvailable models:");
+    for model in available_models() {
+        error_msg.push_str(&format!("\n  - {}", model));
+    }
+    error_msg
+}
+
+/// Fetch the available model IDs from the Anthropic API
+pub(crate) fn fetch_models(api_key: &str) -> Result<Vec<String>, String> {
+    let response = ureq::get(&format!("{}/v1/models", crate::config::api_base_url()))
+        .set("x-api-key", api_key)
+        .set("anthropic-version", "2023-06-01")
+        .call()
+        .map_err(|e| format!("Failed to fetch models: {}", e))?;
+
+    let body: serde_json::Value = response
+        .into_json()
+        .map_err(|e| format!("Failed to parse models response: {}", e))?;
+
+    let models = body["data"]
+        .as_array()
+        .map(|data| {
+            data.iter()
+                .filter_map(|m| m["id"].as_str().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(models)
+}
+
+/// Render the model list returned by the API
+pub(crate) fn render_model_list(models: &[String], current: &str) -> String {
+    let separator = "──────────────────────────────────────────────";
+
+    let mut output = String::new();
+    output.push_str("Available Models (from API)\n");
+    output.push_str(separator);
+    output.push_str("\n\n");
+
+    if models.is_empty() {
+        output.push_str("No models returned by the API.\n");
+    } else {
+        for model in models {
+            if model == current {
+                output.push_str(&format!("  • {} (current)\n", model));
+            } else {
+                output.push_str(&format!("  • {}\n", model));
+            }
+        }
+    }
+
+    output.push('\n');
+    output.push_str(separator);
+    output
+}
+
+/// Render context window, pricing, and capabilities for a model
+pub(crate) fn render_model_info(model: &str) -> String {
+    let separator = "──────────────────────────────────────────────";
+
+    let mut output = String::new();
+    output.push_str("Model Info\n");
+    output.push_str(separator);
+    output.push_str("\n\n");
+    output.push_str(&format!("Model: {}\n", model));
+    output.push_str(&format!(
+        "Context window: {} tokens\n",
+        format_context_window(model)
+    ));
 
-            // Return success with model switch request
-            // The REPL will need to handle this
-            CommandResult::Output(format!("Switched to model: {}\n\nNote: Model switching will take effect on the next message.", model_name))
+    match ModelPricing::from_name(model) {
+        Ok(pricing) => {
+            output.push_str(&format!(
+                "Pricing: ${:.2}/1M input, ${:.2}/1M output\n",
+                pricing.input_cost_per_million, pricing.output_cost_per_million
+            ));
         }
+        Err(_) => {
+            let pricing = ModelPricing::default_pricing();
+            output.push_str(&format!(
+                "Pricing: unknown, assuming ${:.2}/1M input, ${:.2}/1M output\n",
+                pricing.input_cost_per_million, pricing.output_cost_per_million
+            ));
+        }
+    }
+
+    output.push_str(&format!("Capabilities: {}\n", model_capabilities(model)));
+    output.push('\n');
+    output.push_str(separator);
+    output
+}
+
+/// Summarize a model family's capabilities
+fn model_capabilities(model: &str) -> &'static str {
+    if model.contains("haiku") {
+        "tool use, fastest responses, lowest cost"
+    } else if model.contains("opus") {
+        "tool use, vision, most capable reasoning"
+    } else if model.contains("sonnet") {
+        "tool use, vision, balanced speed and capability"
+    } else {
+        "tool use"
     }
 }
 
 /// Format the current model display
-fn format_current_model() -> String {
-    // For now, hardcoded - this will be made dynamic when we add model state to REPL
-    let model = "claude-sonnet-4-20250514";
+pub(crate) fn format_current_model(model: &str) -> String {
     let separator = "──────────────────────────────────────────────";
 
     let mut output = String::new();
@@ -72,7 +185,7 @@ fn format_current_model() -> String {
             output.push_str(&format!("  • {}\n", available_model));
         }
     }
-    output.push_str("\n");
+    output.push('\n');
     output.push_str(separator);
     output
 }
@@ -88,32 +201,31 @@ fn format_context_window(model: &str) -> String {
 fn format_number_with_commas(n: u32) -> String {
     let s = n.to_string();
     let mut result = String::new();
-    let mut count = 0;
 
-    for c in s.chars().rev() {
+    for (count, c) in s.chars().rev().enumerate() {
         if count > 0 && count % 3 == 0 {
             result.push(',');
         }
         result.push(c);
-        count += 1;
     }
 
     result.chars().rev().collect()
 }
 
 /// Get the context window size in tokens for a model
-fn get_context_window(model: &str) -> u32 {
+pub(crate) fn get_context_window(model: &str) -> u32 {
     match model {
         "claude-3-opus" | "claude-3-opus-20240229" => 200_000,
         "claude-3-sonnet" | "claude-3-sonnet-20240229" => 200_000,
         "claude-3-haiku" | "claude-3-haiku-20240307" => 200_000,
         "claude-sonnet-4-20250514" => 200_000,
+        "claude-opus-4" | "claude-opus-4-20250514" => 200_000,
         _ => 200_000, // Default
     }
 }
 
 /// Check if a model name is valid
-fn is_valid_model(model: &str) -> bool {
+pub(crate) fn is_valid_model(model: &str) -> bool {
     available_models().contains(&model)
 }
 
@@ -127,6 +239,8 @@ fn available_models() -> Vec<&'static str> {
         "claude-3-haiku",
         "claude-3-haiku-20240307",
         "claude-sonnet-4-20250514",
+        "claude-opus-4",
+        "claude-opus-4-20250514",
     ]
 }
 
@@ -145,11 +259,10 @@ fn suggest_models(input: &str) -> Vec<&'static str> {
     // If no substring matches, look for partial matches
     if suggestions.is_empty() {
         for model in available_models() {
-            if input_lower.contains("opus") && model.contains("opus") {
-                suggestions.push(model);
-            } else if input_lower.contains("sonnet") && model.contains("sonnet") {
-                suggestions.push(model);
-            } else if input_lower.contains("haiku") && model.contains("haiku") {
+            if ["opus", "sonnet", "haiku"]
+                .iter()
+                .any(|family| input_lower.contains(family) && model.contains(family))
+            {
                 suggestions.push(model);
             }
         }
@@ -180,7 +293,7 @@ mod tests {
     #[test]
     fn test_model_command_usage() {
         let cmd = ModelCommand;
-        assert_eq!(cmd.usage(), "/model [name]");
+        assert_eq!(cmd.usage(), "/model [list|info|set <name>]");
     }
 
     #[test]
@@ -309,4 +422,64 @@ mod tests {
         assert!(formatted.contains("200"));
         assert!(formatted.contains(","));
     }
+
+    #[test]
+    fn test_model_set_subcommand() {
+        let cmd = ModelCommand;
+        let mut ctx = crate::cli::commands::create_test_context();
+
+        let result = cmd.execute(&["set", "claude-opus-4"], &mut ctx);
+
+        if let CommandResult::Output(output) = result {
+            assert!(output.contains("claude-opus-4"));
+            assert!(output.contains("Switched"));
+        } else {
+            panic!("Expected CommandResult::Output");
+        }
+    }
+
+    #[test]
+    fn test_render_model_list_marks_current() {
+        let models = vec![
+            "claude-opus-4".to_string(),
+            "claude-sonnet-4-20250514".to_string(),
+        ];
+
+        let output = render_model_list(&models, "claude-sonnet-4-20250514");
+
+        assert!(output.contains("claude-opus-4"));
+        assert!(output.contains("claude-sonnet-4-20250514 (current)"));
+    }
+
+    #[test]
+    fn test_render_model_list_empty() {
+        let output = render_model_list(&[], DEFAULT_MODEL);
+        assert!(output.contains("No models returned"));
+    }
+
+    #[test]
+    fn test_render_model_info_known_pricing() {
+        let output = render_model_info("claude-3-opus");
+
+        assert!(output.contains("Model: claude-3-opus"));
+        assert!(output.contains("Context window: 200,000 tokens"));
+        assert!(output.contains("$15.00/1M input"));
+        assert!(output.contains("Capabilities:"));
+    }
+
+    #[test]
+    fn test_render_model_info_unknown_pricing() {
+        let output = render_model_info("claude-opus-4");
+
+        assert!(output.contains("Pricing: unknown"));
+        assert!(output.contains("most capable"));
+    }
+
+    #[test]
+    fn test_unknown_model_error_suggests() {
+        let error = unknown_model_error("opus");
+        assert!(error.contains("Unknown model: opus"));
+        assert!(error.contains("Did you mean"));
+        assert!(error.contains("Available models"));
+    }
 }
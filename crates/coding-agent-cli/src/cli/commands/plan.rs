@@ -0,0 +1,39 @@
+//! Plan command - show and manage the active plan
+//!
+//! The REPL intercepts `/plan` because the active plan lives in REPL
+//! state; the registered command only provides the name and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+/// Command to show the active plan and manage its lifecycle
+pub struct PlanCommand;
+
+impl Command for PlanCommand {
+    fn name(&self) -> &'static str {
+        "plan"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show the active plan, or approve/revise/abandon a pending one"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/plan [approve|revise <notes>|abandon]"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        CommandResult::Output("Plans are only available in an interactive session.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plan_command_name() {
+        let cmd = PlanCommand;
+        assert_eq!(cmd.name(), "plan");
+        assert!(cmd.usage().contains("approve"));
+    }
+}
@@ -0,0 +1,37 @@
+//! The /profile command - performance timings for the last turn
+//!
+//! The REPL intercepts `/profile` so it can render the live timing
+//! breakdown; the registered command only provides the name and help
+//! text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct ProfileCommand;
+
+impl Command for ProfileCommand {
+    fn name(&self) -> &'static str {
+        "profile"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show where the last turn's time went: API, tools, rendering"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Without a live REPL there is no profile to show; the REPL
+        // intercepts this command with its stored timings
+        CommandResult::Output("No turns profiled yet — send a message first.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_command_name() {
+        let cmd = ProfileCommand;
+        assert_eq!(cmd.name(), "profile");
+        assert!(!cmd.description().is_empty());
+    }
+}
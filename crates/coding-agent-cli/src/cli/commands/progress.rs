@@ -0,0 +1,136 @@
+//! The /progress command - renders the session's progress log as a checklist
+
+use super::{Command, CommandContext, CommandResult};
+use crate::tools::{ProgressEntry, ProgressFile};
+use std::collections::HashMap;
+
+pub struct ProgressCommand;
+
+impl Command for ProgressCommand {
+    fn name(&self) -> &'static str {
+        "progress"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show the current session's task progress log"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        let session_id = ProgressFile::current_session_id();
+
+        match ProgressFile::read(&session_id) {
+            Ok(entries) if entries.is_empty() => {
+                CommandResult::Output("No progress entries recorded for this session.".to_string())
+            }
+            Ok(entries) => CommandResult::Output(render_checklist(&entries)),
+            Err(e) => CommandResult::Error(format!("Failed to read progress log: {}", e)),
+        }
+    }
+}
+
+/// Render progress entries as a checklist, one line per task
+///
+/// The latest entry for each task determines its marker: `[x]` complete,
+/// `[-]` skipped, `[!]` errored, `[ ]` started but not finished. Tasks keep
+/// the order in which they first appeared.
+fn render_checklist(entries: &[ProgressEntry]) -> String {
+    let mut order: Vec<&str> = Vec::new();
+    let mut latest: HashMap<&str, &ProgressEntry> = HashMap::new();
+
+    for entry in entries {
+        if !latest.contains_key(entry.task_id.as_str()) {
+            order.push(&entry.task_id);
+        }
+        latest.insert(&entry.task_id, entry);
+    }
+
+    let mut out = String::from("Task Progress\n\n");
+    let mut completed = 0;
+
+    for task_id in &order {
+        let entry = latest[task_id];
+        let marker = match entry.action.as_str() {
+            "complete" => {
+                completed += 1;
+                "[x]"
+            }
+            "skip" => "[-]",
+            "error" => "[!]",
+            _ => "[ ]",
+        };
+        out.push_str(&format!("{} {} ({})\n", marker, entry.description, task_id));
+    }
+
+    out.push_str(&format!("\n{}/{} tasks complete", completed, order.len()));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(action: &str, task_id: &str, description: &str) -> ProgressEntry {
+        ProgressEntry::new(action, task_id, description, None)
+    }
+
+    #[test]
+    fn test_progress_command_name() {
+        let cmd = ProgressCommand;
+        assert_eq!(cmd.name(), "progress");
+    }
+
+    #[test]
+    fn test_progress_command_description() {
+        let cmd = ProgressCommand;
+        assert!(!cmd.description().is_empty());
+        assert!(cmd.description().contains("progress"));
+    }
+
+    #[test]
+    fn test_render_checklist_markers() {
+        let entries = vec![
+            entry("start", "task-1", "First task"),
+            entry("complete", "task-1", "First task"),
+            entry("start", "task-2", "Second task"),
+            entry("skip", "task-3", "Third task"),
+            entry("error", "task-4", "Fourth task"),
+        ];
+
+        let output = render_checklist(&entries);
+
+        assert!(output.contains("[x] First task (task-1)"));
+        assert!(output.contains("[ ] Second task (task-2)"));
+        assert!(output.contains("[-] Third task (task-3)"));
+        assert!(output.contains("[!] Fourth task (task-4)"));
+        assert!(output.contains("1/4 tasks complete"));
+    }
+
+    #[test]
+    fn test_render_checklist_latest_entry_wins() {
+        let entries = vec![
+            entry("start", "task-1", "A task"),
+            entry("error", "task-1", "A task"),
+            entry("complete", "task-1", "A task"),
+        ];
+
+        let output = render_checklist(&entries);
+
+        assert!(output.contains("[x] A task (task-1)"));
+        assert!(output.contains("1/1 tasks complete"));
+    }
+
+    #[test]
+    fn test_render_checklist_preserves_first_seen_order() {
+        let entries = vec![
+            entry("start", "beta", "Beta task"),
+            entry("start", "alpha", "Alpha task"),
+            entry("complete", "beta", "Beta task"),
+        ];
+
+        let output = render_checklist(&entries);
+        let beta_pos = output.find("Beta task").unwrap();
+        let alpha_pos = output.find("Alpha task").unwrap();
+
+        assert!(beta_pos < alpha_pos);
+    }
+}
@@ -14,12 +14,36 @@ impl Command for ResultsCommand {
     }
 
     fn usage(&self) -> &'static str {
-        "/results"
+        "/results [n]"
     }
 
-    fn execute(&self, _args: &[&str], ctx: &mut CommandContext) -> CommandResult {
+    fn execute(&self, args: &[&str], ctx: &mut CommandContext) -> CommandResult {
         let collapsed = ctx.collapsed_results.lock().unwrap();
 
+        // With an index, show a result that was trimmed out of the
+        // conversation ("[output elided: ..., see /results n]")
+        if let Some(arg) = args.first() {
+            let Ok(index) = arg.parse::<usize>() else {
+                return CommandResult::Error(format!("Invalid result number: {}", arg));
+            };
+            let Some(elided) = index.checked_sub(1).and_then(|i| collapsed.elided.get(i)) else {
+                return CommandResult::Error(format!(
+                    "No elided result {} ({} stored)",
+                    index,
+                    collapsed.elided.len()
+                ));
+            };
+
+            return CommandResult::Output(format!(
+                "Elided output {} from {} ({} {}):\n\n{}",
+                index,
+                elided.tool_name,
+                elided.lines,
+                if elided.lines == 1 { "line" } else { "lines" },
+                elided.content
+            ));
+        }
+
         if collapsed.content.is_none() {
             return CommandResult::Output(
                 "No collapsed results to show. Results are automatically collapsed when more than 5 items are returned.".to_string()
@@ -102,4 +126,49 @@ mod tests {
             _ => panic!("Expected Output result"),
         }
     }
+
+    #[test]
+    fn test_results_command_indexed_elided_result() {
+        let cmd = ResultsCommand;
+        let mut ctx = create_test_context();
+
+        {
+            let mut collapsed = ctx.collapsed_results.lock().unwrap();
+            let index = collapsed.store_elided("bash", "line one\nline two\n".to_string());
+            assert_eq!(index, 1);
+        }
+
+        let result = cmd.execute(&["1"], &mut ctx);
+        match result {
+            CommandResult::Output(msg) => {
+                assert!(msg.contains("Elided output 1 from bash"));
+                assert!(msg.contains("2 lines"));
+                assert!(msg.contains("line one\nline two"));
+            }
+            _ => panic!("Expected Output result"),
+        }
+    }
+
+    #[test]
+    fn test_results_command_index_out_of_range() {
+        let cmd = ResultsCommand;
+        let mut ctx = create_test_context();
+
+        let result = cmd.execute(&["7"], &mut ctx);
+        match result {
+            CommandResult::Error(msg) => {
+                assert!(msg.contains("No elided result 7"));
+            }
+            _ => panic!("Expected Error result"),
+        }
+    }
+
+    #[test]
+    fn test_results_command_invalid_index() {
+        let cmd = ResultsCommand;
+        let mut ctx = create_test_context();
+
+        let result = cmd.execute(&["abc"], &mut ctx);
+        assert!(matches!(result, CommandResult::Error(_)));
+    }
 }
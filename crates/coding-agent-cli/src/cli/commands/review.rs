@@ -0,0 +1,40 @@
+//! Review command - start a code review session
+//!
+//! The REPL intercepts `/review` because review mode and the collected
+//! findings live in REPL state; the registered command only provides
+//! the name and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+/// Command to review files and report the collected findings
+pub struct ReviewCommand;
+
+impl Command for ReviewCommand {
+    fn name(&self) -> &'static str {
+        "review"
+    }
+
+    fn description(&self) -> &'static str {
+        "Review files in review mode, or generate a findings report"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/review [<file...>|report]"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        CommandResult::Output("Reviews are only available in an interactive session.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_review_command_name() {
+        let cmd = ReviewCommand;
+        assert_eq!(cmd.name(), "review");
+        assert!(cmd.usage().contains("report"));
+    }
+}
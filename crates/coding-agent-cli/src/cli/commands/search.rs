@@ -0,0 +1,176 @@
+//! The /search command - finds text in session messages
+//!
+//! The REPL intercepts `/search` so it can scan the live session; the
+//! registered command only provides the name, usage, and help text.
+
+use super::{Command, CommandContext, CommandResult};
+use crate::cli::search as text_search;
+use crate::integrations::specstory::Session;
+use crate::integrations::SessionManager;
+
+/// Maximum excerpt width in the search output
+const EXCERPT_WIDTH: usize = 80;
+
+pub struct SearchCommand;
+
+impl Command for SearchCommand {
+    fn name(&self) -> &'static str {
+        "search"
+    }
+
+    fn description(&self) -> &'static str {
+        "Search session messages for a term (--all includes saved sessions)"
+    }
+
+    fn execute(&self, args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        if parse_args(args).is_none() {
+            return CommandResult::Error("Usage: /search [--all] <term>".to_string());
+        }
+        // Without a live session (e.g. standalone contexts) there is nothing
+        // to search; the REPL intercepts this command with its session
+        CommandResult::Output("No active session to search.".to_string())
+    }
+}
+
+/// Split `/search` arguments into (include_saved_sessions, term)
+pub fn parse_args(args: &[&str]) -> Option<(bool, String)> {
+    let (all, rest) = match args.first() {
+        Some(&"--all") => (true, &args[1..]),
+        _ => (false, args),
+    };
+    if rest.is_empty() {
+        return None;
+    }
+    Some((all, rest.join(" ")))
+}
+
+/// Render matches for `term` across the session's messages
+///
+/// Each message counts as one turn; excerpts show the matching line with the
+/// term highlighted.
+pub fn render_session_matches(session: &Session, term: &str) -> String {
+    let mut output = String::new();
+    let mut hits = 0;
+
+    for (i, message) in session.messages.iter().enumerate() {
+        if let Some(excerpt) = text_search::excerpt(&message.content, term, EXCERPT_WIDTH) {
+            hits += 1;
+            output.push_str(&format!(
+                "  Turn {} · {}: {}\n",
+                i + 1,
+                message.role,
+                text_search::highlight(&excerpt, term)
+            ));
+        }
+    }
+
+    if hits == 0 {
+        format!("No matches for \"{}\" in this session.", term)
+    } else {
+        format!(
+            "{} match{} for \"{}\":\n{}",
+            hits,
+            if hits == 1 { "" } else { "es" },
+            term,
+            output
+        )
+    }
+}
+
+/// Render matches for `term` across saved sessions
+pub fn render_saved_matches(manager: &SessionManager, term: &str) -> String {
+    let infos = match manager.list_sessions() {
+        Ok(infos) => infos,
+        Err(e) => return format!("Failed to list saved sessions: {}", e),
+    };
+
+    let mut output = String::new();
+    for info in infos {
+        let Ok(session) = manager.load(&info.filename) else {
+            continue;
+        };
+        let matches = render_session_matches(&session, term);
+        if !matches.starts_with("No matches") {
+            output.push_str(&format!(
+                "\n{} ({})\n{}",
+                info.title, info.filename, matches
+            ));
+        }
+    }
+
+    if output.is_empty() {
+        format!("No matches for \"{}\" in saved sessions.", term)
+    } else {
+        format!("Saved sessions:{}", output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse_args(&[]), None);
+        assert_eq!(parse_args(&["--all"]), None);
+        assert_eq!(
+            parse_args(&["lifetime", "error"]),
+            Some((false, "lifetime error".to_string()))
+        );
+        assert_eq!(
+            parse_args(&["--all", "lifetime"]),
+            Some((true, "lifetime".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_render_session_matches_with_turn_numbers() {
+        let mut session = Session::new();
+        session.add_user_message("explain the lifetime error");
+        session.add_agent_message("The lifetime error happens because...");
+        session.add_user_message("thanks");
+
+        let output = render_session_matches(&session, "lifetime");
+
+        assert!(output.starts_with("2 matches"));
+        assert!(output.contains("Turn 1 · User:"));
+        assert!(output.contains("Turn 2 · Agent:"));
+        assert!(!output.contains("Turn 3"));
+    }
+
+    #[test]
+    fn test_render_session_matches_none() {
+        let mut session = Session::new();
+        session.add_user_message("hello");
+
+        let output = render_session_matches(&session, "lifetime");
+
+        assert_eq!(output, "No matches for \"lifetime\" in this session.");
+    }
+
+    #[test]
+    fn test_render_saved_matches() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let manager = SessionManager::new(temp_dir.path().join("history"));
+
+        let mut session = Session::new();
+        session.add_user_message("where is the lifetime error");
+        manager.save(&mut session).expect("Should save");
+
+        let output = render_saved_matches(&manager, "lifetime");
+        assert!(output.starts_with("Saved sessions:"));
+        assert!(output.contains("Turn 1"));
+
+        let output = render_saved_matches(&manager, "zzz-no-such-term");
+        assert!(output.contains("No matches"));
+    }
+
+    #[test]
+    fn test_search_command_name() {
+        let cmd = SearchCommand;
+        assert_eq!(cmd.name(), "search");
+        assert!(!cmd.description().is_empty());
+    }
+}
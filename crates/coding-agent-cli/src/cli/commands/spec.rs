@@ -119,7 +119,7 @@ fn get_spec_path(name: &str) -> PathBuf {
 
 /// Create a template for a new spec file
 fn create_spec_template(name: &str) -> String {
-    let title = name.replace('-', " ").replace('_', " ");
+    let title = name.replace(['-', '_'], " ");
     let title = title
         .split_whitespace()
         .map(|word| {
@@ -0,0 +1,37 @@
+//! The /stats command - per-tool call statistics
+//!
+//! The REPL intercepts `/stats` so it can render the live stats
+//! collector; the registered command only provides the name and help
+//! text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct StatsCommand;
+
+impl Command for StatsCommand {
+    fn name(&self) -> &'static str {
+        "stats"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show per-tool call counts, error rates, and durations"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Without a live executor there are no stats to show; the REPL
+        // intercepts this command with its collector
+        CommandResult::Output("No tool calls recorded yet.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_command_name() {
+        let cmd = StatsCommand;
+        assert_eq!(cmd.name(), "stats");
+        assert!(!cmd.description().is_empty());
+    }
+}
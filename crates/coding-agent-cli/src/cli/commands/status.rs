@@ -1,6 +1,471 @@
-//! The /status command - shows active tasks and running agents
+//! The /status command - environment diagnostics and agent status
+//!
+//! Rendering is pure so it can be tested without a terminal, network, or
+//! git checkout; the caller (the REPL, or `execute` for the registry path)
+//! gathers the facts into a [`StatusReport`] first.
 
-use super::{CollapsedResults, Command, CommandContext, CommandResult};
+use super::{Command, CommandContext, CommandResult};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Git repository state for the diagnostics screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitState {
+    /// Current branch name
+    pub branch: String,
+    /// Whether the working tree has uncommitted changes
+    pub dirty: bool,
+    /// Commits ahead of the upstream branch (0 without an upstream)
+    pub ahead: usize,
+    /// Commits behind the upstream branch (0 without an upstream)
+    pub behind: usize,
+    /// Files with staged changes
+    pub staged: usize,
+    /// Files with unstaged changes (including untracked)
+    pub unstaged: usize,
+}
+
+/// Everything the diagnostics screen reports
+#[derive(Debug, Clone, Default)]
+pub struct StatusReport {
+    /// Model used for API calls
+    pub model: String,
+    /// Provider reachability: Err holds the transport error, None means
+    /// the check was skipped
+    pub provider: Option<Result<(), String>>,
+    /// Where the API key came from; None when no key was found
+    pub api_key_source: Option<String>,
+    /// None when the working directory is not a git repository
+    pub git: Option<GitState>,
+    /// Session file path and its size in bytes, once saved
+    pub session_file: Option<(PathBuf, u64)>,
+    /// Whether session persistence is enabled
+    pub persistence_enabled: bool,
+    /// Search backend in use ("ripgrep" or "built-in")
+    pub search_backend: String,
+    /// Permission mode: "prompt", "auto-accept", or "dry-run"
+    pub permission_mode: String,
+    /// Context usage line (e.g. "12,340 / 200,000 tokens (6%)")
+    pub context: String,
+    /// Session cost so far
+    pub cost: String,
+    /// Active config profile
+    pub profile: Option<String>,
+    /// Messages recorded in the current session
+    pub message_count: usize,
+    /// The API key, masked for display; None when no key was found
+    pub api_key_masked: Option<String>,
+    /// How long the last API call took, if a turn has run
+    pub last_api_latency_ms: Option<u64>,
+    /// Bytes used by saved sessions under `.specstory/history/`
+    pub history_disk_bytes: Option<u64>,
+    /// External tools the agent can use, with whether each is installed
+    pub external_tools: Vec<(String, bool)>,
+    /// Detected Cargo workspace members, as display lines like
+    /// `coding-agent-core (crates/coding-agent-core/)`; empty outside a
+    /// workspace
+    pub workspace_members: Vec<String>,
+    /// Detected language stacks ("rust", "typescript", …), in detection
+    /// order; the `project.type` override yields a single entry
+    pub project_types: Vec<String>,
+}
+
+/// Render the environment section of the diagnostics screen
+///
+/// Every line leads with ✓/✗ and failing lines carry a remediation hint.
+pub fn render_diagnostics(report: &StatusReport, verbose: bool) -> String {
+    let mut output = String::from("Environment:\n\n");
+
+    output.push_str(&format!("  ✓ Model: {}\n", report.model));
+
+    match &report.provider {
+        Some(Ok(())) => output.push_str("  ✓ Provider: reachable\n"),
+        Some(Err(e)) => output.push_str(&format!(
+            "  ✗ Provider: {} — check your network or ANTHROPIC_BASE_URL\n",
+            e
+        )),
+        None => output.push_str("  - Provider: not checked\n"),
+    }
+
+    match &report.api_key_source {
+        Some(source) => output.push_str(&format!("  ✓ API key: from {}\n", source)),
+        None => {
+            output.push_str("  ✗ API key: not found — run `code setup` or set ANTHROPIC_API_KEY\n")
+        }
+    }
+
+    match &report.git {
+        Some(git) => output.push_str(&format!(
+            "  ✓ Git: branch {} ({})\n",
+            git.branch,
+            if git.dirty { "dirty" } else { "clean" }
+        )),
+        None => {
+            output.push_str("  ✗ Git: not a repository — /commit and /diff need one\n");
+        }
+    }
+
+    if !report.project_types.is_empty() {
+        output.push_str(&format!("  ✓ Stack: {}\n", report.project_types.join(", ")));
+    }
+
+    match &report.session_file {
+        Some((path, size)) => output.push_str(&format!(
+            "  ✓ Session file: {} ({})\n",
+            path.display(),
+            format_size(*size)
+        )),
+        None if report.persistence_enabled => {
+            output.push_str("  ✗ Session file: not saved yet — saved after the first turn\n")
+        }
+        None => output.push_str(
+            "  ✗ Session file: persistence disabled — enable [persistence] in the config\n",
+        ),
+    }
+
+    if report.search_backend == "ripgrep" {
+        output.push_str("  ✓ Search backend: ripgrep\n");
+    } else {
+        output.push_str(&format!(
+            "  ✗ Search backend: {} — install ripgrep (rg) for faster searches\n",
+            report.search_backend
+        ));
+    }
+
+    output.push_str(&format!(
+        "  ✓ Permission mode: {}\n",
+        report.permission_mode
+    ));
+    output.push_str(&format!("  ✓ Context: {}\n", report.context));
+    output.push_str(&format!("  ✓ Cost: {}\n", report.cost));
+
+    if let Some(profile) = &report.profile {
+        output.push_str(&format!("  ✓ Profile: {}\n", profile));
+    }
+
+    if verbose {
+        output.push_str("\nVersions:\n\n");
+        output.push_str(&format!(
+            "  code v{} ({} {})\n",
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        ));
+        output.push_str(&format!(
+            "  API endpoint: {}\n",
+            crate::config::api_base_url()
+        ));
+    }
+
+    output
+}
+
+/// Render the agent section of the status screen
+pub(crate) fn render_agent_section(statuses: &[crate::agents::status::AgentStatus]) -> String {
+    let mut output = String::from("Agent Status:\n\n");
+
+    if statuses.is_empty() {
+        output.push_str("  No active agents.\n");
+    } else {
+        output.push_str(&format!("  Active agents: {}\n\n", statuses.len()));
+
+        for status in statuses {
+            let state_symbol = status.state.symbol();
+
+            output.push_str(&format!(
+                "  {} [{:?}] {} - {} ({}%)\n",
+                state_symbol, status.id, status.name, status.description, status.progress
+            ));
+        }
+
+        // Show instructions for cancellation
+        output.push_str("\nTo cancel an agent, use: /cancel <id>\n");
+    }
+
+    output
+}
+
+/// Inner width of the dashboard box, in dashes
+const DASHBOARD_WIDTH: usize = 46;
+
+/// Render the full status dashboard: git, session, agents, API, storage,
+/// and external tool availability as box-drawn sections
+pub fn render_dashboard(
+    report: &StatusReport,
+    agents: &[crate::agents::status::AgentStatus],
+) -> String {
+    let mut output = String::new();
+
+    let section = |output: &mut String, first: bool, title: &str| {
+        let lead = if first { '┌' } else { '├' };
+        let pad = DASHBOARD_WIDTH.saturating_sub(title.chars().count() + 3);
+        output.push_str(&format!("{}─ {} {}\n", lead, title, "─".repeat(pad)));
+    };
+    let line = |output: &mut String, text: &str| {
+        output.push_str(&format!("│ {}\n", text));
+    };
+
+    section(&mut output, true, "Git");
+    match &report.git {
+        Some(git) => {
+            line(
+                &mut output,
+                &format!(
+                    "branch {} · ahead {} · behind {}",
+                    git.branch, git.ahead, git.behind
+                ),
+            );
+            line(
+                &mut output,
+                &format!("staged {} · unstaged {}", git.staged, git.unstaged),
+            );
+        }
+        None => line(&mut output, "not a repository"),
+    }
+
+    if !report.workspace_members.is_empty() || !report.project_types.is_empty() {
+        section(&mut output, false, "Workspace");
+        if !report.project_types.is_empty() {
+            line(
+                &mut output,
+                &format!("stack {}", report.project_types.join(" · ")),
+            );
+        }
+        for member in &report.workspace_members {
+            line(&mut output, member);
+        }
+    }
+
+    section(&mut output, false, "Session");
+    line(
+        &mut output,
+        &format!(
+            "{} message(s) · {} · {}",
+            report.message_count, report.context, report.cost
+        ),
+    );
+
+    section(&mut output, false, "Agents");
+    if agents.is_empty() {
+        line(&mut output, "none active");
+    } else {
+        for status in agents {
+            line(
+                &mut output,
+                &format!(
+                    "{} {} - {} ({}%)",
+                    status.state.symbol(),
+                    status.name,
+                    status.description,
+                    status.progress
+                ),
+            );
+        }
+    }
+
+    section(&mut output, false, "API");
+    line(
+        &mut output,
+        &format!(
+            "key {} · model {}",
+            report.api_key_masked.as_deref().unwrap_or("not set"),
+            report.model
+        ),
+    );
+    match report.last_api_latency_ms {
+        Some(ms) => line(&mut output, &format!("last call {} ms", ms)),
+        None => line(&mut output, "no calls yet"),
+    }
+
+    section(&mut output, false, "Storage");
+    match report.history_disk_bytes {
+        Some(bytes) => line(
+            &mut output,
+            &format!(".specstory/history: {}", format_size(bytes)),
+        ),
+        None => line(&mut output, ".specstory/history: not created yet"),
+    }
+
+    section(&mut output, false, "Tools");
+    let tools: Vec<String> = report
+        .external_tools
+        .iter()
+        .map(|(name, installed)| format!("{} {}", name, if *installed { "✓" } else { "✗" }))
+        .collect();
+    line(&mut output, &tools.join(" · "));
+
+    output.push_str(&format!("└{}", "─".repeat(DASHBOARD_WIDTH)));
+    output
+}
+
+/// Render the same dashboard data as machine-readable JSON, for `--json`
+pub fn status_json(report: &StatusReport, agents: &[crate::agents::status::AgentStatus]) -> String {
+    let git = report.git.as_ref().map(|git| {
+        serde_json::json!({
+            "branch": git.branch,
+            "ahead": git.ahead,
+            "behind": git.behind,
+            "staged": git.staged,
+            "unstaged": git.unstaged,
+        })
+    });
+    let agents: Vec<serde_json::Value> = agents
+        .iter()
+        .map(|status| {
+            serde_json::json!({
+                "id": format!("{:?}", status.id),
+                "name": status.name,
+                "description": status.description,
+                "progress": status.progress,
+            })
+        })
+        .collect();
+    let tools: serde_json::Map<String, serde_json::Value> = report
+        .external_tools
+        .iter()
+        .map(|(name, installed)| (name.clone(), serde_json::Value::Bool(*installed)))
+        .collect();
+
+    let json = serde_json::json!({
+        "git": git,
+        "session": {
+            "messages": report.message_count,
+            "context": report.context,
+            "cost": report.cost,
+        },
+        "agents": agents,
+        "api": {
+            "key": report.api_key_masked,
+            "model": report.model,
+            "last_latency_ms": report.last_api_latency_ms,
+        },
+        "history_disk_bytes": report.history_disk_bytes,
+        "tools": tools,
+        "workspace_members": report.workspace_members,
+        "project_types": report.project_types,
+    });
+    serde_json::to_string_pretty(&json).unwrap_or_else(|_| "{}".to_string())
+}
+
+/// Mask an API key for display: enough to recognize it, not enough to leak
+pub(crate) fn mask_api_key(key: &str) -> String {
+    if key.len() > 14 {
+        format!("{}…{}", &key[..7], &key[key.len() - 4..])
+    } else {
+        "…".to_string()
+    }
+}
+
+/// External tools the agent benefits from, checked for the dashboard
+const EXTERNAL_TOOLS: &[&str] = &["rg", "ast-grep", "prettier"];
+
+/// Check which external tools are installed, by searching PATH
+pub(crate) fn detect_external_tools() -> Vec<(String, bool)> {
+    EXTERNAL_TOOLS
+        .iter()
+        .map(|name| (name.to_string(), tool_installed(name)))
+        .collect()
+}
+
+/// Whether an executable with this name exists on PATH
+fn tool_installed(name: &str) -> bool {
+    std::env::var_os("PATH")
+        .map(|paths| std::env::split_paths(&paths).any(|dir| dir.join(name).is_file()))
+        .unwrap_or(false)
+}
+
+/// Total size of the files directly under a directory (non-recursive),
+/// or None when it doesn't exist
+pub(crate) fn history_disk_usage(dir: &std::path::Path) -> Option<u64> {
+    let entries = std::fs::read_dir(dir).ok()?;
+    Some(
+        entries
+            .filter_map(|entry| entry.ok()?.metadata().ok())
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| metadata.len())
+            .sum(),
+    )
+}
+
+/// Check that the API endpoint answers at all (any HTTP status counts)
+pub(crate) fn check_provider() -> Result<(), String> {
+    let agent = ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(3))
+        .build();
+    match agent.head(&crate::config::api_base_url()).call() {
+        Ok(_) => Ok(()),
+        // An HTTP error still proves the endpoint is reachable
+        Err(ureq::Error::Status(_, _)) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Detect the git branch, dirty state, and change counts of the working
+/// directory
+pub(crate) fn detect_git_state() -> Option<GitState> {
+    let branch = std::process::Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())?;
+
+    let porcelain = std::process::Command::new("git")
+        .args(["status", "--porcelain"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .map(|out| String::from_utf8_lossy(&out.stdout).to_string())
+        .unwrap_or_default();
+
+    let mut staged = 0;
+    let mut unstaged = 0;
+    for line in porcelain.lines() {
+        let mut chars = line.chars();
+        let index_status = chars.next().unwrap_or(' ');
+        let worktree_status = chars.next().unwrap_or(' ');
+        if index_status != ' ' && index_status != '?' {
+            staged += 1;
+        }
+        if worktree_status != ' ' {
+            unstaged += 1;
+        }
+    }
+
+    // "A\tB" where A counts commits only on HEAD (ahead) and B commits
+    // only upstream (behind); fails without an upstream, which counts as 0/0
+    let (ahead, behind) = std::process::Command::new("git")
+        .args(["rev-list", "--left-right", "--count", "HEAD...@{upstream}"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| {
+            let text = String::from_utf8_lossy(&out.stdout).trim().to_string();
+            let (a, b) = text.split_once('\t')?;
+            Some((a.parse().ok()?, b.parse().ok()?))
+        })
+        .unwrap_or((0, 0));
+
+    Some(GitState {
+        branch,
+        dirty: !porcelain.is_empty(),
+        ahead,
+        behind,
+        staged,
+        unstaged,
+    })
+}
+
+/// Format a byte count for the session file line
+fn format_size(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
 
 pub struct StatusCommand;
 
@@ -10,37 +475,66 @@ impl Command for StatusCommand {
     }
 
     fn description(&self) -> &'static str {
-        "Show agent status, active tasks, running agents. Use /cancel <id> to cancel an agent."
+        "Show environment diagnostics, active agents, and costs (--verbose adds version info)"
     }
 
-    fn execute(&self, _args: &[&str], ctx: &mut CommandContext) -> CommandResult {
-        let mut output = String::from("Agent Status:\n\n");
-
-        // Check if agent manager is available
-        if let Some(agent_manager) = &ctx.agent_manager {
-            let statuses = agent_manager.get_all_statuses();
+    fn usage(&self) -> &'static str {
+        "/status [--verbose] [--json] [--watch]"
+    }
 
-            if statuses.is_empty() {
-                output.push_str("  No active agents.\n");
-            } else {
-                output.push_str(&format!("  Active agents: {}\n\n", statuses.len()));
+    fn execute(&self, args: &[&str], ctx: &mut CommandContext) -> CommandResult {
+        let verbose = args.contains(&"--verbose") || args.contains(&"-v");
 
-                for status in &statuses {
-                    let state_symbol = status.state.symbol();
+        let mut output = match &ctx.agent_manager {
+            Some(agent_manager) => render_agent_section(&agent_manager.get_all_statuses()),
+            None => "Agent Status:\n\n  Agent manager not available.\n".to_string(),
+        };
 
-                    output.push_str(&format!(
-                        "  {} [{:?}] {} - {} ({}%)\n",
-                        state_symbol, status.id, status.name, status.description, status.progress
-                    ));
-                }
+        // The registry path lacks live session state (file path, permission
+        // mode); the REPL intercepts /status to fill those in. Skip the
+        // network check here so scripted use stays fast.
+        let report = StatusReport {
+            model: ctx.cost_tracker.model_name().to_string(),
+            provider: None,
+            api_key_source: crate::cli::auth::key_source(),
+            git: detect_git_state(),
+            session_file: None,
+            persistence_enabled: ctx.config.persistence.enabled,
+            search_backend: crate::tools::code_search_backend().to_string(),
+            permission_mode: "prompt".to_string(),
+            context: format!(
+                "{} tokens",
+                crate::tokens::CostTracker::format_tokens(ctx.cost_tracker.total_tokens())
+            ),
+            cost: crate::tokens::CostTracker::format_cost(ctx.cost_tracker.total_cost()),
+            profile: ctx
+                .config
+                .active_profile
+                .clone()
+                .or_else(|| std::env::var("CODING_AGENT_PROFILE").ok()),
+            message_count: 0,
+            api_key_masked: std::env::var("ANTHROPIC_API_KEY")
+                .ok()
+                .map(|key| mask_api_key(&key)),
+            last_api_latency_ms: None,
+            history_disk_bytes: history_disk_usage(std::path::Path::new(".specstory/history")),
+            external_tools: detect_external_tools(),
+            workspace_members: crate::project::member_display_lines(),
+            project_types: crate::project::project_type_names(),
+        };
 
-                // Show instructions for cancellation
-                output.push_str("\nTo cancel an agent, use: /cancel <id>\n");
-            }
-        } else {
-            output.push_str("  Agent manager not available.\n");
+        if args.contains(&"--json") {
+            let agents = ctx
+                .agent_manager
+                .as_ref()
+                .map(|manager| manager.get_all_statuses())
+                .unwrap_or_default();
+            return CommandResult::Output(status_json(&report, &agents));
         }
 
+        output.push('\n');
+        output.push_str(&render_diagnostics(&report, verbose));
+
         CommandResult::Output(output)
     }
 }
@@ -48,14 +542,52 @@ impl Command for StatusCommand {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::cli::commands::CommandRegistry;
+    use crate::cli::commands::{CollapsedResults, CommandRegistry};
     use crate::tokens::CostTracker;
     use std::sync::{Arc, Mutex};
 
+    fn sample_report() -> StatusReport {
+        StatusReport {
+            model: "claude-3-opus".to_string(),
+            provider: Some(Ok(())),
+            api_key_source: Some("environment".to_string()),
+            git: Some(GitState {
+                branch: "main".to_string(),
+                dirty: true,
+                ahead: 1,
+                behind: 0,
+                staged: 2,
+                unstaged: 3,
+            }),
+            session_file: Some((PathBuf::from(".specstory/history/session.md"), 2048)),
+            persistence_enabled: true,
+            search_backend: "ripgrep".to_string(),
+            permission_mode: "prompt".to_string(),
+            context: "1,500 / 200,000 tokens (1%)".to_string(),
+            cost: "$0.042".to_string(),
+            profile: None,
+            message_count: 12,
+            api_key_masked: Some("sk-ant-…abcd".to_string()),
+            last_api_latency_ms: Some(820),
+            history_disk_bytes: Some(4096),
+            external_tools: vec![
+                ("rg".to_string(), true),
+                ("ast-grep".to_string(), false),
+                ("prettier".to_string(), false),
+            ],
+            workspace_members: vec![
+                "my-core (crates/my-core/)".to_string(),
+                "my-cli (crates/my-cli/)".to_string(),
+            ],
+            project_types: vec!["rust".to_string()],
+        }
+    }
+
     #[test]
     fn test_status_command_name() {
         let cmd = StatusCommand;
         assert_eq!(cmd.name(), "status");
+        assert_eq!(cmd.usage(), "/status [--verbose] [--json] [--watch]");
     }
 
     #[test]
@@ -64,6 +596,145 @@ mod tests {
         assert!(!cmd.description().is_empty());
     }
 
+    #[test]
+    fn test_render_diagnostics_healthy_environment() {
+        let report = sample_report();
+
+        let output = render_diagnostics(&report, false);
+
+        assert!(output.contains("✓ Model: claude-3-opus"));
+        assert!(output.contains("✓ Provider: reachable"));
+        assert!(output.contains("✓ API key: from environment"));
+        assert!(output.contains("✓ Git: branch main (dirty)"));
+        assert!(output.contains("✓ Session file: .specstory/history/session.md (2.0 KB)"));
+        assert!(output.contains("✓ Search backend: ripgrep"));
+        assert!(output.contains("✓ Permission mode: prompt"));
+        assert!(output.contains("✓ Cost: $0.042"));
+        // No verbose section unless requested
+        assert!(!output.contains("Versions:"));
+    }
+
+    #[test]
+    fn test_render_diagnostics_failures_carry_hints() {
+        let report = StatusReport {
+            provider: Some(Err("connection refused".to_string())),
+            api_key_source: None,
+            git: None,
+            session_file: None,
+            persistence_enabled: false,
+            search_backend: "built-in".to_string(),
+            ..sample_report()
+        };
+
+        let output = render_diagnostics(&report, false);
+
+        assert!(output.contains("✗ Provider: connection refused — check your network"));
+        assert!(output.contains("✗ API key: not found — run `code setup`"));
+        assert!(output.contains("✗ Git: not a repository"));
+        assert!(output.contains("✗ Session file: persistence disabled"));
+        assert!(output.contains("✗ Search backend: built-in — install ripgrep"));
+    }
+
+    #[test]
+    fn test_render_diagnostics_unsaved_session_with_persistence() {
+        let report = StatusReport {
+            session_file: None,
+            persistence_enabled: true,
+            ..sample_report()
+        };
+
+        let output = render_diagnostics(&report, false);
+
+        assert!(output.contains("✗ Session file: not saved yet"));
+    }
+
+    #[test]
+    fn test_render_diagnostics_verbose_adds_versions() {
+        let output = render_diagnostics(&sample_report(), true);
+
+        assert!(output.contains("Versions:"));
+        assert!(output.contains(&format!("code v{}", env!("CARGO_PKG_VERSION"))));
+        assert!(output.contains("API endpoint:"));
+    }
+
+    #[test]
+    fn test_render_diagnostics_shows_profile() {
+        let report = StatusReport {
+            profile: Some("work".to_string()),
+            ..sample_report()
+        };
+
+        let output = render_diagnostics(&report, false);
+
+        assert!(output.contains("✓ Profile: work"));
+    }
+
+    #[test]
+    fn test_render_dashboard_sections() {
+        let report = sample_report();
+
+        let output = render_dashboard(&report, &[]);
+
+        assert!(output.contains("┌─ Git"));
+        assert!(output.contains("branch main · ahead 1 · behind 0"));
+        assert!(output.contains("staged 2 · unstaged 3"));
+        assert!(output.contains("├─ Workspace"));
+        assert!(output.contains("my-core (crates/my-core/)"));
+        assert!(output.contains("├─ Session"));
+        assert!(output.contains("12 message(s) · 1,500 / 200,000 tokens (1%) · $0.042"));
+        assert!(output.contains("none active"));
+        assert!(output.contains("key sk-ant-…abcd · model claude-3-opus"));
+        assert!(output.contains("last call 820 ms"));
+        assert!(output.contains(".specstory/history: 4.0 KB"));
+        assert!(output.contains("rg ✓ · ast-grep ✗ · prettier ✗"));
+        assert!(output.contains("└─"));
+    }
+
+    #[test]
+    fn test_render_dashboard_outside_git_repo() {
+        let report = StatusReport {
+            git: None,
+            ..sample_report()
+        };
+
+        let output = render_dashboard(&report, &[]);
+
+        assert!(output.contains("not a repository"));
+    }
+
+    #[test]
+    fn test_status_json_round_trips() {
+        let report = sample_report();
+
+        let json = status_json(&report, &[]);
+        let parsed: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+
+        assert_eq!(parsed["git"]["branch"], "main");
+        assert_eq!(parsed["git"]["ahead"], 1);
+        assert_eq!(parsed["session"]["messages"], 12);
+        assert_eq!(parsed["api"]["key"], "sk-ant-…abcd");
+        assert_eq!(parsed["api"]["last_latency_ms"], 820);
+        assert_eq!(parsed["history_disk_bytes"], 4096);
+        assert_eq!(parsed["tools"]["rg"], true);
+        assert_eq!(parsed["tools"]["prettier"], false);
+        assert!(parsed["agents"].as_array().unwrap().is_empty());
+        assert_eq!(parsed["workspace_members"][0], "my-core (crates/my-core/)");
+    }
+
+    #[test]
+    fn test_mask_api_key_keeps_edges_only() {
+        assert_eq!(mask_api_key("sk-ant-api03-abcdefgh1234"), "sk-ant-…1234");
+        // Short keys are fully masked rather than leaking most of the key
+        assert_eq!(mask_api_key("shortkey"), "…");
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MB");
+    }
+
     #[test]
     fn test_status_shows_no_agents_when_none_active() {
         let cmd = StatusCommand;
@@ -86,6 +757,12 @@ mod tests {
                 "Status output should show no active agents: {}",
                 output
             );
+            // Should name the code_search backend in use
+            assert!(
+                output.contains("Search backend:"),
+                "Status output should show the search backend: {}",
+                output
+            );
         } else {
             panic!("Expected CommandResult::Output");
         }
@@ -117,6 +794,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_status_shows_active_profile() {
+        let cmd = StatusCommand;
+        let config = crate::config::Config {
+            active_profile: Some("work".to_string()),
+            ..Default::default()
+        };
+        let mut ctx = CommandContext {
+            registry: CommandRegistry::with_defaults(),
+            cost_tracker: CostTracker::with_default_model(),
+            agent_manager: None,
+            config: std::sync::Arc::new(config),
+            collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
+        };
+
+        let result = cmd.execute(&[], &mut ctx);
+
+        if let CommandResult::Output(output) = result {
+            assert!(
+                output.contains("Profile: work"),
+                "Status output should show the active profile: {}",
+                output
+            );
+        } else {
+            panic!("Expected CommandResult::Output");
+        }
+    }
+
     #[tokio::test]
     async fn test_status_shows_active_agents() {
         use crate::agents::manager::AgentManager;
@@ -0,0 +1,39 @@
+//! Tag command - tag the current session for filtered history browsing
+//!
+//! The REPL intercepts `/tag` because the tags live on the live session;
+//! the registered command only provides the name and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+/// Command to list, add, or remove tags on the current session
+pub struct TagCommand;
+
+impl Command for TagCommand {
+    fn name(&self) -> &'static str {
+        "tag"
+    }
+
+    fn description(&self) -> &'static str {
+        "Tag the current session, for /history --tag filtering"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/tag [add <tag>|rm <tag>]"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        CommandResult::Output("Tags are only available in an interactive session.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tag_command_name() {
+        let cmd = TagCommand;
+        assert_eq!(cmd.name(), "tag");
+        assert!(cmd.usage().contains("add"));
+    }
+}
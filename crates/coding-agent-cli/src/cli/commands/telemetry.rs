@@ -0,0 +1,135 @@
+//! The /telemetry command - shows locally recorded agent telemetry
+
+use super::{Command, CommandContext, CommandResult};
+use crate::agents::{aggregate_stats, load_telemetry, LocalTelemetrySink, PatternStats};
+
+pub struct TelemetryCommand;
+
+impl Command for TelemetryCommand {
+    fn name(&self) -> &'static str {
+        "telemetry"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show locally recorded telemetry (fix-stats: fix-agent outcomes)"
+    }
+
+    fn execute(&self, args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        match args.first() {
+            Some(&"fix-stats") => fix_stats(),
+            _ => CommandResult::Output("Usage: /telemetry fix-stats".to_string()),
+        }
+    }
+}
+
+/// Read the fix telemetry log and render success rates per pattern.
+fn fix_stats() -> CommandResult {
+    let Some(path) = LocalTelemetrySink::default_path() else {
+        return CommandResult::Error("No data directory available for telemetry".to_string());
+    };
+    if !path.exists() {
+        return CommandResult::Output("No fix telemetry recorded yet".to_string());
+    }
+
+    let records = match load_telemetry(&path) {
+        Ok(records) => records,
+        Err(e) => return CommandResult::Error(e),
+    };
+    if records.is_empty() {
+        return CommandResult::Output("No fix telemetry recorded yet".to_string());
+    }
+
+    CommandResult::Output(render_fix_stats(&aggregate_stats(&records)))
+}
+
+/// Render pattern stats as one block of text, most-recorded first.
+fn render_fix_stats(stats: &[PatternStats]) -> String {
+    let mut output = String::from("Fix-agent outcomes by error pattern:\n\n");
+    for entry in stats {
+        let total = entry.successes + entry.failures;
+        output.push_str(&format!(
+            "  {}\n    {} recorded: {} fixed, {} failed ({:.0}% success, {} attempts total)\n",
+            entry.error_pattern,
+            total,
+            entry.successes,
+            entry.failures,
+            entry.success_rate() * 100.0,
+            entry.total_attempts,
+        ));
+    }
+    output.push_str("\nThis data can guide heuristic tuning in categorize_deviation.");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::agents::{aggregate_stats, FixTelemetry};
+    use crate::agents::{DeviationCategory, FixStatus};
+    use crate::cli::commands::{CollapsedResults, CommandRegistry};
+    use crate::tokens::CostTracker;
+    use std::sync::{Arc, Mutex};
+
+    fn make_ctx() -> CommandContext {
+        CommandContext {
+            registry: CommandRegistry::with_defaults(),
+            cost_tracker: CostTracker::with_default_model(),
+            agent_manager: None,
+            config: Arc::new(crate::config::Config::default()),
+            collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
+        }
+    }
+
+    #[test]
+    fn test_telemetry_command_name() {
+        assert_eq!(TelemetryCommand.name(), "telemetry");
+    }
+
+    #[test]
+    fn test_telemetry_command_description() {
+        assert!(TelemetryCommand.description().contains("fix-stats"));
+    }
+
+    #[test]
+    fn test_telemetry_without_subcommand_shows_usage() {
+        let mut ctx = make_ctx();
+
+        let result = TelemetryCommand.execute(&[], &mut ctx);
+
+        if let CommandResult::Output(output) = result {
+            assert!(output.contains("Usage: /telemetry fix-stats"));
+        } else {
+            panic!("Expected CommandResult::Output");
+        }
+    }
+
+    #[test]
+    fn test_render_fix_stats_shows_rates() {
+        // Arrange
+        let records = vec![
+            FixTelemetry {
+                error_pattern: "cannot find crate _".to_string(),
+                category: DeviationCategory::Dependency,
+                outcome: FixStatus::Success,
+                attempts: 1,
+                fix_type: "missing_dependency".to_string(),
+            },
+            FixTelemetry {
+                error_pattern: "cannot find crate _".to_string(),
+                category: DeviationCategory::Dependency,
+                outcome: FixStatus::Failed,
+                attempts: 3,
+                fix_type: "missing_dependency".to_string(),
+            },
+        ];
+
+        // Act
+        let output = render_fix_stats(&aggregate_stats(&records));
+
+        // Assert
+        assert!(output.contains("cannot find crate _"));
+        assert!(output.contains("2 recorded: 1 fixed, 1 failed"));
+        assert!(output.contains("50% success"));
+        assert!(output.contains("4 attempts total"));
+    }
+}
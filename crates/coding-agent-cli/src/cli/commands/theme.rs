@@ -0,0 +1,170 @@
+//! The /theme command - list, preview, and switch UI theme styles
+
+use super::{Command, CommandContext, CommandResult};
+use crate::config::ThemeColorsConfig;
+use crate::ui::theme::{Color, Theme, ThemeStyle};
+
+pub struct ThemeCommand;
+
+/// Theme styles accepted by `/theme <style>`
+const STYLES: [&str; 3] = ["minimal", "colorful", "monochrome"];
+
+impl Command for ThemeCommand {
+    fn name(&self) -> &'static str {
+        "theme"
+    }
+
+    fn description(&self) -> &'static str {
+        "List theme styles, preview the palette, or switch styles"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/theme [style]"
+    }
+
+    fn execute(&self, args: &[&str], ctx: &mut CommandContext) -> CommandResult {
+        if args.is_empty() {
+            return CommandResult::Output(render_overview(
+                &ctx.config.theme.style,
+                &ctx.config.theme.colors,
+            ));
+        }
+
+        let style_name = args[0].to_lowercase();
+        if ThemeStyle::from_str(&style_name).is_none() {
+            return CommandResult::Error(format!(
+                "Unknown theme style: {}\n\nAvailable styles: {}",
+                args[0],
+                STYLES.join(", ")
+            ));
+        }
+
+        // Persist the choice so it survives restarts
+        let mut config = (*ctx.config).clone();
+        config.theme.style = style_name.clone();
+        match config.save() {
+            Ok(()) => CommandResult::Output(format!(
+                "Theme set to '{}' and saved to config.\n\nNote: The new palette takes effect on the next restart.",
+                style_name
+            )),
+            Err(e) => CommandResult::Error(format!("Failed to save theme to config: {}", e)),
+        }
+    }
+}
+
+/// Render the list of styles plus a palette preview for the current one
+fn render_overview(current_style: &str, colors: &ThemeColorsConfig) -> String {
+    let separator = "──────────────────────────────────────────────";
+
+    let mut output = String::new();
+    output.push_str("Theme Styles\n");
+    output.push_str(separator);
+    output.push_str("\n\n");
+
+    for style in STYLES {
+        if style == current_style {
+            output.push_str(&format!("  • {} (current)\n", style));
+        } else {
+            output.push_str(&format!("  • {}\n", style));
+        }
+    }
+
+    let style = ThemeStyle::from_str(current_style).unwrap_or(ThemeStyle::Minimal);
+    let (theme, _warnings) = Theme::with_overrides(style, &colors.entries());
+
+    output.push_str("\nPalette preview:\n");
+    output.push_str(&format!(
+        "  {}\n",
+        theme.apply(Color::UserInput, "> user input (prompt)")
+    ));
+    output.push_str(&format!(
+        "  {}\n",
+        theme.apply(Color::Agent, "Assistant response text (assistant)")
+    ));
+    output.push_str(&format!(
+        "  {}\n",
+        theme.apply(Color::Tool, "→ running read_file (tool)")
+    ));
+    output.push_str(&format!(
+        "  {}\n",
+        theme.apply(Color::Error, "✗ something failed (error)")
+    ));
+    output.push_str(&format!(
+        "  {}\n",
+        theme.apply(Color::Success, "✓ all done (success)")
+    ));
+    output.push_str(&format!(
+        "  {}\n",
+        theme.apply(Color::Muted, "secondary details (dim)")
+    ));
+
+    output.push_str(&format!(
+        "\nSwitch with /theme <style>. Override individual colors via the\n[theme.colors] table in the config file.\n\n{}",
+        separator
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::{CollapsedResults, CommandRegistry};
+    use crate::tokens::{CostTracker, ModelPricing};
+    use std::sync::{Arc, Mutex};
+
+    fn test_context() -> CommandContext {
+        CommandContext {
+            registry: CommandRegistry::with_defaults(),
+            cost_tracker: CostTracker::new(ModelPricing::CLAUDE_3_OPUS),
+            agent_manager: None,
+            config: Arc::new(crate::config::Config::default()),
+            collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
+        }
+    }
+
+    #[test]
+    fn test_theme_command_name() {
+        let cmd = ThemeCommand;
+        assert_eq!(cmd.name(), "theme");
+    }
+
+    #[test]
+    fn test_theme_command_lists_styles() {
+        let cmd = ThemeCommand;
+        let mut ctx = test_context();
+
+        let result = cmd.execute(&[], &mut ctx);
+
+        if let CommandResult::Output(output) = result {
+            assert!(output.contains("minimal (current)"));
+            assert!(output.contains("colorful"));
+            assert!(output.contains("monochrome"));
+            assert!(output.contains("Palette preview"));
+        } else {
+            panic!("Expected CommandResult::Output");
+        }
+    }
+
+    #[test]
+    fn test_theme_command_unknown_style() {
+        let cmd = ThemeCommand;
+        let mut ctx = test_context();
+
+        let result = cmd.execute(&["solarized"], &mut ctx);
+
+        if let CommandResult::Error(error) = result {
+            assert!(error.contains("Unknown theme style: solarized"));
+            assert!(error.contains("minimal, colorful, monochrome"));
+        } else {
+            panic!("Expected CommandResult::Error");
+        }
+    }
+
+    #[test]
+    fn test_render_overview_marks_current() {
+        let output = render_overview("colorful", &ThemeColorsConfig::default());
+
+        assert!(output.contains("colorful (current)"));
+        assert!(!output.contains("minimal (current)"));
+    }
+}
@@ -0,0 +1,39 @@
+//! Todos command - show the model-maintained todo list
+//!
+//! The REPL intercepts `/todos` because the todo list lives in REPL
+//! state; the registered command only provides the name and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+/// Command to show the todo list the model maintains via todo_write
+pub struct TodosCommand;
+
+impl Command for TodosCommand {
+    fn name(&self) -> &'static str {
+        "todos"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show the agent's todo list for the current task"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/todos"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        CommandResult::Output("Todos are only available in an interactive session.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_todos_command_name() {
+        let cmd = TodosCommand;
+        assert_eq!(cmd.name(), "todos");
+        assert_eq!(cmd.usage(), "/todos");
+    }
+}
@@ -0,0 +1,168 @@
+//! The /tools command - lists the tools available to Claude this session
+
+use super::{Command, CommandContext, CommandResult};
+use crate::tools::create_tool_definitions;
+use coding_agent_core::ToolDefinition;
+
+pub struct ToolsCommand;
+
+impl Command for ToolsCommand {
+    fn name(&self) -> &'static str {
+        "tools"
+    }
+
+    fn description(&self) -> &'static str {
+        "List the tools available to Claude, or enable/disable one for this session"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/tools [enable|disable <name>]"
+    }
+
+    fn execute(&self, _args: &[&str], ctx: &mut CommandContext) -> CommandResult {
+        // Apply the config-level allow/deny lists; the REPL overrides this
+        // with its actual (session-filtered) tool set when available
+        let mut definitions = create_tool_definitions();
+        if let Some(ref allowlist) = ctx.config.tools.allowlist {
+            definitions.retain(|def| allowlist.contains(&def.name));
+        }
+        definitions.retain(|def| !ctx.config.tools.denylist.contains(&def.name));
+
+        CommandResult::Output(render_tool_list(&definitions))
+    }
+}
+
+/// Render a list of tool definitions with their names and descriptions
+pub fn render_tool_list(definitions: &[ToolDefinition]) -> String {
+    render_tool_states(definitions, &[])
+}
+
+/// Render tool definitions with their enabled/disabled state
+///
+/// Tools named in `disabled` are marked; everything else is enabled.
+pub fn render_tool_states(definitions: &[ToolDefinition], disabled: &[String]) -> String {
+    let separator = "──────────────────────────────────────────────";
+
+    let mut output = String::new();
+    output.push_str("Available Tools\n");
+    output.push_str(separator);
+    output.push_str("\n\n");
+
+    if definitions.is_empty() {
+        output.push_str("No tools are available in this session.\n");
+    } else {
+        for def in definitions {
+            if disabled.contains(&def.name) {
+                output.push_str(&format!("  • {} (disabled)\n", def.name));
+            } else {
+                output.push_str(&format!("  • {}\n", def.name));
+            }
+            // First sentence of the description is enough for an overview
+            let summary = def.description.split(". ").next().unwrap_or("");
+            output.push_str(&format!("    {}\n", summary.trim_end_matches('.')));
+        }
+    }
+
+    if !disabled.is_empty() {
+        output.push_str("\nRe-enable a disabled tool with /tools enable <name>\n");
+    }
+
+    output.push('\n');
+    output.push_str(separator);
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::commands::{CollapsedResults, CommandRegistry};
+    use crate::config::Config;
+    use crate::tokens::{CostTracker, ModelPricing};
+    use std::sync::{Arc, Mutex};
+
+    fn test_context(config: Config) -> CommandContext {
+        CommandContext {
+            registry: CommandRegistry::with_defaults(),
+            cost_tracker: CostTracker::new(ModelPricing::CLAUDE_3_OPUS),
+            agent_manager: None,
+            config: Arc::new(config),
+            collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
+        }
+    }
+
+    #[test]
+    fn test_tools_command_name() {
+        let cmd = ToolsCommand;
+        assert_eq!(cmd.name(), "tools");
+    }
+
+    #[test]
+    fn test_tools_command_lists_all_by_default() {
+        let cmd = ToolsCommand;
+        let mut ctx = test_context(Config::default());
+
+        let result = cmd.execute(&[], &mut ctx);
+
+        if let CommandResult::Output(output) = result {
+            assert!(output.contains("• read_file"));
+            assert!(output.contains("• bash"));
+            assert!(output.contains("• code_search"));
+        } else {
+            panic!("Expected CommandResult::Output");
+        }
+    }
+
+    #[test]
+    fn test_tools_command_respects_denylist() {
+        let cmd = ToolsCommand;
+        let mut config = Config::default();
+        config.tools.denylist = vec!["bash".to_string()];
+        let mut ctx = test_context(config);
+
+        let result = cmd.execute(&[], &mut ctx);
+
+        if let CommandResult::Output(output) = result {
+            assert!(!output.contains("• bash"));
+            assert!(output.contains("• read_file"));
+        } else {
+            panic!("Expected CommandResult::Output");
+        }
+    }
+
+    #[test]
+    fn test_tools_command_respects_allowlist() {
+        let cmd = ToolsCommand;
+        let mut config = Config::default();
+        config.tools.allowlist = Some(vec!["read_file".to_string(), "list_files".to_string()]);
+        let mut ctx = test_context(config);
+
+        let result = cmd.execute(&[], &mut ctx);
+
+        if let CommandResult::Output(output) = result {
+            assert!(output.contains("• read_file"));
+            assert!(output.contains("• list_files"));
+            assert!(!output.contains("• bash"));
+            assert!(!output.contains("• write_file"));
+        } else {
+            panic!("Expected CommandResult::Output");
+        }
+    }
+
+    #[test]
+    fn test_render_tool_list_empty() {
+        let output = render_tool_list(&[]);
+        assert!(output.contains("No tools are available"));
+    }
+
+    #[test]
+    fn test_render_tool_states_marks_disabled() {
+        let definitions = create_tool_definitions();
+        let disabled = vec!["bash".to_string()];
+
+        let output = render_tool_states(&definitions, &disabled);
+
+        assert!(output.contains("• bash (disabled)"));
+        assert!(output.contains("• read_file\n"));
+        assert!(output.contains("/tools enable"));
+    }
+}
@@ -0,0 +1,41 @@
+//! The /trim command - frees context by trimming the conversation
+//!
+//! Lists suggestions (oldest messages, largest tool result, summarizable
+//! ranges) and applies one by number. The REPL intercepts `/trim` so it
+//! can mutate the live conversation; the registered command only provides
+//! the name and help text.
+
+use super::{Command, CommandContext, CommandResult};
+
+pub struct TrimCommand;
+
+impl Command for TrimCommand {
+    fn name(&self) -> &'static str {
+        "trim"
+    }
+
+    fn description(&self) -> &'static str {
+        "Free context space by trimming old or oversized conversation parts"
+    }
+
+    fn usage(&self) -> &'static str {
+        "/trim [<suggestion-id>]"
+    }
+
+    fn execute(&self, _args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+        // Trimming needs the live conversation; the REPL intercepts this
+        // command before it reaches the registry
+        CommandResult::Output("Trim is only available in an interactive session.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trim_command_name() {
+        let cmd = TrimCommand;
+        assert_eq!(cmd.name(), "trim");
+    }
+}
@@ -25,7 +25,7 @@ impl Command for UndoCommand {
         "/undo [--hard] [file...]"
     }
 
-    fn execute(&self, args: &[&str], _ctx: &mut CommandContext) -> CommandResult {
+    fn execute(&self, args: &[&str], ctx: &mut CommandContext) -> CommandResult {
         // Parse arguments
         let options = parse_undo_args(args);
 
@@ -54,6 +54,24 @@ impl Command for UndoCommand {
             Err(e) => return CommandResult::Error(format!("Failed to open repository: {}", e)),
         };
 
+        // Every undo path rewrites history or file contents; ask first
+        let prompt = if !options.files.is_empty() {
+            if options.hard {
+                "Revert the listed files to HEAD, discarding changes?"
+            } else {
+                "Unstage the listed files?"
+            }
+        } else if options.hard {
+            "Undo the last commit and discard its changes?"
+        } else {
+            "Undo the last commit (changes stay staged)?"
+        };
+        match ctx.confirm(prompt) {
+            Ok(true) => {}
+            Ok(false) => return CommandResult::Output("Undo cancelled.".to_string()),
+            Err(e) => return CommandResult::Error(e),
+        }
+
         // If specific files are provided, revert those files
         if !options.files.is_empty() {
             return revert_files(&repo, &options.files, options.hard);
@@ -326,6 +344,13 @@ mod tests {
     // Mutex to serialize tests that change directories
     static TEST_MUTEX: Mutex<()> = Mutex::new(());
 
+    // Confirmation prompts need a terminal, so tests run with them skipped
+    fn non_interactive_config() -> std::sync::Arc<crate::config::Config> {
+        let mut config = crate::config::Config::default();
+        config.behavior.skip_confirmations = true;
+        std::sync::Arc::new(config)
+    }
+
     fn init_test_repo() -> (TempDir, Repository) {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
         let repo = Repository::init(temp_dir.path()).expect("Failed to init repo");
@@ -459,7 +484,7 @@ mod tests {
                 registry,
                 cost_tracker,
                 agent_manager: None,
-                config: std::sync::Arc::new(crate::config::Config::default()),
+                config: non_interactive_config(),
                 collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
             };
 
@@ -539,7 +564,7 @@ mod tests {
                 registry,
                 cost_tracker,
                 agent_manager: None,
-                config: std::sync::Arc::new(crate::config::Config::default()),
+                config: non_interactive_config(),
                 collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
             };
 
@@ -599,7 +624,7 @@ mod tests {
                 registry,
                 cost_tracker,
                 agent_manager: None,
-                config: std::sync::Arc::new(crate::config::Config::default()),
+                config: non_interactive_config(),
                 collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
             };
 
@@ -651,7 +676,7 @@ mod tests {
                 registry,
                 cost_tracker,
                 agent_manager: None,
-                config: std::sync::Arc::new(crate::config::Config::default()),
+                config: non_interactive_config(),
                 collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
             };
 
@@ -701,7 +726,7 @@ mod tests {
                 registry,
                 cost_tracker,
                 agent_manager: None,
-                config: std::sync::Arc::new(crate::config::Config::default()),
+                config: non_interactive_config(),
                 collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
             };
 
@@ -764,7 +789,7 @@ mod tests {
                 registry,
                 cost_tracker,
                 agent_manager: None,
-                config: std::sync::Arc::new(crate::config::Config::default()),
+                config: non_interactive_config(),
                 collapsed_results: Arc::new(Mutex::new(CollapsedResults::default())),
             };
 
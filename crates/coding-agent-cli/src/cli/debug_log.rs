@@ -0,0 +1,178 @@
+//! Session debug log
+//!
+//! Appends every API request, response, tool execution, and error as one
+//! timestamped JSON record per line, so bug reports can include a full
+//! trace of a session. Unlike `--verbose`, which interleaves with the UI
+//! on stderr, the log goes to its own file (`.specstory/debug/<session>
+//! .jsonl` unless `--debug-log` picks another path) and stays readable
+//! after the fact. Secrets registered via [`DebugLog::scrub`] are
+//! replaced before anything reaches disk, and `/debug on|off` toggles
+//! writing mid-session.
+
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Appends timestamped JSONL records of a session's API traffic
+pub struct DebugLog {
+    path: PathBuf,
+    /// Strings replaced with `[REDACTED]` in every record (the API key)
+    secrets: Vec<String>,
+    enabled: bool,
+}
+
+impl DebugLog {
+    /// Create a log that appends to the given file
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            secrets: Vec::new(),
+            enabled: true,
+        }
+    }
+
+    /// The default per-session path under the SpecStory directory
+    pub fn default_path(session_id: &str) -> PathBuf {
+        PathBuf::from(format!(".specstory/debug/{}.jsonl", session_id))
+    }
+
+    /// Register a secret to scrub from every record before it is written
+    pub fn scrub(&mut self, secret: &str) {
+        if !secret.is_empty() {
+            self.secrets.push(secret.to_string());
+        }
+    }
+
+    /// The file records are appended to
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Point the log at a different file (e.g. after a session change)
+    pub fn retarget(&mut self, path: PathBuf) {
+        self.path = path;
+    }
+
+    /// Whether records are currently being written
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Turn writing on or off (the `/debug` command)
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Append one record with the given kind and payload
+    ///
+    /// Records look like `{"timestamp": ..., "kind": "request", "payload":
+    /// {...}}` with registered secrets scrubbed from the serialized line.
+    /// Write failures are logged and never fail the caller.
+    pub fn record(&self, kind: &str, payload: Value) {
+        if !self.enabled {
+            return;
+        }
+
+        let record = serde_json::json!({
+            "timestamp": crate::integrations::specstory::chrono_now(),
+            "kind": kind,
+            "payload": payload,
+        });
+
+        let mut line = record.to_string();
+        for secret in &self.secrets {
+            line = line.replace(secret.as_str(), "[REDACTED]");
+        }
+        line.push('\n');
+
+        let written = self
+            .path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|()| {
+                std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&self.path)
+            })
+            .and_then(|mut file| std::io::Write::write_all(&mut file, line.as_bytes()));
+        if let Err(e) = written {
+            tracing::warn!(path = %self.path.display(), error = %e, "Failed to write debug log");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_record_appends_timestamped_records() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("debug.jsonl");
+        let log = DebugLog::new(path.clone());
+
+        log.record("request", serde_json::json!({"model": "claude"}));
+        log.record("error", serde_json::json!({"message": "boom"}));
+
+        let content = std::fs::read_to_string(&path).expect("Should read log");
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let first: Value = serde_json::from_str(lines[0]).expect("Valid JSON");
+        assert_eq!(first["kind"], "request");
+        assert_eq!(first["payload"]["model"], "claude");
+        assert!(first["timestamp"].is_string());
+        let second: Value = serde_json::from_str(lines[1]).expect("Valid JSON");
+        assert_eq!(second["kind"], "error");
+    }
+
+    #[test]
+    fn test_record_scrubs_secrets() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("debug.jsonl");
+        let mut log = DebugLog::new(path.clone());
+        log.scrub("sk-ant-secret123");
+
+        log.record(
+            "tool_execution",
+            serde_json::json!({"output": "key is sk-ant-secret123 here"}),
+        );
+
+        let content = std::fs::read_to_string(&path).expect("Should read log");
+        assert!(!content.contains("sk-ant-secret123"));
+        assert!(content.contains("[REDACTED]"));
+    }
+
+    #[test]
+    fn test_record_creates_parent_directory() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("nested/dir/debug.jsonl");
+        let log = DebugLog::new(path.clone());
+
+        log.record("response", serde_json::json!({}));
+
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn test_disabled_log_writes_nothing() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("debug.jsonl");
+        let mut log = DebugLog::new(path.clone());
+        log.set_enabled(false);
+
+        log.record("request", serde_json::json!({}));
+
+        assert!(!path.exists());
+        log.set_enabled(true);
+        log.record("request", serde_json::json!({}));
+        assert!(path.exists());
+    }
+
+    #[test]
+    fn test_default_path_uses_session_id() {
+        let path = DebugLog::default_path("2026-01-01-abc");
+        assert_eq!(path, PathBuf::from(".specstory/debug/2026-01-01-abc.jsonl"));
+    }
+}
@@ -0,0 +1,266 @@
+//! Per-turn diff-stat summary
+//!
+//! Tracks the files a turn modified through write/edit tool calls and
+//! renders a git-style "3 files changed, +120 −14" line after the turn,
+//! shown in the success color under the context bar. Files that turn up
+//! in the git status delta without a matching write/edit call (a
+//! `sed -i` run through bash, a formatter) are flagged as external
+//! changes. `/diff` shows the detail.
+
+use crate::integrations::GitRepo;
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+
+/// Line counts for the files a turn changed
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub(crate) struct DiffStat {
+    /// Files changed through write/edit tool calls
+    pub files: usize,
+    /// Lines added across those files
+    pub added: usize,
+    /// Lines removed across those files
+    pub removed: usize,
+    /// Repo-relative paths dirtied without a write/edit call
+    pub external: Vec<String>,
+}
+
+impl DiffStat {
+    /// Render the one-line summary, or None when the turn touched nothing
+    pub fn render(&self) -> Option<String> {
+        let mut parts = Vec::new();
+        if self.files > 0 {
+            let noun = if self.files == 1 { "file" } else { "files" };
+            parts.push(format!(
+                "{} {} changed, +{} −{}",
+                self.files, noun, self.added, self.removed
+            ));
+        }
+        if !self.external.is_empty() {
+            let noun = if self.external.len() == 1 {
+                "file"
+            } else {
+                "files"
+            };
+            parts.push(format!(
+                "external changes in {} {}",
+                self.external.len(),
+                noun
+            ));
+        }
+        if parts.is_empty() {
+            None
+        } else {
+            Some(format!("{} (see /diff)", parts.join(" · ")))
+        }
+    }
+}
+
+/// Tracks what one conversation turn did to the working tree
+#[derive(Default)]
+pub(crate) struct TurnDiffTracker {
+    /// Each file's content before its first write/edit of the turn
+    /// (None = the file did not exist yet)
+    before: HashMap<PathBuf, Option<String>>,
+    /// Dirty repo-relative paths when the turn started; None outside a
+    /// git repo
+    baseline: Option<BTreeSet<String>>,
+}
+
+impl TurnDiffTracker {
+    /// Start tracking a turn, snapshotting the repo's dirty set
+    pub fn begin_turn(&mut self) {
+        self.before.clear();
+        self.baseline = dirty_paths();
+    }
+
+    /// Record a file's content ahead of a write/edit tool call
+    ///
+    /// Only the first record per path counts: the summary compares
+    /// against the state at the start of the turn, not the last edit.
+    pub fn record_before(&mut self, path: PathBuf, content: Option<String>) {
+        self.before.entry(path).or_insert(content);
+    }
+
+    /// Compare the tracked files (and the git status delta) against the
+    /// turn start and summarize
+    pub fn finish(&mut self) -> DiffStat {
+        let recorded: Vec<PathBuf> = self.before.keys().cloned().collect();
+
+        let mut stat = DiffStat::default();
+        for (path, before) in self.before.drain() {
+            let after = std::fs::read_to_string(&path).ok();
+            if before == after {
+                continue;
+            }
+            let (added, removed) = count_changed_lines(
+                before.as_deref().unwrap_or(""),
+                after.as_deref().unwrap_or(""),
+            );
+            stat.files += 1;
+            stat.added += added;
+            stat.removed += removed;
+        }
+
+        if let (Some(baseline), Some(now)) = (self.baseline.take(), dirty_paths()) {
+            stat.external = external_paths(&baseline, &now, &recorded);
+        }
+
+        stat
+    }
+}
+
+/// Dirty repo-relative paths, or None outside a git repo
+fn dirty_paths() -> Option<BTreeSet<String>> {
+    let repo = GitRepo::open_cwd().ok()?;
+    let status = repo.status().ok()?;
+    Some(
+        status
+            .files
+            .iter()
+            .map(|f| f.path.display().to_string())
+            .collect(),
+    )
+}
+
+/// Count lines added and removed between two versions of a file
+fn count_changed_lines(before: &str, after: &str) -> (usize, usize) {
+    let diff = similar::TextDiff::from_lines(before, after);
+    let mut added = 0;
+    let mut removed = 0;
+    for change in diff.iter_all_changes() {
+        match change.tag() {
+            similar::ChangeTag::Insert => added += 1,
+            similar::ChangeTag::Delete => removed += 1,
+            similar::ChangeTag::Equal => {}
+        }
+    }
+    (added, removed)
+}
+
+/// Paths dirty now but not at turn start and not accounted for by a
+/// write/edit call — i.e. modified behind the executor's back
+///
+/// Recorded paths may be absolute or cwd-relative while git reports
+/// repo-relative ones, so matching is by path suffix.
+fn external_paths(
+    baseline: &BTreeSet<String>,
+    now: &BTreeSet<String>,
+    recorded: &[PathBuf],
+) -> Vec<String> {
+    now.difference(baseline)
+        .filter(|path| {
+            !recorded.iter().any(|r| {
+                let r = r.to_string_lossy();
+                r.ends_with(path.as_str()) || path.ends_with(r.as_ref())
+            })
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_changed_lines() {
+        let (added, removed) = count_changed_lines("a\nb\nc\n", "a\nx\nc\nd\n");
+        assert_eq!(added, 2); // x and d
+        assert_eq!(removed, 1); // b
+
+        let (added, removed) = count_changed_lines("same\n", "same\n");
+        assert_eq!((added, removed), (0, 0));
+    }
+
+    #[test]
+    fn test_render_formats_counts() {
+        let stat = DiffStat {
+            files: 3,
+            added: 120,
+            removed: 14,
+            external: Vec::new(),
+        };
+        assert_eq!(
+            stat.render().unwrap(),
+            "3 files changed, +120 −14 (see /diff)"
+        );
+
+        let stat = DiffStat {
+            files: 1,
+            added: 2,
+            removed: 0,
+            external: Vec::new(),
+        };
+        assert!(stat.render().unwrap().starts_with("1 file changed"));
+    }
+
+    #[test]
+    fn test_render_nothing_for_untouched_turn() {
+        assert_eq!(DiffStat::default().render(), None);
+    }
+
+    #[test]
+    fn test_render_flags_external_changes() {
+        let stat = DiffStat {
+            files: 1,
+            added: 5,
+            removed: 1,
+            external: vec!["Cargo.lock".to_string()],
+        };
+        let line = stat.render().unwrap();
+        assert!(line.contains("1 file changed, +5 −1"));
+        assert!(line.contains("external changes in 1 file"));
+    }
+
+    #[test]
+    fn test_external_paths_excludes_recorded_files() {
+        let baseline: BTreeSet<String> = ["src/old.rs".to_string()].into_iter().collect();
+        let now: BTreeSet<String> = [
+            "src/old.rs".to_string(),
+            "src/edited.rs".to_string(),
+            "Cargo.lock".to_string(),
+        ]
+        .into_iter()
+        .collect();
+        let recorded = vec![PathBuf::from("/project/src/edited.rs")];
+
+        let external = external_paths(&baseline, &now, &recorded);
+
+        assert_eq!(external, vec!["Cargo.lock".to_string()]);
+    }
+
+    #[test]
+    fn test_tracker_diffs_against_first_record() {
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("file.txt");
+        std::fs::write(&path, "one\ntwo\n").expect("Should write");
+
+        let mut tracker = TurnDiffTracker::default();
+        tracker.record_before(path.clone(), Some("one\ntwo\n".to_string()));
+        std::fs::write(&path, "one\ntwo\nthree\n").expect("Should write");
+        // A second edit in the same turn must not reset the baseline
+        tracker.record_before(path.clone(), Some("one\ntwo\nthree\n".to_string()));
+        std::fs::write(&path, "one\nthree\n").expect("Should write");
+
+        let stat = tracker.finish();
+
+        assert_eq!(stat.files, 1);
+        assert_eq!(stat.added, 1); // three
+        assert_eq!(stat.removed, 1); // two
+    }
+
+    #[test]
+    fn test_tracker_skips_unchanged_files() {
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("file.txt");
+        std::fs::write(&path, "content\n").expect("Should write");
+
+        let mut tracker = TurnDiffTracker::default();
+        tracker.record_before(path, Some("content\n".to_string()));
+
+        let stat = tracker.finish();
+
+        assert_eq!(stat.files, 0);
+        assert_eq!(stat.render(), None);
+    }
+}
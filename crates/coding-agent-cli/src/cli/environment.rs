@@ -0,0 +1,158 @@
+//! Environment context appended to the system prompt
+//!
+//! The model routinely guesses the platform wrong or asks which directory
+//! it is in; this block answers those questions up front. It is rebuilt
+//! on every API call so the git state stays accurate as the session
+//! mutates the repository. `behavior.include_environment_context = false`
+//! turns it off.
+
+use crate::integrations::git::GitRepo;
+use crate::integrations::specstory::chrono_now;
+use ignore::WalkBuilder;
+use std::path::Path;
+
+/// Maximum entries included in the top-level directory listing
+const MAX_LISTING_ENTRIES: usize = 25;
+
+/// Build the environment context block appended to the system prompt
+pub fn environment_context() -> String {
+    let mut context = String::from("\n\n# Environment\n");
+
+    if let Ok(cwd) = std::env::current_dir() {
+        context.push_str(&format!("Working directory: {}\n", cwd.display()));
+    }
+    context.push_str(&format!(
+        "Platform: {} ({})\n",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    ));
+    context.push_str(&format!("Date: {}\n", today()));
+
+    if let Some(git) = git_context() {
+        context.push_str(&git);
+    }
+
+    let stacks = crate::project::project_types();
+    if !stacks.is_empty() {
+        let names: Vec<&str> = stacks.iter().map(|t| t.name()).collect();
+        context.push_str(&format!("Project type: {}\n", names.join(", ")));
+    }
+
+    let members = crate::project::member_display_lines();
+    if !members.is_empty() {
+        context.push_str("Cargo workspace members:\n");
+        for member in members {
+            context.push_str(&format!("  {}\n", member));
+        }
+    }
+
+    let listing = directory_listing();
+    if !listing.is_empty() {
+        context.push_str("Top-level entries:\n");
+        context.push_str(&listing);
+    }
+
+    context
+}
+
+/// Today's date (UTC), e.g. "2024-01-15"
+fn today() -> String {
+    chrono_now()
+        .split('T')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Git branch and dirty-file count for the current directory
+///
+/// `None` when the cwd is not inside a git repository.
+fn git_context() -> Option<String> {
+    let repo = GitRepo::open_cwd().ok()?;
+    let status = repo.status().ok()?;
+    let branch = status
+        .branch
+        .clone()
+        .unwrap_or_else(|| "(detached HEAD)".to_string());
+
+    Some(if status.is_clean() {
+        format!("Git: branch {}, working tree clean\n", branch)
+    } else {
+        format!(
+            "Git: branch {}, {} file(s) with uncommitted changes\n",
+            branch,
+            status.files.len()
+        )
+    })
+}
+
+/// List the top-level directory entries, honoring the same ignore rules
+/// as the `list_files` tool and capped at [`MAX_LISTING_ENTRIES`]
+fn directory_listing() -> String {
+    let mut builder = WalkBuilder::new(".");
+    builder
+        .max_depth(Some(1))
+        .hidden(false)
+        .ignore(false)
+        .git_global(false)
+        .require_git(false)
+        .add_custom_ignore_filename(".agentignore")
+        .filter_entry(|e| {
+            let name = e.file_name().to_string_lossy();
+            name != ".git" && name != ".devenv" && name != "target" && name != "node_modules"
+        });
+
+    let mut entries: Vec<String> = Vec::new();
+    for entry in builder.build().flatten() {
+        let path = entry.path();
+        if path == Path::new(".") {
+            continue;
+        }
+        let name = path
+            .strip_prefix(".")
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+        if entry.file_type().is_some_and(|t| t.is_dir()) {
+            entries.push(format!("{}/", name));
+        } else {
+            entries.push(name);
+        }
+    }
+    entries.sort();
+
+    let total = entries.len();
+    let mut listing = String::new();
+    for name in entries.into_iter().take(MAX_LISTING_ENTRIES) {
+        listing.push_str(&format!("  {}\n", name));
+    }
+    if total > MAX_LISTING_ENTRIES {
+        listing.push_str(&format!("  … and {} more\n", total - MAX_LISTING_ENTRIES));
+    }
+    listing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_environment_context_names_platform_and_cwd() {
+        let context = environment_context();
+
+        assert!(context.starts_with("\n\n# Environment\n"));
+        assert!(context.contains("Working directory: "));
+        assert!(context.contains(&format!("Platform: {} (", std::env::consts::OS)));
+        assert!(context.contains("Date: "));
+    }
+
+    #[test]
+    fn test_today_is_a_bare_date() {
+        let date = today();
+
+        // ISO date without the time component
+        assert_eq!(date.len(), 10);
+        assert!(!date.contains('T'));
+        assert_eq!(date.matches('-').count(), 2);
+    }
+}
@@ -1,6 +1,145 @@
+use super::keybindings::{InputAction, KeyMap};
 use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::PathBuf;
 use std::time::Duration;
 
+/// Persistent history of submitted inputs, navigated with up/down arrows
+#[derive(Debug)]
+pub struct InputHistory {
+    /// Past entries, oldest first
+    entries: VecDeque<String>,
+    /// Cursor into `entries` while navigating (None = editing a new input)
+    index: Option<usize>,
+    /// Maximum number of retained entries
+    max_entries: usize,
+}
+
+impl InputHistory {
+    /// Default number of entries to retain
+    pub const DEFAULT_MAX_ENTRIES: usize = 200;
+
+    /// Create an empty history with the given capacity
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            index: None,
+            max_entries: max_entries.max(1),
+        }
+    }
+
+    /// Load history from the default path, starting empty on any error
+    pub fn load(max_entries: usize) -> Self {
+        let mut history = Self::new(max_entries);
+
+        let entries: Vec<String> = Self::default_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+        for entry in entries {
+            history.push(entry);
+        }
+
+        history
+    }
+
+    /// Persist history to the default path, ignoring failures
+    ///
+    /// History is a convenience; a read-only filesystem should not break
+    /// input handling.
+    pub fn save(&self) {
+        let Some(path) = Self::default_path() else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        if let Ok(json) = serde_json::to_string_pretty(&Vec::from_iter(self.entries.iter())) {
+            let _ = fs::write(path, json);
+        }
+    }
+
+    /// Default path: `~/.local/share/coding-agent/input_history.json`
+    fn default_path() -> Option<PathBuf> {
+        dirs::data_dir().map(|dir| dir.join("coding-agent").join("input_history.json"))
+    }
+
+    /// Record a submitted entry, dropping empty and identical consecutive ones
+    pub fn push(&mut self, entry: String) {
+        self.index = None;
+
+        if entry.trim().is_empty() || self.entries.back() == Some(&entry) {
+            return;
+        }
+
+        self.entries.push_back(entry);
+        while self.entries.len() > self.max_entries {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Move back in history (up arrow), clamping at the oldest entry
+    pub fn previous(&mut self) -> Option<&str> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let index = match self.index {
+            None => self.entries.len() - 1,
+            Some(i) => i.saturating_sub(1),
+        };
+        self.index = Some(index);
+        self.entries.get(index).map(|s| s.as_str())
+    }
+
+    /// Move forward in history (down arrow)
+    ///
+    /// Returns None when stepping past the newest entry, which takes the
+    /// user back to an empty buffer.
+    pub fn next_entry(&mut self) -> Option<&str> {
+        let index = self.index?;
+        if index + 1 >= self.entries.len() {
+            self.index = None;
+            return None;
+        }
+
+        self.index = Some(index + 1);
+        self.entries.get(index + 1).map(|s| s.as_str())
+    }
+
+    /// Whether the user is currently navigating through history
+    pub fn is_navigating(&self) -> bool {
+        self.index.is_some()
+    }
+
+    /// Iterate over entries, most recent first (for reverse search)
+    pub fn iter_recent_first(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().rev().map(|s| s.as_str())
+    }
+
+    /// Stop navigating; the next up-arrow starts from the newest entry again
+    pub fn reset_cursor(&mut self) {
+        self.index = None;
+    }
+
+    /// Number of retained entries
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the history is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Default for InputHistory {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_ENTRIES)
+    }
+}
+
 /// Result of reading user input
 #[derive(Debug, Clone, PartialEq)]
 pub enum InputResult {
@@ -10,36 +149,142 @@ pub enum InputResult {
     Cancelled,
     /// User requested exit (Ctrl+D)
     Exit,
+    /// User pressed Shift+Tab to toggle auto-accept mode
+    ToggleAutoAccept,
 }
 
+/// Continuation prompt shown on draft lines after the first
+pub const CONTINUATION_PROMPT: &str = "… ";
+
 /// Handles multi-line input with double-enter submission
+///
+/// The draft is redrawn in full after every edit, which lets the cursor move
+/// between lines (Up/Down) and sit mid-line (Left/Right). Column math counts
+/// chars, not display width, so wide glyphs in the prompt can be off by a
+/// column — the same trade-off as the rest of the terminal handling.
 pub struct InputHandler {
     buffer: String,
+    /// Byte index of the edit cursor within `buffer`
+    cursor: usize,
+    /// Prompt printed before the first draft line
+    prompt: String,
+    /// Visual draft line the terminal cursor currently sits on
+    rendered_cursor_line: usize,
     /// Tracks if the last key was Enter (for double-enter detection)
     last_was_enter: bool,
+    /// Submitted-input history for up/down recall
+    history: InputHistory,
+    /// Active Ctrl+R reverse search, if any
+    reverse_search: Option<ReverseSearch>,
+    /// Configurable action bindings (see `[keybindings]` in the config)
+    keymap: KeyMap,
+    /// Vim-style modal editing state (opt-in via `input.vim_mode`)
+    vim: Option<VimState>,
+    /// Lazily built project file index for the fuzzy file finder
+    file_index: Option<crate::ui::FileIndex>,
+    /// Text queued to seed the next draft (e.g. a /files pick)
+    pending_insert: Option<String>,
+}
+
+/// State for vim-style modal editing
+#[derive(Debug)]
+struct VimState {
+    mode: VimMode,
+    /// Operator awaiting a motion (`d` for dd, `c` for cw)
+    pending: Option<char>,
+}
+
+/// The two vim editing modes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum VimMode {
+    Insert,
+    Normal,
+}
+
+/// State of an in-progress Ctrl+R reverse search
+struct ReverseSearch {
+    /// The incremental query typed so far
+    query: String,
+    /// How many ranked matches to skip (repeated Ctrl+R steps back)
+    offset: usize,
 }
 
 impl InputHandler {
     pub fn new() -> Self {
+        Self::with_history(InputHistory::load(InputHistory::DEFAULT_MAX_ENTRIES))
+    }
+
+    /// Create a handler with a specific (e.g. preloaded) history
+    pub fn with_history(history: InputHistory) -> Self {
         Self {
             buffer: String::new(),
+            cursor: 0,
+            prompt: "> ".to_string(),
+            rendered_cursor_line: 0,
             last_was_enter: false,
+            history,
+            reverse_search: None,
+            keymap: KeyMap::default(),
+            vim: None,
+            file_index: None,
+            pending_insert: None,
         }
     }
 
+    /// Enable or disable vim-style modal editing (starts in insert mode)
+    pub fn set_vim_mode(&mut self, enabled: bool) {
+        self.vim = enabled.then_some(VimState {
+            mode: VimMode::Insert,
+            pending: None,
+        });
+    }
+
+    /// Set the prompt reprinted on redraws (must match what the caller printed)
+    pub fn set_prompt(&mut self, prompt: impl Into<String>) {
+        self.prompt = prompt.into();
+    }
+
+    /// Replace the active keymap (built from the `[keybindings]` config)
+    pub fn set_keymap(&mut self, keymap: KeyMap) {
+        self.keymap = keymap;
+    }
+
     /// Read input from the terminal until submission, cancellation, or exit
     pub async fn read_input(&mut self) -> Result<InputResult, String> {
         self.buffer.clear();
+        self.cursor = 0;
+        self.rendered_cursor_line = 0;
         self.last_was_enter = false;
+        self.history.reset_cursor();
+        if let Some(vim) = self.vim.as_mut() {
+            vim.mode = VimMode::Insert;
+            vim.pending = None;
+        }
+
+        // A queued insert (e.g. from /files) seeds the fresh draft
+        if let Some(text) = self.pending_insert.take() {
+            self.insert_text(&text);
+        }
+
+        // Bracketed paste delivers pasted text as one event, so embedded
+        // newlines never trigger the double-enter submit
+        let _ = crossterm::execute!(std::io::stdout(), event::EnableBracketedPaste);
+        let result = self.read_input_loop().await;
+        let _ = crossterm::execute!(std::io::stdout(), event::DisableBracketedPaste);
+        result
+    }
 
+    async fn read_input_loop(&mut self) -> Result<InputResult, String> {
         loop {
             // Poll for events with a timeout
             if event::poll(Duration::from_millis(100)).map_err(|e| e.to_string())? {
-                if let Event::Key(key_event) = event::read().map_err(|e| e.to_string())? {
-                    match self.handle_key_event(key_event) {
+                match event::read().map_err(|e| e.to_string())? {
+                    Event::Key(key_event) => match self.handle_key_event(key_event) {
                         KeyAction::Continue => continue,
                         KeyAction::Submit => {
                             let text = self.buffer.trim_end().to_string();
+                            self.history.push(text.clone());
+                            self.history.save();
                             return Ok(InputResult::Submitted(text));
                         }
                         KeyAction::Cancel => {
@@ -49,7 +294,15 @@ impl InputHandler {
                         KeyAction::Exit => {
                             return Ok(InputResult::Exit);
                         }
+                        KeyAction::ToggleAutoAccept => {
+                            return Ok(InputResult::ToggleAutoAccept);
+                        }
+                    },
+                    Event::Paste(text) => {
+                        self.last_was_enter = false;
+                        self.insert_text(&text.replace("\r\n", "\n").replace('\r', "\n"));
                     }
+                    _ => continue,
                 }
             }
         }
@@ -57,11 +310,37 @@ impl InputHandler {
 
     /// Process a key event and return the action to take
     fn handle_key_event(&mut self, event: KeyEvent) -> KeyAction {
+        if self.reverse_search.is_some() {
+            return self.handle_search_key(event);
+        }
+
+        // Vim normal mode replaces the regular key handling entirely
+        if matches!(&self.vim, Some(vim) if vim.mode == VimMode::Normal) {
+            return self.handle_vim_normal_key(event);
+        }
+
+        // Esc enters vim normal mode; without vim mode it stays a no-op
+        if event.code == KeyCode::Esc && self.vim.is_some() {
+            self.last_was_enter = false;
+            self.set_vim_state(VimMode::Normal);
+            return KeyAction::Continue;
+        }
+
+        // Configurable bindings take precedence over the built-in keys
+        if let Some(action) = self.keymap.action_for(&event) {
+            return self.handle_action(action);
+        }
+
         match (event.code, event.modifiers) {
-            // Ctrl+C: Cancel current input
-            (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+            // Ctrl+R: Start incremental reverse search over history
+            (KeyCode::Char('r'), KeyModifiers::CONTROL) => {
                 self.last_was_enter = false;
-                KeyAction::Cancel
+                self.reverse_search = Some(ReverseSearch {
+                    query: String::new(),
+                    offset: 0,
+                });
+                self.render_search();
+                KeyAction::Continue
             }
 
             // Ctrl+D: Exit application
@@ -70,71 +349,572 @@ impl InputHandler {
                 KeyAction::Exit
             }
 
-            // Enter: Add newline or submit on double-enter
-            (KeyCode::Enter, KeyModifiers::NONE) => {
+            // Backspace: Remove the character before the cursor
+            (KeyCode::Backspace, _) => {
+                self.last_was_enter = false;
+                if let Some(c) = self.buffer[..self.cursor].chars().next_back() {
+                    self.cursor -= c.len_utf8();
+                    self.buffer.remove(self.cursor);
+                    self.redraw();
+                }
+                KeyAction::Continue
+            }
+
+            // Regular character input
+            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+                self.last_was_enter = false;
+                self.insert_text(&c.to_string());
+                KeyAction::Continue
+            }
+
+            // Left/Right: move the cursor within the draft
+            (KeyCode::Left, KeyModifiers::NONE) => {
+                self.last_was_enter = false;
+                if let Some(c) = self.buffer[..self.cursor].chars().next_back() {
+                    self.cursor -= c.len_utf8();
+                    self.redraw();
+                }
+                KeyAction::Continue
+            }
+            (KeyCode::Right, KeyModifiers::NONE) => {
+                self.last_was_enter = false;
+                if let Some(c) = self.buffer[self.cursor..].chars().next() {
+                    self.cursor += c.len_utf8();
+                    self.redraw();
+                }
+                KeyAction::Continue
+            }
+
+            // Home/End: jump to the start/end of the current line
+            (KeyCode::Home, KeyModifiers::NONE) => {
+                self.last_was_enter = false;
+                let (line, _) = line_col(&self.buffer, self.cursor);
+                self.cursor = cursor_for_line_col(&self.buffer, line, 0);
+                self.redraw();
+                KeyAction::Continue
+            }
+            (KeyCode::End, KeyModifiers::NONE) => {
+                self.last_was_enter = false;
+                let (line, _) = line_col(&self.buffer, self.cursor);
+                self.cursor = cursor_for_line_col(&self.buffer, line, usize::MAX);
+                self.redraw();
+                KeyAction::Continue
+            }
+
+            // Up: move up a draft line, falling back to history on the first
+            (KeyCode::Up, KeyModifiers::NONE) => {
+                self.last_was_enter = false;
+                let (line, col) = line_col(&self.buffer, self.cursor);
+                if line > 0 {
+                    self.cursor = cursor_for_line_col(&self.buffer, line - 1, col);
+                    self.redraw();
+                } else if let Some(entry) = self.history.previous().map(|s| s.to_string()) {
+                    self.replace_buffer(&entry);
+                }
+                KeyAction::Continue
+            }
+
+            // Down: move down a draft line, falling back to history on the last
+            (KeyCode::Down, KeyModifiers::NONE) => {
+                self.last_was_enter = false;
+                let (line, col) = line_col(&self.buffer, self.cursor);
+                if line + 1 < self.buffer.split('\n').count() {
+                    self.cursor = cursor_for_line_col(&self.buffer, line + 1, col);
+                    self.redraw();
+                } else if self.history.is_navigating() {
+                    let entry = self.history.next_entry().map(|s| s.to_string());
+                    self.replace_buffer(entry.as_deref().unwrap_or(""));
+                }
+                KeyAction::Continue
+            }
+
+            // Tab: Insert spaces (or tab character)
+            (KeyCode::Tab, _) => {
+                self.last_was_enter = false;
+                self.insert_text("    ");
+                KeyAction::Continue
+            }
+
+            // Shift+Tab: toggle auto-accept mode (handled by the REPL)
+            (KeyCode::BackTab, _) => {
+                self.last_was_enter = false;
+                KeyAction::ToggleAutoAccept
+            }
+
+            // Ignore other keys
+            _ => {
+                self.last_was_enter = false;
+                KeyAction::Continue
+            }
+        }
+    }
+
+    /// Perform a bound input action
+    fn handle_action(&mut self, action: InputAction) -> KeyAction {
+        match action {
+            // Add newline, or submit on double-press
+            InputAction::Submit => {
                 if self.last_was_enter {
-                    // Double-enter: submit
-                    // Remove the trailing newline from the first enter
+                    // Remove the trailing newline from the first press
                     if self.buffer.ends_with('\n') {
                         self.buffer.pop();
+                        self.cursor = self.cursor.min(self.buffer.len());
                     }
                     KeyAction::Submit
                 } else {
-                    // First enter: add newline
-                    // In raw mode, need \r\n for proper line break
-                    self.buffer.push('\n');
                     self.last_was_enter = true;
-                    print!("\r\n");
-                    let _ = std::io::Write::flush(&mut std::io::stdout());
+                    self.insert_text("\n");
                     KeyAction::Continue
                 }
             }
 
-            // Backspace: Remove last character
-            (KeyCode::Backspace, _) => {
+            // Insert a newline without ever submitting
+            InputAction::Newline => {
+                self.last_was_enter = false;
+                self.insert_text("\n");
+                KeyAction::Continue
+            }
+
+            InputAction::Clear => {
                 self.last_was_enter = false;
                 if !self.buffer.is_empty() {
-                    let removed = self.buffer.pop();
-                    // Handle visual backspace
-                    if removed == Some('\n') {
-                        // Move cursor up and to end of previous line
-                        // This is simplified - in a real app we'd track line lengths
-                        print!("\x1b[A\x1b[999C");
-                    } else {
-                        // Simple backspace: move back, write space, move back
-                        print!("\x08 \x08");
-                    }
-                    let _ = std::io::Write::flush(&mut std::io::stdout());
+                    self.buffer.clear();
+                    self.cursor = 0;
+                    self.redraw();
                 }
                 KeyAction::Continue
             }
 
-            // Regular character input
-            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+            InputAction::Cancel => {
                 self.last_was_enter = false;
-                self.buffer.push(c);
-                print!("{}", c);
-                let _ = std::io::Write::flush(&mut std::io::stdout());
+                KeyAction::Cancel
+            }
+
+            InputAction::HistoryPrev => {
+                self.last_was_enter = false;
+                if let Some(entry) = self.history.previous().map(|s| s.to_string()) {
+                    self.replace_buffer(&entry);
+                }
                 KeyAction::Continue
             }
 
-            // Tab: Insert spaces (or tab character)
-            (KeyCode::Tab, _) => {
+            InputAction::HistoryNext => {
                 self.last_was_enter = false;
-                self.buffer.push_str("    ");
-                print!("    ");
-                let _ = std::io::Write::flush(&mut std::io::stdout());
+                if self.history.is_navigating() {
+                    let entry = self.history.next_entry().map(|s| s.to_string());
+                    self.replace_buffer(entry.as_deref().unwrap_or(""));
+                }
                 KeyAction::Continue
             }
 
-            // Ignore other keys
-            _ => {
+            InputAction::DeleteWord => {
+                self.last_was_enter = false;
+                self.delete_word_before_cursor();
+                KeyAction::Continue
+            }
+
+            InputAction::OpenEditor => {
+                self.last_was_enter = false;
+                self.edit_in_external_editor();
+                KeyAction::Continue
+            }
+
+            InputAction::FileFinder => {
                 self.last_was_enter = false;
+                self.open_file_finder();
                 KeyAction::Continue
             }
         }
     }
 
+    /// Switch vim mode and redraw so the prompt indicator updates
+    fn set_vim_state(&mut self, mode: VimMode) {
+        if let Some(vim) = self.vim.as_mut() {
+            vim.mode = mode;
+            vim.pending = None;
+        }
+        self.redraw();
+    }
+
+    /// Process a key event in vim normal mode
+    fn handle_vim_normal_key(&mut self, event: KeyEvent) -> KeyAction {
+        // Submit and cancel chords keep working in normal mode
+        if let Some(action @ (InputAction::Submit | InputAction::Cancel)) =
+            self.keymap.action_for(&event)
+        {
+            if let Some(vim) = self.vim.as_mut() {
+                vim.pending = None;
+            }
+            return self.handle_action(action);
+        }
+        self.last_was_enter = false;
+
+        let KeyCode::Char(c) = event.code else {
+            if let Some(vim) = self.vim.as_mut() {
+                vim.pending = None;
+            }
+            return KeyAction::Continue;
+        };
+
+        // A pending operator consumes the next key as its motion
+        if let Some(op) = self.vim.as_mut().and_then(|vim| vim.pending.take()) {
+            match (op, c) {
+                ('d', 'd') => self.vim_delete_line(),
+                ('c', 'w') => {
+                    self.vim_delete_word_forward();
+                    self.set_vim_state(VimMode::Insert);
+                }
+                _ => {}
+            }
+            return KeyAction::Continue;
+        }
+
+        match c {
+            // Motions
+            'h' => {
+                if let Some(c) = self.buffer[..self.cursor].chars().next_back() {
+                    self.cursor -= c.len_utf8();
+                    self.redraw();
+                }
+            }
+            'l' => {
+                if let Some(c) = self.buffer[self.cursor..].chars().next() {
+                    self.cursor += c.len_utf8();
+                    self.redraw();
+                }
+            }
+            'w' => {
+                self.cursor = next_word_start(&self.buffer, self.cursor);
+                self.redraw();
+            }
+            'b' => {
+                self.cursor = prev_word_start(&self.buffer, self.cursor);
+                self.redraw();
+            }
+
+            // Edits
+            'x' if self.cursor < self.buffer.len() => {
+                self.buffer.remove(self.cursor);
+                self.cursor = self.cursor.min(self.buffer.len());
+                self.redraw();
+            }
+            'd' | 'c' => {
+                if let Some(vim) = self.vim.as_mut() {
+                    vim.pending = Some(c);
+                }
+            }
+
+            // Back to insert mode
+            'i' => self.set_vim_state(VimMode::Insert),
+            'a' => {
+                if let Some(c) = self.buffer[self.cursor..].chars().next() {
+                    self.cursor += c.len_utf8();
+                }
+                self.set_vim_state(VimMode::Insert);
+            }
+            'A' => {
+                let (line, _) = line_col(&self.buffer, self.cursor);
+                self.cursor = cursor_for_line_col(&self.buffer, line, usize::MAX);
+                self.set_vim_state(VimMode::Insert);
+            }
+
+            _ => {}
+        }
+        KeyAction::Continue
+    }
+
+    /// Delete the line under the cursor, including its newline (vim `dd`)
+    fn vim_delete_line(&mut self) {
+        let (line, _) = line_col(&self.buffer, self.cursor);
+        let start = cursor_for_line_col(&self.buffer, line, 0);
+        let mut end = cursor_for_line_col(&self.buffer, line, usize::MAX);
+        if self.buffer[end..].starts_with('\n') {
+            end += 1;
+        } else if start > 0 {
+            // Last line: remove the preceding newline instead
+            // (start is always at a char boundary right after '\n')
+            return self.replace_range(start - 1, end);
+        }
+        self.replace_range(start, end);
+    }
+
+    /// Delete from the cursor to the end of the current word (vim `cw`)
+    fn vim_delete_word_forward(&mut self) {
+        let rest = &self.buffer[self.cursor..];
+        let span = if rest.starts_with(|c: char| c.is_whitespace()) {
+            rest.len() - rest.trim_start().len()
+        } else {
+            rest.find(char::is_whitespace).unwrap_or(rest.len())
+        };
+        self.replace_range(self.cursor, self.cursor + span);
+    }
+
+    /// Remove a byte range from the buffer, clamping the cursor
+    fn replace_range(&mut self, start: usize, end: usize) {
+        self.buffer.replace_range(start..end, "");
+        self.cursor = start.min(self.buffer.len());
+        self.redraw();
+    }
+
+    /// Delete the word before the cursor (trailing whitespace, then the word)
+    fn delete_word_before_cursor(&mut self) {
+        let before = &self.buffer[..self.cursor];
+        let without_ws = before.trim_end_matches(char::is_whitespace);
+        let start = without_ws
+            .trim_end_matches(|c: char| !c.is_whitespace())
+            .len();
+        if start < self.cursor {
+            self.buffer.replace_range(start..self.cursor, "");
+            self.cursor = start;
+            self.redraw();
+        }
+    }
+
+    /// Process a key event while reverse search is active
+    fn handle_search_key(&mut self, event: KeyEvent) -> KeyAction {
+        match (event.code, event.modifiers) {
+            // Ctrl+R again: step to the next (older/worse-ranked) match
+            (KeyCode::Char('r'), KeyModifiers::CONTROL) => {
+                if let Some(search) = self.reverse_search.as_mut() {
+                    search.offset += 1;
+                }
+                self.render_search();
+            }
+
+            // Enter: accept the current match into the buffer
+            (KeyCode::Enter, _) => {
+                let accepted = self.current_search_match();
+                self.reverse_search = None;
+                if let Some(text) = accepted {
+                    self.replace_buffer(&text);
+                } else {
+                    self.redraw();
+                }
+            }
+
+            // Esc / Ctrl+C / Ctrl+G: cancel, keeping the draft as it was
+            (KeyCode::Esc, _)
+            | (KeyCode::Char('c'), KeyModifiers::CONTROL)
+            | (KeyCode::Char('g'), KeyModifiers::CONTROL) => {
+                self.reverse_search = None;
+                self.redraw();
+            }
+
+            (KeyCode::Backspace, _) => {
+                if let Some(search) = self.reverse_search.as_mut() {
+                    search.query.pop();
+                    search.offset = 0;
+                }
+                self.render_search();
+            }
+
+            (KeyCode::Char(c), KeyModifiers::NONE | KeyModifiers::SHIFT) => {
+                if let Some(search) = self.reverse_search.as_mut() {
+                    search.query.push(c);
+                    search.offset = 0;
+                }
+                self.render_search();
+            }
+
+            _ => {}
+        }
+        KeyAction::Continue
+    }
+
+    /// Get the history entry the active reverse search currently points at
+    fn current_search_match(&self) -> Option<String> {
+        let search = self.reverse_search.as_ref()?;
+        let candidates: Vec<&str> = self.history.iter_recent_first().collect();
+        let matches = super::search::search(&search.query, &candidates);
+        if matches.is_empty() {
+            return None;
+        }
+        // Repeated Ctrl+R clamps at the last match rather than wrapping
+        let index = search.offset.min(matches.len() - 1);
+        Some(matches[index].text.clone())
+    }
+
+    /// Redraw the reverse-search prompt in place of the draft
+    fn render_search(&mut self) {
+        let Some(query) = self.reverse_search.as_ref().map(|s| s.query.clone()) else {
+            return;
+        };
+
+        let display = match self.current_search_match() {
+            Some(text) => {
+                // Searching multi-line entries: show the first matching line
+                let line = text
+                    .lines()
+                    .find(|l| l.to_lowercase().contains(&query.to_lowercase()))
+                    .unwrap_or_else(|| text.lines().next().unwrap_or(""))
+                    .to_string();
+                super::search::highlight(&line, &query)
+            }
+            None if query.is_empty() => String::new(),
+            None => "(no match)".to_string(),
+        };
+
+        let mut out = String::from("\r");
+        for _ in 0..self.rendered_cursor_line {
+            out.push_str("\x1b[A");
+        }
+        out.push_str("\x1b[J");
+        out.push_str(&format!("(reverse-i-search)`{}': {}", query, display));
+        self.rendered_cursor_line = 0;
+
+        print!("{}", out);
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+    }
+
+    /// The prompt including the vim normal-mode indicator, when active
+    fn effective_prompt(&self) -> String {
+        match &self.vim {
+            Some(vim) if vim.mode == VimMode::Normal => format!("[N] {}", self.prompt),
+            _ => self.prompt.clone(),
+        }
+    }
+
+    /// Insert text at the cursor and redraw the draft
+    fn insert_text(&mut self, text: &str) {
+        self.buffer.insert_str(self.cursor, text);
+        self.cursor += text.len();
+        self.redraw();
+    }
+
+    /// Replace the entire draft with `text` (history recall) and redraw
+    fn replace_buffer(&mut self, text: &str) {
+        self.buffer.clear();
+        self.buffer.push_str(text);
+        self.cursor = self.buffer.len();
+        self.redraw();
+    }
+
+    /// Redraw the whole draft and reposition the terminal cursor
+    ///
+    /// Moves to the first draft line, clears downward, reprints the prompt
+    /// plus every line (continuation lines prefixed with `…`), then puts the
+    /// terminal cursor back on the edit position.
+    fn redraw(&mut self) {
+        let mut out = String::from("\r");
+        for _ in 0..self.rendered_cursor_line {
+            out.push_str("\x1b[A");
+        }
+        out.push_str("\x1b[J");
+
+        let prompt = self.effective_prompt();
+        let lines: Vec<&str> = self.buffer.split('\n').collect();
+        out.push_str(&prompt);
+        out.push_str(lines[0]);
+        for line in &lines[1..] {
+            out.push_str("\r\n");
+            out.push_str(CONTINUATION_PROMPT);
+            out.push_str(line);
+        }
+
+        // Park the terminal cursor on the edit position
+        let (cursor_line, col) = line_col(&self.buffer, self.cursor);
+        for _ in 0..(lines.len() - 1 - cursor_line) {
+            out.push_str("\x1b[A");
+        }
+        out.push('\r');
+        let prefix = if cursor_line == 0 {
+            prompt.chars().count()
+        } else {
+            CONTINUATION_PROMPT.chars().count()
+        };
+        if prefix + col > 0 {
+            out.push_str(&format!("\x1b[{}C", prefix + col));
+        }
+        self.rendered_cursor_line = cursor_line;
+
+        print!("{}", out);
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+    }
+
+    /// Edit the draft in `$EDITOR`, then show the result for confirmation
+    ///
+    /// Raw mode is suspended while the editor runs. The edited draft is
+    /// reprinted afterwards so the user can review it before submitting with
+    /// double-enter as usual.
+    fn edit_in_external_editor(&mut self) {
+        let Ok(editor) = std::env::var("EDITOR") else {
+            print!("\r\n$EDITOR is not set\r\n");
+            self.rendered_cursor_line = 0;
+            self.redraw();
+            return;
+        };
+
+        let path =
+            std::env::temp_dir().join(format!("coding-agent-draft-{}.md", std::process::id()));
+        if fs::write(&path, &self.buffer).is_err() {
+            return;
+        }
+
+        let _ = crossterm::terminal::disable_raw_mode();
+        print!("\r\n");
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+        let status = std::process::Command::new(&editor).arg(&path).status();
+        let _ = crossterm::terminal::enable_raw_mode();
+
+        match status {
+            Ok(status) if status.success() => {
+                if let Ok(content) = fs::read_to_string(&path) {
+                    self.buffer = content.trim_end().to_string();
+                    self.cursor = self.buffer.len();
+                }
+            }
+            _ => {
+                print!("\r\nEditor exited with an error; draft unchanged\r\n");
+            }
+        }
+        let _ = fs::remove_file(&path);
+
+        // Reprint the (possibly edited) draft for review
+        print!("\r\n");
+        self.rendered_cursor_line = 0;
+        self.redraw();
+    }
+
+    /// Queue text to insert at the start of the next draft
+    ///
+    /// Used by `/files`, which picks a path after the triggering input has
+    /// already been consumed.
+    pub fn queue_insert(&mut self, text: impl Into<String>) {
+        self.pending_insert = Some(text.into());
+    }
+
+    /// Run the fuzzy file finder at the current terminal position
+    ///
+    /// The project index is built on first use and cached for the session.
+    /// Returns the chosen path, or None on cancel.
+    pub fn pick_file(&mut self) -> Option<String> {
+        let index = self
+            .file_index
+            .get_or_insert_with(|| crate::ui::FileIndex::build(std::path::Path::new(".")));
+        crate::ui::FileFinder::new(index).run().ok().flatten()
+    }
+
+    /// Open the fuzzy file finder and insert the pick at the cursor
+    ///
+    /// The overlay renders below the draft and clears itself on exit; the
+    /// draft is then redrawn whether or not a file was chosen, so Esc
+    /// leaves the prompt exactly as it was.
+    fn open_file_finder(&mut self) {
+        // Move below the draft so the overlay does not overwrite it
+        let lines_below = self.buffer.split('\n').count() - self.rendered_cursor_line;
+        print!("{}", "\r\n".repeat(lines_below));
+        let _ = std::io::Write::flush(&mut std::io::stdout());
+
+        let picked = self.pick_file();
+
+        // The overlay cleared itself; climb back to the cursor's line
+        print!("{}", "\x1b[A".repeat(lines_below));
+        match picked {
+            Some(path) => self.insert_text(&format!("@{} ", path)),
+            None => self.redraw(),
+        }
+    }
+
     /// Get the current buffer contents (for testing)
     #[cfg(test)]
     pub fn buffer(&self) -> &str {
@@ -167,6 +947,51 @@ pub enum KeyAction {
     Submit,
     Cancel,
     Exit,
+    ToggleAutoAccept,
+}
+
+/// Get the (line, column) of a byte cursor within a buffer (both 0-based,
+/// column in chars)
+fn line_col(buffer: &str, cursor: usize) -> (usize, usize) {
+    let before = &buffer[..cursor];
+    let line = before.matches('\n').count();
+    let col = before
+        .rsplit('\n')
+        .next()
+        .map(|l| l.chars().count())
+        .unwrap_or(0);
+    (line, col)
+}
+
+/// Byte index of the next word start after `cursor` (vim `w`)
+fn next_word_start(buffer: &str, cursor: usize) -> usize {
+    let rest = &buffer[cursor..];
+    let after_word = rest.trim_start_matches(|c: char| !c.is_whitespace());
+    let after_ws = after_word.trim_start_matches(char::is_whitespace);
+    buffer.len() - after_ws.len()
+}
+
+/// Byte index of the previous word start before `cursor` (vim `b`)
+fn prev_word_start(buffer: &str, cursor: usize) -> usize {
+    let before = &buffer[..cursor];
+    let without_ws = before.trim_end_matches(char::is_whitespace);
+    without_ws
+        .trim_end_matches(|c: char| !c.is_whitespace())
+        .len()
+}
+
+/// Get the byte cursor for a (line, column) position, clamping the column to
+/// the line length
+fn cursor_for_line_col(buffer: &str, line: usize, col: usize) -> usize {
+    let mut offset = 0;
+    for (i, text) in buffer.split('\n').enumerate() {
+        if i == line {
+            let byte_col: usize = text.chars().take(col).map(|c| c.len_utf8()).sum();
+            return offset + byte_col;
+        }
+        offset += text.len() + 1; // +1 for the newline
+    }
+    buffer.len()
 }
 
 #[cfg(test)]
@@ -183,6 +1008,492 @@ mod tests {
         }
     }
 
+    fn preloaded_handler(entries: &[&str]) -> InputHandler {
+        let mut history = InputHistory::new(InputHistory::DEFAULT_MAX_ENTRIES);
+        for entry in entries {
+            history.push(entry.to_string());
+        }
+        InputHandler::with_history(history)
+    }
+
+    #[test]
+    fn test_history_push_skips_consecutive_duplicates() {
+        let mut history = InputHistory::new(10);
+
+        history.push("hello".to_string());
+        history.push("hello".to_string());
+        history.push("world".to_string());
+        history.push("hello".to_string());
+
+        assert_eq!(history.len(), 3);
+    }
+
+    #[test]
+    fn test_history_push_skips_empty_entries() {
+        let mut history = InputHistory::new(10);
+
+        history.push(String::new());
+        history.push("   ".to_string());
+
+        assert!(history.is_empty());
+    }
+
+    #[test]
+    fn test_history_trims_to_max_entries() {
+        let mut history = InputHistory::new(3);
+
+        for i in 0..5 {
+            history.push(format!("entry {}", i));
+        }
+
+        assert_eq!(history.len(), 3);
+        // Oldest entries were dropped
+        assert_eq!(history.previous(), Some("entry 4"));
+        assert_eq!(history.previous(), Some("entry 3"));
+        assert_eq!(history.previous(), Some("entry 2"));
+        // Clamped at the oldest remaining entry
+        assert_eq!(history.previous(), Some("entry 2"));
+    }
+
+    #[test]
+    fn test_history_navigation_forward_and_back() {
+        let mut history = InputHistory::new(10);
+        history.push("first".to_string());
+        history.push("second".to_string());
+
+        assert_eq!(history.previous(), Some("second"));
+        assert_eq!(history.previous(), Some("first"));
+        assert_eq!(history.next_entry(), Some("second"));
+        // Stepping past the newest entry ends navigation
+        assert_eq!(history.next_entry(), None);
+        assert!(!history.is_navigating());
+    }
+
+    #[test]
+    fn test_up_arrow_recalls_previous_entry() {
+        let mut handler = preloaded_handler(&["cargo build", "cargo test"]);
+
+        handler.simulate_key(key_event(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(handler.buffer(), "cargo test");
+
+        handler.simulate_key(key_event(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(handler.buffer(), "cargo build");
+    }
+
+    #[test]
+    fn test_down_arrow_returns_to_empty_buffer() {
+        let mut handler = preloaded_handler(&["cargo build"]);
+
+        handler.simulate_key(key_event(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(handler.buffer(), "cargo build");
+
+        handler.simulate_key(key_event(KeyCode::Down, KeyModifiers::NONE));
+        assert_eq!(handler.buffer(), "");
+    }
+
+    #[test]
+    fn test_down_arrow_without_navigation_keeps_buffer() {
+        let mut handler = preloaded_handler(&["cargo build"]);
+
+        handler.simulate_key(key_event(KeyCode::Char('h'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Down, KeyModifiers::NONE));
+
+        assert_eq!(handler.buffer(), "h");
+    }
+
+    #[test]
+    fn test_up_arrow_with_empty_history_keeps_buffer() {
+        let mut handler = preloaded_handler(&[]);
+
+        handler.simulate_key(key_event(KeyCode::Char('x'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Up, KeyModifiers::NONE));
+
+        assert_eq!(handler.buffer(), "x");
+    }
+
+    #[test]
+    fn test_alt_enter_inserts_newline_without_submitting() {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+
+        handler.simulate_key(key_event(KeyCode::Char('a'), KeyModifiers::NONE));
+        let action = handler.simulate_key(key_event(KeyCode::Enter, KeyModifiers::ALT));
+        handler.simulate_key(key_event(KeyCode::Char('b'), KeyModifiers::NONE));
+
+        assert_eq!(action, KeyAction::Continue);
+        assert_eq!(handler.buffer(), "a\nb");
+        // Alt+Enter never counts toward the double-enter submit
+        assert!(!handler.last_was_enter());
+    }
+
+    #[test]
+    fn test_up_moves_within_multiline_draft_before_history() {
+        let mut handler = preloaded_handler(&["old entry"]);
+
+        handler.simulate_key(key_event(KeyCode::Char('a'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('b'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Enter, KeyModifiers::ALT));
+        handler.simulate_key(key_event(KeyCode::Char('c'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('d'), KeyModifiers::NONE));
+
+        // Up moves to the first draft line, not into history
+        handler.simulate_key(key_event(KeyCode::Up, KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('x'), KeyModifiers::NONE));
+        assert_eq!(handler.buffer(), "abx\ncd");
+
+        // A second Up from the first line recalls history
+        handler.simulate_key(key_event(KeyCode::Up, KeyModifiers::NONE));
+        assert_eq!(handler.buffer(), "old entry");
+    }
+
+    #[test]
+    fn test_down_moves_within_multiline_draft() {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+
+        handler.simulate_key(key_event(KeyCode::Char('a'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Enter, KeyModifiers::ALT));
+        handler.simulate_key(key_event(KeyCode::Char('b'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Up, KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Down, KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('c'), KeyModifiers::NONE));
+
+        assert_eq!(handler.buffer(), "a\nbc");
+    }
+
+    #[test]
+    fn test_left_right_move_cursor_within_line() {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+
+        handler.simulate_key(key_event(KeyCode::Char('a'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('c'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Left, KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('b'), KeyModifiers::NONE));
+
+        assert_eq!(handler.buffer(), "abc");
+
+        handler.simulate_key(key_event(KeyCode::Right, KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('d'), KeyModifiers::NONE));
+        assert_eq!(handler.buffer(), "abcd");
+    }
+
+    #[test]
+    fn test_backspace_removes_before_cursor() {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+
+        handler.simulate_key(key_event(KeyCode::Char('a'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('b'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('c'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Left, KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Backspace, KeyModifiers::NONE));
+
+        assert_eq!(handler.buffer(), "ac");
+    }
+
+    #[test]
+    fn test_pasted_text_does_not_submit() {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+
+        handler.insert_text("line one\nline two\n");
+
+        // The pasted newlines sit in the buffer; nothing was submitted
+        assert_eq!(handler.buffer(), "line one\nline two\n");
+        assert!(!handler.last_was_enter());
+    }
+
+    #[test]
+    fn test_ctrl_r_search_accepts_match() {
+        let mut handler = preloaded_handler(&["cargo build", "cargo test", "git status"]);
+
+        handler.simulate_key(key_event(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        handler.simulate_key(key_event(KeyCode::Char('g'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('i'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('t'), KeyModifiers::NONE));
+        let action = handler.simulate_key(key_event(KeyCode::Enter, KeyModifiers::NONE));
+
+        // Enter accepts the match into the buffer without submitting
+        assert_eq!(action, KeyAction::Continue);
+        assert_eq!(handler.buffer(), "git status");
+    }
+
+    #[test]
+    fn test_ctrl_r_steps_through_matches() {
+        let mut handler = preloaded_handler(&["cargo build", "cargo test"]);
+
+        handler.simulate_key(key_event(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        for c in "cargo".chars() {
+            handler.simulate_key(key_event(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+        // Most recent match first; a second Ctrl+R steps to the older one
+        handler.simulate_key(key_event(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        handler.simulate_key(key_event(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert_eq!(handler.buffer(), "cargo build");
+    }
+
+    #[test]
+    fn test_ctrl_r_escape_cancels() {
+        let mut handler = preloaded_handler(&["cargo build"]);
+
+        handler.simulate_key(key_event(KeyCode::Char('a'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        handler.simulate_key(key_event(KeyCode::Char('c'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Esc, KeyModifiers::NONE));
+
+        // The draft survives a cancelled search
+        assert_eq!(handler.buffer(), "a");
+    }
+
+    #[test]
+    fn test_ctrl_r_enter_without_match_keeps_buffer() {
+        let mut handler = preloaded_handler(&["cargo build"]);
+
+        handler.simulate_key(key_event(KeyCode::Char('x'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('r'), KeyModifiers::CONTROL));
+        handler.simulate_key(key_event(KeyCode::Char('z'), KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert_eq!(handler.buffer(), "x");
+    }
+
+    #[test]
+    fn test_ctrl_w_deletes_word_before_cursor() {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+
+        handler.insert_text("cargo build --release");
+        handler.simulate_key(key_event(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        assert_eq!(handler.buffer(), "cargo build ");
+
+        handler.simulate_key(key_event(KeyCode::Char('w'), KeyModifiers::CONTROL));
+        assert_eq!(handler.buffer(), "cargo ");
+    }
+
+    #[test]
+    fn test_ctrl_u_clears_draft() {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+
+        handler.insert_text("half-typed thought");
+        handler.simulate_key(key_event(KeyCode::Char('u'), KeyModifiers::CONTROL));
+
+        assert_eq!(handler.buffer(), "");
+    }
+
+    #[test]
+    fn test_home_end_jump_within_line() {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+
+        handler.insert_text("abc");
+        handler.simulate_key(key_event(KeyCode::Home, KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('x'), KeyModifiers::NONE));
+        assert_eq!(handler.buffer(), "xabc");
+
+        handler.simulate_key(key_event(KeyCode::End, KeyModifiers::NONE));
+        handler.simulate_key(key_event(KeyCode::Char('y'), KeyModifiers::NONE));
+        assert_eq!(handler.buffer(), "xabcy");
+    }
+
+    #[test]
+    fn test_ctrl_p_recalls_history() {
+        let mut handler = preloaded_handler(&["cargo build", "cargo test"]);
+
+        handler.simulate_key(key_event(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert_eq!(handler.buffer(), "cargo test");
+
+        handler.simulate_key(key_event(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert_eq!(handler.buffer(), "cargo build");
+
+        handler.simulate_key(key_event(KeyCode::Char('n'), KeyModifiers::CONTROL));
+        assert_eq!(handler.buffer(), "cargo test");
+    }
+
+    #[test]
+    fn test_custom_keymap_rebinds_action() {
+        use super::super::keybindings::{InputAction, KeyChord, KeyMap};
+
+        let mut keymap = KeyMap::default();
+        keymap.bind(
+            InputAction::Clear,
+            KeyChord::new(KeyCode::Char('l'), KeyModifiers::CONTROL),
+        );
+
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+        handler.set_keymap(keymap);
+        handler.insert_text("draft");
+
+        // The old default no longer clears
+        handler.simulate_key(key_event(KeyCode::Char('u'), KeyModifiers::CONTROL));
+        assert_eq!(handler.buffer(), "draft");
+
+        handler.simulate_key(key_event(KeyCode::Char('l'), KeyModifiers::CONTROL));
+        assert_eq!(handler.buffer(), "");
+    }
+
+    fn vim_handler(text: &str) -> InputHandler {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+        handler.set_vim_mode(true);
+        handler.insert_text(text);
+        handler
+    }
+
+    fn press_chars(handler: &mut InputHandler, keys: &str) {
+        for c in keys.chars() {
+            handler.simulate_key(key_event(KeyCode::Char(c), KeyModifiers::NONE));
+        }
+    }
+
+    fn press_esc(handler: &mut InputHandler) {
+        handler.simulate_key(key_event(KeyCode::Esc, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn test_esc_is_noop_without_vim_mode() {
+        let mut handler = InputHandler::with_history(InputHistory::new(10));
+
+        press_chars(&mut handler, "ab");
+        press_esc(&mut handler);
+        press_chars(&mut handler, "c");
+
+        assert_eq!(handler.buffer(), "abc");
+    }
+
+    #[test]
+    fn test_vim_normal_mode_blocks_typing_until_insert() {
+        let mut handler = vim_handler("ab");
+
+        press_esc(&mut handler);
+        press_chars(&mut handler, "z");
+        assert_eq!(handler.buffer(), "ab");
+
+        press_chars(&mut handler, "ic");
+        assert_eq!(handler.buffer(), "abc");
+    }
+
+    #[test]
+    fn test_vim_h_l_motions_and_x() {
+        let mut handler = vim_handler("abc");
+
+        press_esc(&mut handler);
+        press_chars(&mut handler, "hhx");
+        assert_eq!(handler.buffer(), "ac");
+
+        // `x` past the end of the buffer is a no-op
+        press_chars(&mut handler, "lx");
+        assert_eq!(handler.buffer(), "ac");
+    }
+
+    #[test]
+    fn test_vim_word_motions() {
+        let mut handler = vim_handler("foo bar baz");
+
+        press_esc(&mut handler);
+        press_chars(&mut handler, "bbx");
+        assert_eq!(handler.buffer(), "foo ar baz");
+
+        press_chars(&mut handler, "wx");
+        assert_eq!(handler.buffer(), "foo ar az");
+    }
+
+    #[test]
+    fn test_vim_dd_deletes_line() {
+        let mut handler = vim_handler("one\ntwo");
+
+        // Cursor sits on the last line; dd removes it and its newline
+        press_esc(&mut handler);
+        press_chars(&mut handler, "dd");
+        assert_eq!(handler.buffer(), "one");
+
+        press_chars(&mut handler, "dd");
+        assert_eq!(handler.buffer(), "");
+    }
+
+    #[test]
+    fn test_vim_cw_changes_word() {
+        let mut handler = vim_handler("foo bar");
+
+        press_esc(&mut handler);
+        press_chars(&mut handler, "bcw");
+        assert_eq!(handler.buffer(), "foo ");
+
+        // cw drops back into insert mode
+        press_chars(&mut handler, "qux");
+        assert_eq!(handler.buffer(), "foo qux");
+    }
+
+    #[test]
+    fn test_vim_i_and_a_insert_positions() {
+        let mut handler = vim_handler("ab");
+
+        press_esc(&mut handler);
+        press_chars(&mut handler, "hiX");
+        assert_eq!(handler.buffer(), "aXb");
+
+        // `a` appends after the character under the cursor
+        press_esc(&mut handler);
+        press_chars(&mut handler, "aY");
+        assert_eq!(handler.buffer(), "aXbY");
+    }
+
+    #[test]
+    fn test_vim_shift_a_appends_at_line_end() {
+        let mut handler = vim_handler("abc");
+
+        press_esc(&mut handler);
+        press_chars(&mut handler, "hh");
+        handler.simulate_key(key_event(KeyCode::Char('A'), KeyModifiers::SHIFT));
+        press_chars(&mut handler, "!");
+
+        assert_eq!(handler.buffer(), "abc!");
+    }
+
+    #[test]
+    fn test_vim_double_enter_submits_from_normal_mode() {
+        let mut handler = vim_handler("hi");
+
+        press_esc(&mut handler);
+        handler.simulate_key(key_event(KeyCode::Enter, KeyModifiers::NONE));
+        let action = handler.simulate_key(key_event(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert_eq!(action, KeyAction::Submit);
+        assert_eq!(handler.buffer(), "hi");
+    }
+
+    #[test]
+    fn test_vim_unknown_operator_motion_is_ignored() {
+        let mut handler = vim_handler("abc");
+
+        press_esc(&mut handler);
+        press_chars(&mut handler, "dw");
+        assert_eq!(handler.buffer(), "abc");
+    }
+
+    #[test]
+    fn test_next_and_prev_word_start() {
+        let buffer = "foo bar  baz";
+
+        assert_eq!(next_word_start(buffer, 0), 4);
+        assert_eq!(next_word_start(buffer, 4), 9);
+        assert_eq!(next_word_start(buffer, 9), buffer.len());
+
+        assert_eq!(prev_word_start(buffer, buffer.len()), 9);
+        assert_eq!(prev_word_start(buffer, 9), 4);
+        assert_eq!(prev_word_start(buffer, 4), 0);
+        assert_eq!(prev_word_start(buffer, 0), 0);
+    }
+
+    #[test]
+    fn test_line_col_and_back() {
+        let buffer = "ab\ncdef\ng";
+
+        assert_eq!(line_col(buffer, 0), (0, 0));
+        assert_eq!(line_col(buffer, 2), (0, 2));
+        assert_eq!(line_col(buffer, 5), (1, 2));
+        assert_eq!(line_col(buffer, 9), (2, 1));
+
+        assert_eq!(cursor_for_line_col(buffer, 1, 2), 5);
+        // Column clamps to the line length
+        assert_eq!(cursor_for_line_col(buffer, 0, 99), 2);
+        // Line clamps to the buffer end
+        assert_eq!(cursor_for_line_col(buffer, 99, 0), buffer.len());
+    }
+
     #[test]
     fn test_double_enter_detection() {
         let mut handler = InputHandler::new();
@@ -328,6 +1639,18 @@ mod tests {
         assert_eq!(handler.buffer(), "x    y");
     }
 
+    #[test]
+    fn test_shift_tab_toggles_auto_accept() {
+        let mut handler = InputHandler::new();
+
+        // Shift+Tab arrives as BackTab and surfaces the toggle to the REPL
+        let action = handler.simulate_key(key_event(KeyCode::BackTab, KeyModifiers::SHIFT));
+        assert_eq!(action, KeyAction::ToggleAutoAccept);
+
+        // The draft buffer is untouched
+        assert_eq!(handler.buffer(), "");
+    }
+
     #[test]
     fn test_enter_after_text_resets_on_other_input() {
         let mut handler = InputHandler::new();
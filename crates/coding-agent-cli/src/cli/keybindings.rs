@@ -0,0 +1,412 @@
+//! Configurable keybindings for the input handler
+//!
+//! Input actions (submit, delete-word, open-editor, ...) are bound to key
+//! chords like `ctrl+w` or `alt+enter`. The defaults are emacs-style; the
+//! `[keybindings]` config table overrides individual actions and is
+//! validated at load time, rejecting unknown actions and unparsable chords.
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::fmt;
+
+/// An input-handler action that can be bound to a key chord
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputAction {
+    /// Add a newline, or submit on double-press
+    Submit,
+    /// Insert a newline without ever submitting
+    Newline,
+    /// Clear the current draft
+    Clear,
+    /// Cancel the current input
+    Cancel,
+    /// Recall the previous history entry
+    HistoryPrev,
+    /// Step forward through history
+    HistoryNext,
+    /// Delete the word before the cursor
+    DeleteWord,
+    /// Edit the draft in $EDITOR
+    OpenEditor,
+    /// Open the fuzzy file finder
+    FileFinder,
+}
+
+impl InputAction {
+    /// All bindable actions, in display order
+    pub const ALL: [InputAction; 9] = [
+        InputAction::Submit,
+        InputAction::Newline,
+        InputAction::Clear,
+        InputAction::Cancel,
+        InputAction::HistoryPrev,
+        InputAction::HistoryNext,
+        InputAction::DeleteWord,
+        InputAction::OpenEditor,
+        InputAction::FileFinder,
+    ];
+
+    /// The config-table name for this action
+    pub fn name(&self) -> &'static str {
+        match self {
+            InputAction::Submit => "submit",
+            InputAction::Newline => "newline",
+            InputAction::Clear => "clear",
+            InputAction::Cancel => "cancel",
+            InputAction::HistoryPrev => "history-prev",
+            InputAction::HistoryNext => "history-next",
+            InputAction::DeleteWord => "delete-word",
+            InputAction::OpenEditor => "open-editor",
+            InputAction::FileFinder => "file-finder",
+        }
+    }
+
+    /// Look up an action by its config-table name
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|action| action.name() == name)
+    }
+
+    /// Short description for the /keys listing
+    pub fn description(&self) -> &'static str {
+        match self {
+            InputAction::Submit => "Add newline; submit on double-press",
+            InputAction::Newline => "Insert newline (never submits)",
+            InputAction::Clear => "Clear the current draft",
+            InputAction::Cancel => "Cancel the current input",
+            InputAction::HistoryPrev => "Recall previous history entry",
+            InputAction::HistoryNext => "Step forward through history",
+            InputAction::DeleteWord => "Delete the word before the cursor",
+            InputAction::OpenEditor => "Edit the draft in $EDITOR",
+            InputAction::FileFinder => "Insert a file path via the fuzzy finder",
+        }
+    }
+}
+
+/// A key plus modifiers, parsed from a chord string like `ctrl+w`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Create a chord directly (used for the defaults)
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Parse a chord string: modifiers joined with `+`, key last
+    ///
+    /// Modifiers: `ctrl`, `alt`, `shift`. Keys: a single character or a
+    /// named key (`enter`, `tab`, `esc`, `space`, `backspace`, `delete`,
+    /// `up`, `down`, `left`, `right`, `home`, `end`).
+    pub fn parse(chord: &str) -> Result<Self, String> {
+        let chord = chord.trim().to_lowercase();
+        let mut parts: Vec<&str> = chord.split('+').collect();
+        let key = parts
+            .pop()
+            .filter(|k| !k.is_empty())
+            .ok_or_else(|| format!("Unparsable key chord: \"{}\"", chord))?;
+
+        let mut modifiers = KeyModifiers::NONE;
+        for part in parts {
+            match part {
+                "ctrl" | "control" => modifiers |= KeyModifiers::CONTROL,
+                "alt" | "meta" => modifiers |= KeyModifiers::ALT,
+                "shift" => modifiers |= KeyModifiers::SHIFT,
+                other => {
+                    return Err(format!(
+                        "Unknown modifier \"{}\" in key chord \"{}\"",
+                        other, chord
+                    ))
+                }
+            }
+        }
+
+        let code = match key {
+            "enter" | "return" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "esc" | "escape" => KeyCode::Esc,
+            "space" => KeyCode::Char(' '),
+            "backspace" => KeyCode::Backspace,
+            "delete" | "del" => KeyCode::Delete,
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            key if key.chars().count() == 1 => KeyCode::Char(key.chars().next().unwrap()),
+            other => {
+                return Err(format!(
+                    "Unknown key \"{}\" in key chord \"{}\"",
+                    other, chord
+                ))
+            }
+        };
+
+        Ok(Self { code, modifiers })
+    }
+
+    /// Check whether a key event matches this chord
+    pub fn matches(&self, event: &KeyEvent) -> bool {
+        event.code == self.code && event.modifiers == self.modifiers
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c.to_uppercase()),
+            KeyCode::Enter => write!(f, "Enter"),
+            KeyCode::Tab => write!(f, "Tab"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::Backspace => write!(f, "Backspace"),
+            KeyCode::Delete => write!(f, "Delete"),
+            KeyCode::Up => write!(f, "Up"),
+            KeyCode::Down => write!(f, "Down"),
+            KeyCode::Left => write!(f, "Left"),
+            KeyCode::Right => write!(f, "Right"),
+            KeyCode::Home => write!(f, "Home"),
+            KeyCode::End => write!(f, "End"),
+            other => write!(f, "{:?}", other),
+        }
+    }
+}
+
+/// The active action → chord bindings
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    /// One chord per action, in `InputAction::ALL` order
+    bindings: Vec<(InputAction, KeyChord)>,
+}
+
+impl KeyMap {
+    /// Build a keymap from config overrides on top of the defaults
+    ///
+    /// Fails on unknown action names or unparsable chords so a typo in the
+    /// config surfaces instead of silently leaving a key unbound.
+    pub fn from_config(config: &crate::config::KeybindingsConfig) -> Result<Self, String> {
+        let mut keymap = Self::default();
+
+        for (name, chord) in &config.bindings {
+            let action = InputAction::from_name(name).ok_or_else(|| {
+                let known: Vec<&str> = InputAction::ALL.iter().map(|a| a.name()).collect();
+                format!(
+                    "Unknown keybinding action \"{}\" (expected one of: {})",
+                    name,
+                    known.join(", ")
+                )
+            })?;
+            let chord = KeyChord::parse(chord)?;
+            keymap.bind(action, chord);
+        }
+
+        Ok(keymap)
+    }
+
+    /// Bind an action to a chord, replacing its previous binding
+    pub fn bind(&mut self, action: InputAction, chord: KeyChord) {
+        for binding in &mut self.bindings {
+            if binding.0 == action {
+                binding.1 = chord;
+                return;
+            }
+        }
+        self.bindings.push((action, chord));
+    }
+
+    /// Find the action bound to a key event, if any
+    pub fn action_for(&self, event: &KeyEvent) -> Option<InputAction> {
+        self.bindings
+            .iter()
+            .find(|(_, chord)| chord.matches(event))
+            .map(|(action, _)| *action)
+    }
+
+    /// Get the chord bound to an action
+    pub fn chord_for(&self, action: InputAction) -> KeyChord {
+        self.bindings
+            .iter()
+            .find(|(a, _)| *a == action)
+            .map(|(_, chord)| *chord)
+            .expect("every action has a default binding")
+    }
+
+    /// The bindings in display order (for the /keys listing)
+    pub fn entries(&self) -> impl Iterator<Item = (InputAction, KeyChord)> + '_ {
+        self.bindings.iter().copied()
+    }
+}
+
+impl Default for KeyMap {
+    /// Emacs-style defaults
+    fn default() -> Self {
+        Self {
+            bindings: vec![
+                (
+                    InputAction::Submit,
+                    KeyChord::new(KeyCode::Enter, KeyModifiers::NONE),
+                ),
+                (
+                    InputAction::Newline,
+                    KeyChord::new(KeyCode::Enter, KeyModifiers::ALT),
+                ),
+                (
+                    InputAction::Clear,
+                    KeyChord::new(KeyCode::Char('u'), KeyModifiers::CONTROL),
+                ),
+                (
+                    InputAction::Cancel,
+                    KeyChord::new(KeyCode::Char('c'), KeyModifiers::CONTROL),
+                ),
+                (
+                    InputAction::HistoryPrev,
+                    KeyChord::new(KeyCode::Char('p'), KeyModifiers::CONTROL),
+                ),
+                (
+                    InputAction::HistoryNext,
+                    KeyChord::new(KeyCode::Char('n'), KeyModifiers::CONTROL),
+                ),
+                (
+                    InputAction::DeleteWord,
+                    KeyChord::new(KeyCode::Char('w'), KeyModifiers::CONTROL),
+                ),
+                (
+                    InputAction::OpenEditor,
+                    KeyChord::new(KeyCode::Char('e'), KeyModifiers::CONTROL),
+                ),
+                (
+                    InputAction::FileFinder,
+                    KeyChord::new(KeyCode::Char('t'), KeyModifiers::CONTROL),
+                ),
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chord_with_modifiers() {
+        let chord = KeyChord::parse("ctrl+w").expect("Should parse");
+        assert_eq!(
+            chord,
+            KeyChord::new(KeyCode::Char('w'), KeyModifiers::CONTROL)
+        );
+
+        let chord = KeyChord::parse("alt+enter").expect("Should parse");
+        assert_eq!(chord, KeyChord::new(KeyCode::Enter, KeyModifiers::ALT));
+
+        let chord = KeyChord::parse("Ctrl+Shift+P").expect("Should parse case-insensitively");
+        assert_eq!(
+            chord,
+            KeyChord::new(
+                KeyCode::Char('p'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_named_keys() {
+        assert_eq!(
+            KeyChord::parse("home").expect("Should parse"),
+            KeyChord::new(KeyCode::Home, KeyModifiers::NONE)
+        );
+        assert_eq!(
+            KeyChord::parse("esc").expect("Should parse"),
+            KeyChord::new(KeyCode::Esc, KeyModifiers::NONE)
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_chords() {
+        assert!(KeyChord::parse("").is_err());
+        assert!(KeyChord::parse("ctrl+").is_err());
+        assert!(KeyChord::parse("hyper+w").is_err());
+        assert!(KeyChord::parse("ctrl+pageup").is_err());
+    }
+
+    #[test]
+    fn test_chord_display() {
+        let chord = KeyChord::new(KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(chord.to_string(), "Ctrl+W");
+
+        let chord = KeyChord::new(KeyCode::Enter, KeyModifiers::ALT);
+        assert_eq!(chord.to_string(), "Alt+Enter");
+    }
+
+    #[test]
+    fn test_action_name_roundtrip() {
+        for action in InputAction::ALL {
+            assert_eq!(InputAction::from_name(action.name()), Some(action));
+        }
+        assert_eq!(InputAction::from_name("frobnicate"), None);
+    }
+
+    #[test]
+    fn test_keymap_default_lookups() {
+        let keymap = KeyMap::default();
+
+        let event = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::CONTROL);
+        assert_eq!(keymap.action_for(&event), Some(InputAction::DeleteWord));
+
+        let event = KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE);
+        assert_eq!(keymap.action_for(&event), None);
+    }
+
+    #[test]
+    fn test_keymap_from_config_overrides() {
+        let mut config = crate::config::KeybindingsConfig::default();
+        config
+            .bindings
+            .insert("delete-word".to_string(), "alt+backspace".to_string());
+
+        let keymap = KeyMap::from_config(&config).expect("Should build keymap");
+
+        assert_eq!(
+            keymap.chord_for(InputAction::DeleteWord),
+            KeyChord::new(KeyCode::Backspace, KeyModifiers::ALT)
+        );
+        // Untouched actions keep their defaults
+        assert_eq!(
+            keymap.chord_for(InputAction::Clear),
+            KeyChord::new(KeyCode::Char('u'), KeyModifiers::CONTROL)
+        );
+    }
+
+    #[test]
+    fn test_keymap_from_config_rejects_unknown_action() {
+        let mut config = crate::config::KeybindingsConfig::default();
+        config
+            .bindings
+            .insert("teleport".to_string(), "ctrl+t".to_string());
+
+        let err = KeyMap::from_config(&config).expect_err("Should reject");
+        assert!(err.contains("Unknown keybinding action"));
+        assert!(err.contains("teleport"));
+    }
+
+    #[test]
+    fn test_keymap_from_config_rejects_bad_chord() {
+        let mut config = crate::config::KeybindingsConfig::default();
+        config
+            .bindings
+            .insert("clear".to_string(), "hyper+u".to_string());
+
+        let err = KeyMap::from_config(&config).expect_err("Should reject");
+        assert!(err.contains("hyper"));
+    }
+}
@@ -0,0 +1,96 @@
+//! Structured logging via `tracing`
+//!
+//! Replaces the old `eprintln!("[verbose] ...")` pattern. The level comes
+//! from `[log]` in the config (`--verbose` raises it to at least debug),
+//! and output goes to stderr or, with `log.file` / `--log-file`, to a file
+//! for post-hoc debugging.
+
+use std::path::Path;
+use tracing::level_filters::LevelFilter;
+
+pub struct Logger;
+
+impl Logger {
+    /// Parse a config level string (`off`, `error`, `warn`, `info`,
+    /// `debug`, `trace`)
+    pub fn parse_level(level: &str) -> Result<LevelFilter, String> {
+        match level.to_lowercase().as_str() {
+            "off" => Ok(LevelFilter::OFF),
+            "error" => Ok(LevelFilter::ERROR),
+            "warn" => Ok(LevelFilter::WARN),
+            "info" => Ok(LevelFilter::INFO),
+            "debug" => Ok(LevelFilter::DEBUG),
+            "trace" => Ok(LevelFilter::TRACE),
+            other => Err(format!(
+                "Unknown log level: \"{}\" (expected off, error, warn, info, debug, or trace)",
+                other
+            )),
+        }
+    }
+
+    /// Install the global subscriber
+    ///
+    /// `verbose` raises the configured level to at least debug. Fails if a
+    /// subscriber is already installed or the log file cannot be opened.
+    pub fn init(level: &str, file: Option<&Path>, verbose: bool) -> Result<(), String> {
+        let mut filter = Self::parse_level(level)?;
+        if verbose && filter < LevelFilter::DEBUG {
+            filter = LevelFilter::DEBUG;
+        }
+
+        let builder = tracing_subscriber::fmt()
+            .with_max_level(filter)
+            .with_ansi(false)
+            .with_target(false);
+
+        match file {
+            Some(path) => {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)
+                        .map_err(|e| format!("Failed to create log directory: {}", e))?;
+                }
+                let file = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .map_err(|e| format!("Failed to open log file: {}", e))?;
+                builder
+                    .with_writer(std::sync::Mutex::new(file))
+                    .try_init()
+                    .map_err(|e| format!("Failed to install logger: {}", e))
+            }
+            None => builder
+                .with_writer(std::io::stderr)
+                .try_init()
+                .map_err(|e| format!("Failed to install logger: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_level_accepts_all_levels() {
+        assert_eq!(Logger::parse_level("off"), Ok(LevelFilter::OFF));
+        assert_eq!(Logger::parse_level("error"), Ok(LevelFilter::ERROR));
+        assert_eq!(Logger::parse_level("warn"), Ok(LevelFilter::WARN));
+        assert_eq!(Logger::parse_level("info"), Ok(LevelFilter::INFO));
+        assert_eq!(Logger::parse_level("debug"), Ok(LevelFilter::DEBUG));
+        assert_eq!(Logger::parse_level("trace"), Ok(LevelFilter::TRACE));
+    }
+
+    #[test]
+    fn test_parse_level_is_case_insensitive() {
+        assert_eq!(Logger::parse_level("DEBUG"), Ok(LevelFilter::DEBUG));
+        assert_eq!(Logger::parse_level("Warn"), Ok(LevelFilter::WARN));
+    }
+
+    #[test]
+    fn test_parse_level_rejects_unknown() {
+        let err = Logger::parse_level("loud").expect_err("Should reject");
+        assert!(err.contains("loud"));
+        assert!(err.contains("expected"));
+    }
+}
@@ -3,28 +3,73 @@
 //! This module provides the main entry point for the CLI, including
 //! terminal handling, input processing, and the REPL loop.
 
+pub mod auth;
 pub mod commands;
+mod debug_log;
+pub(crate) mod diffstat;
+mod environment;
 mod input;
+pub(crate) mod keybindings;
+mod logger;
 pub mod modes;
+pub(crate) mod plan;
 mod repl;
+pub(crate) mod review;
+pub(crate) mod search;
+mod setup;
+mod shutdown;
 mod startup;
 mod terminal;
 
 use commands::Command;
 
-pub use input::InputHandler;
+pub use input::{InputHandler, InputHistory};
+pub use logger::Logger;
 pub use modes::Mode;
+pub(crate) use repl::truncate_for_display;
 pub use repl::{Repl, ReplConfig};
+pub use setup::{resolve_api_key, SetupWizard};
 pub use startup::{StartupOption, StartupScreen};
 pub use terminal::Terminal;
 
 /// Run the CLI application
-pub async fn run(verbose: bool) -> Result<(), String> {
-    run_with_startup(verbose, true).await
+#[allow(clippy::too_many_arguments)]
+pub async fn run(
+    verbose: bool,
+    tool_denylist: Vec<String>,
+    initial_message: Option<String>,
+    non_interactive: bool,
+    dry_run: bool,
+    debug_log: Option<std::path::PathBuf>,
+    model: Option<String>,
+) -> Result<(), String> {
+    // An initial message from the command line skips the startup screen
+    let show_startup = initial_message.is_none();
+    run_with_startup(
+        verbose,
+        show_startup,
+        tool_denylist,
+        initial_message,
+        non_interactive,
+        dry_run,
+        debug_log,
+        model,
+    )
+    .await
 }
 
 /// Run the CLI application with optional startup screen
-pub async fn run_with_startup(verbose: bool, show_startup: bool) -> Result<(), String> {
+#[allow(clippy::too_many_arguments)]
+pub async fn run_with_startup(
+    verbose: bool,
+    show_startup: bool,
+    tool_denylist: Vec<String>,
+    initial_message: Option<String>,
+    non_interactive: bool,
+    dry_run: bool,
+    debug_log: Option<std::path::PathBuf>,
+    model: Option<String>,
+) -> Result<(), String> {
     use crate::integrations::SessionManager;
     use std::path::PathBuf;
 
@@ -45,9 +90,7 @@ pub async fn run_with_startup(verbose: bool, show_startup: bool) -> Result<(), S
 
     terminal.enable_raw_mode().map_err(|e| e.to_string())?;
 
-    if verbose {
-        eprintln!("[verbose] Terminal initialized in raw mode");
-    }
+    tracing::debug!("Terminal initialized in raw mode");
 
     // Create session manager for startup screen
     let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
@@ -106,8 +149,21 @@ pub async fn run_with_startup(verbose: bool, show_startup: bool) -> Result<(), S
         _ => {}
     }
 
+    // The startup screen can also hand us a seeded new session
+    let initial_message =
+        initial_message.or_else(|| match startup_result.as_ref().map(|r| &r.option) {
+            Some(StartupOption::NewSession { initial_message }) => initial_message.clone(),
+            _ => None,
+        });
+
     let config = ReplConfig {
         verbose,
+        tool_denylist,
+        initial_message,
+        non_interactive,
+        dry_run,
+        debug_log_path: debug_log,
+        model,
         ..ReplConfig::default()
     };
     let mut repl = Repl::new(config);
@@ -117,8 +173,8 @@ pub async fn run_with_startup(verbose: bool, show_startup: bool) -> Result<(), S
     {
         if let Err(e) = repl.load_session(filename) {
             eprintln!("Warning: Failed to load session: {}", e);
-        } else if verbose {
-            eprintln!("[verbose] Resumed session from: {}", filename);
+        } else {
+            tracing::info!(filename = %filename, "Resumed session");
         }
     }
 
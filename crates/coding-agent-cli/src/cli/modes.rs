@@ -1,14 +1,22 @@
-//! CLI modes - normal conversation vs planning mode
+//! CLI modes - normal conversation, planning mode, and review mode
 //!
 //! Modes affect how the AI agent behaves and what prompts it receives.
 
+use std::path::PathBuf;
+
 /// The current mode of the CLI
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum Mode {
     /// Normal conversation mode - general purpose assistant
+    #[default]
     Normal,
     /// Planning mode - collaborative spec writing and design
     Planning { spec_file: String },
+    /// Review mode - code review of specific files against a checklist
+    Review {
+        files: Vec<PathBuf>,
+        checklist: Vec<String>,
+    },
 }
 
 impl Mode {
@@ -17,16 +25,26 @@ impl Mode {
         Mode::Planning { spec_file }
     }
 
+    /// Create a new review mode for the given files and checklist
+    pub fn review(files: Vec<PathBuf>, checklist: Vec<String>) -> Self {
+        Mode::Review { files, checklist }
+    }
+
     /// Check if currently in planning mode
     pub fn is_planning(&self) -> bool {
         matches!(self, Mode::Planning { .. })
     }
 
+    /// Check if currently in review mode
+    pub fn is_review(&self) -> bool {
+        matches!(self, Mode::Review { .. })
+    }
+
     /// Get the spec file if in planning mode
     pub fn spec_file(&self) -> Option<&str> {
         match self {
             Mode::Planning { spec_file } => Some(spec_file),
-            Mode::Normal => None,
+            _ => None,
         }
     }
 
@@ -73,6 +91,45 @@ Remember: You're helping the user think through their design, not designing it f
                     spec_file
                 )
             }
+            Mode::Review { files, checklist } => {
+                let file_list = files
+                    .iter()
+                    .map(|f| format!("- {}", f.display()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let checklist_items = checklist
+                    .iter()
+                    .map(|item| format!("- {}", item))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!(
+                    r#"You are an experienced code reviewer.
+
+The user has asked you to review these files:
+{}
+
+Review checklist:
+{}
+
+Your role in review mode is to:
+- Identify bugs, security issues, and correctness problems
+- Suggest concrete improvements, not vague advice
+- Work through every checklist item for every file
+- Acknowledge what the code does well, briefly
+
+Flag each issue on its own line in exactly this format, so findings can be collected:
+
+[CRITICAL] <file>:<line> — <what is wrong and why it matters>
+[WARNING] <file>:<line> — <likely problem>
+[SUGGESTION] <file>:<line> — <improvement>
+
+Use CRITICAL for bugs, data loss, and security holes; WARNING for likely
+problems like swallowed errors; SUGGESTION for style and structure. Omit
+`:<line>` when a finding applies to a whole file. Surrounding prose is
+fine — only tagged lines are collected as findings."#,
+                    file_list, checklist_items
+                )
+            }
         }
     }
 
@@ -81,16 +138,11 @@ Remember: You're helping the user think through their design, not designing it f
         match self {
             Mode::Normal => None,
             Mode::Planning { spec_file } => Some(format!("📋 Planning: {}", spec_file)),
+            Mode::Review { .. } => Some("[review]".to_string()),
         }
     }
 }
 
-impl Default for Mode {
-    fn default() -> Self {
-        Mode::Normal
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,6 +185,25 @@ mod tests {
         assert!(planning_prompt.contains("collaborative"));
     }
 
+    #[test]
+    fn test_mode_review() {
+        let review = Mode::review(
+            vec![PathBuf::from("src/auth.rs")],
+            vec!["Correctness".to_string()],
+        );
+        assert!(review.is_review());
+        assert!(!review.is_planning());
+        assert_eq!(review.spec_file(), None);
+
+        let prompt = review.system_prompt();
+        assert!(prompt.contains("code reviewer"));
+        assert!(prompt.contains("src/auth.rs"));
+        assert!(prompt.contains("- Correctness"));
+        assert!(prompt.contains("[CRITICAL]"));
+
+        assert_eq!(review.indicator(), Some("[review]".to_string()));
+    }
+
     #[test]
     fn test_mode_indicator() {
         let normal = Mode::Normal;
@@ -0,0 +1,294 @@
+//! Plan artifacts produced by planning mode
+//!
+//! Planning mode used to be prompt-only: the plan the model settled on
+//! evaporated into chat history. A [`Plan`] captures it as ordered steps
+//! (with the files each step touches), persists it under
+//! `.agent/plans/<slug>.md`, and tracks per-step completion as the agent
+//! declares steps done via the `update_plan` tool.
+
+use crate::integrations::specstory::slugify;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Directory plans are written to, relative to the working directory
+pub const PLANS_DIR: &str = ".agent/plans";
+
+/// One step of a plan
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanStep {
+    /// What the step does, as the model wrote it
+    pub title: String,
+    /// File paths the step names (backticked paths in the step text)
+    pub files: Vec<String>,
+    /// Whether the agent has declared this step done
+    pub done: bool,
+}
+
+/// An approvable, persistent plan with ordered steps
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plan {
+    /// Plan title, used for the slug and the checklist header
+    pub title: String,
+    /// Ordered steps
+    pub steps: Vec<PlanStep>,
+    /// Where the plan is saved (set by [`Plan::save`])
+    pub file_path: Option<PathBuf>,
+}
+
+impl Plan {
+    /// Extract a plan from the model's response text.
+    ///
+    /// List items (`-`, `*`, or `1.` style) become steps; backticked
+    /// paths in a step become its file targets. Responses with fewer
+    /// than two list items are not plans.
+    pub fn from_response(title: &str, text: &str) -> Option<Self> {
+        let mut steps = Vec::new();
+        for line in text.lines() {
+            let Some(item) = list_item(line) else {
+                continue;
+            };
+            let item = item
+                .trim_start_matches("[ ]")
+                .trim_start_matches("[x]")
+                .trim();
+            if item.is_empty() {
+                continue;
+            }
+            steps.push(PlanStep {
+                title: item.to_string(),
+                files: backticked_paths(item),
+                done: false,
+            });
+        }
+
+        if steps.len() < 2 {
+            return None;
+        }
+        Some(Self {
+            title: title.to_string(),
+            steps,
+            file_path: None,
+        })
+    }
+
+    /// Serialize the plan as a markdown checklist
+    pub fn to_markdown(&self) -> String {
+        let mut md = format!("# {}\n\n", self.title);
+        for step in &self.steps {
+            md.push_str(&format!(
+                "- [{}] {}\n",
+                if step.done { 'x' } else { ' ' },
+                step.title
+            ));
+        }
+        md
+    }
+
+    /// Parse a plan back from its markdown checklist form
+    pub fn from_markdown(content: &str) -> Option<Self> {
+        let mut title = String::new();
+        let mut steps = Vec::new();
+
+        for line in content.lines() {
+            if let Some(heading) = line.strip_prefix("# ") {
+                if title.is_empty() {
+                    title = heading.trim().to_string();
+                }
+                continue;
+            }
+            let Some(item) = line.trim_start().strip_prefix("- ") else {
+                continue;
+            };
+            let (done, rest) = if let Some(rest) = item.strip_prefix("[x] ") {
+                (true, rest)
+            } else if let Some(rest) = item.strip_prefix("[ ] ") {
+                (false, rest)
+            } else {
+                (false, item)
+            };
+            steps.push(PlanStep {
+                title: rest.trim().to_string(),
+                files: backticked_paths(rest),
+                done,
+            });
+        }
+
+        if steps.is_empty() {
+            return None;
+        }
+        Some(Self {
+            title,
+            steps,
+            file_path: None,
+        })
+    }
+
+    /// Write the plan under `{base_dir}/.agent/plans/<slug>.md`.
+    ///
+    /// Re-saving an already-saved plan overwrites its existing file, so
+    /// step ticks persist.
+    pub fn save(&mut self, base_dir: &Path) -> Result<PathBuf, String> {
+        let path = match &self.file_path {
+            Some(path) => path.clone(),
+            None => {
+                let dir = base_dir.join(PLANS_DIR);
+                fs::create_dir_all(&dir)
+                    .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+                dir.join(format!("{}.md", slugify(&self.title)))
+            }
+        };
+
+        fs::write(&path, self.to_markdown())
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        self.file_path = Some(path.clone());
+        Ok(path)
+    }
+
+    /// Mark a step (1-based) done or not done; returns its title
+    pub fn mark_done(&mut self, step: usize, done: bool) -> Result<String, String> {
+        let total = self.steps.len();
+        let step = self
+            .steps
+            .get_mut(step.wrapping_sub(1))
+            .ok_or_else(|| format!("No step {} — the plan has {} steps", step, total))?;
+        step.done = done;
+        Ok(step.title.clone())
+    }
+
+    /// How many steps are done, out of how many
+    pub fn progress(&self) -> (usize, usize) {
+        let done = self.steps.iter().filter(|s| s.done).count();
+        (done, self.steps.len())
+    }
+
+    /// Whether every step is done
+    pub fn is_complete(&self) -> bool {
+        self.steps.iter().all(|s| s.done)
+    }
+
+    /// Render the plan as a ticked checklist for the status area
+    pub fn render_checklist(&self) -> String {
+        let (done, total) = self.progress();
+        let mut lines = vec![format!("Plan: {} ({}/{} done)", self.title, done, total)];
+        for (index, step) in self.steps.iter().enumerate() {
+            lines.push(format!(
+                "  {} {}. {}",
+                if step.done { "✓" } else { "○" },
+                index + 1,
+                step.title
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Strip a markdown list marker (`- `, `* `, or `1.` style) from a line
+fn list_item(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    if let Some(item) = trimmed
+        .strip_prefix("- ")
+        .or_else(|| trimmed.strip_prefix("* "))
+    {
+        return Some(item.trim());
+    }
+
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = &trimmed[digits..];
+    rest.strip_prefix(". ")
+        .or_else(|| rest.strip_prefix(") "))
+        .map(str::trim)
+}
+
+/// Backticked spans that look like file paths (a slash or an extension)
+fn backticked_paths(text: &str) -> Vec<String> {
+    text.split('`')
+        .skip(1)
+        .step_by(2)
+        .filter(|span| span.contains('/') || Path::new(span).extension().is_some())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const RESPONSE: &str = "Here is the plan:\n\n\
+        1. Add the parser in `src/parser.rs`\n\
+        2. Wire it into `src/main.rs` and `src/lib.rs`\n\
+        - Update the docs\n\n\
+        Shall we proceed?";
+
+    #[test]
+    fn test_from_response_extracts_steps_and_files() {
+        let plan = Plan::from_response("Parser work", RESPONSE).expect("Should parse a plan");
+
+        assert_eq!(plan.title, "Parser work");
+        assert_eq!(plan.steps.len(), 3);
+        assert_eq!(plan.steps[0].files, vec!["src/parser.rs"]);
+        assert_eq!(plan.steps[1].files, vec!["src/main.rs", "src/lib.rs"]);
+        assert!(plan.steps[2].files.is_empty());
+        assert!(!plan.steps[0].done);
+    }
+
+    #[test]
+    fn test_from_response_rejects_non_plans() {
+        assert!(Plan::from_response("Chat", "Sure, sounds good!").is_none());
+        assert!(Plan::from_response("Chat", "- a single bullet").is_none());
+    }
+
+    #[test]
+    fn test_markdown_roundtrip_preserves_ticks() {
+        let mut plan = Plan::from_response("Roundtrip", RESPONSE).unwrap();
+        plan.mark_done(2, true).unwrap();
+
+        let parsed = Plan::from_markdown(&plan.to_markdown()).expect("Should parse back");
+
+        assert_eq!(parsed.title, "Roundtrip");
+        assert_eq!(parsed.steps.len(), 3);
+        assert!(!parsed.steps[0].done);
+        assert!(parsed.steps[1].done);
+    }
+
+    #[test]
+    fn test_save_writes_under_plans_dir() {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let mut plan = Plan::from_response("Auth flow rework", RESPONSE).unwrap();
+
+        let path = plan.save(temp_dir.path()).expect("Should save");
+
+        assert!(path.ends_with(".agent/plans/auth-flow-rework.md"));
+        assert!(path.exists());
+        assert_eq!(plan.file_path, Some(path));
+    }
+
+    #[test]
+    fn test_mark_done_and_progress() {
+        let mut plan = Plan::from_response("Progress", RESPONSE).unwrap();
+        assert_eq!(plan.progress(), (0, 3));
+        assert!(!plan.is_complete());
+
+        let title = plan.mark_done(1, true).unwrap();
+        assert!(title.contains("parser"));
+        assert_eq!(plan.progress(), (1, 3));
+
+        assert!(plan.mark_done(4, true).is_err());
+        assert!(plan.mark_done(0, true).is_err());
+    }
+
+    #[test]
+    fn test_render_checklist_ticks_steps() {
+        let mut plan = Plan::from_response("Checklist", RESPONSE).unwrap();
+        plan.mark_done(1, true).unwrap();
+
+        let checklist = plan.render_checklist();
+
+        assert!(checklist.contains("Plan: Checklist (1/3 done)"));
+        assert!(checklist.contains("✓ 1."));
+        assert!(checklist.contains("○ 2."));
+    }
+}
@@ -0,0 +1,294 @@
+//! Review findings produced by review mode
+//!
+//! Review mode asks the model to flag each issue on its own line as
+//! `[SEVERITY] file:line — message`. [`parse_findings`] lifts those
+//! lines out of the response text so the REPL can accumulate them
+//! across turns, and [`ReviewFormatter`] renders the collection grouped
+//! by severity and file — compactly after each review turn, and as a
+//! Markdown report for `/review report`.
+
+/// How serious a review finding is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// Bugs, data loss, security holes — must be fixed
+    Critical,
+    /// Likely problems: missing error handling, races, dead code
+    Warning,
+    /// Style, naming, and structure improvements
+    Suggestion,
+}
+
+impl Severity {
+    /// Every severity, in display order (most serious first)
+    pub const ALL: &'static [Severity] =
+        &[Severity::Critical, Severity::Warning, Severity::Suggestion];
+
+    /// Display label, as used for grouping headers
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Critical => "Critical",
+            Severity::Warning => "Warning",
+            Severity::Suggestion => "Suggestion",
+        }
+    }
+
+    /// Parse the bracketed tag the model emits, case-insensitively
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label.to_lowercase().as_str() {
+            "critical" => Some(Severity::Critical),
+            "warning" => Some(Severity::Warning),
+            "suggestion" => Some(Severity::Suggestion),
+            _ => None,
+        }
+    }
+}
+
+/// One issue the reviewer flagged
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReviewFinding {
+    /// How serious the finding is
+    pub severity: Severity,
+    /// File the finding is about; None when the model omitted it
+    pub file: Option<String>,
+    /// Line number within the file, when given
+    pub line: Option<usize>,
+    /// What the reviewer said
+    pub message: String,
+}
+
+/// Checklist applied when `/review` is run without a custom one
+pub fn default_checklist() -> Vec<String> {
+    [
+        "Correctness: logic errors, edge cases, and off-by-one mistakes",
+        "Error handling: failures surfaced, not swallowed or unwrapped",
+        "Clarity: naming, structure, and comments that match the code",
+        "Tests: behavior changes covered, existing tests still honest",
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect()
+}
+
+/// Extract findings from a review-mode response.
+///
+/// A finding line starts with a bracketed severity tag, optionally
+/// followed by a `file` or `file:line` location, then the message after
+/// a dash or em-dash. Lines without a recognized tag are ordinary
+/// review prose and are skipped.
+pub fn parse_findings(text: &str) -> Vec<ReviewFinding> {
+    text.lines().filter_map(parse_finding_line).collect()
+}
+
+/// Parse one `[SEVERITY] file:line — message` line, if it is one
+fn parse_finding_line(line: &str) -> Option<ReviewFinding> {
+    // Findings may arrive as list items; strip the marker first
+    let trimmed = line.trim().trim_start_matches(['-', '*']).trim_start();
+    let rest = trimmed.strip_prefix('[')?;
+    let (tag, rest) = rest.split_once(']')?;
+    let severity = Severity::from_label(tag.trim())?;
+
+    let rest = rest.trim_start();
+    let (location, message) = split_location(rest);
+    let message = message
+        .trim_start_matches(['—', '-', ':'])
+        .trim()
+        .to_string();
+    if message.is_empty() {
+        return None;
+    }
+
+    let (file, line) = match location {
+        Some(location) => parse_location(location),
+        None => (None, None),
+    };
+    Some(ReviewFinding {
+        severity,
+        file,
+        line,
+        message,
+    })
+}
+
+/// Split the text after the tag into an optional location token and the
+/// message. The first word is a location when it looks like a path.
+fn split_location(text: &str) -> (Option<&str>, &str) {
+    let Some((first, rest)) = text.split_once(char::is_whitespace) else {
+        return (None, text);
+    };
+    if first.contains('/') || first.contains('.') {
+        (Some(first), rest)
+    } else {
+        (None, text)
+    }
+}
+
+/// Split a `file:line` location into its parts
+fn parse_location(location: &str) -> (Option<String>, Option<usize>) {
+    if let Some((file, line)) = location.rsplit_once(':') {
+        if let Ok(line) = line.parse::<usize>() {
+            return (Some(file.to_string()), Some(line));
+        }
+    }
+    (Some(location.trim_end_matches(':').to_string()), None)
+}
+
+/// Renders collected findings, grouped by severity and file
+pub struct ReviewFormatter;
+
+impl ReviewFormatter {
+    /// Compact terminal rendering: a count summary, then each severity
+    /// group with its findings grouped by file
+    pub fn format(findings: &[ReviewFinding]) -> String {
+        let mut output = format!("Review findings: {}\n", Self::summary(findings));
+        for severity in Severity::ALL {
+            let group: Vec<&ReviewFinding> = findings
+                .iter()
+                .filter(|f| f.severity == *severity)
+                .collect();
+            if group.is_empty() {
+                continue;
+            }
+            output.push_str(&format!("\n{}:\n", severity.label()));
+            for finding in group {
+                output.push_str(&format!("  {}\n", Self::render_line(finding)));
+            }
+        }
+        output
+    }
+
+    /// Markdown report for `/review report`: one section per severity,
+    /// findings listed under per-file subheadings
+    pub fn report(findings: &[ReviewFinding]) -> String {
+        let mut output = format!(
+            "# Code review report\n\nFindings: {}\n",
+            Self::summary(findings)
+        );
+        for severity in Severity::ALL {
+            let group: Vec<&ReviewFinding> = findings
+                .iter()
+                .filter(|f| f.severity == *severity)
+                .collect();
+            if group.is_empty() {
+                continue;
+            }
+            output.push_str(&format!("\n## {}\n", severity.label()));
+
+            // Group by file, preserving first-seen order
+            let mut files: Vec<&str> = Vec::new();
+            for finding in &group {
+                let file = finding.file.as_deref().unwrap_or("(general)");
+                if !files.contains(&file) {
+                    files.push(file);
+                }
+            }
+            for file in files {
+                output.push_str(&format!("\n### {}\n\n", file));
+                for finding in group
+                    .iter()
+                    .filter(|f| f.file.as_deref().unwrap_or("(general)") == file)
+                {
+                    match finding.line {
+                        Some(line) => {
+                            output.push_str(&format!("- line {}: {}\n", line, finding.message))
+                        }
+                        None => output.push_str(&format!("- {}\n", finding.message)),
+                    }
+                }
+            }
+        }
+        output
+    }
+
+    /// Count summary like "3 (1 critical, 2 warnings)"
+    fn summary(findings: &[ReviewFinding]) -> String {
+        let counts: Vec<String> = Severity::ALL
+            .iter()
+            .filter_map(|severity| {
+                let count = findings.iter().filter(|f| f.severity == *severity).count();
+                (count > 0).then(|| format!("{} {}", count, severity.label().to_lowercase()))
+            })
+            .collect();
+        if counts.is_empty() {
+            "0".to_string()
+        } else {
+            format!("{} ({})", findings.len(), counts.join(", "))
+        }
+    }
+
+    /// One finding as `file:line — message` (location parts optional)
+    fn render_line(finding: &ReviewFinding) -> String {
+        match (&finding.file, finding.line) {
+            (Some(file), Some(line)) => format!("{}:{} — {}", file, line, finding.message),
+            (Some(file), None) => format!("{} — {}", file, finding.message),
+            _ => finding.message.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RESPONSE: &str = "I reviewed both files.\n\n\
+        [CRITICAL] src/auth.rs:42 — password compared without constant-time equality\n\
+        - [Warning] src/auth.rs — login errors are swallowed\n\
+        [SUGGESTION] src/main.rs:7 - rename `x` to `attempt_count`\n\n\
+        Overall the structure is sound.";
+
+    #[test]
+    fn test_parse_findings_extracts_tagged_lines() {
+        let findings = parse_findings(RESPONSE);
+
+        assert_eq!(findings.len(), 3);
+        assert_eq!(findings[0].severity, Severity::Critical);
+        assert_eq!(findings[0].file.as_deref(), Some("src/auth.rs"));
+        assert_eq!(findings[0].line, Some(42));
+        assert!(findings[0].message.contains("constant-time"));
+
+        assert_eq!(findings[1].severity, Severity::Warning);
+        assert_eq!(findings[1].line, None);
+
+        assert_eq!(findings[2].severity, Severity::Suggestion);
+        assert_eq!(findings[2].line, Some(7));
+    }
+
+    #[test]
+    fn test_parse_findings_skips_prose() {
+        assert!(parse_findings("Looks good to me!\nNo issues found.").is_empty());
+        assert!(parse_findings("[unknown] src/a.rs — not a severity").is_empty());
+    }
+
+    #[test]
+    fn test_format_groups_by_severity() {
+        let findings = parse_findings(RESPONSE);
+
+        let output = ReviewFormatter::format(&findings);
+
+        assert!(output.contains("Review findings: 3 (1 critical, 1 warning, 1 suggestion)"));
+        let critical = output.find("Critical:").unwrap();
+        let warning = output.find("Warning:").unwrap();
+        let suggestion = output.find("Suggestion:").unwrap();
+        assert!(critical < warning && warning < suggestion);
+        assert!(output.contains("src/auth.rs:42 — password"));
+    }
+
+    #[test]
+    fn test_report_groups_by_severity_and_file() {
+        let findings = parse_findings(RESPONSE);
+
+        let report = ReviewFormatter::report(&findings);
+
+        assert!(report.starts_with("# Code review report"));
+        assert!(report.contains("## Critical"));
+        assert!(report.contains("### src/auth.rs"));
+        assert!(report.contains("- line 42: password"));
+        assert!(report.contains("### src/main.rs"));
+    }
+
+    #[test]
+    fn test_default_checklist_is_not_empty() {
+        let checklist = default_checklist();
+        assert!(!checklist.is_empty());
+        assert!(checklist.iter().any(|item| item.contains("Correctness")));
+    }
+}
@@ -0,0 +1,174 @@
+//! Shared text search used by Ctrl+R reverse search and `/search`
+//!
+//! Matching is case-insensitive substring search. Results are ranked by how
+//! early the match occurs in the candidate, with ties broken by input order —
+//! so callers pass candidates most-recent-first to prefer recent entries.
+
+/// A single search hit
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchMatch {
+    /// Index of the candidate in the input order
+    pub index: usize,
+    /// Byte offset of the match within the candidate
+    pub position: usize,
+    /// The full candidate text
+    pub text: String,
+}
+
+/// Search `candidates` for `query`, returning ranked matches
+///
+/// An empty query matches nothing (an incremental search with no input
+/// should show no result rather than everything).
+pub fn search(query: &str, candidates: &[&str]) -> Vec<SearchMatch> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let query_lower = query.to_lowercase();
+    let mut matches: Vec<SearchMatch> = candidates
+        .iter()
+        .enumerate()
+        .filter_map(|(index, text)| {
+            text.to_lowercase()
+                .find(&query_lower)
+                .map(|position| SearchMatch {
+                    index,
+                    position,
+                    text: text.to_string(),
+                })
+        })
+        .collect();
+
+    matches.sort_by_key(|m| (m.position, m.index));
+    matches
+}
+
+/// Split `text` around the first case-insensitive occurrence of `query`
+///
+/// Returns `(before, matched, after)` with the original casing preserved.
+pub fn split_match<'a>(text: &'a str, query: &str) -> Option<(&'a str, &'a str, &'a str)> {
+    if query.is_empty() {
+        return None;
+    }
+    let position = text.to_lowercase().find(&query.to_lowercase())?;
+    let end = position + query.len();
+    Some((&text[..position], &text[position..end], &text[end..]))
+}
+
+/// Highlight the first match of `query` in `text` with bold ANSI codes
+pub fn highlight(text: &str, query: &str) -> String {
+    match split_match(text, query) {
+        Some((before, matched, after)) => format!("{}\x1b[1m{}\x1b[0m{}", before, matched, after),
+        None => text.to_string(),
+    }
+}
+
+/// Extract the line containing the first match, trimmed to `max_len` chars
+/// around the match
+pub fn excerpt(text: &str, query: &str, max_len: usize) -> Option<String> {
+    let query_lower = query.to_lowercase();
+    let line = text
+        .lines()
+        .find(|line| line.to_lowercase().contains(&query_lower))?;
+
+    let line = line.trim();
+    if line.chars().count() <= max_len {
+        return Some(line.to_string());
+    }
+
+    // Center the window on the match as far as possible
+    let match_char = line
+        .to_lowercase()
+        .find(&query_lower)
+        .map(|byte| line[..byte].chars().count())
+        .unwrap_or(0);
+    let start = match_char.saturating_sub(max_len / 2);
+    let window: String = line.chars().skip(start).take(max_len).collect();
+
+    let mut result = String::new();
+    if start > 0 {
+        result.push('…');
+    }
+    result.push_str(&window);
+    if start + max_len < line.chars().count() {
+        result.push('…');
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_ranks_earlier_matches_first() {
+        let candidates = ["echo cargo", "cargo build", "no match here"];
+
+        let matches = search("car", &candidates);
+
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].text, "cargo build");
+        assert_eq!(matches[0].position, 0);
+        assert_eq!(matches[1].text, "echo cargo");
+    }
+
+    #[test]
+    fn test_search_ties_keep_input_order() {
+        let candidates = ["cargo test", "cargo build"];
+
+        let matches = search("cargo", &candidates);
+
+        assert_eq!(matches[0].text, "cargo test");
+        assert_eq!(matches[1].text, "cargo build");
+    }
+
+    #[test]
+    fn test_search_is_case_insensitive() {
+        let matches = search("HELLO", &["say hello world"]);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].position, 4);
+    }
+
+    #[test]
+    fn test_search_empty_query_matches_nothing() {
+        assert!(search("", &["anything"]).is_empty());
+    }
+
+    #[test]
+    fn test_split_match_preserves_casing() {
+        let (before, matched, after) = split_match("The Lifetime Error", "lifetime").unwrap();
+        assert_eq!(before, "The ");
+        assert_eq!(matched, "Lifetime");
+        assert_eq!(after, " Error");
+    }
+
+    #[test]
+    fn test_highlight_wraps_match_in_bold() {
+        assert_eq!(highlight("abc", "b"), "a\x1b[1mb\x1b[0mc");
+        // No match leaves the text untouched
+        assert_eq!(highlight("abc", "z"), "abc");
+    }
+
+    #[test]
+    fn test_excerpt_finds_matching_line() {
+        let text = "first line\nthe lifetime error is here\nlast line";
+        assert_eq!(
+            excerpt(text, "lifetime", 80),
+            Some("the lifetime error is here".to_string())
+        );
+        assert_eq!(excerpt(text, "missing", 80), None);
+    }
+
+    #[test]
+    fn test_excerpt_trims_long_lines() {
+        let text = format!("{}needle{}", "a".repeat(100), "b".repeat(100));
+
+        let result = excerpt(&text, "needle", 20).unwrap();
+
+        assert!(result.contains("needle"));
+        assert!(result.starts_with('…'));
+        assert!(result.ends_with('…'));
+        // 20 chars plus the two ellipses
+        assert_eq!(result.chars().count(), 22);
+    }
+}
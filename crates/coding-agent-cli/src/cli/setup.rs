@@ -0,0 +1,434 @@
+//! First-run setup wizard for the API key, default model, and theme
+//!
+//! Without a key, a new user gets all the way into the REPL and only hits
+//! "ANTHROPIC_API_KEY not set" on their first message. The wizard runs
+//! before the REPL when no key is found in the environment, `.env`, or the
+//! user config, and can be re-run any time with `code setup`. It prompts
+//! for the key with masked input, validates it against the models
+//! endpoint, and offers to persist it to the user config or a project
+//! `.env` (added to `.gitignore`).
+
+use crate::config::Config;
+use crate::ui::{Color, Theme};
+use crossterm::event::{self, Event, KeyCode, KeyModifiers};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Where the wizard stores the API key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyStorage {
+    /// Write to `~/.config/coding-agent/config.toml`
+    UserConfig,
+    /// Append to a project-local `.env` (and add `.env` to `.gitignore`)
+    ProjectEnv,
+    /// Keep in the process environment only
+    SessionOnly,
+}
+
+/// Resolve the API key into the environment, returning whether one exists
+///
+/// Checks the environment (after loading `.env` via dotenvy), then falls
+/// back to `api_key` in the user config, exporting it so the rest of the
+/// CLI can keep reading `ANTHROPIC_API_KEY` from the environment.
+pub fn resolve_api_key() -> bool {
+    // Keychain first (when built with the `keyring` feature), then the
+    // environment and `.env`, then the user config
+    match crate::security::keychain::get_api_key() {
+        Ok(Some(key)) => {
+            std::env::set_var("ANTHROPIC_API_KEY", key);
+            return true;
+        }
+        Ok(None) => {}
+        Err(e) => tracing::warn!(error = %e, "Keychain unavailable, falling back"),
+    }
+
+    let _ = dotenvy::dotenv();
+    if std::env::var("ANTHROPIC_API_KEY").is_ok() {
+        return true;
+    }
+
+    if let Ok(config) = Config::load() {
+        if let Some(key) = config.api_key.filter(|k| !k.is_empty()) {
+            std::env::set_var("ANTHROPIC_API_KEY", key);
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Interactive setup wizard
+pub struct SetupWizard {
+    theme: Theme,
+}
+
+impl SetupWizard {
+    /// Create a wizard with the default theme
+    pub fn new() -> Self {
+        Self {
+            theme: Theme::default(),
+        }
+    }
+
+    /// Run the wizard: prompt for the key, validate it, store it, and pick
+    /// a default model and theme. Returns `Ok(())` when cancelled, too —
+    /// the REPL still works without a key until the first message.
+    pub fn run(&self) -> Result<(), String> {
+        println!("{}", self.theme.apply(Color::Agent, "coding-agent setup"));
+        println!("{}", self.theme.apply(Color::Muted, "──────────────────"));
+        println!();
+
+        let Some(key) = self.prompt_for_key()? else {
+            println!(
+                "{}",
+                self.theme.apply(
+                    Color::Muted,
+                    "Setup cancelled. Run `code setup` to try again."
+                )
+            );
+            return Ok(());
+        };
+
+        // Make the key available to this session regardless of storage choice
+        std::env::set_var("ANTHROPIC_API_KEY", &key);
+
+        let mut config = Config::load().map_err(|e| e.to_string())?;
+
+        match self.prompt_for_storage()? {
+            KeyStorage::UserConfig => {
+                config.api_key = Some(key);
+                config.save().map_err(|e| e.to_string())?;
+                let path = Config::default_path().map_err(|e| e.to_string())?;
+                println!(
+                    "{}",
+                    self.theme.apply(
+                        Color::Success,
+                        &format!("✓ Key saved to {}", path.display())
+                    )
+                );
+            }
+            KeyStorage::ProjectEnv => {
+                let cwd = std::env::current_dir().map_err(|e| e.to_string())?;
+                write_env_key(&cwd, &key)?;
+                ensure_env_gitignored(&cwd)?;
+                println!(
+                    "{}",
+                    self.theme
+                        .apply(Color::Success, "✓ Key saved to .env (added to .gitignore)")
+                );
+            }
+            KeyStorage::SessionOnly => {
+                println!(
+                    "{}",
+                    self.theme
+                        .apply(Color::Muted, "Key kept for this session only.")
+                );
+            }
+        }
+
+        if let Some(model) =
+            self.prompt_for_model(&config.model.available, &config.model.default)?
+        {
+            config.model.default = model;
+            config.save().map_err(|e| e.to_string())?;
+        }
+
+        if let Some(style) = self.prompt_for_theme(&config.theme.style)? {
+            config.theme.style = style;
+            config.save().map_err(|e| e.to_string())?;
+        }
+
+        println!();
+        println!("{}", self.theme.apply(Color::Success, "Setup complete."));
+        Ok(())
+    }
+
+    /// Prompt for the API key with masked input, validating against the API
+    ///
+    /// Returns `None` when the user submits an empty key or presses
+    /// Ctrl+C/Esc.
+    fn prompt_for_key(&self) -> Result<Option<String>, String> {
+        loop {
+            print!("Anthropic API key (input hidden): ");
+            io::stdout().flush().map_err(|e| e.to_string())?;
+
+            let Some(key) = read_masked_line().map_err(|e| e.to_string())? else {
+                return Ok(None);
+            };
+            let key = key.trim().to_string();
+            if key.is_empty() {
+                return Ok(None);
+            }
+
+            print!("Validating key... ");
+            io::stdout().flush().map_err(|e| e.to_string())?;
+            match validate_api_key(&key) {
+                Ok(()) => {
+                    println!("{}", self.theme.apply(Color::Success, "ok"));
+                    return Ok(Some(key));
+                }
+                Err(e) => {
+                    println!("{}", self.theme.apply(Color::Error, &e));
+                    println!("Try again, or press Enter to skip.");
+                }
+            }
+        }
+    }
+
+    /// Ask where to store the key
+    fn prompt_for_storage(&self) -> Result<KeyStorage, String> {
+        println!();
+        println!("Where should the key be stored?");
+        println!("  1. User config (~/.config/coding-agent/config.toml) [default]");
+        println!("  2. Project .env (added to .gitignore)");
+        println!("  3. This session only");
+        print!("> ");
+        io::stdout().flush().map_err(|e| e.to_string())?;
+
+        let line = read_line().map_err(|e| e.to_string())?;
+        Ok(parse_storage_choice(&line))
+    }
+
+    /// Ask for a default model; `None` keeps the current one
+    fn prompt_for_model(
+        &self,
+        available: &[String],
+        current: &str,
+    ) -> Result<Option<String>, String> {
+        println!();
+        println!("Default model (Enter keeps {}):", current);
+        for (i, model) in available.iter().enumerate() {
+            println!("  {}. {}", i + 1, model);
+        }
+        print!("> ");
+        io::stdout().flush().map_err(|e| e.to_string())?;
+
+        let line = read_line().map_err(|e| e.to_string())?;
+        Ok(parse_model_choice(&line, available))
+    }
+
+    /// Ask for a theme style; `None` keeps the current one
+    fn prompt_for_theme(&self, current: &str) -> Result<Option<String>, String> {
+        println!();
+        println!("Theme (Enter keeps {}):", current);
+        println!("  1. minimal");
+        println!("  2. colorful");
+        println!("  3. monochrome");
+        print!("> ");
+        io::stdout().flush().map_err(|e| e.to_string())?;
+
+        let line = read_line().map_err(|e| e.to_string())?;
+        Ok(parse_theme_choice(&line).map(|s| s.to_string()))
+    }
+}
+
+impl Default for SetupWizard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Validate the key with a cheap call to the models endpoint
+pub(crate) fn validate_api_key(key: &str) -> Result<(), String> {
+    crate::cli::commands::model::fetch_models(key).map(|_| ())
+}
+
+/// Parse the storage menu answer; empty or unrecognized input picks the default
+pub(crate) fn parse_storage_choice(input: &str) -> KeyStorage {
+    match input.trim() {
+        "2" | "env" | ".env" => KeyStorage::ProjectEnv,
+        "3" | "session" => KeyStorage::SessionOnly,
+        _ => KeyStorage::UserConfig,
+    }
+}
+
+/// Parse the model menu answer: a 1-based index into `available`, a model
+/// name typed directly, or `None` for blank input (keep current)
+pub(crate) fn parse_model_choice(input: &str, available: &[String]) -> Option<String> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+    if let Ok(index) = trimmed.parse::<usize>() {
+        return available.get(index.wrapping_sub(1)).cloned();
+    }
+    Some(trimmed.to_string())
+}
+
+/// Parse the theme menu answer; `None` for blank or unrecognized input
+pub(crate) fn parse_theme_choice(input: &str) -> Option<&'static str> {
+    match input.trim() {
+        "1" | "minimal" => Some("minimal"),
+        "2" | "colorful" => Some("colorful"),
+        "3" | "monochrome" => Some("monochrome"),
+        _ => None,
+    }
+}
+
+/// Append `ANTHROPIC_API_KEY=<key>` to `.env` in `dir`, creating it if needed
+pub(crate) fn write_env_key(dir: &Path, key: &str) -> Result<(), String> {
+    let env_path = dir.join(".env");
+    let mut contents = match fs::read_to_string(&env_path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Failed to read .env: {}", e)),
+    };
+
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(&format!("ANTHROPIC_API_KEY={}\n", key));
+
+    fs::write(&env_path, contents).map_err(|e| format!("Failed to write .env: {}", e))
+}
+
+/// Make sure `.env` is listed in `.gitignore` so the key is never committed
+pub(crate) fn ensure_env_gitignored(dir: &Path) -> Result<(), String> {
+    let gitignore_path = dir.join(".gitignore");
+    let mut contents = match fs::read_to_string(&gitignore_path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("Failed to read .gitignore: {}", e)),
+    };
+
+    if contents.lines().any(|line| line.trim() == ".env") {
+        return Ok(());
+    }
+
+    if !contents.is_empty() && !contents.ends_with('\n') {
+        contents.push('\n');
+    }
+    contents.push_str(".env\n");
+
+    fs::write(&gitignore_path, contents).map_err(|e| format!("Failed to write .gitignore: {}", e))
+}
+
+/// Read a line from stdin (the wizard runs in cooked mode)
+fn read_line() -> io::Result<String> {
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+    Ok(line)
+}
+
+/// Read a line with characters echoed as `*`, using raw mode
+///
+/// Returns `None` when the user cancels with Ctrl+C or Esc.
+pub(crate) fn read_masked_line() -> io::Result<Option<String>> {
+    crossterm::terminal::enable_raw_mode().map_err(io::Error::other)?;
+    let result = read_masked_line_inner();
+    crossterm::terminal::disable_raw_mode().map_err(io::Error::other)?;
+    println!();
+    result
+}
+
+fn read_masked_line_inner() -> io::Result<Option<String>> {
+    let mut buffer = String::new();
+    loop {
+        if !event::poll(Duration::from_millis(100)).map_err(io::Error::other)? {
+            continue;
+        }
+        let Event::Key(key_event) = event::read().map_err(io::Error::other)? else {
+            continue;
+        };
+        match (key_event.code, key_event.modifiers) {
+            (KeyCode::Enter, _) => return Ok(Some(buffer)),
+            (KeyCode::Esc, _) | (KeyCode::Char('c'), KeyModifiers::CONTROL) => {
+                return Ok(None);
+            }
+            (KeyCode::Backspace, _) if buffer.pop().is_some() => {
+                print!("\x08 \x08");
+                io::stdout().flush()?;
+            }
+            (KeyCode::Char(c), KeyModifiers::NONE) | (KeyCode::Char(c), KeyModifiers::SHIFT) => {
+                buffer.push(c);
+                print!("*");
+                io::stdout().flush()?;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_parse_storage_choice_defaults_to_config() {
+        assert_eq!(parse_storage_choice(""), KeyStorage::UserConfig);
+        assert_eq!(parse_storage_choice("1"), KeyStorage::UserConfig);
+        assert_eq!(parse_storage_choice("nonsense"), KeyStorage::UserConfig);
+    }
+
+    #[test]
+    fn test_parse_storage_choice_env_and_session() {
+        assert_eq!(parse_storage_choice("2"), KeyStorage::ProjectEnv);
+        assert_eq!(parse_storage_choice(".env"), KeyStorage::ProjectEnv);
+        assert_eq!(parse_storage_choice("3"), KeyStorage::SessionOnly);
+        assert_eq!(parse_storage_choice("session"), KeyStorage::SessionOnly);
+    }
+
+    #[test]
+    fn test_parse_model_choice_by_index_and_name() {
+        let available = vec!["claude-3-opus".to_string(), "claude-3-sonnet".to_string()];
+
+        assert_eq!(
+            parse_model_choice("2", &available),
+            Some("claude-3-sonnet".to_string())
+        );
+        assert_eq!(
+            parse_model_choice("claude-opus-4", &available),
+            Some("claude-opus-4".to_string())
+        );
+        assert_eq!(parse_model_choice("", &available), None);
+        assert_eq!(parse_model_choice("99", &available), None);
+    }
+
+    #[test]
+    fn test_parse_theme_choice() {
+        assert_eq!(parse_theme_choice("1"), Some("minimal"));
+        assert_eq!(parse_theme_choice("colorful"), Some("colorful"));
+        assert_eq!(parse_theme_choice("3"), Some("monochrome"));
+        assert_eq!(parse_theme_choice(""), None);
+        assert_eq!(parse_theme_choice("neon"), None);
+    }
+
+    #[test]
+    fn test_write_env_key_creates_and_appends() {
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+
+        write_env_key(temp_dir.path(), "sk-test-123").expect("Should create .env");
+        let contents = fs::read_to_string(temp_dir.path().join(".env")).unwrap();
+        assert_eq!(contents, "ANTHROPIC_API_KEY=sk-test-123\n");
+
+        // Existing content without a trailing newline is preserved
+        fs::write(temp_dir.path().join(".env"), "OTHER=1").unwrap();
+        write_env_key(temp_dir.path(), "sk-test-456").expect("Should append to .env");
+        let contents = fs::read_to_string(temp_dir.path().join(".env")).unwrap();
+        assert_eq!(contents, "OTHER=1\nANTHROPIC_API_KEY=sk-test-456\n");
+    }
+
+    #[test]
+    fn test_ensure_env_gitignored() {
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+
+        // Creates .gitignore when missing
+        ensure_env_gitignored(temp_dir.path()).expect("Should create .gitignore");
+        let contents = fs::read_to_string(temp_dir.path().join(".gitignore")).unwrap();
+        assert_eq!(contents, ".env\n");
+
+        // Already listed: no duplicate entry
+        ensure_env_gitignored(temp_dir.path()).expect("Should be idempotent");
+        let contents = fs::read_to_string(temp_dir.path().join(".gitignore")).unwrap();
+        assert_eq!(contents, ".env\n");
+
+        // Appends to an existing file
+        fs::write(temp_dir.path().join(".gitignore"), "target/\n").unwrap();
+        ensure_env_gitignored(temp_dir.path()).expect("Should append");
+        let contents = fs::read_to_string(temp_dir.path().join(".gitignore")).unwrap();
+        assert_eq!(contents, "target/\n.env\n");
+    }
+}
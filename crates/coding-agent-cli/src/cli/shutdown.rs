@@ -0,0 +1,166 @@
+//! Shutdown coordination for SIGINT/SIGTERM
+//!
+//! Raw mode and synchronous tool execution mean signals cannot unwind the
+//! REPL directly, so handlers set flags here and the loop checks them at
+//! safe points. The first Ctrl+C cancels the current turn, a second
+//! within [`Shutdown::DOUBLE_PRESS_WINDOW`] requests a clean exit, and
+//! SIGTERM always takes the clean path (children killed, session saved,
+//! terminal restored).
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// Flags shared between the signal handler tasks and the REPL loop
+pub struct Shutdown {
+    /// Set by the first Ctrl+C; consumed when the REPL cancels the turn
+    interrupted: AtomicBool,
+    /// Set by a double Ctrl+C or SIGTERM; never cleared
+    exit: AtomicBool,
+    /// When the last Ctrl+C arrived, for the double-press window
+    last_interrupt: Mutex<Option<Instant>>,
+    /// Wakes a prompt that is mid-await when an exit is requested
+    notify: Notify,
+    /// How close together two Ctrl+Cs must be to mean "exit"
+    window: Duration,
+}
+
+impl Shutdown {
+    /// A second Ctrl+C within this window exits instead of cancelling
+    pub const DOUBLE_PRESS_WINDOW: Duration = Duration::from_secs(2);
+
+    /// Create shutdown state with the standard double-press window
+    pub fn new() -> Self {
+        Self::with_window(Self::DOUBLE_PRESS_WINDOW)
+    }
+
+    /// Create shutdown state with a custom double-press window
+    pub fn with_window(window: Duration) -> Self {
+        Self {
+            interrupted: AtomicBool::new(false),
+            exit: AtomicBool::new(false),
+            last_interrupt: Mutex::new(None),
+            notify: Notify::new(),
+            window,
+        }
+    }
+
+    /// Record a Ctrl+C
+    ///
+    /// Returns true when it was the second within the window, in which
+    /// case a clean exit has been requested; otherwise only the turn
+    /// interrupt flag is set.
+    pub fn interrupt(&self) -> bool {
+        let mut last = self.last_interrupt.lock().unwrap();
+        let double = last.is_some_and(|at| at.elapsed() <= self.window);
+        *last = Some(Instant::now());
+
+        if double {
+            self.request_exit();
+        } else {
+            self.interrupted.store(true, Ordering::SeqCst);
+        }
+        double
+    }
+
+    /// Request a clean exit (SIGTERM, or a double Ctrl+C)
+    pub fn request_exit(&self) {
+        self.exit.store(true, Ordering::SeqCst);
+        self.notify.notify_waiters();
+    }
+
+    /// Whether a clean exit has been requested
+    pub fn exit_requested(&self) -> bool {
+        self.exit.load(Ordering::SeqCst)
+    }
+
+    /// Consume a pending turn interrupt, if any
+    pub fn take_interrupt(&self) -> bool {
+        self.interrupted.swap(false, Ordering::SeqCst)
+    }
+
+    /// Wait until a clean exit is requested
+    pub async fn exited(&self) {
+        loop {
+            // Register before checking the flag so a request_exit between
+            // the check and the await cannot be missed
+            let notified = self.notify.notified();
+            if self.exit_requested() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_interrupt_sets_turn_flag_only() {
+        let shutdown = Shutdown::new();
+
+        assert!(!shutdown.interrupt());
+
+        assert!(shutdown.take_interrupt());
+        assert!(!shutdown.take_interrupt(), "Interrupt should be consumed");
+        assert!(!shutdown.exit_requested());
+    }
+
+    #[test]
+    fn test_double_interrupt_within_window_requests_exit() {
+        let shutdown = Shutdown::new();
+
+        assert!(!shutdown.interrupt());
+        assert!(shutdown.interrupt());
+
+        assert!(shutdown.exit_requested());
+    }
+
+    #[test]
+    fn test_interrupts_outside_window_do_not_exit() {
+        // A zero window means the second press can never be "within" it
+        let shutdown = Shutdown::with_window(Duration::ZERO);
+
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(!shutdown.interrupt());
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(!shutdown.interrupt());
+
+        assert!(!shutdown.exit_requested());
+    }
+
+    #[test]
+    fn test_request_exit_is_sticky() {
+        let shutdown = Shutdown::new();
+
+        shutdown.request_exit();
+
+        assert!(shutdown.exit_requested());
+        assert!(shutdown.exit_requested());
+    }
+
+    #[tokio::test]
+    async fn test_exited_wakes_on_request() {
+        use std::sync::Arc;
+
+        let shutdown = Arc::new(Shutdown::new());
+        let waiter = Arc::clone(&shutdown);
+        let handle = tokio::spawn(async move { waiter.exited().await });
+
+        shutdown.request_exit();
+
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .expect("Should wake promptly")
+            .expect("Task should not panic");
+    }
+}
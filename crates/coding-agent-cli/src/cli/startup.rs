@@ -3,7 +3,7 @@
 //! This module provides the welcome screen displayed when the CLI starts,
 //! including the ASCII logo and session selection options.
 
-use crate::integrations::{SessionInfo, SessionManager};
+use crate::integrations::{SessionInfo, SessionManager, SessionPreview};
 use crossterm::{
     cursor, execute,
     style::{Color, Print, ResetColor, SetForegroundColor},
@@ -24,8 +24,11 @@ const ASCII_LOGO: &str = r#"
 /// Startup screen options
 #[derive(Debug, Clone, PartialEq)]
 pub enum StartupOption {
-    /// Start a new session
-    NewSession,
+    /// Start a new session, optionally seeded with a first user message
+    NewSession {
+        /// Submitted as the first turn before reading from the terminal
+        initial_message: Option<String>,
+    },
     /// Resume the last session
     ResumeSession(String), // filename
     /// Show help
@@ -55,7 +58,26 @@ impl StartupScreen {
     }
 
     /// Display the startup screen and return the selected option
+    ///
+    /// With saved sessions available this shows an interactive session list
+    /// (arrow navigation, filter-as-you-type, preview pane); otherwise it
+    /// falls back to the simple option screen.
     pub fn show(&self) -> io::Result<StartupResult> {
+        let sessions = self
+            .session_manager
+            .as_ref()
+            .and_then(|manager| manager.list_sessions().ok())
+            .unwrap_or_default();
+
+        if sessions.is_empty() {
+            self.show_simple()
+        } else {
+            self.show_session_list(sessions)
+        }
+    }
+
+    /// Display the simple option screen (no saved sessions)
+    fn show_simple(&self) -> io::Result<StartupResult> {
         let mut stdout = io::stdout();
 
         // Clear screen and move cursor to top
@@ -69,12 +91,22 @@ impl StartupScreen {
         }
         execute!(stdout, ResetColor)?;
 
-        // Print version
+        // Print version (and the active profile, when one is selected)
         execute!(
             stdout,
-            Print("\r\n   coding-agent v0.1.0\r\n\r\n"),
+            Print("\r\n   coding-agent v0.1.0\r\n"),
             SetForegroundColor(Color::Reset)
         )?;
+        if let Ok(profile) = std::env::var("CODING_AGENT_PROFILE") {
+            execute!(
+                stdout,
+                SetForegroundColor(Color::DarkGrey),
+                Print(format!("   profile: {}\r\n", profile)),
+                ResetColor
+            )?;
+        }
+        print_mock_banner(&mut stdout)?;
+        execute!(stdout, Print("\r\n"))?;
 
         // Get last session info
         let last_session = self.get_last_session();
@@ -162,7 +194,9 @@ impl StartupScreen {
 
                 match code {
                     KeyCode::Char('n') | KeyCode::Char('N') => {
-                        return Ok(StartupOption::NewSession);
+                        return Ok(StartupOption::NewSession {
+                            initial_message: None,
+                        });
                     }
                     KeyCode::Char('r') | KeyCode::Char('R') => {
                         if let Some(ref info) = last_session {
@@ -181,13 +215,329 @@ impl StartupScreen {
                     }
                     KeyCode::Enter => {
                         // Enter defaults to new session
-                        return Ok(StartupOption::NewSession);
+                        return Ok(StartupOption::NewSession {
+                            initial_message: None,
+                        });
                     }
                     _ => continue,
                 }
             }
         }
     }
+
+    /// Display the interactive session list and return the selected option
+    fn show_session_list(&self, mut sessions: Vec<SessionInfo>) -> io::Result<StartupResult> {
+        use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+
+        let mut filter = String::new();
+        let mut selected: usize = 0;
+
+        loop {
+            let filtered: Vec<&SessionInfo> = sessions
+                .iter()
+                .filter(|s| session_matches(&filter, s))
+                .collect();
+            if selected >= filtered.len() {
+                selected = filtered.len().saturating_sub(1);
+            }
+
+            let preview = filtered
+                .get(selected)
+                .and_then(|info| self.peek(&info.filename));
+            self.render_session_list(&filtered, selected, &filter, preview.as_ref())?;
+
+            if let Event::Key(KeyEvent {
+                code, modifiers, ..
+            }) = event::read()?
+            {
+                if modifiers.contains(KeyModifiers::CONTROL) {
+                    match code {
+                        KeyCode::Char('c') | KeyCode::Char('d') => {
+                            return Ok(StartupResult {
+                                option: StartupOption::Exit,
+                            });
+                        }
+                        _ => continue,
+                    }
+                }
+
+                match code {
+                    KeyCode::Up => selected = selected.saturating_sub(1),
+                    KeyCode::Down => {
+                        if selected + 1 < filtered.len() {
+                            selected += 1;
+                        }
+                    }
+                    KeyCode::Enter => {
+                        if let Some(info) = filtered.get(selected) {
+                            return Ok(StartupResult {
+                                option: StartupOption::ResumeSession(info.filename.clone()),
+                            });
+                        }
+                    }
+                    KeyCode::Esc => {
+                        return Ok(StartupResult {
+                            option: StartupOption::Exit,
+                        });
+                    }
+                    KeyCode::Backspace => {
+                        filter.pop();
+                    }
+                    // Tab completes a partial #tag filter to a known tag
+                    KeyCode::Tab => {
+                        if let Some(completed) = complete_tag(&filter, &sessions) {
+                            filter = completed;
+                        }
+                    }
+                    // n/d are commands only while the filter is empty;
+                    // otherwise every letter goes to the filter
+                    KeyCode::Char('n') if filter.is_empty() => {
+                        return Ok(StartupResult {
+                            option: StartupOption::NewSession {
+                                initial_message: None,
+                            },
+                        });
+                    }
+                    KeyCode::Char('d') if filter.is_empty() => {
+                        let target = filtered
+                            .get(selected)
+                            .map(|info| (info.filename.clone(), info.title.clone()));
+                        if let Some((filename, title)) = target {
+                            if self.confirm_delete(&title)? {
+                                if let Some(ref manager) = self.session_manager {
+                                    let _ = manager.delete(&filename);
+                                }
+                                sessions.retain(|s| s.filename != filename);
+                                if sessions.is_empty() {
+                                    return self.show_simple();
+                                }
+                            }
+                        }
+                    }
+                    KeyCode::Char(c) => filter.push(c),
+                    _ => continue,
+                }
+            }
+        }
+    }
+
+    /// Render the session list screen with the preview pane
+    fn render_session_list(
+        &self,
+        filtered: &[&SessionInfo],
+        selected: usize,
+        filter: &str,
+        preview: Option<&SessionPreview>,
+    ) -> io::Result<()> {
+        let mut stdout = io::stdout();
+
+        execute!(stdout, Clear(ClearType::All), cursor::MoveTo(0, 0))?;
+
+        execute!(stdout, SetForegroundColor(Color::Cyan))?;
+        for line in ASCII_LOGO.lines() {
+            execute!(stdout, Print(format!("   {}\r\n", line)))?;
+        }
+        execute!(stdout, ResetColor)?;
+        print_mock_banner(&mut stdout)?;
+
+        execute!(
+            stdout,
+            SetForegroundColor(Color::DarkGrey),
+            Print(format!(
+                "\r\n   Filter: {}\r\n\r\n",
+                if filter.is_empty() {
+                    "(type to filter, #tag to filter by tag)".to_string()
+                } else {
+                    format!("{}_", filter)
+                }
+            )),
+            ResetColor
+        )?;
+
+        let preview_lines = filtered
+            .get(selected)
+            .map(|info| build_preview_lines(info, preview))
+            .unwrap_or_default();
+
+        let rows = filtered.len().min(MAX_LIST_ROWS).max(preview_lines.len());
+        for row in 0..rows {
+            let (marker, cell, highlight) = match filtered.get(row) {
+                Some(info) if row < MAX_LIST_ROWS => (
+                    if row == selected { "▸ " } else { "  " },
+                    format!(
+                        "{} ({})",
+                        truncate_title(&info.title, LIST_TITLE_WIDTH),
+                        info.time_ago()
+                    ),
+                    row == selected,
+                ),
+                _ => ("  ", String::new(), false),
+            };
+
+            if highlight {
+                execute!(stdout, SetForegroundColor(Color::Yellow))?;
+            }
+            execute!(
+                stdout,
+                Print(format!("   {}{:<width$}", marker, cell, width = LIST_WIDTH))
+            )?;
+            execute!(stdout, ResetColor)?;
+
+            if let Some(line) = preview_lines.get(row) {
+                execute!(
+                    stdout,
+                    SetForegroundColor(Color::DarkGrey),
+                    Print(format!("│ {}", line)),
+                    ResetColor
+                )?;
+            }
+            execute!(stdout, Print("\r\n"))?;
+        }
+
+        if filtered.is_empty() {
+            execute!(
+                stdout,
+                SetForegroundColor(Color::DarkGrey),
+                Print("   No sessions match the filter\r\n"),
+                ResetColor
+            )?;
+        }
+
+        execute!(
+            stdout,
+            SetForegroundColor(Color::DarkGrey),
+            Print("\r\n   ↑/↓ navigate  Enter resume  [n] new  [d] delete  Esc exit\r\n"),
+            ResetColor
+        )?;
+
+        stdout.flush()
+    }
+
+    /// Ask the user to confirm deleting a session
+    fn confirm_delete(&self, title: &str) -> io::Result<bool> {
+        use crossterm::event::{self, Event, KeyCode, KeyEvent};
+
+        let mut stdout = io::stdout();
+        execute!(
+            stdout,
+            SetForegroundColor(Color::Red),
+            Print(format!(
+                "\r\n   Delete \"{}\"? [y/N] ",
+                truncate_title(title, 40)
+            )),
+            ResetColor
+        )?;
+        stdout.flush()?;
+
+        loop {
+            if let Event::Key(KeyEvent { code, .. }) = event::read()? {
+                return Ok(matches!(code, KeyCode::Char('y') | KeyCode::Char('Y')));
+            }
+        }
+    }
+
+    /// Cheaply preview a session, ignoring failures
+    fn peek(&self, filename: &str) -> Option<SessionPreview> {
+        self.session_manager
+            .as_ref()
+            .and_then(|manager| manager.peek(filename).ok())
+    }
+}
+
+/// Maximum number of sessions shown in the list at once
+const MAX_LIST_ROWS: usize = 10;
+
+/// Width of the session list column (before the preview pane)
+const LIST_WIDTH: usize = 44;
+
+/// Maximum title length inside a list entry
+const LIST_TITLE_WIDTH: usize = 26;
+
+/// Maximum width of a preview pane line
+const PREVIEW_WIDTH: usize = 50;
+
+/// Match the filter against a session: a `#tag` filter matches sessions
+/// carrying a tag with that prefix, anything else fuzzy-matches the title
+/// Make mock mode unmissable on every startup screen variant: nothing in
+/// a mock session reaches the API, so a demo can never be mistaken for a
+/// live one.
+fn print_mock_banner(stdout: &mut io::Stdout) -> io::Result<()> {
+    if crate::providers::ProviderKind::from_env() == crate::providers::ProviderKind::Mock {
+        execute!(
+            stdout,
+            SetForegroundColor(Color::Magenta),
+            Print("   ★ MOCK MODE — canned responses, no API key, nothing sent anywhere\r\n"),
+            ResetColor
+        )?;
+    }
+    Ok(())
+}
+
+fn session_matches(filter: &str, info: &SessionInfo) -> bool {
+    match filter.strip_prefix('#') {
+        Some(tag) => info.tags.iter().any(|t| t.starts_with(tag)),
+        None => fuzzy_match(filter, &info.title),
+    }
+}
+
+/// Complete a partial `#tag` filter to the first known tag with that
+/// prefix (tags considered in sorted order)
+fn complete_tag(filter: &str, sessions: &[SessionInfo]) -> Option<String> {
+    let partial = filter.strip_prefix('#')?;
+    let mut tags: Vec<&str> = sessions
+        .iter()
+        .flat_map(|s| s.tags.iter())
+        .map(String::as_str)
+        .collect();
+    tags.sort_unstable();
+    tags.dedup();
+    tags.iter()
+        .find(|tag| tag.starts_with(partial))
+        .map(|tag| format!("#{}", tag))
+}
+
+/// Case-insensitive subsequence match, used for filter-as-you-type
+///
+/// Every character of `query` must appear in `candidate` in order, but not
+/// necessarily adjacently — so "bst" matches "Binary search tree".
+fn fuzzy_match(query: &str, candidate: &str) -> bool {
+    let mut candidate_chars = candidate.chars().flat_map(|c| c.to_lowercase());
+    query
+        .chars()
+        .flat_map(|c| c.to_lowercase())
+        .all(|q| candidate_chars.any(|c| c == q))
+}
+
+/// Build the preview pane lines for the highlighted session
+fn build_preview_lines(info: &SessionInfo, preview: Option<&SessionPreview>) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    lines.push(truncate_title(&info.title, PREVIEW_WIDTH));
+    lines.push(format!(
+        "{} · {} messages",
+        info.time_ago(),
+        info.message_count
+    ));
+    if !info.tags.is_empty() {
+        lines.push(format!("tags: {}", info.tags.join(", ")));
+    }
+
+    if let Some(preview) = preview {
+        if let Some(ref cost) = preview.cost {
+            lines.push(format!("cost: {}", cost));
+        }
+        if !preview.snippets.is_empty() {
+            lines.push(String::new());
+            for (role, snippet) in &preview.snippets {
+                lines.push(truncate_title(
+                    &format!("{}: {}", role, snippet),
+                    PREVIEW_WIDTH,
+                ));
+            }
+        }
+    }
+
+    lines
 }
 
 /// Truncate a title to a maximum length, adding "..." if needed
@@ -227,15 +577,62 @@ mod tests {
         assert_eq!(truncate_title("", 10), "");
     }
 
+    fn tagged_session(title: &str, tags: &[&str]) -> SessionInfo {
+        SessionInfo {
+            filename: format!("{}.md", title),
+            title: title.to_string(),
+            created: String::new(),
+            updated: String::new(),
+            message_count: 1,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            modified: std::time::SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn test_session_matches_tag_filter() {
+        let rust = tagged_session("Parser work", &["rust"]);
+        let untagged = tagged_session("Rust chat", &[]);
+
+        assert!(session_matches("#ru", &rust));
+        assert!(!session_matches("#ru", &untagged));
+        // Without the # prefix it is an ordinary title filter
+        assert!(session_matches("rust", &untagged));
+    }
+
+    #[test]
+    fn test_complete_tag_from_known_tags() {
+        let sessions = vec![
+            tagged_session("a", &["rust", "cli"]),
+            tagged_session("b", &["refactor"]),
+        ];
+
+        assert_eq!(
+            complete_tag("#re", &sessions),
+            Some("#refactor".to_string())
+        );
+        assert_eq!(complete_tag("#c", &sessions), Some("#cli".to_string()));
+        assert_eq!(complete_tag("#zzz", &sessions), None);
+        // No # prefix means there is nothing to complete
+        assert_eq!(complete_tag("ru", &sessions), None);
+    }
+
     #[test]
     fn test_startup_option_variants() {
-        let new = StartupOption::NewSession;
+        let new = StartupOption::NewSession {
+            initial_message: None,
+        };
         let resume = StartupOption::ResumeSession("test.md".to_string());
         let help = StartupOption::Help;
         let config = StartupOption::Config;
         let exit = StartupOption::Exit;
 
-        assert_eq!(new, StartupOption::NewSession);
+        assert_eq!(
+            new,
+            StartupOption::NewSession {
+                initial_message: None
+            }
+        );
         assert_eq!(resume, StartupOption::ResumeSession("test.md".to_string()));
         assert_eq!(help, StartupOption::Help);
         assert_eq!(config, StartupOption::Config);
@@ -253,4 +650,62 @@ mod tests {
         let screen = StartupScreen::new(None);
         assert!(screen.get_last_session().is_none());
     }
+
+    #[test]
+    fn test_fuzzy_match_subsequence() {
+        assert!(fuzzy_match("", "anything"));
+        assert!(fuzzy_match("bst", "Binary search tree"));
+        assert!(fuzzy_match("BST", "binary search tree"));
+        assert!(!fuzzy_match("tsb", "Binary search tree"));
+        assert!(!fuzzy_match("xyz", "Binary search tree"));
+    }
+
+    #[test]
+    fn test_build_preview_lines_without_peek() {
+        let info = SessionInfo {
+            filename: "test.md".to_string(),
+            title: "Refactor the parser".to_string(),
+            created: "2024-01-15T10:30:00Z".to_string(),
+            updated: "2024-01-15T11:00:00Z".to_string(),
+            message_count: 7,
+            tags: Vec::new(),
+            modified: std::time::SystemTime::now(),
+        };
+
+        let lines = build_preview_lines(&info, None);
+
+        assert_eq!(lines[0], "Refactor the parser");
+        assert!(lines[1].contains("7 messages"));
+    }
+
+    #[test]
+    fn test_build_preview_lines_with_cost_and_snippets() {
+        use crate::integrations::specstory::MessageRole;
+
+        let info = SessionInfo {
+            filename: "test.md".to_string(),
+            title: "Refactor the parser".to_string(),
+            created: "2024-01-15T10:30:00Z".to_string(),
+            updated: "2024-01-15T11:00:00Z".to_string(),
+            message_count: 2,
+            tags: Vec::new(),
+            modified: std::time::SystemTime::now(),
+        };
+        let preview = SessionPreview {
+            title: "Refactor the parser".to_string(),
+            created: "2024-01-15T10:30:00Z".to_string(),
+            model: "claude-3-opus".to_string(),
+            cost: Some("$0.42".to_string()),
+            snippets: vec![
+                (MessageRole::User, "Please refactor".to_string()),
+                (MessageRole::Agent, "Done".to_string()),
+            ],
+        };
+
+        let lines = build_preview_lines(&info, Some(&preview));
+
+        assert!(lines.iter().any(|l| l == "cost: $0.42"));
+        assert!(lines.iter().any(|l| l == "User: Please refactor"));
+        assert!(lines.iter().any(|l| l == "Agent: Done"));
+    }
 }
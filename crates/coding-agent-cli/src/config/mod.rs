@@ -4,4 +4,18 @@
 
 mod settings;
 
-pub use settings::{BehaviorConfig, Config, PersistenceConfig};
+pub use settings::{
+    BehaviorConfig, Config, ConfirmConfig, ErrorRecoveryConfig, ExportConfig, HooksConfig,
+    InputConfig, KeybindingsConfig, LogConfig, MetricsConfig, PersistenceConfig, ProfileConfig,
+    ProjectConfig, SecurityConfig, ThemeColorsConfig, ToolsConfig,
+};
+
+/// Base URL for the Anthropic API
+///
+/// Honors the `ANTHROPIC_BASE_URL` env var (exported by profiles and the
+/// test harness); defaults to the public endpoint.
+pub fn api_base_url() -> String {
+    std::env::var("ANTHROPIC_BASE_URL")
+        .map(|url| url.trim_end_matches('/').to_string())
+        .unwrap_or_else(|_| "https://api.anthropic.com".to_string())
+}
@@ -7,7 +7,11 @@ use std::path::PathBuf;
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
+#[derive(Default)]
 pub struct Config {
+    /// API key used when ANTHROPIC_API_KEY is not set in the environment
+    /// (written by the setup wizard)
+    pub api_key: Option<String>,
     /// Permission settings
     pub permissions: PermissionsConfig,
     /// Model settings
@@ -22,6 +26,247 @@ pub struct Config {
     pub error_recovery: ErrorRecoveryConfig,
     /// Integration settings
     pub integrations: IntegrationsConfig,
+    /// Tool availability settings
+    pub tools: ToolsConfig,
+    /// Keybinding overrides for the input handler
+    pub keybindings: KeybindingsConfig,
+    /// Logging settings
+    pub log: LogConfig,
+    /// Input handling settings
+    pub input: InputConfig,
+    /// User-defined lifecycle hooks
+    pub hooks: HooksConfig,
+    /// Secret scanning and redaction settings
+    pub security: SecurityConfig,
+    /// Project stack settings
+    pub project: ProjectConfig,
+    /// Prometheus metrics exposition settings
+    pub metrics: MetricsConfig,
+    /// Session export settings
+    pub export: ExportConfig,
+    /// Named profiles (e.g. work/personal) overriding key source, model,
+    /// base URL, and permissions; selected via `--profile` or the
+    /// `CODING_AGENT_PROFILE` env var
+    pub profiles: std::collections::BTreeMap<String, ProfileConfig>,
+    /// Name of the profile applied via [`apply_profile`], shown in
+    /// `/status` and on the startup screen
+    ///
+    /// [`apply_profile`]: Config::apply_profile
+    #[serde(skip)]
+    pub active_profile: Option<String>,
+}
+
+/// A named profile overriding parts of the base config
+///
+/// Every field is optional; unset fields keep the base config's value.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct ProfileConfig {
+    /// Environment variable to read the API key from instead of
+    /// `ANTHROPIC_API_KEY` (e.g. "WORK_ANTHROPIC_KEY")
+    pub api_key_env: Option<String>,
+    /// Default model for this profile
+    pub model: Option<String>,
+    /// API base URL for this profile (exported as `ANTHROPIC_BASE_URL`)
+    pub base_url: Option<String>,
+    /// Permission settings replacing the base `[permissions]` section
+    pub permissions: Option<PermissionsConfig>,
+}
+
+/// Secret scanning and redaction settings
+///
+/// Tool results are scanned before they are added to the conversation;
+/// matching spans are replaced with `[REDACTED:<label>]` so secrets never
+/// reach the API request serializer.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct SecurityConfig {
+    /// Scan tool results and redact secrets before sending them to the API
+    pub redact_secrets: bool,
+    /// Additional regex patterns to redact (reported as `custom`)
+    pub custom_patterns: Vec<String>,
+}
+
+impl Default for SecurityConfig {
+    fn default() -> Self {
+        Self {
+            redact_secrets: true,
+            custom_patterns: Vec::new(),
+        }
+    }
+}
+
+/// Prometheus metrics exposition settings
+///
+/// When enabled, an HTTP server on localhost serves `GET /metrics` in the
+/// Prometheus text format for scraping (Grafana, DataDog, etc.). The
+/// `/metrics` command prints the same counters without the server.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct MetricsConfig {
+    /// Serve metrics over HTTP while a session is running
+    pub enabled: bool,
+    /// Port for the metrics server
+    pub port: u16,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 9090,
+        }
+    }
+}
+
+/// Session export settings, used by `/export`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ExportConfig {
+    /// Include session timestamps and token counts in exported HTML
+    pub include_metadata: bool,
+}
+
+impl Default for ExportConfig {
+    fn default() -> Self {
+        Self {
+            include_metadata: true,
+        }
+    }
+}
+
+/// User-defined lifecycle hooks
+///
+/// Each event maps to a list of shell commands that run when the event
+/// fires. Commands receive a JSON payload on stdin describing the event
+/// (tool name, input, result). For `pre_tool_use` a non-zero exit code
+/// blocks the tool call.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct HooksConfig {
+    /// Run before each tool call; non-zero exit blocks the call
+    pub pre_tool_use: Vec<String>,
+    /// Run after each tool call completes (success or failure)
+    pub post_tool_use: Vec<String>,
+    /// Run after each conversation turn
+    pub post_turn: Vec<String>,
+    /// Run when the session ends
+    pub session_end: Vec<String>,
+    /// Maximum seconds a hook may run before it is killed
+    pub timeout_secs: u64,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            pre_tool_use: Vec::new(),
+            post_tool_use: Vec::new(),
+            post_turn: Vec::new(),
+            session_end: Vec::new(),
+            timeout_secs: 10,
+        }
+    }
+}
+
+/// Project stack settings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct ProjectConfig {
+    /// Override the detected project type: "rust", "typescript",
+    /// "python", or "go"; unset means detect from marker files
+    pub r#type: Option<String>,
+}
+
+/// Input handling settings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct InputConfig {
+    /// Enable vim-style modal editing (Esc for normal mode)
+    pub vim_mode: bool,
+}
+
+/// Logging settings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct LogConfig {
+    /// Log level: off, error, warn, info, debug, or trace
+    pub level: String,
+    /// Log file path; logs go to stderr when unset
+    pub file: Option<PathBuf>,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            // Off by default: stderr output garbles the raw-mode terminal,
+            // matching the old opt-in `--verbose` behavior
+            level: "off".to_string(),
+            file: None,
+        }
+    }
+}
+
+/// Tool availability settings
+///
+/// Restricts which tools Claude can use. When `allowlist` is set, only the
+/// listed tools are registered; `denylist` removes specific tools on top.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ToolsConfig {
+    /// If set, only these tools are available
+    pub allowlist: Option<Vec<String>>,
+    /// Tools that are never available
+    pub denylist: Vec<String>,
+    /// Default timeout for the bash tool, in seconds
+    pub bash_timeout_secs: u64,
+    /// Whether list_files and code_search honor .gitignore rules
+    pub respect_gitignore: bool,
+    /// Custom documentation roots for doc_search, keyed by language
+    pub doc_paths: std::collections::HashMap<String, String>,
+    /// Largest file read_file and write_file will handle, in bytes
+    pub max_file_size_bytes: u64,
+    /// If set, append a JSONL record of every tool call to this file
+    pub log_file: Option<std::path::PathBuf>,
+    /// Whether write_file checks content for security anti-patterns
+    pub write_validation: bool,
+    /// Extra regex patterns treated as hardcoded secrets by the
+    /// write_file validator, on top of the built-in ones
+    pub secret_patterns: Vec<String>,
+    /// Whether write_file/edit_file fail on files modified outside the
+    /// agent since their last read (false downgrades to a warning)
+    pub stale_check_strict: bool,
+}
+
+impl Default for ToolsConfig {
+    fn default() -> Self {
+        Self {
+            allowlist: None,
+            denylist: Vec::new(),
+            bash_timeout_secs: 120,
+            respect_gitignore: true,
+            doc_paths: std::collections::HashMap::new(),
+            max_file_size_bytes: 10 * 1024 * 1024,
+            log_file: None,
+            write_validation: true,
+            secret_patterns: Vec::new(),
+            stale_check_strict: true,
+        }
+    }
+}
+
+/// Keybinding overrides for the input handler
+///
+/// Maps action names (submit, newline, clear, cancel, history-prev,
+/// history-next, delete-word, open-editor, file-finder) to key chords
+/// like "ctrl+w".
+/// Validated into a `KeyMap` at startup; unset actions keep the
+/// emacs-style defaults.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct KeybindingsConfig {
+    /// Action name → key chord
+    #[serde(flatten)]
+    pub bindings: std::collections::BTreeMap<String, String>,
 }
 
 /// Permission settings
@@ -29,6 +274,9 @@ pub struct Config {
 #[serde(default)]
 pub struct PermissionsConfig {
     /// Paths that are trusted (no confirmation needed)
+    ///
+    /// Entries can be exact paths (trusted together with everything under
+    /// them), `~`-prefixed paths, or glob patterns like `~/projects/**`.
     pub trusted_paths: Vec<String>,
     /// Whether to auto-allow read operations
     pub auto_read: bool,
@@ -52,6 +300,50 @@ pub struct ModelConfig {
 pub struct ThemeConfig {
     /// Theme style: minimal, colorful, or monochrome
     pub style: String,
+    /// Whether color output is enabled (false forces monochrome)
+    pub color: bool,
+    /// Per-role color overrides, merged over the chosen style
+    pub colors: ThemeColorsConfig,
+}
+
+/// Per-role color overrides for the theme
+///
+/// Values can be named ANSI colors ("red", "cyan", ...), xterm-256 indices
+/// ("208"), or hex colors ("#ff8700"). Unset roles keep the style's default.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[serde(default)]
+pub struct ThemeColorsConfig {
+    /// Color for the user input prompt
+    pub prompt: Option<String>,
+    /// Color for assistant responses
+    pub assistant: Option<String>,
+    /// Color for tool calls
+    pub tool: Option<String>,
+    /// Color for error messages
+    pub error: Option<String>,
+    /// Color for success messages
+    pub success: Option<String>,
+    /// Color for muted/secondary text
+    pub dim: Option<String>,
+}
+
+impl ThemeColorsConfig {
+    /// Role/value pairs for the overrides that are actually set
+    pub fn entries(&self) -> Vec<(&'static str, &str)> {
+        let roles = [
+            ("prompt", &self.prompt),
+            ("assistant", &self.assistant),
+            ("tool", &self.tool),
+            ("error", &self.error),
+            ("success", &self.success),
+            ("dim", &self.dim),
+        ];
+
+        roles
+            .into_iter()
+            .filter_map(|(role, value)| value.as_ref().map(|v| (role, v.as_str())))
+            .collect()
+    }
 }
 
 /// Persistence settings
@@ -76,12 +368,74 @@ pub struct BehaviorConfig {
     pub tool_verbosity: String,
     /// Whether to show the context bar
     pub show_context_bar: bool,
+    /// Whether to show the branch/model/mode status line above the prompt
+    pub show_status_line: bool,
     /// Whether to show fun facts during long waits
     pub fun_facts: bool,
     /// Delay in seconds before showing fun facts
     pub fun_fact_delay: u32,
     /// Maximum number of tool iterations before stopping
     pub max_tool_iterations: usize,
+    /// Whether to emit terminal/desktop notifications for long operations
+    pub notifications: bool,
+    /// Minimum turn duration in seconds before a notification is emitted
+    pub notification_threshold: u32,
+    /// Number of input history entries retained for up-arrow recall
+    pub input_history_size: usize,
+    /// Whether to trim old tool results out of the conversation once the
+    /// context grows past `trim_threshold_tokens`
+    pub trim_tool_results: bool,
+    /// Context size in tokens at which trimming kicks in
+    pub trim_threshold_tokens: u64,
+    /// Number of most recent turns whose tool results are never trimmed
+    pub trim_keep_recent_turns: usize,
+    /// Whether tool result tables use box-drawing borders (ASCII when off)
+    pub unicode_tables: bool,
+    /// Whether plain-text tool output gets a content-detection pass
+    /// (JSON, diffs, error messages render with structure-aware styling)
+    pub auto_format_tool_output: bool,
+    /// Whether to write a checkpoint before each file-modifying tool call
+    pub auto_checkpoint: bool,
+    /// Whether sessions are auto-tagged from their opening message
+    /// (e.g. a mention of a `.rs` file tags the session `rust`)
+    pub auto_tag: bool,
+    /// Whether to append environment context (cwd, platform, date, git
+    /// state, directory listing) to the system prompt
+    pub include_environment_context: bool,
+    /// Whether to print the workspace_info project summary at session
+    /// start (off by default: the environment context already carries
+    /// the layout for the model; this is for the user)
+    pub auto_workspace_detect: bool,
+    /// Submission guards that ask before sending likely-accidental input
+    pub confirm: ConfirmConfig,
+    /// Whether [y/N] command confirmations assume yes without prompting
+    /// (for non-interactive/scripted usage; also set by `--yes`)
+    pub skip_confirmations: bool,
+}
+
+/// Submission guard settings (`[behavior.confirm]`)
+///
+/// Each guard independently asks "send anyway? y/N" before dispatching
+/// input that looks accidental.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct ConfirmConfig {
+    /// Confirm input that ends inside an unclosed ``` code fence
+    pub unclosed_fence: bool,
+    /// Confirm single-character messages
+    pub single_char: bool,
+    /// Confirm messages that are just a path to an existing file
+    pub bare_path: bool,
+}
+
+impl Default for ConfirmConfig {
+    fn default() -> Self {
+        Self {
+            unclosed_fence: true,
+            single_char: true,
+            bare_path: true,
+        }
+    }
 }
 
 /// Error recovery settings
@@ -90,15 +444,22 @@ pub struct BehaviorConfig {
 pub struct ErrorRecoveryConfig {
     /// Whether to auto-fix errors
     pub auto_fix: bool,
+    /// Whether to retry transient tool errors at all
+    pub retries_enabled: bool,
     /// Whether to generate tests for fixes
     pub generate_tests: bool,
     /// Maximum number of retry attempts
     pub max_retry_attempts: u32,
+    /// Glob patterns for files the fix-agent is allowed to modify
+    pub fix_allowed_patterns: Vec<String>,
+    /// Glob patterns for files the fix-agent must never modify
+    pub fix_forbidden_patterns: Vec<String>,
 }
 
 /// Integration settings
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default)]
+#[derive(Default)]
 pub struct IntegrationsConfig {
     /// Obsidian integration settings
     pub obsidian: ObsidianConfig,
@@ -124,20 +485,6 @@ pub struct GitConfig {
     pub commit_style: String,
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            permissions: PermissionsConfig::default(),
-            model: ModelConfig::default(),
-            theme: ThemeConfig::default(),
-            persistence: PersistenceConfig::default(),
-            behavior: BehaviorConfig::default(),
-            error_recovery: ErrorRecoveryConfig::default(),
-            integrations: IntegrationsConfig::default(),
-        }
-    }
-}
-
 impl Default for PermissionsConfig {
     fn default() -> Self {
         Self {
@@ -161,6 +508,8 @@ impl Default for ThemeConfig {
     fn default() -> Self {
         Self {
             style: "minimal".to_string(),
+            color: true,
+            colors: ThemeColorsConfig::default(),
         }
     }
 }
@@ -181,9 +530,24 @@ impl Default for BehaviorConfig {
             streaming: true,
             tool_verbosity: "standard".to_string(),
             show_context_bar: true,
+            show_status_line: true,
             fun_facts: true,
             fun_fact_delay: 10,
             max_tool_iterations: 50,
+            notifications: false,
+            notification_threshold: 30,
+            input_history_size: 200,
+            trim_tool_results: true,
+            trim_threshold_tokens: 50_000,
+            trim_keep_recent_turns: 3,
+            unicode_tables: true,
+            auto_format_tool_output: true,
+            auto_checkpoint: false,
+            auto_tag: true,
+            include_environment_context: true,
+            auto_workspace_detect: false,
+            confirm: ConfirmConfig::default(),
+            skip_confirmations: false,
         }
     }
 }
@@ -192,17 +556,11 @@ impl Default for ErrorRecoveryConfig {
     fn default() -> Self {
         Self {
             auto_fix: true,
+            retries_enabled: true,
             generate_tests: true,
             max_retry_attempts: 3,
-        }
-    }
-}
-
-impl Default for IntegrationsConfig {
-    fn default() -> Self {
-        Self {
-            obsidian: ObsidianConfig::default(),
-            git: GitConfig::default(),
+            fix_allowed_patterns: vec!["**/*.rs".to_string(), "Cargo.toml".to_string()],
+            fix_forbidden_patterns: vec!["**/*.lock".to_string()],
         }
     }
 }
@@ -237,6 +595,8 @@ pub enum ConfigError {
     ParseError(toml::de::Error),
     /// Failed to serialize TOML
     SerializeError(toml::ser::Error),
+    /// A requested profile has no `[profiles.<name>]` section
+    UnknownProfile(String),
 }
 
 impl std::fmt::Display for ConfigError {
@@ -247,6 +607,11 @@ impl std::fmt::Display for ConfigError {
             ConfigError::WriteError(e) => write!(f, "Failed to write config file: {}", e),
             ConfigError::ParseError(e) => write!(f, "Failed to parse config file: {}", e),
             ConfigError::SerializeError(e) => write!(f, "Failed to serialize config: {}", e),
+            ConfigError::UnknownProfile(name) => write!(
+                f,
+                "Unknown profile '{}' (no [profiles.{}] section in the config)",
+                name, name
+            ),
         }
     }
 }
@@ -354,6 +719,30 @@ impl Config {
         }
         Ok(())
     }
+
+    /// Apply a named `[profiles.<name>]` section over the base config
+    ///
+    /// Overrides the default model and permissions where the profile sets
+    /// them and records the name in `active_profile`. The profile's
+    /// `api_key_env` and `base_url` are left for the caller to export into
+    /// the environment, since they are consumed there.
+    pub fn apply_profile(&mut self, name: &str) -> Result<(), ConfigError> {
+        let profile = self
+            .profiles
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ConfigError::UnknownProfile(name.to_string()))?;
+
+        if let Some(model) = profile.model {
+            self.model.default = model;
+        }
+        if let Some(permissions) = profile.permissions {
+            self.permissions = permissions;
+        }
+
+        self.active_profile = Some(name.to_string());
+        Ok(())
+    }
 }
 
 /// Merge two TOML values, with the second taking precedence
@@ -612,12 +1001,276 @@ mod tests {
         assert_eq!(loaded.permissions.trusted_paths.len(), 2);
     }
 
+    #[test]
+    fn test_keybindings_table_parses() {
+        let toml = r#"
+            [keybindings]
+            delete-word = "ctrl+w"
+            open-editor = "ctrl+x"
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse keybindings");
+
+        assert_eq!(
+            config.keybindings.bindings.get("delete-word"),
+            Some(&"ctrl+w".to_string())
+        );
+        assert_eq!(
+            config.keybindings.bindings.get("open-editor"),
+            Some(&"ctrl+x".to_string())
+        );
+    }
+
+    #[test]
+    fn test_keybindings_default_empty() {
+        let config = Config::default();
+        assert!(config.keybindings.bindings.is_empty());
+    }
+
+    #[test]
+    fn test_hooks_section_parses() {
+        let toml = r#"
+            [hooks]
+            pre_tool_use = ["./check-generated.sh"]
+            post_tool_use = ["cargo fmt"]
+            timeout_secs = 5
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse hooks");
+
+        assert_eq!(config.hooks.pre_tool_use, vec!["./check-generated.sh"]);
+        assert_eq!(config.hooks.post_tool_use, vec!["cargo fmt"]);
+        assert!(config.hooks.post_turn.is_empty());
+        assert_eq!(config.hooks.timeout_secs, 5);
+    }
+
+    #[test]
+    fn test_hooks_default_empty() {
+        let config = Config::default();
+        assert!(config.hooks.pre_tool_use.is_empty());
+        assert!(config.hooks.post_tool_use.is_empty());
+        assert!(config.hooks.post_turn.is_empty());
+        assert!(config.hooks.session_end.is_empty());
+        assert_eq!(config.hooks.timeout_secs, 10);
+    }
+
+    #[test]
+    fn test_fix_patterns_default() {
+        let config = Config::default();
+        assert_eq!(
+            config.error_recovery.fix_allowed_patterns,
+            vec!["**/*.rs".to_string(), "Cargo.toml".to_string()]
+        );
+        assert_eq!(
+            config.error_recovery.fix_forbidden_patterns,
+            vec!["**/*.lock".to_string()]
+        );
+    }
+
     #[test]
     fn test_tool_iterations_default() {
         let config = Config::default();
         assert_eq!(config.behavior.max_tool_iterations, 50);
     }
 
+    #[test]
+    fn test_trim_settings_default() {
+        let config = Config::default();
+        assert!(config.behavior.trim_tool_results);
+        assert_eq!(config.behavior.trim_threshold_tokens, 50_000);
+        assert_eq!(config.behavior.trim_keep_recent_turns, 3);
+    }
+
+    #[test]
+    fn test_trim_settings_configurable() {
+        let toml = r#"
+            [behavior]
+            trim_tool_results = false
+            trim_threshold_tokens = 30000
+            trim_keep_recent_turns = 5
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse config");
+        assert!(!config.behavior.trim_tool_results);
+        assert_eq!(config.behavior.trim_threshold_tokens, 30_000);
+        assert_eq!(config.behavior.trim_keep_recent_turns, 5);
+    }
+
+    #[test]
+    fn test_unicode_tables_default_and_configurable() {
+        let config = Config::default();
+        assert!(config.behavior.unicode_tables);
+
+        let toml = r#"
+            [behavior]
+            unicode_tables = false
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse config");
+        assert!(!config.behavior.unicode_tables);
+    }
+
+    #[test]
+    fn test_auto_format_tool_output_default_and_configurable() {
+        let config = Config::default();
+        assert!(config.behavior.auto_format_tool_output);
+
+        let toml = r#"
+            [behavior]
+            auto_format_tool_output = false
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse config");
+        assert!(!config.behavior.auto_format_tool_output);
+    }
+
+    #[test]
+    fn test_auto_checkpoint_default_and_configurable() {
+        let config = Config::default();
+        assert!(!config.behavior.auto_checkpoint);
+
+        let toml = r#"
+            [behavior]
+            auto_checkpoint = true
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse config");
+        assert!(config.behavior.auto_checkpoint);
+    }
+
+    #[test]
+    fn test_confirm_guards_default_and_configurable() {
+        let config = Config::default();
+        assert!(config.behavior.confirm.unclosed_fence);
+        assert!(config.behavior.confirm.single_char);
+        assert!(config.behavior.confirm.bare_path);
+
+        let toml = r#"
+            [behavior.confirm]
+            unclosed_fence = false
+            single_char = false
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse config");
+        assert!(!config.behavior.confirm.unclosed_fence);
+        assert!(!config.behavior.confirm.single_char);
+        assert!(config.behavior.confirm.bare_path);
+    }
+
+    #[test]
+    fn test_metrics_section_defaults_and_parses() {
+        let config = Config::default();
+        assert!(!config.metrics.enabled);
+        assert_eq!(config.metrics.port, 9090);
+
+        let toml = r#"
+            [metrics]
+            enabled = true
+            port = 9191
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse metrics");
+        assert!(config.metrics.enabled);
+        assert_eq!(config.metrics.port, 9191);
+    }
+
+    #[test]
+    fn test_export_section_defaults_and_parses() {
+        let config = Config::default();
+        assert!(config.export.include_metadata);
+
+        let toml = r#"
+            [export]
+            include_metadata = false
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse export");
+        assert!(!config.export.include_metadata);
+    }
+
+    #[test]
+    fn test_profiles_section_parses() {
+        let toml = r#"
+            [profiles.work]
+            api_key_env = "WORK_ANTHROPIC_KEY"
+            model = "claude-3-haiku"
+            base_url = "https://proxy.corp.example/anthropic"
+
+            [profiles.work.permissions]
+            trusted_paths = ["/work/repos"]
+            auto_read = false
+
+            [profiles.personal]
+            model = "claude-3-opus"
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse profiles");
+
+        let work = config.profiles.get("work").expect("work profile");
+        assert_eq!(work.api_key_env.as_deref(), Some("WORK_ANTHROPIC_KEY"));
+        assert_eq!(work.model.as_deref(), Some("claude-3-haiku"));
+        assert_eq!(
+            work.base_url.as_deref(),
+            Some("https://proxy.corp.example/anthropic")
+        );
+        let permissions = work.permissions.as_ref().expect("permissions override");
+        assert_eq!(permissions.trusted_paths, vec!["/work/repos"]);
+        assert!(!permissions.auto_read);
+
+        let personal = config.profiles.get("personal").expect("personal profile");
+        assert!(personal.api_key_env.is_none());
+        assert_eq!(personal.model.as_deref(), Some("claude-3-opus"));
+    }
+
+    #[test]
+    fn test_apply_profile_overrides_model_and_permissions() {
+        let toml = r#"
+            [profiles.work]
+            model = "claude-3-haiku"
+
+            [profiles.work.permissions]
+            trusted_paths = ["/work/repos"]
+            auto_read = false
+        "#;
+        let mut config = Config::parse(toml).expect("Should parse profiles");
+
+        config.apply_profile("work").expect("Should apply profile");
+
+        assert_eq!(config.model.default, "claude-3-haiku");
+        assert_eq!(config.permissions.trusted_paths, vec!["/work/repos"]);
+        assert!(!config.permissions.auto_read);
+        assert_eq!(config.active_profile.as_deref(), Some("work"));
+    }
+
+    #[test]
+    fn test_apply_profile_keeps_unset_fields() {
+        let toml = r#"
+            [profiles.minimal]
+            api_key_env = "OTHER_KEY"
+        "#;
+        let mut config = Config::parse(toml).expect("Should parse profiles");
+
+        config.apply_profile("minimal").expect("Should apply");
+
+        // Model and permissions keep their defaults
+        assert_eq!(config.model.default, "claude-3-opus");
+        assert!(config.permissions.auto_read);
+        assert_eq!(config.active_profile.as_deref(), Some("minimal"));
+    }
+
+    #[test]
+    fn test_apply_profile_unknown_name() {
+        let mut config = Config::default();
+
+        let result = config.apply_profile("work");
+
+        match result {
+            Err(ConfigError::UnknownProfile(name)) => assert_eq!(name, "work"),
+            other => panic!("Expected UnknownProfile, got {:?}", other.err()),
+        }
+        assert!(config.active_profile.is_none());
+    }
+
     #[test]
     fn test_tool_iterations_configurable() {
         let toml = r#"
@@ -628,4 +1281,17 @@ mod tests {
         let config = Config::parse(toml).expect("Should parse config");
         assert_eq!(config.behavior.max_tool_iterations, 100);
     }
+
+    #[test]
+    fn test_environment_context_configurable() {
+        assert!(Config::default().behavior.include_environment_context);
+
+        let toml = r#"
+            [behavior]
+            include_environment_context = false
+        "#;
+
+        let config = Config::parse(toml).expect("Should parse config");
+        assert!(!config.behavior.include_environment_context);
+    }
 }
@@ -28,6 +28,19 @@ pub enum GitError {
     DetachedHead,
     /// Merge conflict detected
     MergeConflict,
+    /// Failed to reset the repository
+    ResetError(Git2Error),
+    /// Failed to cherry-pick a commit
+    CherryPickError(Git2Error),
+    /// Failed to compute a diff
+    DiffError(Git2Error),
+    /// A cherry-pick or rebase produced conflicts in the listed files
+    Conflicts(Vec<PathBuf>),
+    /// Failed to rewrite history during an interactive rebase
+    RebaseError(Git2Error),
+    /// The rebase plan itself is invalid (bad action sequence, missing
+    /// reword message, dirty working tree)
+    InvalidRebasePlan(String),
 }
 
 impl std::fmt::Display for GitError {
@@ -39,6 +52,19 @@ impl std::fmt::Display for GitError {
             GitError::HeadError(e) => write!(f, "Failed to read HEAD: {}", e),
             GitError::DetachedHead => write!(f, "Repository is in detached HEAD state"),
             GitError::MergeConflict => write!(f, "Repository has merge conflicts"),
+            GitError::ResetError(e) => write!(f, "Failed to reset repository: {}", e),
+            GitError::CherryPickError(e) => write!(f, "Failed to cherry-pick: {}", e),
+            GitError::DiffError(e) => write!(f, "Failed to compute diff: {}", e),
+            GitError::Conflicts(paths) => {
+                let files: Vec<String> = paths.iter().map(|p| p.display().to_string()).collect();
+                write!(
+                    f,
+                    "Applying a commit produced conflicts in: {}",
+                    files.join(", ")
+                )
+            }
+            GitError::RebaseError(e) => write!(f, "Failed to rebase: {}", e),
+            GitError::InvalidRebasePlan(reason) => write!(f, "Invalid rebase plan: {}", reason),
         }
     }
 }
@@ -49,6 +75,10 @@ impl std::error::Error for GitError {
             GitError::OpenError(e) => Some(e),
             GitError::StatusError(e) => Some(e),
             GitError::HeadError(e) => Some(e),
+            GitError::ResetError(e) => Some(e),
+            GitError::CherryPickError(e) => Some(e),
+            GitError::DiffError(e) => Some(e),
+            GitError::RebaseError(e) => Some(e),
             _ => None,
         }
     }
@@ -191,6 +221,58 @@ impl RepoStatus {
     }
 }
 
+/// Lines added and removed in a single file of a diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffFileStat {
+    /// Path of the file relative to the repository root
+    pub path: PathBuf,
+    /// Number of lines added
+    pub added: usize,
+    /// Number of lines removed
+    pub removed: usize,
+}
+
+/// What to do with a commit during an interactive rebase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RebaseAction {
+    /// Re-apply the commit as-is
+    Pick,
+    /// Fold the commit into the previous one, combining the messages
+    Squash,
+    /// Fold the commit into the previous one, keeping its message
+    Fixup,
+    /// Leave the commit out entirely
+    Drop,
+    /// Re-apply the commit with a new message
+    Reword,
+}
+
+impl RebaseAction {
+    /// Parse the todo-list action names git uses (`pick`, `squash`, ...)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "pick" => Some(RebaseAction::Pick),
+            "squash" => Some(RebaseAction::Squash),
+            "fixup" => Some(RebaseAction::Fixup),
+            "drop" => Some(RebaseAction::Drop),
+            "reword" => Some(RebaseAction::Reword),
+            _ => None,
+        }
+    }
+}
+
+/// One step of an interactive rebase plan
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RebaseOperation {
+    /// What to do with the commit
+    pub action: RebaseAction,
+    /// The commit to operate on; anything `git rev-parse` accepts
+    pub commit_id: String,
+    /// Replacement message, required for `Reword` and optional for
+    /// `Squash` (which otherwise concatenates the two messages)
+    pub new_message: Option<String>,
+}
+
 /// A git repository wrapper
 pub struct GitRepo {
     repo: Repository,
@@ -338,6 +420,303 @@ impl GitRepo {
         None
     }
 
+    /// Get the short (7-character) ID of the current HEAD commit
+    pub fn head_short_id(&self) -> Result<String, GitError> {
+        let head = self.repo.head().map_err(GitError::HeadError)?;
+        let commit = head.peel_to_commit().map_err(GitError::HeadError)?;
+        Ok(commit.id().to_string()[..7].to_string())
+    }
+
+    /// Hard-reset HEAD and the working tree to the given revision
+    ///
+    /// `spec` is anything `git rev-parse` accepts, e.g. `"HEAD~2"`.
+    /// All uncommitted changes and the commits being unwound are discarded.
+    pub fn reset_hard(&self, spec: &str) -> Result<(), GitError> {
+        let target = self
+            .repo
+            .revparse_single(spec)
+            .map_err(GitError::ResetError)?;
+        self.repo
+            .reset(&target, git2::ResetType::Hard, None)
+            .map_err(GitError::ResetError)
+    }
+
+    /// Cherry-pick the given commit onto HEAD
+    ///
+    /// `id` is anything `git rev-parse` accepts. On a clean apply the
+    /// result is committed with the original message and author. If the
+    /// pick produces merge conflicts, the conflicted files are left in
+    /// the working tree and returned as `GitError::Conflicts` so the
+    /// caller can resolve them (or call [`abort_cherry_pick`]).
+    ///
+    /// [`abort_cherry_pick`]: GitRepo::abort_cherry_pick
+    pub fn cherry_pick(&self, id: &str) -> Result<(), GitError> {
+        let commit = self
+            .repo
+            .revparse_single(id)
+            .and_then(|obj| obj.peel_to_commit())
+            .map_err(GitError::CherryPickError)?;
+
+        self.repo
+            .cherrypick(&commit, None)
+            .map_err(GitError::CherryPickError)?;
+
+        let mut index = self.repo.index().map_err(GitError::CherryPickError)?;
+        if index.has_conflicts() {
+            let conflicts = conflict_paths(&index).map_err(GitError::CherryPickError)?;
+            return Err(GitError::Conflicts(conflicts));
+        }
+
+        // Clean apply: commit with the original message and author,
+        // matching `git cherry-pick` semantics
+        let tree_id = index.write_tree().map_err(GitError::CherryPickError)?;
+        let tree = self
+            .repo
+            .find_tree(tree_id)
+            .map_err(GitError::CherryPickError)?;
+        let head = self
+            .repo
+            .head()
+            .and_then(|h| h.peel_to_commit())
+            .map_err(GitError::HeadError)?;
+        let committer = self.repo.signature().map_err(GitError::CherryPickError)?;
+        self.repo
+            .commit(
+                Some("HEAD"),
+                &commit.author(),
+                &committer,
+                commit.message().unwrap_or(""),
+                &tree,
+                &[&head],
+            )
+            .map_err(GitError::CherryPickError)?;
+        self.repo.cleanup_state().map_err(GitError::CherryPickError)
+    }
+
+    /// Abandon an in-progress cherry-pick
+    ///
+    /// Hard-resets the index and working tree back to HEAD and clears
+    /// the repository's cherry-pick state.
+    pub fn abort_cherry_pick(&self) -> Result<(), GitError> {
+        let head = self
+            .repo
+            .head()
+            .and_then(|h| h.peel_to_commit())
+            .map_err(GitError::HeadError)?;
+        self.repo
+            .reset(head.as_object(), git2::ResetType::Hard, None)
+            .map_err(GitError::ResetError)?;
+        self.repo.cleanup_state().map_err(GitError::CherryPickError)
+    }
+
+    /// Rewrite the commits after `base` according to an interactive
+    /// rebase plan, without spawning `git rebase -i`
+    ///
+    /// `operations` is the todo list, oldest commit first; commits
+    /// reachable from HEAD but absent from the list are dropped. Each
+    /// step is applied in memory via [`cherrypick_commit`], so on
+    /// `GitError::Conflicts` the repository is left untouched. On
+    /// success HEAD (and its branch) are hard-reset to the rewritten
+    /// history and the new short id is returned.
+    ///
+    /// [`cherrypick_commit`]: git2::Repository::cherrypick_commit
+    pub fn interactive_rebase(
+        &self,
+        base: &str,
+        operations: &[RebaseOperation],
+    ) -> Result<String, GitError> {
+        if operations.is_empty() {
+            return Err(GitError::InvalidRebasePlan(
+                "the operation list is empty".to_string(),
+            ));
+        }
+        let status = self.status()?;
+        if status.has_staged() || status.has_unstaged() || status.has_conflicts {
+            return Err(GitError::InvalidRebasePlan(
+                "the working tree has uncommitted changes; commit or stash them first".to_string(),
+            ));
+        }
+
+        let base_commit = self
+            .repo
+            .revparse_single(base)
+            .and_then(|obj| obj.peel_to_commit())
+            .map_err(GitError::RebaseError)?;
+        let base_id = base_commit.id();
+        let mut tip = base_commit;
+
+        for op in operations {
+            let commit = self
+                .repo
+                .revparse_single(&op.commit_id)
+                .and_then(|obj| obj.peel_to_commit())
+                .map_err(GitError::RebaseError)?;
+
+            let new_id = match op.action {
+                RebaseAction::Drop => continue,
+                RebaseAction::Pick | RebaseAction::Reword => {
+                    let message = match op.action {
+                        RebaseAction::Reword => op.new_message.as_deref().ok_or_else(|| {
+                            GitError::InvalidRebasePlan(format!(
+                                "reword of {} requires new_message",
+                                op.commit_id
+                            ))
+                        })?,
+                        _ => commit.message().unwrap_or(""),
+                    };
+                    self.apply_rebase_step(&commit, &tip, &commit.author(), message, &[&tip])?
+                }
+                RebaseAction::Squash | RebaseAction::Fixup => {
+                    // Folding replaces the previous rewritten commit, so
+                    // there has to be one
+                    if tip.id() == base_id {
+                        return Err(GitError::InvalidRebasePlan(format!(
+                            "cannot squash or fixup {} with no prior commit to fold into",
+                            op.commit_id
+                        )));
+                    }
+                    let message = match (&op.action, &op.new_message) {
+                        (_, Some(message)) => message.clone(),
+                        (RebaseAction::Squash, None) => format!(
+                            "{}\n\n{}",
+                            tip.message().unwrap_or("").trim_end(),
+                            commit.message().unwrap_or("").trim_end()
+                        ),
+                        _ => tip.message().unwrap_or("").to_string(),
+                    };
+                    let parents: Vec<git2::Commit> = tip.parents().collect();
+                    let parent_refs: Vec<&git2::Commit> = parents.iter().collect();
+                    self.apply_rebase_step(&commit, &tip, &tip.author(), &message, &parent_refs)?
+                }
+            };
+            tip = self
+                .repo
+                .find_commit(new_id)
+                .map_err(GitError::RebaseError)?;
+        }
+
+        // Move HEAD (and its branch) plus the working tree onto the
+        // rewritten history
+        self.repo
+            .reset(tip.as_object(), git2::ResetType::Hard, None)
+            .map_err(GitError::ResetError)?;
+        Ok(tip.id().to_string()[..7].to_string())
+    }
+
+    /// Apply one commit's changes onto `onto` in memory and commit the
+    /// result with the given message and parents, returning the new OID
+    ///
+    /// Nothing touches the working tree: conflicts surface as
+    /// `GitError::Conflicts` with the repository unchanged.
+    fn apply_rebase_step(
+        &self,
+        commit: &git2::Commit,
+        onto: &git2::Commit,
+        author: &git2::Signature,
+        message: &str,
+        parents: &[&git2::Commit],
+    ) -> Result<git2::Oid, GitError> {
+        let mut index = self
+            .repo
+            .cherrypick_commit(commit, onto, 0, None)
+            .map_err(GitError::RebaseError)?;
+        if index.has_conflicts() {
+            let conflicts = conflict_paths(&index).map_err(GitError::RebaseError)?;
+            return Err(GitError::Conflicts(conflicts));
+        }
+        let tree_id = index
+            .write_tree_to(&self.repo)
+            .map_err(GitError::RebaseError)?;
+        let tree = self
+            .repo
+            .find_tree(tree_id)
+            .map_err(GitError::RebaseError)?;
+        let committer = self.repo.signature().map_err(GitError::RebaseError)?;
+        self.repo
+            .commit(None, author, &committer, message, &tree, parents)
+            .map_err(GitError::RebaseError)
+    }
+
+    /// Get the summary line and changed files of a commit, for display
+    /// after a successful cherry-pick
+    pub fn commit_details(&self, id: &str) -> Result<(String, Vec<PathBuf>), GitError> {
+        let commit = self
+            .repo
+            .revparse_single(id)
+            .and_then(|obj| obj.peel_to_commit())
+            .map_err(GitError::CherryPickError)?;
+        let message = commit.summary().unwrap_or("").to_string();
+
+        let tree = commit.tree().map_err(GitError::CherryPickError)?;
+        let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+        let diff = self
+            .repo
+            .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+            .map_err(GitError::CherryPickError)?;
+
+        let files = diff
+            .deltas()
+            .filter_map(|delta| delta.new_file().path().map(Path::to_path_buf))
+            .collect();
+        Ok((message, files))
+    }
+
+    /// Get the patch text of staged changes (HEAD tree vs index)
+    ///
+    /// Pass a path to limit the diff to one file. Returns an empty
+    /// string when nothing is staged.
+    pub fn diff_staged(&self, path: Option<&Path>) -> Result<String, GitError> {
+        let diff = self.staged_diff(path)?;
+        format_patch(&diff)
+    }
+
+    /// Get the patch text of unstaged changes (index vs working tree)
+    ///
+    /// Pass a path to limit the diff to one file. Returns an empty
+    /// string when the working tree matches the index.
+    pub fn diff_unstaged(&self, path: Option<&Path>) -> Result<String, GitError> {
+        let diff = self.unstaged_diff(path)?;
+        format_patch(&diff)
+    }
+
+    /// Lines added/removed per file in the staged diff
+    pub fn diff_staged_stats(&self, path: Option<&Path>) -> Result<Vec<DiffFileStat>, GitError> {
+        let diff = self.staged_diff(path)?;
+        collect_stats(&diff)
+    }
+
+    /// Lines added/removed per file in the unstaged diff
+    pub fn diff_unstaged_stats(&self, path: Option<&Path>) -> Result<Vec<DiffFileStat>, GitError> {
+        let diff = self.unstaged_diff(path)?;
+        collect_stats(&diff)
+    }
+
+    /// Build the staged diff (HEAD tree vs index)
+    ///
+    /// A missing HEAD (repository without commits) diffs the index
+    /// against an empty tree, so freshly added files still show up.
+    fn staged_diff(&self, path: Option<&Path>) -> Result<git2::Diff<'_>, GitError> {
+        let head = self.repo.head().ok().and_then(|h| h.peel_to_tree().ok());
+        let mut opts = git2::DiffOptions::new();
+        if let Some(path) = path {
+            opts.pathspec(path);
+        }
+        self.repo
+            .diff_tree_to_index(head.as_ref(), None, Some(&mut opts))
+            .map_err(GitError::DiffError)
+    }
+
+    /// Build the unstaged diff (index vs working tree)
+    fn unstaged_diff(&self, path: Option<&Path>) -> Result<git2::Diff<'_>, GitError> {
+        let mut opts = git2::DiffOptions::new();
+        if let Some(path) = path {
+            opts.pathspec(path);
+        }
+        self.repo
+            .diff_index_to_workdir(None, Some(&mut opts))
+            .map_err(GitError::DiffError)
+    }
+
     /// Check if a specific path is inside this repository
     ///
     /// Canonicalizes both paths to handle symlinks correctly.
@@ -358,6 +737,75 @@ impl GitRepo {
     }
 }
 
+/// Collect the paths of an index's conflict entries
+fn conflict_paths(index: &git2::Index) -> Result<Vec<PathBuf>, Git2Error> {
+    Ok(index
+        .conflicts()?
+        .filter_map(|conflict| conflict.ok())
+        .filter_map(|conflict| {
+            conflict
+                .our
+                .or(conflict.their)
+                .or(conflict.ancestor)
+                .map(|entry| PathBuf::from(String::from_utf8_lossy(&entry.path).as_ref()))
+        })
+        .collect())
+}
+
+/// Render a diff as patch text, prefixing content lines with +/-/space
+fn format_patch(diff: &git2::Diff) -> Result<String, GitError> {
+    let mut output = String::new();
+    diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| {
+        match line.origin() {
+            '+' | '-' | ' ' => output.push(line.origin()),
+            // File and hunk headers carry their own text
+            _ => {}
+        }
+        output.push_str(std::str::from_utf8(line.content()).unwrap_or("<binary>\n"));
+        true
+    })
+    .map_err(GitError::DiffError)?;
+    Ok(output)
+}
+
+/// Count lines added/removed per file in a diff
+fn collect_stats(diff: &git2::Diff) -> Result<Vec<DiffFileStat>, GitError> {
+    // Diff::foreach borrows both callbacks mutably at once, so the
+    // accumulator goes behind a RefCell.
+    let stats = std::cell::RefCell::new(Vec::<DiffFileStat>::new());
+    diff.foreach(
+        &mut |delta, _progress| {
+            let path = delta
+                .new_file()
+                .path()
+                .or_else(|| delta.old_file().path())
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            stats.borrow_mut().push(DiffFileStat {
+                path,
+                added: 0,
+                removed: 0,
+            });
+            true
+        },
+        None,
+        None,
+        Some(&mut |_delta, _hunk, line| {
+            let mut stats = stats.borrow_mut();
+            if let Some(current) = stats.last_mut() {
+                match line.origin() {
+                    '+' => current.added += 1,
+                    '-' => current.removed += 1,
+                    _ => {}
+                }
+            }
+            true
+        }),
+    )
+    .map_err(GitError::DiffError)?;
+    Ok(stats.into_inner())
+}
+
 /// Check if a path is inside a git repository
 pub fn is_git_repository<P: AsRef<Path>>(path: P) -> bool {
     GitRepo::open(path).is_ok()
@@ -1411,4 +1859,453 @@ mod tests {
         ));
         assert!(!FileGrouper::matches_impl_pattern("src/lexer.rs", "parser"));
     }
+
+    /// Commit `name` with the given message and return the new commit's OID.
+    fn commit_file(repo: &Repository, dir: &Path, name: &str, message: &str) -> git2::Oid {
+        commit_content(repo, dir, name, "content", message)
+    }
+
+    /// Commit `name` with the given content and message, returning the OID.
+    fn commit_content(
+        repo: &Repository,
+        dir: &Path,
+        name: &str,
+        content: &str,
+        message: &str,
+    ) -> git2::Oid {
+        fs::write(dir.join(name), content).expect("Failed to write file");
+
+        let mut index = repo.index().expect("Failed to get index");
+        index.add_path(Path::new(name)).expect("Failed to add file");
+        index.write().expect("Failed to write index");
+
+        let tree_id = index.write_tree().expect("Failed to write tree");
+        let tree = repo.find_tree(tree_id).expect("Failed to find tree");
+        let sig = repo.signature().expect("Failed to get signature");
+        let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+        let parents: Vec<_> = parent.iter().collect();
+
+        repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)
+            .expect("Failed to commit")
+    }
+
+    #[test]
+    fn test_head_short_id() {
+        // Arrange
+        let (temp_dir, repo) = init_test_repo();
+        let oid = commit_file(&repo, temp_dir.path(), "a.txt", "First");
+
+        // Act
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let short_id = git_repo.head_short_id().expect("Failed to read HEAD");
+
+        // Assert
+        assert_eq!(short_id, oid.to_string()[..7]);
+    }
+
+    #[test]
+    fn test_reset_hard_unwinds_commits() {
+        // Arrange: two commits on top of the first
+        let (temp_dir, repo) = init_test_repo();
+        let first = commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        commit_file(&repo, temp_dir.path(), "b.txt", "Second");
+        commit_file(&repo, temp_dir.path(), "c.txt", "Third");
+
+        // Act
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        git_repo.reset_hard("HEAD~2").expect("Failed to reset");
+
+        // Assert: HEAD is back on the first commit and the tree matches it
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.id(), first);
+        assert!(temp_dir.path().join("a.txt").exists());
+        assert!(!temp_dir.path().join("b.txt").exists());
+    }
+
+    #[test]
+    fn test_reset_hard_invalid_spec_errors() {
+        let (temp_dir, repo) = init_test_repo();
+        commit_file(&repo, temp_dir.path(), "a.txt", "Only");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let result = git_repo.reset_hard("HEAD~5");
+
+        assert!(matches!(result, Err(GitError::ResetError(_))));
+    }
+
+    #[test]
+    fn test_cherry_pick_clean_apply() {
+        // Arrange: commit B on top of A, then rewind to A so B is
+        // no longer in HEAD's history
+        let (temp_dir, repo) = init_test_repo();
+        commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        let second = commit_file(&repo, temp_dir.path(), "b.txt", "Second");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        git_repo.reset_hard("HEAD~1").expect("Failed to reset");
+        assert!(!temp_dir.path().join("b.txt").exists());
+
+        // Act
+        git_repo
+            .cherry_pick(&second.to_string())
+            .expect("Cherry-pick failed");
+
+        // Assert: the commit was re-applied with its original message
+        assert!(temp_dir.path().join("b.txt").exists());
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.message(), Some("Second"));
+        assert!(git_repo.status().expect("Failed to get status").is_clean());
+    }
+
+    #[test]
+    fn test_cherry_pick_conflict_lists_files() {
+        // Arrange: two commits that change the same file divergently
+        let (temp_dir, repo) = init_test_repo();
+        commit_content(&repo, temp_dir.path(), "file.txt", "base", "Base");
+        let theirs = commit_content(&repo, temp_dir.path(), "file.txt", "theirs", "Their change");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        git_repo.reset_hard("HEAD~1").expect("Failed to reset");
+        commit_content(&repo, temp_dir.path(), "file.txt", "ours", "Our change");
+
+        // Act: reopen so the handle sees the commit made above
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let result = git_repo.cherry_pick(&theirs.to_string());
+
+        // Assert
+        match result {
+            Err(GitError::Conflicts(paths)) => {
+                assert_eq!(paths, vec![PathBuf::from("file.txt")]);
+            }
+            other => panic!("Expected Conflicts, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_abort_cherry_pick_restores_working_tree() {
+        // Arrange: get into a conflicted cherry-pick state
+        let (temp_dir, repo) = init_test_repo();
+        commit_content(&repo, temp_dir.path(), "file.txt", "base", "Base");
+        let theirs = commit_content(&repo, temp_dir.path(), "file.txt", "theirs", "Their change");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        git_repo.reset_hard("HEAD~1").expect("Failed to reset");
+        commit_content(&repo, temp_dir.path(), "file.txt", "ours", "Our change");
+
+        // Reopen so the handle sees the commit made above
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        assert!(matches!(
+            git_repo.cherry_pick(&theirs.to_string()),
+            Err(GitError::Conflicts(_))
+        ));
+
+        // Act
+        git_repo.abort_cherry_pick().expect("Abort failed");
+
+        // Assert: back to a clean tree with our version of the file
+        let status = git_repo.status().expect("Failed to get status");
+        assert!(status.is_clean());
+        assert!(!status.has_conflicts);
+        let contents = fs::read_to_string(temp_dir.path().join("file.txt")).unwrap();
+        assert_eq!(contents, "ours");
+    }
+
+    #[test]
+    fn test_cherry_pick_unknown_commit_errors() {
+        let (temp_dir, repo) = init_test_repo();
+        commit_file(&repo, temp_dir.path(), "a.txt", "Only");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let result = git_repo.cherry_pick("deadbeef");
+
+        assert!(matches!(result, Err(GitError::CherryPickError(_))));
+    }
+
+    /// Shorthand for building a rebase plan step.
+    fn rebase_op(action: RebaseAction, commit_id: git2::Oid) -> RebaseOperation {
+        RebaseOperation {
+            action,
+            commit_id: commit_id.to_string(),
+            new_message: None,
+        }
+    }
+
+    #[test]
+    fn test_interactive_rebase_reorders_commits() {
+        // Arrange: base, then B and C on top
+        let (temp_dir, repo) = init_test_repo();
+        let base = commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        let b = commit_file(&repo, temp_dir.path(), "b.txt", "Second");
+        let c = commit_file(&repo, temp_dir.path(), "c.txt", "Third");
+
+        // Act: replay C before B
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        git_repo
+            .interactive_rebase(
+                &base.to_string(),
+                &[
+                    rebase_op(RebaseAction::Pick, c),
+                    rebase_op(RebaseAction::Pick, b),
+                ],
+            )
+            .expect("Rebase failed");
+
+        // Assert: history is now base -> Third -> Second with all files
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.message(), Some("Second"));
+        assert_eq!(head.parent(0).unwrap().message(), Some("Third"));
+        assert_eq!(head.parent(0).unwrap().parent(0).unwrap().id(), base);
+        assert!(temp_dir.path().join("b.txt").exists());
+        assert!(temp_dir.path().join("c.txt").exists());
+        assert!(git_repo.status().expect("Failed to get status").is_clean());
+    }
+
+    #[test]
+    fn test_interactive_rebase_squash_combines_messages() {
+        let (temp_dir, repo) = init_test_repo();
+        let base = commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        let b = commit_file(&repo, temp_dir.path(), "b.txt", "Second");
+        let c = commit_file(&repo, temp_dir.path(), "c.txt", "Third");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        git_repo
+            .interactive_rebase(
+                &base.to_string(),
+                &[
+                    rebase_op(RebaseAction::Pick, b),
+                    rebase_op(RebaseAction::Squash, c),
+                ],
+            )
+            .expect("Rebase failed");
+
+        // Assert: one commit on top of base, carrying both changes and
+        // both messages
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.message(), Some("Second\n\nThird"));
+        assert_eq!(head.parent(0).unwrap().id(), base);
+        assert!(temp_dir.path().join("b.txt").exists());
+        assert!(temp_dir.path().join("c.txt").exists());
+    }
+
+    #[test]
+    fn test_interactive_rebase_fixup_and_drop() {
+        let (temp_dir, repo) = init_test_repo();
+        let base = commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        let b = commit_file(&repo, temp_dir.path(), "b.txt", "Second");
+        let c = commit_file(&repo, temp_dir.path(), "c.txt", "Third");
+        let d = commit_file(&repo, temp_dir.path(), "d.txt", "Fourth");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        git_repo
+            .interactive_rebase(
+                &base.to_string(),
+                &[
+                    rebase_op(RebaseAction::Pick, b),
+                    rebase_op(RebaseAction::Fixup, c),
+                    rebase_op(RebaseAction::Drop, d),
+                ],
+            )
+            .expect("Rebase failed");
+
+        // Assert: fixup keeps the picked commit's message, drop removes
+        // the commit and its file
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.message(), Some("Second"));
+        assert_eq!(head.parent(0).unwrap().id(), base);
+        assert!(temp_dir.path().join("c.txt").exists());
+        assert!(!temp_dir.path().join("d.txt").exists());
+    }
+
+    #[test]
+    fn test_interactive_rebase_reword() {
+        let (temp_dir, repo) = init_test_repo();
+        let base = commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        let b = commit_file(&repo, temp_dir.path(), "b.txt", "WIP");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        git_repo
+            .interactive_rebase(
+                &base.to_string(),
+                &[RebaseOperation {
+                    action: RebaseAction::Reword,
+                    commit_id: b.to_string(),
+                    new_message: Some("Add b.txt".to_string()),
+                }],
+            )
+            .expect("Rebase failed");
+
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.message(), Some("Add b.txt"));
+    }
+
+    #[test]
+    fn test_interactive_rebase_reword_requires_message() {
+        let (temp_dir, repo) = init_test_repo();
+        let base = commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        let b = commit_file(&repo, temp_dir.path(), "b.txt", "Second");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let result =
+            git_repo.interactive_rebase(&base.to_string(), &[rebase_op(RebaseAction::Reword, b)]);
+
+        assert!(matches!(result, Err(GitError::InvalidRebasePlan(_))));
+    }
+
+    #[test]
+    fn test_interactive_rebase_conflict_leaves_repo_untouched() {
+        // Arrange: B and C rewrite the same file, so picking C without
+        // B cannot apply cleanly
+        let (temp_dir, repo) = init_test_repo();
+        let base = commit_content(&repo, temp_dir.path(), "file.txt", "base", "Base");
+        commit_content(&repo, temp_dir.path(), "file.txt", "ours", "Our change");
+        let c = commit_content(&repo, temp_dir.path(), "file.txt", "theirs", "Their change");
+
+        // Act
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let result =
+            git_repo.interactive_rebase(&base.to_string(), &[rebase_op(RebaseAction::Pick, c)]);
+
+        // Assert: conflicts are reported and nothing moved
+        match result {
+            Err(GitError::Conflicts(paths)) => {
+                assert_eq!(paths, vec![PathBuf::from("file.txt")]);
+            }
+            other => panic!("Expected Conflicts, got {:?}", other),
+        }
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.id(), c);
+        assert!(git_repo.status().expect("Failed to get status").is_clean());
+    }
+
+    #[test]
+    fn test_interactive_rebase_requires_clean_tree() {
+        let (temp_dir, repo) = init_test_repo();
+        let base = commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        let b = commit_file(&repo, temp_dir.path(), "b.txt", "Second");
+        fs::write(temp_dir.path().join("a.txt"), "dirty").expect("Failed to write file");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let result =
+            git_repo.interactive_rebase(&base.to_string(), &[rebase_op(RebaseAction::Pick, b)]);
+
+        assert!(matches!(result, Err(GitError::InvalidRebasePlan(_))));
+    }
+
+    #[test]
+    fn test_interactive_rebase_squash_needs_prior_pick() {
+        let (temp_dir, repo) = init_test_repo();
+        let base = commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        let b = commit_file(&repo, temp_dir.path(), "b.txt", "Second");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let result =
+            git_repo.interactive_rebase(&base.to_string(), &[rebase_op(RebaseAction::Squash, b)]);
+
+        assert!(matches!(result, Err(GitError::InvalidRebasePlan(_))));
+    }
+
+    #[test]
+    fn test_commit_details_message_and_files() {
+        let (temp_dir, repo) = init_test_repo();
+        commit_file(&repo, temp_dir.path(), "a.txt", "First");
+        let second = commit_file(&repo, temp_dir.path(), "b.txt", "Add b");
+
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let (message, files) = git_repo
+            .commit_details(&second.to_string())
+            .expect("Failed to read commit");
+
+        assert_eq!(message, "Add b");
+        assert_eq!(files, vec![PathBuf::from("b.txt")]);
+    }
+
+    /// Write `name` with the given content and stage it, without committing.
+    fn stage_content(repo: &Repository, dir: &Path, name: &str, content: &str) {
+        fs::write(dir.join(name), content).expect("Failed to write file");
+        let mut index = repo.index().expect("Failed to get index");
+        index.add_path(Path::new(name)).expect("Failed to add file");
+        index.write().expect("Failed to write index");
+    }
+
+    #[test]
+    fn test_diff_staged_shows_index_changes() {
+        // Arrange: commit a file, then stage a modification
+        let (temp_dir, repo) = init_test_repo();
+        commit_content(&repo, temp_dir.path(), "a.txt", "line1\n", "First");
+        stage_content(&repo, temp_dir.path(), "a.txt", "line1\nline2\n");
+
+        // Act
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let staged = git_repo.diff_staged(None).expect("Failed to diff");
+        let unstaged = git_repo.diff_unstaged(None).expect("Failed to diff");
+
+        // Assert: the addition is staged, the working tree matches the index
+        assert!(staged.contains("+line2"));
+        assert!(staged.contains("a.txt"));
+        assert!(unstaged.is_empty());
+    }
+
+    #[test]
+    fn test_diff_unstaged_shows_worktree_changes() {
+        // Arrange: commit a file, then modify it without staging
+        let (temp_dir, repo) = init_test_repo();
+        commit_content(&repo, temp_dir.path(), "a.txt", "line1\n", "First");
+        fs::write(temp_dir.path().join("a.txt"), "changed\n").expect("Failed to write file");
+
+        // Act
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let staged = git_repo.diff_staged(None).expect("Failed to diff");
+        let unstaged = git_repo.diff_unstaged(None).expect("Failed to diff");
+
+        // Assert
+        assert!(staged.is_empty());
+        assert!(unstaged.contains("-line1"));
+        assert!(unstaged.contains("+changed"));
+    }
+
+    #[test]
+    fn test_diff_staged_path_filter() {
+        // Arrange: stage modifications to two files
+        let (temp_dir, repo) = init_test_repo();
+        commit_content(&repo, temp_dir.path(), "a.txt", "a\n", "First");
+        commit_content(&repo, temp_dir.path(), "b.txt", "b\n", "Second");
+        stage_content(&repo, temp_dir.path(), "a.txt", "a changed\n");
+        stage_content(&repo, temp_dir.path(), "b.txt", "b changed\n");
+
+        // Act: limit the diff to one file
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let diff = git_repo
+            .diff_staged(Some(Path::new("a.txt")))
+            .expect("Failed to diff");
+
+        // Assert
+        assert!(diff.contains("a.txt"));
+        assert!(!diff.contains("b.txt"));
+    }
+
+    #[test]
+    fn test_diff_stats_count_lines_per_file() {
+        // Arrange: one staged change that adds two lines and removes one
+        let (temp_dir, repo) = init_test_repo();
+        commit_content(&repo, temp_dir.path(), "a.txt", "one\ntwo\n", "First");
+        stage_content(&repo, temp_dir.path(), "a.txt", "one\nthree\nfour\n");
+
+        // Act
+        let git_repo = GitRepo::open(temp_dir.path()).expect("Failed to open repo");
+        let stats = git_repo.diff_staged_stats(None).expect("Failed to diff");
+
+        // Assert
+        assert_eq!(
+            stats,
+            vec![DiffFileStat {
+                path: PathBuf::from("a.txt"),
+                added: 2,
+                removed: 1,
+            }]
+        );
+        assert!(git_repo
+            .diff_unstaged_stats(None)
+            .expect("Failed to diff")
+            .is_empty());
+    }
 }
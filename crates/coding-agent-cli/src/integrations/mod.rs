@@ -5,8 +5,13 @@
 
 pub mod git;
 pub mod obsidian;
+pub mod session_diff;
 pub mod specstory;
 
-pub use git::{FileGrouper, GitRepo};
+pub use git::{FileGrouper, GitRepo, RebaseAction, RebaseOperation};
 pub use obsidian::{NoteType, ObsidianError, ObsidianVault};
-pub use specstory::{Session, SessionInfo, SessionManager, SpecStoryError};
+pub use session_diff::{ModifiedMessage, SessionDiff};
+pub use specstory::{
+    Session, SessionInfo, SessionManager, SessionPreview, SpecStoryError, ToolRecord, TurnRecord,
+    UndoRecord,
+};
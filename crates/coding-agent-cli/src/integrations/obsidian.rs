@@ -67,6 +67,7 @@ pub enum NoteType {
 
 impl NoteType {
     /// Parse a note type from a string
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "meeting" => Some(NoteType::Meeting),
@@ -364,7 +365,7 @@ impl ObsidianVault {
                 content.push_str("## Action Items\n\n");
                 content.push_str("- [ ] \n\n");
                 content.push_str("## Next Steps\n\n");
-                content.push_str("\n");
+                content.push('\n');
             }
             NoteType::Concept => {
                 content.push_str(&format!("# {}\n\n", topic));
@@ -399,7 +400,7 @@ impl ObsidianVault {
             NoteType::General => {
                 content.push_str(&format!("# {}\n\n", topic));
                 content.push_str("## Notes\n\n");
-                content.push_str("\n");
+                content.push('\n');
             }
         }
 
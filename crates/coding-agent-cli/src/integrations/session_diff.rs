@@ -0,0 +1,278 @@
+//! Structural diffs between two sessions
+//!
+//! `SessionDiff::compute` compares two sessions message-by-message and
+//! records what was added, removed, or modified, plus the net token
+//! change. A diff can be rendered as a compact unified diff via
+//! `Display`, or applied to a session with [`Session::patch`] to
+//! cherry-pick message changes between session branches.
+
+use super::specstory::{Message, Session};
+use crate::tokens::TokenCounter;
+
+/// A message whose text changed between two sessions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModifiedMessage {
+    /// Position of the message in both sessions (0-based)
+    pub index: usize,
+    /// The message as it appears in session `a`
+    pub before: Message,
+    /// The message as it appears in session `b`
+    pub after: Message,
+}
+
+/// The structural difference between two sessions
+///
+/// Computed positionally: messages at the same index are compared
+/// directly, extra messages at the end of `b` are additions, and extra
+/// messages at the end of `a` are removals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionDiff {
+    /// Messages present in `b` but not in `a`, with their index in `b`
+    pub added: Vec<(usize, Message)>,
+    /// Messages present in `a` but not in `b`, with their index in `a`
+    pub removed: Vec<(usize, Message)>,
+    /// Messages whose content or role changed
+    pub modified: Vec<ModifiedMessage>,
+    /// Net token change from `a` to `b` (negative when `b` is smaller)
+    pub token_delta: i64,
+}
+
+impl SessionDiff {
+    /// Compute the diff that transforms session `a` into session `b`
+    pub fn compute(a: &Session, b: &Session) -> SessionDiff {
+        let common = a.messages.len().min(b.messages.len());
+
+        let mut modified = Vec::new();
+        for index in 0..common {
+            let before = &a.messages[index];
+            let after = &b.messages[index];
+            if before.role != after.role || before.content != after.content {
+                modified.push(ModifiedMessage {
+                    index,
+                    before: before.clone(),
+                    after: after.clone(),
+                });
+            }
+        }
+
+        let added = b.messages[common..]
+            .iter()
+            .enumerate()
+            .map(|(i, msg)| (common + i, msg.clone()))
+            .collect();
+
+        let removed = a.messages[common..]
+            .iter()
+            .enumerate()
+            .map(|(i, msg)| (common + i, msg.clone()))
+            .collect();
+
+        let token_delta = session_tokens(b) - session_tokens(a);
+
+        SessionDiff {
+            added,
+            removed,
+            modified,
+            token_delta,
+        }
+    }
+
+    /// Check whether the two sessions had identical messages
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
+impl std::fmt::Display for SessionDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return write!(f, "Sessions are identical.");
+        }
+
+        for change in &self.modified {
+            writeln!(
+                f,
+                "@@ message {} ({} -> {})",
+                change.index + 1,
+                change.before.role,
+                change.after.role
+            )?;
+            write_prefixed(f, '-', &change.before.content)?;
+            write_prefixed(f, '+', &change.after.content)?;
+        }
+
+        for (index, msg) in &self.removed {
+            writeln!(f, "@@ message {} removed ({})", index + 1, msg.role)?;
+            write_prefixed(f, '-', &msg.content)?;
+        }
+
+        for (index, msg) in &self.added {
+            writeln!(f, "@@ message {} added ({})", index + 1, msg.role)?;
+            write_prefixed(f, '+', &msg.content)?;
+        }
+
+        write!(f, "Token change: {:+}", self.token_delta)
+    }
+}
+
+/// Write each line of `content` with a diff prefix (`-` or `+`)
+fn write_prefixed(
+    f: &mut std::fmt::Formatter<'_>,
+    prefix: char,
+    content: &str,
+) -> std::fmt::Result {
+    for line in content.lines() {
+        writeln!(f, "{}{}", prefix, line)?;
+    }
+    Ok(())
+}
+
+/// Estimate the token total for a session's message content
+fn session_tokens(session: &Session) -> i64 {
+    let Ok(counter) = TokenCounter::new() else {
+        return 0;
+    };
+    session
+        .messages
+        .iter()
+        .map(|msg| counter.count(&msg.content).tokens as i64)
+        .sum()
+}
+
+impl Session {
+    /// Apply a diff to this session, producing the patched session
+    ///
+    /// Cherry-picks the diff's message changes onto this session:
+    /// modifications replace messages at their index, removals drop the
+    /// trailing messages they cover, and additions are appended. Applying
+    /// `SessionDiff::compute(&a, &b)` to `a` yields `b`'s messages.
+    pub fn patch(&self, diff: &SessionDiff) -> Session {
+        let mut patched = self.clone();
+
+        for change in &diff.modified {
+            if change.index < patched.messages.len() {
+                patched.messages[change.index] = change.after.clone();
+            }
+        }
+
+        // Removals cover a trailing range; drop from the end so earlier
+        // indices stay valid
+        for (index, _) in diff.removed.iter().rev() {
+            if *index < patched.messages.len() {
+                patched.messages.remove(*index);
+            }
+        }
+
+        for (_, msg) in &diff.added {
+            patched.messages.push(msg.clone());
+        }
+
+        patched
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session_with(messages: &[(&str, &str)]) -> Session {
+        let mut session = Session::new();
+        for (role, content) in messages {
+            match *role {
+                "user" => session.add_user_message(content),
+                "agent" => session.add_agent_message(content),
+                _ => session.add_system_message(content),
+            }
+        }
+        session
+    }
+
+    #[test]
+    fn test_identical_sessions_produce_empty_diff() {
+        let a = session_with(&[("user", "hello"), ("agent", "hi there")]);
+        let b = a.clone();
+
+        let diff = SessionDiff::compute(&a, &b);
+
+        assert!(diff.is_empty());
+        assert_eq!(diff.token_delta, 0);
+        assert_eq!(diff.to_string(), "Sessions are identical.");
+    }
+
+    #[test]
+    fn test_added_messages_detected() {
+        let a = session_with(&[("user", "hello")]);
+        let b = session_with(&[("user", "hello"), ("agent", "hi there")]);
+
+        let diff = SessionDiff::compute(&a, &b);
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].0, 1);
+        assert_eq!(diff.added[0].1.content, "hi there");
+        assert!(diff.removed.is_empty());
+        assert!(diff.modified.is_empty());
+        assert!(diff.token_delta > 0);
+    }
+
+    #[test]
+    fn test_removed_and_modified_messages_detected() {
+        let a = session_with(&[("user", "hello"), ("agent", "old reply"), ("user", "bye")]);
+        let b = session_with(&[("user", "hello"), ("agent", "new reply")]);
+
+        let diff = SessionDiff::compute(&a, &b);
+
+        assert_eq!(diff.modified.len(), 1);
+        assert_eq!(diff.modified[0].index, 1);
+        assert_eq!(diff.modified[0].before.content, "old reply");
+        assert_eq!(diff.modified[0].after.content, "new reply");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0].1.content, "bye");
+        assert!(diff.added.is_empty());
+    }
+
+    #[test]
+    fn test_display_renders_unified_diff_lines() {
+        let a = session_with(&[("user", "hello")]);
+        let b = session_with(&[("user", "hello world"), ("agent", "hi")]);
+
+        let output = SessionDiff::compute(&a, &b).to_string();
+
+        assert!(output.contains("@@ message 1"));
+        assert!(output.contains("-hello"));
+        assert!(output.contains("+hello world"));
+        assert!(output.contains("@@ message 2 added (Agent)"));
+        assert!(output.contains("+hi"));
+        assert!(output.contains("Token change: +"));
+    }
+
+    #[test]
+    fn test_patch_round_trips_to_target_session() {
+        let a = session_with(&[("user", "hello"), ("agent", "old reply"), ("user", "bye")]);
+        let b = session_with(&[
+            ("user", "hello"),
+            ("agent", "new reply"),
+            ("agent", "extra"),
+        ]);
+
+        let diff = SessionDiff::compute(&a, &b);
+        let patched = a.patch(&diff);
+
+        assert_eq!(patched.messages, b.messages);
+        // The patched session keeps its own metadata
+        assert_eq!(patched.metadata.title, a.metadata.title);
+    }
+
+    #[test]
+    fn test_patch_tolerates_out_of_range_indices() {
+        let a = session_with(&[("user", "hello")]);
+        let b = session_with(&[("user", "hello"), ("agent", "hi")]);
+        let diff = SessionDiff::compute(&a, &b);
+
+        // Apply to a shorter session than the diff was computed against
+        let empty = Session::new();
+        let patched = empty.patch(&diff);
+
+        assert_eq!(patched.messages.len(), 1);
+        assert_eq!(patched.messages[0].content, "hi");
+    }
+}
@@ -11,7 +11,11 @@ pub mod agents;
 pub mod cli;
 pub mod config;
 pub mod integrations;
+pub mod metrics;
 pub mod permissions;
+pub mod project;
+pub mod providers;
+pub mod security;
 pub mod tokens;
 pub mod tools;
 pub mod ui;
@@ -6,7 +6,11 @@ mod agents;
 mod cli;
 mod config;
 mod integrations;
+mod metrics;
 mod permissions;
+mod project;
+mod providers;
+mod security;
 mod tokens;
 mod tools;
 mod ui;
@@ -22,13 +26,226 @@ struct Args {
     /// Enable verbose output
     #[arg(short, long)]
     verbose: bool,
+
+    /// Disable colored output (also honored via the NO_COLOR env var)
+    #[arg(long)]
+    no_color: bool,
+
+    /// Disable the bash tool for this session (read-only analysis)
+    #[arg(long)]
+    no_bash: bool,
+
+    /// Write logs to this file instead of stderr (overrides log.file)
+    #[arg(long)]
+    log_file: Option<std::path::PathBuf>,
+
+    /// Send this message as the first user input (skips the startup screen)
+    #[arg(short, long)]
+    message: Option<String>,
+
+    /// Exit after the first complete conversation turn (for scripting)
+    #[arg(short = 'n', long)]
+    no_interactive: bool,
+
+    /// Simulate mutating tools (write_file, edit_file, bash) instead of executing them
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Append a JSONL debug log of API requests, responses, and tool calls
+    /// to this file (default: .specstory/debug/<session>.jsonl)
+    #[arg(long)]
+    debug_log: Option<std::path::PathBuf>,
+
+    /// Answer yes to all [y/N] confirmation prompts (for scripted usage)
+    #[arg(short = 'y', long)]
+    yes: bool,
+
+    /// Use a named [profiles.<name>] section from the config
+    /// (also honored via the CODING_AGENT_PROFILE env var)
+    #[arg(long)]
+    profile: Option<String>,
+
+    /// Backend provider: "anthropic" (default) or "mock", which needs no
+    /// API key and answers with canned responses for offline demos
+    /// (also honored via the CODING_AGENT_PROVIDER env var)
+    #[arg(long)]
+    provider: Option<String>,
+
+    /// Scenario file (TOML or JSON) scripting the mock provider's responses
+    #[arg(long)]
+    mock_scenario: Option<std::path::PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<CliCommand>,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum CliCommand {
+    /// Run the setup wizard (API key, default model, theme)
+    Setup,
+
+    /// Manage the API key in the OS keychain
+    Auth {
+        #[command(subcommand)]
+        action: AuthAction,
+    },
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum AuthAction {
+    /// Store an API key in the OS keychain (prompts with hidden input)
+    Login,
+    /// Remove the API key from the OS keychain
+    Logout,
+    /// Show where the API key is coming from
+    Status,
 }
 
 #[tokio::main]
 async fn main() -> ExitCode {
     let args = Args::parse();
 
-    match cli::run(args.verbose).await {
+    // Install the logger before anything else so startup is captured
+    let log_config = config::Config::load()
+        .map(|cfg| cfg.log)
+        .unwrap_or_default();
+    let log_file = args.log_file.as_deref().or(log_config.file.as_deref());
+    if let Err(e) = cli::Logger::init(&log_config.level, log_file, args.verbose) {
+        eprintln!("Warning: {}", e);
+    }
+
+    if args.no_color {
+        ui::Theme::force_no_color();
+    }
+
+    if args.yes {
+        cli::commands::set_skip_confirmations(true);
+    }
+
+    match args.command {
+        Some(CliCommand::Setup) => {
+            return match cli::SetupWizard::new().run() {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Some(CliCommand::Auth { action }) => {
+            let result = match action {
+                AuthAction::Login => cli::auth::login(),
+                AuthAction::Logout => cli::auth::logout(),
+                AuthAction::Status => cli::auth::status(),
+            };
+            return match result {
+                Ok(message) => {
+                    println!("{}", message);
+                    ExitCode::SUCCESS
+                }
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        None => {}
+    }
+
+    // Resolve the backend provider; mock mode needs no key, so it must be
+    // known before the first-run setup check below
+    let provider = match providers::ProviderKind::resolve(args.provider.as_deref()) {
+        Ok(provider) => provider,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if provider == providers::ProviderKind::Mock {
+        // Normalize so the startup screen and the REPL both see mock mode
+        std::env::set_var(providers::PROVIDER_ENV, "mock");
+    }
+    if let Some(path) = &args.mock_scenario {
+        // Fail fast on a broken scenario instead of warning mid-session
+        if let Err(e) = providers::Scenario::load(path) {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        std::env::set_var(providers::SCENARIO_ENV, path);
+    }
+
+    // Resolve the active profile before the API key so a profile's key
+    // env var wins over ANTHROPIC_API_KEY and the keychain
+    let profile_name = args
+        .profile
+        .clone()
+        .or_else(|| std::env::var("CODING_AGENT_PROFILE").ok());
+    let mut profile_key: Option<String> = None;
+    let mut profile_model: Option<String> = None;
+    if let Some(name) = &profile_name {
+        let _ = dotenvy::dotenv();
+        let mut cfg = config::Config::load().unwrap_or_default();
+        if let Err(e) = cfg.apply_profile(name) {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+
+        let profile = &cfg.profiles[name.as_str()];
+        if let Some(env_name) = &profile.api_key_env {
+            match std::env::var(env_name) {
+                Ok(key) => profile_key = Some(key),
+                Err(_) => eprintln!(
+                    "Warning: profile '{}' reads its API key from {}, which is not set",
+                    name, env_name
+                ),
+            }
+        }
+        if let Some(url) = &profile.base_url {
+            std::env::set_var("ANTHROPIC_BASE_URL", url);
+        }
+        profile_model = profile.model.clone();
+
+        // Normalize so /status, the startup screen, and the cost tracker
+        // can all report the active profile regardless of how it was set
+        std::env::set_var("CODING_AGENT_PROFILE", name);
+    }
+
+    // First run: without a key the REPL can only error on the first
+    // message, so offer the setup wizard before starting it
+    if provider.requires_api_key()
+        && profile_key.is_none()
+        && !cli::resolve_api_key()
+        && args.message.is_none()
+        && !args.no_interactive
+    {
+        if let Err(e) = cli::SetupWizard::new().run() {
+            eprintln!("Error: {}", e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    // The profile's key wins over whatever resolution found
+    if let Some(key) = profile_key {
+        std::env::set_var("ANTHROPIC_API_KEY", key);
+    }
+
+    let tool_denylist = if args.no_bash {
+        vec!["bash".to_string()]
+    } else {
+        Vec::new()
+    };
+
+    match cli::run(
+        args.verbose,
+        tool_denylist,
+        args.message,
+        args.no_interactive,
+        args.dry_run,
+        args.debug_log,
+        profile_model,
+    )
+    .await
+    {
         Ok(()) => ExitCode::SUCCESS,
         Err(e) => {
             eprintln!("Error: {}", e);
@@ -0,0 +1,247 @@
+//! Prometheus-compatible metrics for monitoring the agent.
+//!
+//! [`Metrics`] collects labelled counters (tool calls, API calls, token
+//! consumption) behind interior mutability so a single `Arc<Metrics>` can
+//! be shared across the REPL and recorded from anywhere. The counters are
+//! rendered in the Prometheus text exposition format, either through the
+//! `/metrics` command or, when `[metrics]` is enabled in the config, via
+//! a small HTTP server serving `GET /metrics`.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Counters for one session, shared as `Arc<Metrics>`.
+///
+/// All counters only ever increase; `session_duration_seconds` is derived
+/// from the construction time at render time.
+#[derive(Debug)]
+pub struct Metrics {
+    /// Tool executions keyed by (tool name, status)
+    tool_calls: Mutex<BTreeMap<(String, String), u64>>,
+    /// API calls keyed by (model, status)
+    api_calls: Mutex<BTreeMap<(String, String), u64>>,
+    /// Tokens consumed keyed by direction ("input"/"output")
+    tokens_consumed: Mutex<BTreeMap<String, u64>>,
+    /// When this session's metrics started
+    start_time: Instant,
+}
+
+impl Metrics {
+    /// Create a new metrics collector with all counters at zero.
+    pub fn new() -> Self {
+        Self {
+            tool_calls: Mutex::new(BTreeMap::new()),
+            api_calls: Mutex::new(BTreeMap::new()),
+            tokens_consumed: Mutex::new(BTreeMap::new()),
+            start_time: Instant::now(),
+        }
+    }
+
+    /// Record a tool execution with its outcome ("ok" or "error").
+    pub fn record_tool_call(&self, tool_name: &str, status: &str) {
+        let mut counts = self.tool_calls.lock().unwrap();
+        *counts
+            .entry((tool_name.to_string(), status.to_string()))
+            .or_insert(0) += 1;
+    }
+
+    /// Record an API call with its outcome ("ok" or "error").
+    pub fn record_api_call(&self, model: &str, status: &str) {
+        let mut counts = self.api_calls.lock().unwrap();
+        *counts
+            .entry((model.to_string(), status.to_string()))
+            .or_insert(0) += 1;
+    }
+
+    /// Record consumed tokens for a direction ("input" or "output").
+    pub fn add_tokens(&self, direction: &str, tokens: u64) {
+        let mut counts = self.tokens_consumed.lock().unwrap();
+        let entry = counts.entry(direction.to_string()).or_insert(0);
+        *entry = entry.saturating_add(tokens);
+    }
+
+    /// How long this session has been running, in seconds.
+    pub fn session_duration_seconds(&self) -> f64 {
+        self.start_time.elapsed().as_secs_f64()
+    }
+
+    /// Render all counters in the Prometheus text exposition format.
+    ///
+    /// BTreeMap keys keep the output deterministic, which scrapers don't
+    /// need but tests and diffs appreciate.
+    pub fn to_prometheus_text(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str("# HELP tool_calls_total Number of tool executions by tool and status\n");
+        output.push_str("# TYPE tool_calls_total counter\n");
+        for ((tool_name, status), count) in self.tool_calls.lock().unwrap().iter() {
+            output.push_str(&format!(
+                "tool_calls_total{{tool_name=\"{}\",status=\"{}\"}} {}\n",
+                tool_name, status, count
+            ));
+        }
+
+        output.push_str("# HELP api_calls_total Number of API calls by model and status\n");
+        output.push_str("# TYPE api_calls_total counter\n");
+        for ((model, status), count) in self.api_calls.lock().unwrap().iter() {
+            output.push_str(&format!(
+                "api_calls_total{{model=\"{}\",status=\"{}\"}} {}\n",
+                model, status, count
+            ));
+        }
+
+        output.push_str("# HELP tokens_consumed_total Number of tokens consumed by direction\n");
+        output.push_str("# TYPE tokens_consumed_total counter\n");
+        for (direction, count) in self.tokens_consumed.lock().unwrap().iter() {
+            output.push_str(&format!(
+                "tokens_consumed_total{{direction=\"{}\"}} {}\n",
+                direction, count
+            ));
+        }
+
+        output.push_str("# HELP session_duration_seconds How long this session has been running\n");
+        output.push_str("# TYPE session_duration_seconds gauge\n");
+        output.push_str(&format!(
+            "session_duration_seconds {:.3}\n",
+            self.session_duration_seconds()
+        ));
+
+        output
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serve `GET /metrics` on the given port until the process exits.
+///
+/// Binds to localhost only; metrics are for the local scraper, not the
+/// network. Any other path gets a 404 so misconfigured scrapers fail
+/// loudly.
+pub async fn serve(metrics: Arc<Metrics>, port: u16) -> Result<(), String> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .await
+        .map_err(|e| format!("Failed to bind metrics server on port {}: {}", port, e))?;
+
+    loop {
+        let Ok((mut stream, _)) = listener.accept().await else {
+            continue;
+        };
+        let metrics = Arc::clone(&metrics);
+        tokio::spawn(async move {
+            let mut buffer = [0u8; 1024];
+            let Ok(read) = stream.read(&mut buffer).await else {
+                return;
+            };
+            let request = String::from_utf8_lossy(&buffer[..read]);
+
+            let response = if request.starts_with("GET /metrics") {
+                let body = metrics.to_prometheus_text();
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+            } else {
+                "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    .to_string()
+            };
+            let _ = stream.write_all(response.as_bytes()).await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_start_at_zero() {
+        let metrics = Metrics::new();
+        let text = metrics.to_prometheus_text();
+
+        assert!(!text.contains("tool_calls_total{"));
+        assert!(!text.contains("api_calls_total{"));
+        assert!(text.contains("session_duration_seconds "));
+    }
+
+    #[test]
+    fn test_record_tool_call_counts_by_label() {
+        let metrics = Metrics::new();
+        metrics.record_tool_call("bash", "ok");
+        metrics.record_tool_call("bash", "ok");
+        metrics.record_tool_call("bash", "error");
+        metrics.record_tool_call("read_file", "ok");
+
+        let text = metrics.to_prometheus_text();
+
+        assert!(text.contains("tool_calls_total{tool_name=\"bash\",status=\"ok\"} 2"));
+        assert!(text.contains("tool_calls_total{tool_name=\"bash\",status=\"error\"} 1"));
+        assert!(text.contains("tool_calls_total{tool_name=\"read_file\",status=\"ok\"} 1"));
+    }
+
+    #[test]
+    fn test_record_api_call_and_tokens() {
+        let metrics = Metrics::new();
+        metrics.record_api_call("claude-3-opus", "ok");
+        metrics.add_tokens("input", 1200);
+        metrics.add_tokens("input", 300);
+        metrics.add_tokens("output", 450);
+
+        let text = metrics.to_prometheus_text();
+
+        assert!(text.contains("api_calls_total{model=\"claude-3-opus\",status=\"ok\"} 1"));
+        assert!(text.contains("tokens_consumed_total{direction=\"input\"} 1500"));
+        assert!(text.contains("tokens_consumed_total{direction=\"output\"} 450"));
+    }
+
+    #[test]
+    fn test_exposition_format_headers() {
+        let metrics = Metrics::new();
+        let text = metrics.to_prometheus_text();
+
+        assert!(text.contains("# TYPE tool_calls_total counter"));
+        assert!(text.contains("# TYPE api_calls_total counter"));
+        assert!(text.contains("# TYPE tokens_consumed_total counter"));
+        assert!(text.contains("# TYPE session_duration_seconds gauge"));
+    }
+
+    #[tokio::test]
+    async fn test_serve_responds_to_metrics_path() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let metrics = Arc::new(Metrics::new());
+        metrics.record_tool_call("bash", "ok");
+
+        // Bind to an ephemeral port by probing for a free one
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let server = Arc::clone(&metrics);
+        tokio::spawn(async move {
+            let _ = serve(server, port).await;
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+
+        let mut stream = tokio::net::TcpStream::connect(("127.0.0.1", port))
+            .await
+            .expect("Failed to connect");
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("tool_calls_total{tool_name=\"bash\",status=\"ok\"} 1"));
+    }
+}
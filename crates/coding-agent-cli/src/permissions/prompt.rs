@@ -101,12 +101,8 @@ impl PermissionPrompt {
     fn read_response(&self) -> io::Result<PermissionResponse> {
         loop {
             // Poll for events with a timeout
-            if event::poll(Duration::from_millis(100))
-                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
-            {
-                if let Event::Key(key_event) =
-                    event::read().map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
-                {
+            if event::poll(Duration::from_millis(100)).map_err(io::Error::other)? {
+                if let Event::Key(key_event) = event::read().map_err(io::Error::other)? {
                     if let Some(response) = self.handle_key_event(key_event) {
                         println!(); // Move to next line after input
                         return Ok(response);
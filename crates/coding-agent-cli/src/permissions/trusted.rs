@@ -107,6 +107,27 @@ impl TrustedPaths {
         self.is_trusted(Path::new(path))
     }
 
+    /// Check a path against just the glob patterns (e.g. `~/projects/**`).
+    ///
+    /// Exact-path entries are skipped; [`TrustedPaths::is_trusted`] covers
+    /// both kinds. The path is canonicalized the same way before matching.
+    pub fn matches_glob(&self, path: &Path) -> bool {
+        let canonical = canonicalize_or_parent(path);
+        self.patterns.iter().any(|pattern| {
+            matches!(pattern, TrustedPattern::Glob(..)) && matches_pattern(&canonical, pattern)
+        })
+    }
+
+    /// Compile a single pattern string into a [`glob::Pattern`], with the
+    /// same tilde expansion [`TrustedPaths::new`] applies to its entries.
+    // Not the FromStr trait: this compiles one entry, not a TrustedPaths
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<glob::Pattern, TrustedPathsError> {
+        let expanded = expand_tilde(s)?;
+        glob::Pattern::new(&expanded)
+            .map_err(|_| TrustedPathsError::InvalidGlobPattern(s.to_string()))
+    }
+
     /// Add a new trusted path
     pub fn add(&mut self, path: &str) -> Result<(), TrustedPathsError> {
         let expanded = expand_tilde(path)?;
@@ -129,9 +150,9 @@ impl TrustedPaths {
 
 /// Expand tilde (~) to the user's home directory
 fn expand_tilde(path: &str) -> Result<String, TrustedPathsError> {
-    if path.starts_with("~/") {
+    if let Some(rest) = path.strip_prefix("~/") {
         let home = dirs::home_dir().ok_or(TrustedPathsError::HomeExpansionFailed)?;
-        Ok(format!("{}/{}", home.display(), &path[2..]))
+        Ok(format!("{}/{}", home.display(), rest))
     } else if path == "~" {
         let home = dirs::home_dir().ok_or(TrustedPathsError::HomeExpansionFailed)?;
         Ok(home.display().to_string())
@@ -142,6 +163,15 @@ fn expand_tilde(path: &str) -> Result<String, TrustedPathsError> {
 
 /// Parse a path string into a TrustedPattern
 fn parse_pattern(path: &str) -> Result<TrustedPattern, TrustedPathsError> {
+    // A pattern globbing from the filesystem root trusts everything;
+    // that's almost always a typo for something narrower, so call it out
+    if path == "/**" || path.starts_with("/**/") {
+        tracing::warn!(
+            pattern = %path,
+            "Trusted path pattern matches from the filesystem root; every write will be allowed"
+        );
+    }
+
     // Check if this is a glob pattern
     if path.contains('*') || path.contains('?') || path.contains('[') {
         // For glob patterns, try to canonicalize the base directory (before the glob chars)
@@ -161,9 +191,7 @@ fn parse_pattern(path: &str) -> Result<TrustedPattern, TrustedPathsError> {
 /// Canonicalize the base directory of a glob pattern
 fn canonicalize_glob_base(pattern: &str) -> String {
     // Find the position of the first glob character
-    let glob_pos = pattern
-        .find(|c| c == '*' || c == '?' || c == '[')
-        .unwrap_or(pattern.len());
+    let glob_pos = pattern.find(['*', '?', '[']).unwrap_or(pattern.len());
 
     // Find the last path separator before the glob character
     let base_end = pattern[..glob_pos].rfind('/').unwrap_or(0);
@@ -309,6 +337,46 @@ mod tests {
         assert!(trusted.is_trusted(&main_rs));
     }
 
+    #[test]
+    fn test_matches_glob_skips_exact_entries() {
+        let temp_dir = TempDir::new().expect("Should create temp dir");
+        let exact_dir = temp_dir.path().join("exact");
+        let globbed_dir = temp_dir.path().join("globbed");
+        fs::create_dir_all(exact_dir.join("sub")).expect("Should create dirs");
+        fs::create_dir_all(globbed_dir.join("sub")).expect("Should create dirs");
+
+        let trusted = TrustedPaths::new(&[
+            exact_dir.to_string_lossy().to_string(),
+            format!("{}/**", globbed_dir.display()),
+        ])
+        .expect("Should create");
+
+        // Only the glob entry counts for matches_glob
+        assert!(trusted.matches_glob(&globbed_dir.join("sub")));
+        assert!(!trusted.matches_glob(&exact_dir.join("sub")));
+        // is_trusted still covers both
+        assert!(trusted.is_trusted(&exact_dir.join("sub")));
+    }
+
+    #[test]
+    fn test_from_str_compiles_pattern_with_tilde() {
+        let pattern = TrustedPaths::from_str("~/projects/**").expect("Should compile");
+
+        let home = dirs::home_dir().expect("Should have home dir");
+        assert!(pattern.matches_path(&home.join("projects/app/src")));
+        assert!(!pattern.matches_path(Path::new("/etc/passwd")));
+    }
+
+    #[test]
+    fn test_from_str_invalid_pattern() {
+        let result = TrustedPaths::from_str("~/projects/[invalid");
+
+        assert!(matches!(
+            result,
+            Err(TrustedPathsError::InvalidGlobPattern(_))
+        ));
+    }
+
     #[test]
     fn test_read_always_allowed() {
         // This test documents that read operations should always be allowed
@@ -0,0 +1,472 @@
+//! Mock provider: canned responses with no network and no API key.
+//!
+//! Built for developing and demoing the UI offline. Three built-in
+//! behaviors key off the prompt: `tools` walks through each tool that has
+//! a canned safe input, one call per turn iteration; `lorem` returns a
+//! long markdown response for exercising the renderer; anything else is
+//! echoed back. A scenario file (`--mock-scenario`, TOML or JSON) scripts
+//! the responses instead, one entry per API call, falling back to the
+//! built-ins when the script runs out.
+
+use super::Provider;
+use coding_agent_core::{ContentBlock, MessageRequest, MessageResponse, Usage};
+use serde::Deserialize;
+use serde_json::json;
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Environment variable holding the scenario file path; `--mock-scenario`
+/// normalizes into it, mirroring how `--profile` is handled.
+pub const SCENARIO_ENV: &str = "CODING_AGENT_MOCK_SCENARIO";
+
+/// Scripted responses loaded from a TOML or JSON scenario file.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct Scenario {
+    #[serde(default)]
+    pub responses: Vec<ScriptedResponse>,
+}
+
+/// One scripted API response: text, a tool call, or both.
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+pub struct ScriptedResponse {
+    /// Text shown before any tool call
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Name of a tool to call; the response stops for tool use
+    #[serde(default)]
+    pub tool: Option<String>,
+    /// Input for the tool call (defaults to an empty object)
+    #[serde(default)]
+    pub input: Option<serde_json::Value>,
+}
+
+impl Scenario {
+    /// Load a scenario from a `.toml` or `.json` file.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read scenario {}: {}", path.display(), e))?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => toml::from_str(&content)
+                .map_err(|e| format!("Invalid TOML scenario {}: {}", path.display(), e)),
+            Some("json") => serde_json::from_str(&content)
+                .map_err(|e| format!("Invalid JSON scenario {}: {}", path.display(), e)),
+            _ => Err(format!(
+                "Scenario {} must be a .toml or .json file",
+                path.display()
+            )),
+        }
+    }
+}
+
+#[derive(Default)]
+struct MockState {
+    /// Index of the next scripted response to play
+    next_scripted: usize,
+    /// Tools still to call in the running `tools` tour; `Some` while the
+    /// tour is active so the closing summary fires exactly once
+    tour: Option<Vec<String>>,
+    /// Counter for unique tool_use ids
+    calls: u64,
+}
+
+/// Provider that answers every request locally.
+pub struct MockProvider {
+    scenario: Scenario,
+    state: Mutex<MockState>,
+}
+
+impl MockProvider {
+    /// Mock provider playing the given scenario before the built-ins.
+    pub fn new(scenario: Scenario) -> Self {
+        Self {
+            scenario,
+            state: Mutex::new(MockState::default()),
+        }
+    }
+
+    /// Mock provider with the scenario named by `CODING_AGENT_MOCK_SCENARIO`,
+    /// or unscripted when the variable is not set.
+    pub fn from_env() -> Result<Self, String> {
+        let scenario = match std::env::var(SCENARIO_ENV) {
+            Ok(path) => Scenario::load(Path::new(&path))?,
+            Err(_) => Scenario::default(),
+        };
+        Ok(Self::new(scenario))
+    }
+
+    fn scripted_response(
+        &self,
+        scripted: &ScriptedResponse,
+        state: &mut MockState,
+        request: &MessageRequest,
+    ) -> MessageResponse {
+        match &scripted.tool {
+            Some(tool) => {
+                let input = scripted.input.clone().unwrap_or_else(|| json!({}));
+                tool_response(scripted.text.clone(), tool, input, state, request)
+            }
+            None => text_response(
+                scripted
+                    .text
+                    .clone()
+                    .unwrap_or_else(|| "(empty scripted response)".to_string()),
+                request,
+            ),
+        }
+    }
+
+    /// Advance the `tools` tour: the next canned tool call, or the closing
+    /// summary once every tool has run.
+    fn tour_response(&self, state: &mut MockState, request: &MessageRequest) -> MessageResponse {
+        let Some(queue) = state.tour.as_mut() else {
+            unreachable!("tour_response called without an active tour");
+        };
+        if queue.is_empty() {
+            state.tour = None;
+            return text_response(
+                "That completes the tool tour — each tool with a canned safe input \
+                 ran exactly once."
+                    .to_string(),
+                request,
+            );
+        }
+        let tool = queue.remove(0);
+        let input = canned_input(&tool).unwrap_or_else(|| json!({}));
+        tool_response(None, &tool, input, state, request)
+    }
+}
+
+impl Provider for MockProvider {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn send(&self, request: &MessageRequest) -> Result<MessageResponse, String> {
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|_| "Mock provider state poisoned".to_string())?;
+
+        // A running tool tour takes priority: each tool result the REPL
+        // sends back triggers the next call
+        if state.tour.is_some() {
+            return Ok(self.tour_response(&mut state, request));
+        }
+
+        // Then the scenario script, one entry per API call
+        if let Some(scripted) = self.scenario.responses.get(state.next_scripted) {
+            state.next_scripted += 1;
+            return Ok(self.scripted_response(&scripted.clone(), &mut state, request));
+        }
+
+        // Built-in behaviors keyed off the latest user text
+        let prompt = latest_user_text(request);
+        let keyword = prompt.trim().to_lowercase();
+        if keyword == "tools" {
+            let queue: Vec<String> = request
+                .tools
+                .iter()
+                .filter(|tool| canned_input(&tool.name).is_some())
+                .map(|tool| tool.name.clone())
+                .collect();
+            if queue.is_empty() {
+                return Ok(text_response(
+                    "No tools with canned inputs are available in this session.".to_string(),
+                    request,
+                ));
+            }
+            state.tour = Some(queue);
+            return Ok(self.tour_response(&mut state, request));
+        }
+        if keyword.starts_with("lorem") {
+            return Ok(text_response(lorem_text(), request));
+        }
+        Ok(text_response(echo_text(&prompt), request))
+    }
+}
+
+/// Canned, safe input for the tools worth exercising in a demo.
+/// Tools without an entry (spawn_task, the cargo suite, git rebase, ...)
+/// are skipped by the tour rather than called with junk.
+fn canned_input(tool: &str) -> Option<serde_json::Value> {
+    Some(match tool {
+        "read_file" => json!({"path": "README.md"}),
+        "write_file" => json!({
+            "path": "mock-demo.txt",
+            "content": "Written by the mock provider.\n",
+        }),
+        "edit_file" => json!({
+            "path": "mock-demo.txt",
+            "old_str": "mock provider",
+            "new_str": "mock provider (edited)",
+        }),
+        "list_files" => json!({"path": "."}),
+        "bash" => json!({"command": "echo hello from the mock provider"}),
+        "code_search" => json!({"pattern": "fn main"}),
+        _ => return None,
+    })
+}
+
+/// The text of the most recent user message, skipping tool results.
+fn latest_user_text(request: &MessageRequest) -> String {
+    request
+        .messages
+        .iter()
+        .rev()
+        .filter(|message| message.role == "user")
+        .flat_map(|message| &message.content)
+        .find_map(|block| match block {
+            ContentBlock::Text { text } => Some(text.clone()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+fn text_response(text: String, request: &MessageRequest) -> MessageResponse {
+    let usage = estimate_usage(request, &text);
+    MessageResponse {
+        content: vec![ContentBlock::Text { text }],
+        stop_reason: Some("end_turn".to_string()),
+        usage,
+    }
+}
+
+fn tool_response(
+    text: Option<String>,
+    tool: &str,
+    input: serde_json::Value,
+    state: &mut MockState,
+    request: &MessageRequest,
+) -> MessageResponse {
+    state.calls += 1;
+    let mut content = Vec::new();
+    if let Some(text) = text {
+        content.push(ContentBlock::Text { text });
+    }
+    content.push(ContentBlock::ToolUse {
+        id: format!("toolu_mock_{:03}", state.calls),
+        name: tool.to_string(),
+        input,
+    });
+    let usage = estimate_usage(request, tool);
+    MessageResponse {
+        content,
+        stop_reason: Some("tool_use".to_string()),
+        usage,
+    }
+}
+
+/// Rough 4-bytes-per-token estimate so the context bar and cost tracker
+/// move during demos instead of sitting at zero.
+fn estimate_usage(request: &MessageRequest, output: &str) -> Usage {
+    let input_bytes = serde_json::to_string(request).map(|s| s.len()).unwrap_or(0);
+    Usage {
+        input_tokens: (input_bytes / 4) as u64,
+        output_tokens: (output.len() / 4).max(1) as u64,
+    }
+}
+
+fn echo_text(prompt: &str) -> String {
+    let quoted: String = prompt.lines().map(|line| format!("> {}\n", line)).collect();
+    format!(
+        "**Mock provider** — echoing your message back:\n\n{}\n\
+         Try `tools` to watch each tool run once, or `lorem` for a long \
+         response.",
+        quoted
+    )
+}
+
+fn lorem_text() -> String {
+    let paragraph = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do \
+        eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim \
+        veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo \
+        consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse cillum \
+        dolore eu fugiat nulla pariatur.";
+    let mut text = String::from(
+        "# Lorem ipsum\n\nA long canned response for exercising \
+        the markdown renderer, scrollback, and spinners.\n\n",
+    );
+    for index in 1..=6 {
+        text.push_str(&format!("## Section {}\n\n{}\n\n", index, paragraph));
+    }
+    text.push_str(
+        "Key points:\n\n\
+         - *Emphasis* and **strong emphasis**\n\
+         - `inline code` and a code block below\n\
+         - A final paragraph to close\n\n\
+         ```rust\n\
+         fn main() {\n    println!(\"hello from the mock provider\");\n}\n\
+         ```\n\n",
+    );
+    text.push_str(paragraph);
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use coding_agent_core::{Message, Tool};
+
+    fn tool(name: &str) -> Tool {
+        Tool {
+            name: name.to_string(),
+            description: format!("{} (test)", name),
+            input_schema: json!({"type": "object"}),
+        }
+    }
+
+    fn request(messages: Vec<Message>, tools: Vec<Tool>) -> MessageRequest {
+        MessageRequest {
+            model: "claude-test".to_string(),
+            max_tokens: 1024,
+            messages,
+            tools,
+            system: None,
+        }
+    }
+
+    fn response_text(response: &MessageResponse) -> String {
+        response
+            .content
+            .iter()
+            .filter_map(|block| match block {
+                ContentBlock::Text { text } => Some(text.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn tool_use(response: &MessageResponse) -> Option<(String, serde_json::Value)> {
+        response.content.iter().find_map(|block| match block {
+            ContentBlock::ToolUse { name, input, .. } => Some((name.clone(), input.clone())),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn test_echoes_the_prompt() {
+        // Arrange
+        let provider = MockProvider::new(Scenario::default());
+        let req = request(vec![Message::user("hello there")], Vec::new());
+
+        // Act
+        let response = provider.send(&req).unwrap();
+
+        // Assert
+        assert_eq!(response.stop_reason.as_deref(), Some("end_turn"));
+        assert!(response_text(&response).contains("> hello there"));
+        assert!(response.usage.output_tokens > 0);
+    }
+
+    #[test]
+    fn test_lorem_is_a_long_markdown_response() {
+        let provider = MockProvider::new(Scenario::default());
+        let req = request(vec![Message::user("lorem")], Vec::new());
+
+        let response = provider.send(&req).unwrap();
+
+        let text = response_text(&response);
+        assert!(text.len() > 1500);
+        assert!(text.contains("## Section 6"));
+        assert!(text.contains("```rust"));
+    }
+
+    #[test]
+    fn test_tool_tour_runs_each_canned_tool_once() {
+        // Arrange: spawn_task has no canned input and must be skipped
+        let provider = MockProvider::new(Scenario::default());
+        let tools = vec![tool("read_file"), tool("bash"), tool("spawn_task")];
+        let mut messages = vec![Message::user("tools")];
+
+        // Act: drive the turn loop until the mock stops calling tools
+        let mut called = Vec::new();
+        loop {
+            let response = provider
+                .send(&request(messages.clone(), tools.clone()))
+                .unwrap();
+            match tool_use(&response) {
+                Some((name, _)) => {
+                    called.push(name);
+                    messages.push(Message::tool_result("toolu_mock_test", "ok"));
+                }
+                None => {
+                    // Assert: the tour closes with a summary
+                    assert!(response_text(&response).contains("tool tour"));
+                    break;
+                }
+            }
+        }
+
+        // Assert
+        assert_eq!(called, vec!["read_file", "bash"]);
+    }
+
+    #[test]
+    fn test_scripted_responses_play_in_order_then_fall_back() {
+        // Arrange
+        let scenario = Scenario {
+            responses: vec![
+                ScriptedResponse {
+                    text: Some("first".to_string()),
+                    ..Default::default()
+                },
+                ScriptedResponse {
+                    text: Some("checking".to_string()),
+                    tool: Some("read_file".to_string()),
+                    input: Some(json!({"path": "Cargo.toml"})),
+                },
+            ],
+        };
+        let provider = MockProvider::new(scenario);
+        let req = request(vec![Message::user("anything")], Vec::new());
+
+        // Act & Assert: entries play in order
+        let first = provider.send(&req).unwrap();
+        assert_eq!(response_text(&first), "first");
+        assert_eq!(first.stop_reason.as_deref(), Some("end_turn"));
+
+        let second = provider.send(&req).unwrap();
+        assert_eq!(second.stop_reason.as_deref(), Some("tool_use"));
+        let (name, input) = tool_use(&second).unwrap();
+        assert_eq!(name, "read_file");
+        assert_eq!(input, json!({"path": "Cargo.toml"}));
+
+        // Script exhausted: built-in echo takes over
+        let third = provider.send(&req).unwrap();
+        assert!(response_text(&third).contains("> anything"));
+    }
+
+    #[test]
+    fn test_scenario_loads_toml_and_json() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let toml_path = dir.path().join("demo.toml");
+        std::fs::write(
+            &toml_path,
+            "[[responses]]\ntext = \"hi\"\n\n[[responses]]\ntool = \"bash\"\n",
+        )
+        .unwrap();
+        let json_path = dir.path().join("demo.json");
+        std::fs::write(&json_path, r#"{"responses": [{"text": "hi"}]}"#).unwrap();
+
+        // Act
+        let from_toml = Scenario::load(&toml_path).unwrap();
+        let from_json = Scenario::load(&json_path).unwrap();
+
+        // Assert
+        assert_eq!(from_toml.responses.len(), 2);
+        assert_eq!(from_toml.responses[1].tool.as_deref(), Some("bash"));
+        assert_eq!(from_json.responses[0].text.as_deref(), Some("hi"));
+    }
+
+    #[test]
+    fn test_scenario_rejects_other_extensions() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("demo.yaml");
+        std::fs::write(&path, "responses: []").unwrap();
+
+        let error = Scenario::load(&path).unwrap_err();
+
+        assert!(error.contains("must be a .toml or .json file"));
+    }
+}
@@ -0,0 +1,160 @@
+//! Backend providers for the REPL's API calls.
+//!
+//! The REPL talks to whatever implements [`Provider`]: the real Anthropic
+//! API by default, or the mock provider (`--provider mock`) which answers
+//! every request locally so the UI — markdown rendering, spinners,
+//! permission prompts — can be developed and demoed with no network and
+//! no API key.
+
+mod mock;
+
+pub use mock::{MockProvider, Scenario, ScriptedResponse, SCENARIO_ENV};
+
+use coding_agent_core::{AnthropicClient, MessageRequest, MessageResponse};
+
+/// Environment variable naming the active provider ("anthropic" or "mock").
+/// `--provider` normalizes into it so the startup screen, the REPL, and
+/// child processes all agree on the backend.
+pub const PROVIDER_ENV: &str = "CODING_AGENT_PROVIDER";
+
+/// Which backend the session runs against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProviderKind {
+    #[default]
+    Anthropic,
+    Mock,
+}
+
+impl ProviderKind {
+    /// Parse a provider name from the command line or environment.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "anthropic" => Ok(Self::Anthropic),
+            "mock" => Ok(Self::Mock),
+            other => Err(format!(
+                "Unknown provider '{}'. Available providers: anthropic, mock",
+                other
+            )),
+        }
+    }
+
+    /// Resolve the active provider from the `--provider` flag, falling
+    /// back to `CODING_AGENT_PROVIDER`, then to the Anthropic default.
+    pub fn resolve(flag: Option<&str>) -> Result<Self, String> {
+        match flag
+            .map(str::to_string)
+            .or_else(|| std::env::var(PROVIDER_ENV).ok())
+        {
+            Some(name) => Self::parse(&name),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// The active provider, as normalized into the environment by startup.
+    /// Unknown values fall back to Anthropic: startup already validated
+    /// the name, and display code has no error channel.
+    pub fn from_env() -> Self {
+        std::env::var(PROVIDER_ENV)
+            .ok()
+            .and_then(|name| Self::parse(&name).ok())
+            .unwrap_or_default()
+    }
+
+    /// Whether this backend needs an Anthropic API key.
+    pub fn requires_api_key(&self) -> bool {
+        matches!(self, Self::Anthropic)
+    }
+}
+
+/// A backend that can answer one API-shaped request.
+///
+/// Implementations take `&self` because the REPL calls the provider while
+/// borrowing the conversation it owns; stateful providers keep their
+/// bookkeeping behind a mutex.
+pub trait Provider: Send + Sync {
+    /// Short name for banners and logs
+    fn name(&self) -> &'static str;
+
+    /// Answer one request.
+    fn send(&self, request: &MessageRequest) -> Result<MessageResponse, String>;
+}
+
+/// The real backend: posts each request to the Anthropic API.
+pub struct AnthropicProvider {
+    api_key: Option<String>,
+}
+
+impl AnthropicProvider {
+    /// Provider using the given key; `None` defers the missing-key error
+    /// until the first call, matching the REPL's historical behavior.
+    pub fn new(api_key: Option<String>) -> Self {
+        Self { api_key }
+    }
+}
+
+impl Provider for AnthropicProvider {
+    fn name(&self) -> &'static str {
+        "anthropic"
+    }
+
+    fn send(&self, request: &MessageRequest) -> Result<MessageResponse, String> {
+        let api_key = self.api_key.as_ref().ok_or_else(|| {
+            "ANTHROPIC_API_KEY not set. Please set it in your environment or .env file.".to_string()
+        })?;
+
+        // The request already carries the model, tools, and system prompt;
+        // the client only contributes the key and base URL
+        AnthropicClient::builder(api_key.clone())
+            .base_url(crate::config::api_base_url())
+            .build()
+            .send_request(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_provider_kind_parse() {
+        assert_eq!(
+            ProviderKind::parse("anthropic"),
+            Ok(ProviderKind::Anthropic)
+        );
+        assert_eq!(ProviderKind::parse("mock"), Ok(ProviderKind::Mock));
+        assert!(ProviderKind::parse("openai")
+            .unwrap_err()
+            .contains("Unknown provider 'openai'"));
+    }
+
+    #[test]
+    fn test_provider_kind_defaults_to_anthropic() {
+        assert_eq!(ProviderKind::default(), ProviderKind::Anthropic);
+        assert_eq!(ProviderKind::resolve(Some("mock")), Ok(ProviderKind::Mock));
+    }
+
+    #[test]
+    fn test_requires_api_key() {
+        assert!(ProviderKind::Anthropic.requires_api_key());
+        assert!(!ProviderKind::Mock.requires_api_key());
+    }
+
+    #[test]
+    fn test_anthropic_provider_without_key_errors() {
+        // Arrange
+        let provider = AnthropicProvider::new(None);
+        let request = MessageRequest {
+            model: "claude-test".to_string(),
+            max_tokens: 16,
+            messages: Vec::new(),
+            tools: Vec::new(),
+            system: None,
+        };
+
+        // Act
+        let result = provider.send(&request);
+
+        // Assert
+        assert!(result.unwrap_err().contains("ANTHROPIC_API_KEY not set"));
+    }
+}
@@ -0,0 +1,115 @@
+//! OS keychain storage for the Anthropic API key
+//!
+//! Behind the optional `keyring` feature: the key lives in the platform
+//! keychain (macOS Keychain, Windows Credential Manager, Linux keyutils)
+//! instead of a plaintext `.env` or config.toml. Managed with the
+//! `code auth login/logout/status` subcommands; `resolve_api_key` consults
+//! the keychain first and falls back to the environment and config when it
+//! is empty or unavailable.
+
+/// Keychain entry service name
+#[cfg(feature = "keyring")]
+const SERVICE: &str = "coding-agent";
+
+/// Keychain entry account name
+#[cfg(feature = "keyring")]
+const ACCOUNT: &str = "anthropic-api-key";
+
+/// Whether this build can talk to the OS keychain at all
+pub fn is_available() -> bool {
+    cfg!(feature = "keyring")
+}
+
+/// Fetch the API key from the OS keychain
+///
+/// Returns `Ok(None)` when no key is stored (or the build has no keychain
+/// support), and `Err` when a keychain backend exists but cannot be
+/// reached, so callers can fall back and surface the reason.
+#[cfg(feature = "keyring")]
+pub fn get_api_key() -> Result<Option<String>, String> {
+    let entry = entry()?;
+    match entry.get_password() {
+        Ok(key) => Ok(Some(key)),
+        Err(keyring::Error::NoEntry) => Ok(None),
+        Err(e) => Err(unavailable_error(&e)),
+    }
+}
+
+/// Store the API key in the OS keychain
+#[cfg(feature = "keyring")]
+pub fn store_api_key(key: &str) -> Result<(), String> {
+    let entry = entry()?;
+    entry.set_password(key).map_err(|e| unavailable_error(&e))
+}
+
+/// Remove the API key from the OS keychain
+///
+/// Returns `Ok(false)` when no key was stored.
+#[cfg(feature = "keyring")]
+pub fn delete_api_key() -> Result<bool, String> {
+    let entry = entry()?;
+    match entry.delete_credential() {
+        Ok(()) => Ok(true),
+        Err(keyring::Error::NoEntry) => Ok(false),
+        Err(e) => Err(unavailable_error(&e)),
+    }
+}
+
+#[cfg(feature = "keyring")]
+fn entry() -> Result<keyring::Entry, String> {
+    keyring::Entry::new(SERVICE, ACCOUNT).map_err(|e| unavailable_error(&e))
+}
+
+/// Describe a keychain failure with a hint for headless machines
+#[cfg(feature = "keyring")]
+fn unavailable_error(error: &keyring::Error) -> String {
+    format!(
+        "Keychain unavailable: {}. On headless machines the key can be kept \
+         in the environment or config instead (run `code setup`).",
+        error
+    )
+}
+
+#[cfg(not(feature = "keyring"))]
+pub fn get_api_key() -> Result<Option<String>, String> {
+    Ok(None)
+}
+
+#[cfg(not(feature = "keyring"))]
+pub fn store_api_key(_key: &str) -> Result<(), String> {
+    Err(build_without_keyring_error())
+}
+
+#[cfg(not(feature = "keyring"))]
+pub fn delete_api_key() -> Result<bool, String> {
+    Err(build_without_keyring_error())
+}
+
+#[cfg(not(feature = "keyring"))]
+fn build_without_keyring_error() -> String {
+    "This build has no keychain support. Reinstall with `cargo install --features keyring` \
+     to store the key in the OS keychain."
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_api_key_without_feature_is_silent_none() {
+        // Without the feature the keychain is simply not consulted, so
+        // key resolution can fall back without an error
+        if !is_available() {
+            assert_eq!(get_api_key(), Ok(None));
+        }
+    }
+
+    #[test]
+    fn test_store_without_feature_mentions_keyring() {
+        if !is_available() {
+            let error = store_api_key("sk-test").unwrap_err();
+            assert!(error.contains("keyring"));
+        }
+    }
+}
@@ -0,0 +1,11 @@
+//! Security helpers for the coding-agent CLI
+//!
+//! This covers secret scanning — tool results are checked for
+//! credential-shaped content before they enter the conversation, so keys
+//! read from local files never reach the API — and OS keychain storage
+//! for the Anthropic API key (optional `keyring` feature).
+
+pub mod keychain;
+mod redact;
+
+pub use redact::{Redaction, SecretRedactor};
@@ -19,6 +19,9 @@ pub struct CostTracker {
     start_time: Option<Instant>,
     /// Current model pricing.
     pricing: ModelPricing,
+    /// Active config profile, so persisted cost records can be tagged
+    /// (e.g. for expensing work vs personal usage).
+    profile: Option<String>,
 }
 
 impl CostTracker {
@@ -30,6 +33,7 @@ impl CostTracker {
             message_count: 0,
             start_time: Some(Instant::now()),
             pricing,
+            profile: std::env::var("CODING_AGENT_PROFILE").ok(),
         }
     }
 
@@ -78,6 +82,16 @@ impl CostTracker {
         self.pricing.name
     }
 
+    /// Get the active config profile, if one was selected.
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Set the active config profile.
+    pub fn set_profile(&mut self, profile: Option<String>) {
+        self.profile = profile;
+    }
+
     /// Get the context window size.
     pub fn context_window(&self) -> usize {
         self.pricing.context_window
@@ -146,7 +160,7 @@ impl CostTracker {
         let mut result = String::new();
         let chars: Vec<char> = s.chars().collect();
         for (i, c) in chars.iter().enumerate() {
-            if i > 0 && (chars.len() - i) % 3 == 0 {
+            if i > 0 && (chars.len() - i).is_multiple_of(3) {
                 result.push(',');
             }
             result.push(*c);
@@ -174,7 +188,11 @@ impl CostTracker {
         output.push_str(separator);
         output.push_str("\n\n");
 
-        output.push_str(&format!("Model: {}\n\n", self.model_name()));
+        output.push_str(&format!("Model: {}\n", self.model_name()));
+        if let Some(profile) = self.profile() {
+            output.push_str(&format!("Profile: {}\n", profile));
+        }
+        output.push('\n');
 
         output.push_str(&format!(
             "Input tokens:   {:>10}   ({})\n",
@@ -187,7 +205,7 @@ impl CostTracker {
             Self::format_cost(self.output_cost())
         ));
         output.push_str(separator);
-        output.push_str("\n");
+        output.push('\n');
         output.push_str(&format!(
             "Total:          {:>10}   ({})\n\n",
             Self::format_tokens(self.total_tokens()),
@@ -351,6 +369,18 @@ mod tests {
         assert_eq!(tracker.model_name(), "claude-3-haiku");
     }
 
+    #[test]
+    fn test_profile_tag_in_breakdown() {
+        let mut tracker = CostTracker::with_default_model();
+        tracker.set_profile(Some("work".to_string()));
+
+        assert_eq!(tracker.profile(), Some("work"));
+        assert!(tracker.render_breakdown().contains("Profile: work"));
+
+        tracker.set_profile(None);
+        assert!(!tracker.render_breakdown().contains("Profile:"));
+    }
+
     #[test]
     fn test_saturating_add() {
         let mut tracker = CostTracker::with_default_model();
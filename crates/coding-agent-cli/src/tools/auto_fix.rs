@@ -13,6 +13,22 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Line-level record of what a fix changed in one file.
+#[derive(Debug, Clone)]
+pub struct FileChange {
+    /// The file that was modified.
+    pub path: PathBuf,
+
+    /// Number of lines the fix added.
+    pub lines_added: usize,
+
+    /// Number of lines the fix removed.
+    pub lines_removed: usize,
+
+    /// Unified diff of the change.
+    pub patch: String,
+}
+
 /// Result of applying a fix.
 #[derive(Debug, Clone)]
 pub struct FixApplicationResult {
@@ -22,6 +38,9 @@ pub struct FixApplicationResult {
     /// Files that were modified.
     pub modified_files: Vec<PathBuf>,
 
+    /// Line-level change records for the modified files.
+    pub changes: Vec<FileChange>,
+
     /// Description of what was done.
     pub description: String,
 
@@ -38,6 +57,7 @@ impl FixApplicationResult {
         Self {
             success: true,
             modified_files,
+            changes: vec![],
             description: description.into(),
             error: None,
             original_content: HashMap::new(),
@@ -53,6 +73,7 @@ impl FixApplicationResult {
         Self {
             success: true,
             modified_files,
+            changes: vec![],
             description: description.into(),
             error: None,
             original_content,
@@ -64,12 +85,36 @@ impl FixApplicationResult {
         Self {
             success: false,
             modified_files: vec![],
+            changes: vec![],
             description: String::new(),
             error: Some(error.into()),
             original_content: HashMap::new(),
         }
     }
 
+    /// Human-readable summary of the line-level changes, e.g.
+    /// "added 3 lines, removed 1 line in Cargo.toml".
+    ///
+    /// Falls back to the fix description when no change records were
+    /// collected (dry runs, or fixes that never wrote a file).
+    pub fn patch_summary(&self) -> String {
+        if self.changes.is_empty() {
+            return self.description.clone();
+        }
+        self.changes
+            .iter()
+            .map(|change| {
+                format!(
+                    "added {}, removed {} in {}",
+                    pluralize_lines(change.lines_added),
+                    pluralize_lines(change.lines_removed),
+                    change.path.display()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+
     /// Rollback the applied fix by restoring original content.
     pub fn rollback(&self) -> Result<(), String> {
         for (path, content) in &self.original_content {
@@ -91,6 +136,14 @@ pub struct AutoFixConfig {
 
     /// Whether to run in dry-run mode (no actual modifications).
     pub dry_run: bool,
+
+    /// Glob patterns for files a fix is allowed to modify.
+    pub allowed_file_patterns: Vec<String>,
+
+    /// Glob patterns for files a fix must never modify. Takes precedence
+    /// over the allowlist, so lock files and the like stay untouched even
+    /// if a broad allowed pattern covers them.
+    pub forbidden_file_patterns: Vec<String>,
 }
 
 impl Default for AutoFixConfig {
@@ -99,6 +152,8 @@ impl Default for AutoFixConfig {
             root_dir: PathBuf::from("."),
             create_backups: false,
             dry_run: false,
+            allowed_file_patterns: vec!["**/*.rs".to_string(), "Cargo.toml".to_string()],
+            forbidden_file_patterns: vec!["**/*.lock".to_string()],
         }
     }
 }
@@ -123,6 +178,54 @@ impl AutoFixConfig {
         self.create_backups = create_backups;
         self
     }
+
+    /// Override the allowed/forbidden file patterns.
+    pub fn with_file_patterns(mut self, allowed: Vec<String>, forbidden: Vec<String>) -> Self {
+        self.allowed_file_patterns = allowed;
+        self.forbidden_file_patterns = forbidden;
+        self
+    }
+
+    /// Load file pattern restrictions from the `[error_recovery]` config
+    /// section.
+    pub fn with_patterns_from(mut self, config: &crate::config::Config) -> Self {
+        self.allowed_file_patterns = config.error_recovery.fix_allowed_patterns.clone();
+        self.forbidden_file_patterns = config.error_recovery.fix_forbidden_patterns.clone();
+        self
+    }
+
+    /// Check that every modified file is permitted by the configured
+    /// patterns. Paths are matched relative to `root_dir`.
+    pub fn check_modified_files(&self, files: &[PathBuf]) -> Result<(), String> {
+        for file in files {
+            let relative = file.strip_prefix(&self.root_dir).unwrap_or(file);
+            if matches_any_pattern(&self.forbidden_file_patterns, relative) {
+                return Err(format!(
+                    "Fix would modify forbidden file: {}",
+                    relative.display()
+                ));
+            }
+            if !matches_any_pattern(&self.allowed_file_patterns, relative) {
+                return Err(format!(
+                    "Fix would modify file outside allowed patterns: {}",
+                    relative.display()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether any of the glob patterns matches the given path.
+///
+/// Invalid patterns never match, so a typo in the config fails closed for
+/// the allowlist and open for the forbidden list.
+fn matches_any_pattern(patterns: &[String], path: &Path) -> bool {
+    patterns.iter().any(|p| {
+        glob::Pattern::new(p)
+            .map(|pattern| pattern.matches_path(path))
+            .unwrap_or(false)
+    })
 }
 
 /// Apply a fix based on the provided FixInfo.
@@ -130,11 +233,72 @@ impl AutoFixConfig {
 /// This is the main entry point for applying auto-fixes. It dispatches to the
 /// appropriate fix handler based on the fix type.
 pub fn apply_fix(fix_info: &FixInfo, config: &AutoFixConfig) -> FixApplicationResult {
-    match fix_info.fix_type {
+    let mut result = match fix_info.fix_type {
         FixType::AddDependency => apply_add_dependency_fix(fix_info, config),
         FixType::AddImport => apply_add_import_fix(fix_info, config),
         FixType::FixType => apply_type_fix(fix_info, config),
         FixType::FixSyntax => apply_syntax_fix(fix_info, config),
+    };
+
+    // Enforce file pattern restrictions before reporting success; anything
+    // already written is rolled back so forbidden files stay untouched
+    if result.success {
+        if let Err(error) = config.check_modified_files(&result.modified_files) {
+            let _ = result.rollback();
+            return FixApplicationResult::failure(error);
+        }
+
+        // Diff every file we kept original content for against what is now
+        // on disk; dry runs write nothing, so they record no changes
+        result.changes = result
+            .original_content
+            .iter()
+            .filter_map(|(path, before)| {
+                let after = fs::read_to_string(path).ok()?;
+                Some(compute_file_change(path, before, &after))
+            })
+            .collect();
+    }
+
+    result
+}
+
+/// Compute the line-level change record between two versions of a file.
+pub fn compute_file_change(path: &Path, before: &str, after: &str) -> FileChange {
+    let diff = similar::TextDiff::from_lines(before, after);
+
+    let mut lines_added = 0;
+    let mut lines_removed = 0;
+    for change in diff.iter_all_changes() {
+        match change.tag() {
+            similar::ChangeTag::Insert => lines_added += 1,
+            similar::ChangeTag::Delete => lines_removed += 1,
+            similar::ChangeTag::Equal => {}
+        }
+    }
+
+    let patch = diff
+        .unified_diff()
+        .header(
+            &format!("a/{}", path.display()),
+            &format!("b/{}", path.display()),
+        )
+        .to_string();
+
+    FileChange {
+        path: path.to_path_buf(),
+        lines_added,
+        lines_removed,
+        patch,
+    }
+}
+
+/// "1 line" or "N lines", for patch summaries.
+fn pluralize_lines(count: usize) -> String {
+    if count == 1 {
+        "1 line".to_string()
+    } else {
+        format!("{} lines", count)
     }
 }
 
@@ -1211,6 +1375,68 @@ fn main() {}
         assert_eq!(result.error, Some("Something went wrong".to_string()));
     }
 
+    #[test]
+    fn test_compute_file_change_counts_lines() {
+        let before = "line one\nline two\nline three\n";
+        let after = "line one\nline 2\nline three\nline four\n";
+
+        let change = compute_file_change(Path::new("src/lib.rs"), before, after);
+
+        assert_eq!(change.lines_added, 2);
+        assert_eq!(change.lines_removed, 1);
+        assert!(change.patch.contains("-line two"));
+        assert!(change.patch.contains("+line 2"));
+        assert!(change.patch.contains("+line four"));
+    }
+
+    #[test]
+    fn test_apply_fix_records_line_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        create_test_cargo_toml(temp_dir.path());
+
+        let fix_info = FixInfo {
+            fix_type: FixType::AddDependency,
+            target_file: Some("Cargo.toml".to_string()),
+            target_item: Some("tokio".to_string()),
+            suggested_change: "Add tokio dependency".to_string(),
+        };
+
+        let config = AutoFixConfig::new(temp_dir.path());
+        let result = apply_fix(&fix_info, &config);
+
+        assert!(result.success, "Fix should succeed: {:?}", result.error);
+        assert_eq!(result.changes.len(), 1);
+        let change = &result.changes[0];
+        assert!(change.lines_added >= 1);
+        assert_eq!(change.lines_removed, 0);
+        assert!(change.patch.contains("+tokio"));
+    }
+
+    #[test]
+    fn test_patch_summary_pluralizes() {
+        let mut result =
+            FixApplicationResult::success(vec![PathBuf::from("Cargo.toml")], "Added dependency");
+        result.changes = vec![FileChange {
+            path: PathBuf::from("Cargo.toml"),
+            lines_added: 3,
+            lines_removed: 1,
+            patch: String::new(),
+        }];
+
+        assert_eq!(
+            result.patch_summary(),
+            "added 3 lines, removed 1 line in Cargo.toml"
+        );
+    }
+
+    #[test]
+    fn test_patch_summary_falls_back_to_description() {
+        let result =
+            FixApplicationResult::success(vec![PathBuf::from("Cargo.toml")], "Added dependency");
+
+        assert_eq!(result.patch_summary(), "Added dependency");
+    }
+
     #[test]
     fn test_config_builder_methods() {
         let config = AutoFixConfig::new("/tmp")
@@ -1245,7 +1471,11 @@ fn main() {}
             suggested_change: "Add lodash dependency".to_string(),
         };
 
-        let config = AutoFixConfig::new(temp_dir.path());
+        // package.json is outside the default Rust allowlist
+        let config = AutoFixConfig::new(temp_dir.path()).with_file_patterns(
+            vec!["package.json".to_string()],
+            vec!["**/*.lock".to_string()],
+        );
         let result = apply_fix(&fix_info, &config);
 
         assert!(result.success, "Fix should succeed: {:?}", result.error);
@@ -1258,6 +1488,89 @@ fn main() {}
         );
     }
 
+    #[test]
+    fn test_fix_blocked_outside_allowed_patterns() {
+        let temp_dir = TempDir::new().unwrap();
+        let package_json = temp_dir.path().join("package.json");
+        let original = r#"{"name": "test-project", "dependencies": {}}"#;
+        fs::write(&package_json, original).unwrap();
+
+        let fix_info = FixInfo {
+            fix_type: FixType::AddDependency,
+            target_file: Some("package.json".to_string()),
+            target_item: Some("lodash".to_string()),
+            suggested_change: "Add lodash dependency".to_string(),
+        };
+
+        // Default allowlist only covers Rust files and Cargo.toml
+        let config = AutoFixConfig::new(temp_dir.path());
+        let result = apply_fix(&fix_info, &config);
+
+        assert!(!result.success);
+        assert!(result
+            .error
+            .unwrap()
+            .contains("outside allowed patterns: package.json"));
+        // The write was rolled back
+        assert_eq!(fs::read_to_string(&package_json).unwrap(), original);
+    }
+
+    #[test]
+    fn test_fix_blocked_by_forbidden_pattern() {
+        let config = AutoFixConfig::new("/project");
+
+        let result = config.check_modified_files(&[PathBuf::from("/project/sub/Cargo.lock")]);
+
+        assert_eq!(
+            result,
+            Err("Fix would modify forbidden file: sub/Cargo.lock".to_string())
+        );
+    }
+
+    #[test]
+    fn test_forbidden_patterns_take_precedence() {
+        // Even if the allowlist covers everything, forbidden files stay out
+        let config = AutoFixConfig::new("/project")
+            .with_file_patterns(vec!["**/*".to_string()], vec!["**/*.lock".to_string()]);
+
+        let allowed = config.check_modified_files(&[PathBuf::from("/project/src/main.rs")]);
+        let forbidden = config.check_modified_files(&[PathBuf::from("/project/Cargo.lock")]);
+
+        assert!(allowed.is_ok());
+        assert!(forbidden.unwrap_err().contains("forbidden file"));
+    }
+
+    #[test]
+    fn test_default_patterns_allow_rust_sources() {
+        let config = AutoFixConfig::new("/project");
+
+        assert!(config
+            .check_modified_files(&[
+                PathBuf::from("/project/src/main.rs"),
+                PathBuf::from("/project/Cargo.toml"),
+            ])
+            .is_ok());
+    }
+
+    #[test]
+    fn test_patterns_from_config() {
+        let mut app_config = crate::config::Config::default();
+        app_config.error_recovery.fix_allowed_patterns = vec!["docs/**/*.md".to_string()];
+        app_config.error_recovery.fix_forbidden_patterns = vec!["docs/README.md".to_string()];
+
+        let config = AutoFixConfig::new("/project").with_patterns_from(&app_config);
+
+        assert!(config
+            .check_modified_files(&[PathBuf::from("/project/docs/guide.md")])
+            .is_ok());
+        assert!(config
+            .check_modified_files(&[PathBuf::from("/project/docs/README.md")])
+            .is_err());
+        assert!(config
+            .check_modified_files(&[PathBuf::from("/project/src/main.rs")])
+            .is_err());
+    }
+
     #[test]
     fn test_insert_go_import() {
         let content = r#"package main
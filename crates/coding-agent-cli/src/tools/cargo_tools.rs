@@ -0,0 +1,373 @@
+//! Cargo-native build, test, and lint tools.
+//!
+//! Without these the model shells out `cargo test 2>&1 | head -50` and
+//! similar pipelines through the bash tool, losing structure along the
+//! way. Each tool here runs the corresponding cargo command directly,
+//! feeds the output through the diagnostics parser, and returns a compact
+//! summary — error/warning counts, failing test names, the first few
+//! diagnostics — plus a pointer to the full log on disk. Failures surface
+//! as `Err` with the diagnostic text, so the fix-agent pipeline
+//! categorizes them exactly like compiler errors from bash.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+use super::definitions::run_shell_command;
+use super::diagnostics::{parse_compiler_output, DiagnosticReport};
+
+/// Cargo builds and test runs routinely outlast the bash default timeout.
+const CARGO_TIMEOUT_SECS: u64 = 600;
+
+/// How many diagnostics are shown inline; the rest live in the log file.
+const MAX_INLINE_DIAGNOSTICS: usize = 5;
+
+/// Run `cargo check` and summarize the diagnostics.
+pub(crate) fn cargo_check(package: Option<&str>) -> Result<String, String> {
+    let scope = package_scope(package)?;
+    run_diagnostic_command(
+        "check",
+        &format!("cargo check {} --message-format=json", scope),
+    )
+}
+
+/// Run `cargo clippy` and summarize the diagnostics.
+pub(crate) fn cargo_clippy(package: Option<&str>) -> Result<String, String> {
+    let scope = package_scope(package)?;
+    run_diagnostic_command(
+        "clippy",
+        &format!("cargo clippy {} --all-targets --message-format=json", scope),
+    )
+}
+
+/// Run `cargo fmt`.
+pub(crate) fn cargo_fmt(package: Option<&str>) -> Result<String, String> {
+    let command = match package {
+        Some(name) => {
+            crate::project::validate_package(name)?;
+            format!("cargo fmt -p {}", name)
+        }
+        None => "cargo fmt".to_string(),
+    };
+    let output = run_shell_command(&command, CARGO_TIMEOUT_SECS, None)?;
+    if output.exit_code == 0 {
+        Ok("cargo fmt: formatting applied".to_string())
+    } else {
+        Err(format!("cargo fmt failed:\n{}", output.stderr.trim()))
+    }
+}
+
+/// Run `cargo test`, optionally filtered by test name.
+pub(crate) fn cargo_test(filter: Option<&str>, package: Option<&str>) -> Result<String, String> {
+    let scope = package_scope(package)?;
+    let command = match filter {
+        Some(f) => {
+            validate_test_filter(f)?;
+            format!("cargo test {} {}", scope, f)
+        }
+        None => format!("cargo test {}", scope),
+    };
+
+    let output = run_shell_command(&command, CARGO_TIMEOUT_SECS, None)?;
+    let combined = format!("{}\n{}", output.stdout, output.stderr);
+    let log_path = write_log("test", &combined);
+
+    let (passed, failed) = test_totals(&output.stdout);
+    let mut summary = format!("cargo test: {} passed, {} failed", passed, failed);
+
+    let failing = failing_test_names(&output.stdout);
+    if !failing.is_empty() {
+        summary.push_str("\n\nFailing tests:");
+        for name in failing.iter().take(MAX_INLINE_DIAGNOSTICS) {
+            summary.push_str(&format!("\n  {}", name));
+        }
+        if failing.len() > MAX_INLINE_DIAGNOSTICS {
+            summary.push_str(&format!(
+                "\n  … and {} more",
+                failing.len() - MAX_INLINE_DIAGNOSTICS
+            ));
+        }
+    }
+
+    // A compile failure before any test ran carries compiler diagnostics
+    // the fix-agent can act on; include them like cargo_check would
+    if output.exit_code != 0 && failing.is_empty() {
+        let report = parse_compiler_output(&combined);
+        if report.error_count > 0 {
+            summary.push_str("\n\n");
+            summary.push_str(&inline_diagnostics(&report));
+        }
+    }
+
+    if let Some(path) = log_path {
+        summary.push_str(&format!("\n\nFull log: {}", path.display()));
+    }
+
+    if output.exit_code == 0 {
+        Ok(summary)
+    } else {
+        Err(summary)
+    }
+}
+
+/// The scope flag for a cargo invocation: `-p <name>` for a validated
+/// workspace member, `--workspace` otherwise.
+fn package_scope(package: Option<&str>) -> Result<String, String> {
+    match package {
+        Some(name) => {
+            crate::project::validate_package(name)?;
+            Ok(format!("-p {}", name))
+        }
+        None => Ok("--workspace".to_string()),
+    }
+}
+
+/// Run a cargo command that emits JSON compiler messages and summarize it.
+fn run_diagnostic_command(name: &str, command: &str) -> Result<String, String> {
+    let output = run_shell_command(command, CARGO_TIMEOUT_SECS, None)?;
+
+    // Recover the human-readable diagnostics the parser understands from
+    // the JSON message stream
+    let rendered = render_compiler_messages(&output.stdout);
+    let log_path = write_log(name, &format!("{}\n{}", rendered, output.stderr));
+
+    let report = parse_compiler_output(&rendered);
+    let mut summary = format!(
+        "cargo {}: {} error{}, {} warning{}",
+        name,
+        report.error_count,
+        plural(report.error_count),
+        report.warning_count,
+        plural(report.warning_count)
+    );
+
+    if !report.diagnostics.is_empty() {
+        summary.push_str("\n\n");
+        summary.push_str(&inline_diagnostics(&report));
+    }
+
+    if let Some(path) = log_path {
+        summary.push_str(&format!("\n\nFull log: {}", path.display()));
+    }
+
+    if output.exit_code == 0 {
+        Ok(summary)
+    } else {
+        Err(summary)
+    }
+}
+
+/// Extract the rendered (human-readable) text from cargo's JSON message
+/// stream, deduplicated — `--all-targets` repeats the same diagnostic for
+/// every target that compiles the offending code.
+fn render_compiler_messages(json_output: &str) -> String {
+    let mut seen = HashSet::new();
+    let mut rendered = String::new();
+    for line in json_output.lines() {
+        let Ok(message) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if message.get("reason").and_then(|r| r.as_str()) != Some("compiler-message") {
+            continue;
+        }
+        let Some(text) = message
+            .get("message")
+            .and_then(|m| m.get("rendered"))
+            .and_then(|r| r.as_str())
+        else {
+            continue;
+        };
+        if seen.insert(text.to_string()) {
+            rendered.push_str(text);
+            if !text.ends_with('\n') {
+                rendered.push('\n');
+            }
+        }
+    }
+    rendered
+}
+
+/// Format the first few diagnostics of a report for inline display.
+fn inline_diagnostics(report: &DiagnosticReport) -> String {
+    let mut out = String::new();
+    for diagnostic in report.diagnostics.iter().take(MAX_INLINE_DIAGNOSTICS) {
+        out.push_str(diagnostic.raw_output.trim_end());
+        out.push('\n');
+    }
+    if report.diagnostics.len() > MAX_INLINE_DIAGNOSTICS {
+        out.push_str(&format!(
+            "… and {} more diagnostics",
+            report.diagnostics.len() - MAX_INLINE_DIAGNOSTICS
+        ));
+    }
+    out.trim_end().to_string()
+}
+
+/// Sum the `test result:` lines across all test binaries in the run.
+fn test_totals(output: &str) -> (usize, usize) {
+    let mut passed = 0;
+    let mut failed = 0;
+    for line in output.lines() {
+        let Some(rest) = line.trim().strip_prefix("test result:") else {
+            continue;
+        };
+        for part in rest.split(';') {
+            let part = part.trim();
+            if let Some(count) = part
+                .strip_suffix(" passed")
+                .and_then(|c| c.split_whitespace().last())
+            {
+                passed += count.parse::<usize>().unwrap_or(0);
+            }
+            if let Some(count) = part.strip_suffix(" failed") {
+                failed += count.parse::<usize>().unwrap_or(0);
+            }
+        }
+    }
+    (passed, failed)
+}
+
+/// Collect the names of failing tests from harness output lines like
+/// `test tools::tests::test_foo ... FAILED`.
+fn failing_test_names(output: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    for line in output.lines() {
+        let line = line.trim();
+        let Some(rest) = line.strip_prefix("test ") else {
+            continue;
+        };
+        if let Some(name) = rest.strip_suffix(" ... FAILED") {
+            if !names.iter().any(|n| n == name) {
+                names.push(name.to_string());
+            }
+        }
+    }
+    names
+}
+
+/// "" or "s", for count-based messages.
+fn plural(count: usize) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+/// Reject test filters that could smuggle shell syntax into the command.
+fn validate_test_filter(filter: &str) -> Result<(), String> {
+    let valid = !filter.is_empty()
+        && filter
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == ':');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid test filter '{}': only letters, digits, '_', and '::' are allowed",
+            filter
+        ))
+    }
+}
+
+/// Write the full command output to a log file in the temp directory.
+///
+/// Logging is best-effort: a failed write drops the pointer from the
+/// summary rather than failing the tool.
+fn write_log(name: &str, content: &str) -> Option<PathBuf> {
+    let path = std::env::temp_dir().join(format!(
+        "coding-agent-cargo-{}-{}.log",
+        name,
+        std::process::id()
+    ));
+    fs::write(&path, content).ok()?;
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_compiler_messages_extracts_and_dedups() {
+        // Arrange: two identical diagnostics (lib + test target) and noise
+        let json = concat!(
+            r#"{"reason":"compiler-artifact","target":{"name":"foo"}}"#,
+            "\n",
+            r#"{"reason":"compiler-message","message":{"rendered":"error[E0463]: can't find crate for `serde`\n"}}"#,
+            "\n",
+            r#"{"reason":"compiler-message","message":{"rendered":"error[E0463]: can't find crate for `serde`\n"}}"#,
+            "\n",
+            "not json at all\n",
+        );
+
+        // Act
+        let rendered = render_compiler_messages(json);
+
+        // Assert
+        assert_eq!(rendered.matches("E0463").count(), 1);
+        assert!(rendered.contains("can't find crate"));
+    }
+
+    #[test]
+    fn test_test_totals_sums_across_binaries() {
+        // Arrange
+        let output = "\
+test result: ok. 12 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out\n\
+some unrelated line\n\
+test result: FAILED. 3 passed; 2 failed; 0 ignored; 0 measured; 0 filtered out\n";
+
+        // Act
+        let (passed, failed) = test_totals(output);
+
+        // Assert
+        assert_eq!(passed, 15);
+        assert_eq!(failed, 2);
+    }
+
+    #[test]
+    fn test_failing_test_names_collected_in_order() {
+        // Arrange
+        let output = "\
+test tools::tests::test_alpha ... ok\n\
+test tools::tests::test_beta ... FAILED\n\
+test cli::tests::test_gamma ... FAILED\n";
+
+        // Act
+        let names = failing_test_names(output);
+
+        // Assert
+        assert_eq!(
+            names,
+            vec!["tools::tests::test_beta", "cli::tests::test_gamma"]
+        );
+    }
+
+    #[test]
+    fn test_validate_test_filter_rejects_shell_syntax() {
+        // Arrange & Act & Assert
+        assert!(validate_test_filter("tools::tests::test_beta").is_ok());
+        assert!(validate_test_filter("test_name_1").is_ok());
+        assert!(validate_test_filter("x; rm -rf /").is_err());
+        assert!(validate_test_filter("a b").is_err());
+        assert!(validate_test_filter("").is_err());
+    }
+
+    #[test]
+    fn test_inline_diagnostics_truncates_to_limit() {
+        // Arrange: more diagnostics than the inline limit
+        let output = (0..8)
+            .map(|i| format!("error[E0425]: cannot find value `x{}` in this scope\n", i))
+            .collect::<String>();
+        let report = parse_compiler_output(&output);
+        assert_eq!(report.error_count, 8);
+
+        // Act
+        let inline = inline_diagnostics(&report);
+
+        // Assert
+        assert!(inline.contains("x0"));
+        assert!(inline.contains("… and 3 more diagnostics"));
+        assert!(!inline.contains("x7"));
+    }
+}
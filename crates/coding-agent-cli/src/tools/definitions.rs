@@ -3,27 +3,63 @@
 //! This module defines all the tools that Claude can use to interact with the codebase.
 //! Each tool has a JSON schema for input validation and a function to execute the tool.
 
-use crate::permissions::{PermissionChecker, PermissionDecision};
+use super::executor::ToolFuture;
+use super::freshness::{check_freshness, note_file_seen, Freshness};
+use super::progress::{ProgressEntry, ProgressFile};
+use super::write_validator::{write_validation_enabled, ValidationSeverity, WriteFileValidator};
+use crate::permissions::{OperationType, PermissionChecker, PermissionDecision};
 use coding_agent_core::{generate_schema, Tool, ToolDefinition};
+use ignore::types::TypesBuilder;
+use ignore::WalkBuilder;
+use regex::RegexBuilder;
 use schemars::JsonSchema;
 use serde::Deserialize;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
-use std::process::Command;
-use walkdir::WalkDir;
+use std::process::{Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 // ============================================================================
 // ReadFile Tool
 // ============================================================================
 
+/// Default cap on file sizes handled by read_file and write_file.
+const DEFAULT_MAX_FILE_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Max file size for read_file/write_file, overridable via config.
+static MAX_FILE_SIZE_BYTES: AtomicU64 = AtomicU64::new(DEFAULT_MAX_FILE_SIZE_BYTES);
+
+/// Override the file size cap (from `tools.max_file_size_bytes`).
+pub fn set_max_file_size_bytes(bytes: u64) {
+    MAX_FILE_SIZE_BYTES.store(bytes, Ordering::Relaxed);
+}
+
+fn max_file_size_bytes() -> u64 {
+    MAX_FILE_SIZE_BYTES.load(Ordering::Relaxed)
+}
+
+/// Bytes sampled from the head of a file for binary detection.
+const BINARY_SNIFF_BYTES: usize = 8_192;
+
 #[derive(Debug, Deserialize, JsonSchema)]
 struct ReadFileInput {
     /// The path of the file to read (relative to current directory or absolute).
     path: String,
+    /// Optional 1-based line number to start reading from (use with limit for large files).
+    offset: Option<usize>,
+    /// Optional maximum number of lines to return.
+    limit: Option<usize>,
 }
 
 fn read_file(input: Value) -> Result<String, String> {
+    read_file_with_max(input, max_file_size_bytes())
+}
+
+fn read_file_with_max(input: Value, max_bytes: u64) -> Result<String, String> {
     let input: ReadFileInput =
         serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
 
@@ -31,6 +67,42 @@ fn read_file(input: Value) -> Result<String, String> {
         return Err("path cannot be empty".to_string());
     }
 
+    // Track which workspace member the conversation is working in
+    crate::project::note_tool_target(Path::new(&input.path));
+
+    let size = fs::metadata(&input.path)
+        .map_err(|e| format!("Failed to read file: {}", e))?
+        .len();
+
+    // Binary files get a short descriptor instead of their contents
+    let head = read_head(&input.path, BINARY_SNIFF_BYTES)?;
+    if is_binary(&head) {
+        return Ok(format!(
+            "Binary file ({}), {} - contents not shown",
+            sniff_file_type(&head),
+            format_size(size)
+        ));
+    }
+
+    // Oversized text files must be read in slices
+    if size > max_bytes && input.offset.is_none() && input.limit.is_none() {
+        return Err(format!(
+            "File is {} ({} bytes), which exceeds the {} limit. \
+             Use the offset and limit parameters to read a slice of the file",
+            format_size(size),
+            size,
+            format_size(max_bytes)
+        ));
+    }
+
+    // Snapshot the content so a later write/edit can detect external
+    // modifications made since this read
+    note_file_seen(Path::new(&input.path));
+
+    if input.offset.is_some() || input.limit.is_some() {
+        return read_file_slice(&input.path, input.offset.unwrap_or(1), input.limit);
+    }
+
     let content =
         fs::read_to_string(&input.path).map_err(|e| format!("Failed to read file: {}", e))?;
 
@@ -51,6 +123,95 @@ fn read_file(input: Value) -> Result<String, String> {
     }
 }
 
+/// Read up to `n` bytes from the start of a file.
+fn read_head(path: &str, n: usize) -> Result<Vec<u8>, String> {
+    use std::io::Read;
+
+    let file = fs::File::open(path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let mut head = Vec::new();
+    file.take(n as u64)
+        .read_to_end(&mut head)
+        .map_err(|e| format!("Failed to read file: {}", e))?;
+    Ok(head)
+}
+
+/// Null bytes or a high invalid-UTF-8 ratio mark a file as binary.
+fn is_binary(head: &[u8]) -> bool {
+    if head.contains(&0) {
+        return true;
+    }
+
+    // A lossy decode cut mid-codepoint produces one stray replacement
+    // character, so require a meaningful ratio before calling it binary
+    let invalid = String::from_utf8_lossy(head)
+        .chars()
+        .filter(|c| *c == char::REPLACEMENT_CHARACTER)
+        .count();
+    invalid * 20 > head.len()
+}
+
+/// Best-effort type sniffing from magic bytes, for the binary descriptor.
+fn sniff_file_type(head: &[u8]) -> &'static str {
+    const MAGIC: &[(&[u8], &str)] = &[
+        (b"\x89PNG", "PNG image"),
+        (b"\xFF\xD8\xFF", "JPEG image"),
+        (b"GIF8", "GIF image"),
+        (b"%PDF", "PDF document"),
+        (b"PK\x03\x04", "ZIP archive"),
+        (b"\x1F\x8B", "gzip archive"),
+        (b"\x7FELF", "ELF executable"),
+        (b"\x00asm", "WebAssembly module"),
+        (b"SQLite format 3", "SQLite database"),
+        (b"OggS", "Ogg media"),
+        (b"RIFF", "RIFF media"),
+    ];
+
+    MAGIC
+        .iter()
+        .find(|(magic, _)| head.starts_with(magic))
+        .map(|(_, name)| *name)
+        .unwrap_or("binary data")
+}
+
+/// Human-readable size for tool messages ("48.1 KB").
+fn format_size(bytes: u64) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} bytes", bytes)
+    }
+}
+
+/// Read `limit` lines starting at 1-based line `offset`, without loading
+/// the whole file into memory.
+fn read_file_slice(path: &str, offset: usize, limit: Option<usize>) -> Result<String, String> {
+    use std::io::{BufRead, BufReader};
+
+    let file = fs::File::open(path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let start = offset.saturating_sub(1);
+    let mut lines = Vec::new();
+    for line in BufReader::new(file).lines().skip(start) {
+        let line = line.map_err(|e| format!("Failed to read file: {}", e))?;
+        lines.push(line);
+        if limit.is_some_and(|limit| lines.len() >= limit) {
+            break;
+        }
+    }
+
+    if lines.is_empty() {
+        return Err(format!("offset {} is past the end of the file", offset));
+    }
+
+    Ok(format!(
+        "{}\n\n[Lines {}-{}]",
+        lines.join("\n"),
+        offset,
+        start + lines.len()
+    ))
+}
+
 // ============================================================================
 // WriteFile Tool
 // ============================================================================
@@ -64,6 +225,10 @@ struct WriteFileInput {
 }
 
 fn write_file(input: Value) -> Result<String, String> {
+    write_file_with_max(input, max_file_size_bytes())
+}
+
+fn write_file_with_max(input: Value, max_bytes: u64) -> Result<String, String> {
     let input: WriteFileInput =
         serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
 
@@ -71,7 +236,50 @@ fn write_file(input: Value) -> Result<String, String> {
         return Err("path cannot be empty".to_string());
     }
 
+    if input.content.len() as u64 > max_bytes {
+        return Err(format!(
+            "Content is {} ({} bytes), which exceeds the {} limit. \
+             Write the file in smaller pieces or raise tools.max_file_size_bytes",
+            format_size(input.content.len() as u64),
+            input.content.len(),
+            format_size(max_bytes)
+        ));
+    }
+
     let path = Path::new(&input.path);
+    crate::project::note_tool_target(path);
+
+    // Refuse to clobber a file changed outside the agent since the
+    // last read; the model should re-read before overwriting
+    let mut stale_warning = None;
+    match check_freshness(path) {
+        Freshness::Fresh => {}
+        Freshness::Stale(message) => {
+            return Err(format!(
+                "Write blocked: {}. Set tools.stale_check_strict = false \
+                 to downgrade this check to a warning",
+                message
+            ));
+        }
+        Freshness::StaleWarning(message) => stale_warning = Some(message),
+    }
+
+    // Validate content for security anti-patterns before touching disk;
+    // Error findings block the write, Warning findings ride along with
+    // the result
+    let mut validation_warnings = Vec::new();
+    if write_validation_enabled() {
+        validation_warnings = WriteFileValidator::check(path, &input.content);
+        if let Some(blocker) = validation_warnings
+            .iter()
+            .find(|w| w.severity == ValidationSeverity::Error)
+        {
+            return Err(format!(
+                "Write blocked: {}. Set tools.write_validation = false to bypass this check",
+                blocker.message
+            ));
+        }
+    }
 
     // Create parent directories if needed
     if let Some(parent) = path.parent() {
@@ -80,12 +288,107 @@ fn write_file(input: Value) -> Result<String, String> {
         }
     }
 
-    fs::write(path, &input.content).map_err(|e| format!("Failed to write file: {}", e))?;
+    atomic_write(path, input.content.as_bytes())?;
+    note_file_seen(path);
 
-    Ok(format!(
+    let mut output = format!(
         "Successfully wrote {} bytes to {}",
         input.content.len(),
         input.path
+    );
+    if let Some(message) = stale_warning {
+        output.push_str(&format!("\nWarning: {}", message));
+    }
+    for warning in validation_warnings
+        .iter()
+        .filter(|w| w.severity == ValidationSeverity::Warning)
+    {
+        output.push_str(&format!("\nWarning: {}", warning.message));
+    }
+    Ok(output)
+}
+
+/// Write `content` to `path` atomically, preserving permission bits and
+/// writing through symlinks.
+///
+/// The content goes to a temp file in the target's directory, is
+/// fsynced, and is renamed over the target, so an interrupted write
+/// (timeout kill, Ctrl+C) never leaves a half-written file. A plain
+/// `fs::write` would also replace a symlink with a regular file; here
+/// the link is resolved first and the write lands on its destination.
+fn atomic_write(path: &Path, content: &[u8]) -> Result<(), String> {
+    use std::io::Write;
+
+    let target = resolve_write_target(path)?;
+
+    let parent = match target.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => std::path::PathBuf::from("."),
+    };
+    let file_name = target
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "file".to_string());
+    let temp_path = parent.join(format!(".{}.agent-write-{}", file_name, std::process::id()));
+
+    let result = (|| {
+        let mut file =
+            fs::File::create(&temp_path).map_err(|e| format!("Failed to write file: {}", e))?;
+        file.write_all(content)
+            .map_err(|e| format!("Failed to write file: {}", e))?;
+        file.sync_all()
+            .map_err(|e| format!("Failed to write file: {}", e))?;
+        // Carry the target's permission bits over; the fresh temp file
+        // would otherwise reset them to the umask default
+        if let Ok(metadata) = fs::metadata(&target) {
+            fs::set_permissions(&temp_path, metadata.permissions())
+                .map_err(|e| format!("Failed to write file: {}", e))?;
+        }
+        fs::rename(&temp_path, &target).map_err(|e| format!("Failed to write file: {}", e))
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// Resolve where a write should actually land, following symlinks.
+///
+/// A symlink inside the project may point anywhere, so only in-tree and
+/// trusted destinations are writable — the same scope bash working
+/// directories get.
+fn resolve_write_target(path: &Path) -> Result<std::path::PathBuf, String> {
+    let is_symlink = fs::symlink_metadata(path)
+        .map(|m| m.file_type().is_symlink())
+        .unwrap_or(false);
+    if !is_symlink {
+        return Ok(path.to_path_buf());
+    }
+
+    let resolved = fs::canonicalize(path)
+        .map_err(|e| format!("Failed to resolve symlink {}: {}", path.display(), e))?;
+    let project_root = std::env::current_dir()
+        .and_then(|cwd| cwd.canonicalize())
+        .map_err(|e| format!("Failed to resolve project root: {}", e))?;
+    if resolved.starts_with(&project_root) {
+        return Ok(resolved);
+    }
+
+    let trusted = TRUSTED_BASH_DIRS.lock().unwrap_or_else(|e| e.into_inner());
+    let is_trusted = trusted.iter().any(|root| {
+        Path::new(root)
+            .canonicalize()
+            .is_ok_and(|root| resolved.starts_with(&root))
+    });
+    if is_trusted {
+        return Ok(resolved);
+    }
+
+    Err(format!(
+        "{} is a symlink to {}, which is outside the project root; \
+         add the destination to permissions.trusted_paths to allow writing through it",
+        path.display(),
+        resolved.display()
     ))
 }
 
@@ -116,6 +419,7 @@ fn edit_file(input: Value) -> Result<String, String> {
     }
 
     let path = Path::new(&input.path);
+    crate::project::note_tool_target(path);
 
     // Check if file exists
     if !path.exists() {
@@ -128,13 +432,29 @@ fn edit_file(input: Value) -> Result<String, String> {
                         .map_err(|e| format!("Failed to create directory: {}", e))?;
                 }
             }
-            fs::write(path, &input.new_str).map_err(|e| format!("Failed to create file: {}", e))?;
+            atomic_write(path, input.new_str.as_bytes())?;
+            note_file_seen(path);
             return Ok(format!("Successfully created file {}", input.path));
         } else {
             return Err(format!("file '{}' does not exist", input.path));
         }
     }
 
+    // A file changed outside the agent since the last read makes
+    // old_str untrustworthy; the model should re-read first
+    let mut stale_warning = None;
+    match check_freshness(path) {
+        Freshness::Fresh => {}
+        Freshness::Stale(message) => {
+            return Err(format!(
+                "Edit blocked: {}. Set tools.stale_check_strict = false \
+                 to downgrade this check to a warning",
+                message
+            ));
+        }
+        Freshness::StaleWarning(message) => stale_warning = Some(message),
+    }
+
     // File exists - read content
     let content = fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
 
@@ -156,15 +476,33 @@ fn edit_file(input: Value) -> Result<String, String> {
         content.replacen(&input.old_str, &input.new_str, 1)
     };
 
-    fs::write(path, &new_content).map_err(|e| format!("Failed to write file: {}", e))?;
+    atomic_write(path, new_content.as_bytes())?;
+    note_file_seen(path);
 
-    Ok("OK".to_string())
+    match stale_warning {
+        Some(message) => Ok(format!("OK\nWarning: {}", message)),
+        None => Ok("OK".to_string()),
+    }
 }
 
 // ============================================================================
 // ListFiles Tool
 // ============================================================================
 
+/// Name of the agent-specific ignore file (gitignore syntax).
+const AGENT_IGNORE_FILE: &str = ".agentignore";
+
+/// Whether list_files and code_search honor .gitignore rules.
+///
+/// Threaded from `tools.respect_gitignore` at startup, like the bash
+/// timeout, because tool functions are plain `fn` pointers.
+static RESPECT_GITIGNORE: AtomicBool = AtomicBool::new(true);
+
+/// Set whether ignore files are honored, from `tools.respect_gitignore`.
+pub fn set_respect_gitignore(respect: bool) {
+    RESPECT_GITIGNORE.store(respect, Ordering::Relaxed);
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 struct ListFilesInput {
     /// Optional path to list files from. Defaults to current directory if not provided.
@@ -176,18 +514,35 @@ fn list_files(input: Value) -> Result<String, String> {
     let input: ListFilesInput =
         serde_json::from_value(input).map_err(|e| format!("Invalid input: {}", e))?;
 
-    let dir = input.path.unwrap_or_else(|| ".".to_string());
+    // Without an explicit path, scope to the workspace member being
+    // worked in rather than listing the entire workspace
+    let dir = input
+        .path
+        .or_else(crate::project::scoped_default_path)
+        .unwrap_or_else(|| ".".to_string());
+    let respect_gitignore = RESPECT_GITIGNORE.load(Ordering::Relaxed);
 
     let mut files: Vec<String> = Vec::new();
 
-    for entry in WalkDir::new(&dir)
-        .max_depth(3)
-        .into_iter()
+    // Honor .gitignore (unless disabled) plus .agentignore for
+    // agent-specific exclusions; nested ignore files apply to their
+    // own subtrees, matching git semantics
+    let mut builder = WalkBuilder::new(&dir);
+    builder
+        .max_depth(Some(3))
+        .hidden(false)
+        .ignore(false)
+        .git_global(false)
+        .require_git(false)
+        .git_ignore(respect_gitignore)
+        .git_exclude(respect_gitignore)
+        .add_custom_ignore_filename(AGENT_IGNORE_FILE)
         .filter_entry(|e| {
             let name = e.file_name().to_string_lossy();
             name != ".git" && name != ".devenv" && name != "target" && name != "node_modules"
-        })
-    {
+        });
+
+    for entry in builder.build() {
         let entry = entry.map_err(|e| format!("Error walking directory: {}", e))?;
         let path = entry.path();
 
@@ -203,7 +558,7 @@ fn list_files(input: Value) -> Result<String, String> {
             .to_string_lossy()
             .to_string();
 
-        if entry.file_type().is_dir() {
+        if entry.file_type().is_some_and(|t| t.is_dir()) {
             files.push(format!("{}/", rel_path));
         } else {
             files.push(rel_path);
@@ -223,10 +578,242 @@ fn list_files(input: Value) -> Result<String, String> {
 // Bash Tool
 // ============================================================================
 
+/// Default bash timeout when no config or per-call override is given.
+const DEFAULT_BASH_TIMEOUT_SECS: u64 = 120;
+
+/// Grace period between SIGTERM and SIGKILL when a command times out.
+const BASH_KILL_GRACE_SECS: u64 = 5;
+
+/// Process-wide default timeout for the bash tool, in seconds.
+///
+/// Tool functions are plain `fn` pointers, so the configured default from
+/// `tools.bash_timeout_secs` is threaded through this global at startup.
+static BASH_TIMEOUT_SECS: AtomicU64 = AtomicU64::new(DEFAULT_BASH_TIMEOUT_SECS);
+
+/// Set the default bash timeout from `tools.bash_timeout_secs`.
+pub fn set_bash_timeout_secs(secs: u64) {
+    BASH_TIMEOUT_SECS.store(secs, Ordering::Relaxed);
+}
+
+/// PIDs of bash children currently running, so shutdown can kill them.
+static RUNNING_CHILDREN: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+/// Registers a child PID for the lifetime of the guard, so a shutdown
+/// that arrives mid-command can find and kill it.
+struct ChildRegistration(u32);
+
+impl ChildRegistration {
+    fn new(pid: u32) -> Self {
+        RUNNING_CHILDREN.lock().unwrap().push(pid);
+        Self(pid)
+    }
+}
+
+impl Drop for ChildRegistration {
+    fn drop(&mut self) {
+        RUNNING_CHILDREN
+            .lock()
+            .unwrap()
+            .retain(|pid| *pid != self.0);
+    }
+}
+
+/// Kill any bash children still running, as part of process shutdown.
+///
+/// SIGTERM only: shutdown should not wait out a grace period. The signal
+/// goes to each child's whole process group (bash commands run in their
+/// own group), so descendants are covered too.
+pub fn kill_running_children() {
+    let pids: Vec<u32> = RUNNING_CHILDREN.lock().unwrap().drain(..).collect();
+    for pid in pids {
+        signal_process_group(pid, "TERM");
+    }
+}
+
+/// Signal a command's whole process group (`kill -<signal> -- -<pgid>`).
+///
+/// Bash children run in their own group (see `run_shell_command`), so this
+/// reaches their descendants too. Descendants inherit the output pipes, and
+/// one left running would keep the drain threads — and with them the REPL —
+/// blocked long after bash itself is dead.
+fn signal_process_group(pgid: u32, signal: &str) {
+    let _ = Command::new("kill")
+        .arg(format!("-{}", signal))
+        .arg("--")
+        .arg(format!("-{}", pgid))
+        .status();
+}
+
+/// Directories outside the project root that bash may still use as a
+/// working directory, from `permissions.trusted_paths`.
+static TRUSTED_BASH_DIRS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Set the trusted working directories (from `permissions.trusted_paths`).
+pub fn set_trusted_bash_dirs(paths: Vec<String>) {
+    *TRUSTED_BASH_DIRS.lock().unwrap_or_else(|e| e.into_inner()) = paths;
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 struct BashInput {
     /// The bash command to execute.
     command: String,
+    /// Optional timeout in seconds. Defaults to the configured
+    /// `tools.bash_timeout_secs` (120 if unset). The process is killed
+    /// when the timeout expires.
+    #[serde(default)]
+    timeout_secs: Option<u64>,
+    /// Optional directory to run the command in. Must exist and lie
+    /// under the project root or a trusted path.
+    #[serde(default)]
+    working_directory: Option<String>,
+}
+
+/// Validate a bash working directory and resolve it to a canonical path.
+///
+/// The directory must exist, and must sit under the project root (the
+/// current working directory) or one of the trusted paths, so a command
+/// cannot be aimed outside the project scope.
+fn validate_working_directory(dir: &str) -> Result<std::path::PathBuf, String> {
+    let path = Path::new(dir);
+    if !path.exists() {
+        return Err(format!("working_directory does not exist: {}", dir));
+    }
+    if !path.is_dir() {
+        return Err(format!("working_directory is not a directory: {}", dir));
+    }
+
+    let canonical = path
+        .canonicalize()
+        .map_err(|e| format!("Failed to resolve working_directory: {}", e))?;
+    let project_root = std::env::current_dir()
+        .and_then(|cwd| cwd.canonicalize())
+        .map_err(|e| format!("Failed to resolve project root: {}", e))?;
+    if canonical.starts_with(&project_root) {
+        return Ok(canonical);
+    }
+
+    let trusted = TRUSTED_BASH_DIRS.lock().unwrap_or_else(|e| e.into_inner());
+    let is_trusted = trusted.iter().any(|root| {
+        Path::new(root)
+            .canonicalize()
+            .is_ok_and(|root| canonical.starts_with(&root))
+    });
+    if is_trusted {
+        return Ok(canonical);
+    }
+
+    Err(format!(
+        "working_directory {} is outside the project root; add it to permissions.trusted_paths to allow it",
+        dir
+    ))
+}
+
+/// Spawn a reader thread that drains a pipe so the child cannot block on a
+/// full pipe buffer, and so partial output survives a timeout kill.
+fn drain_pipe<R: std::io::Read + Send + 'static>(mut pipe: R) -> std::thread::JoinHandle<Vec<u8>> {
+    std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = pipe.read_to_end(&mut buf);
+        buf
+    })
+}
+
+/// Captured output of a shell command run through the bash machinery.
+pub(crate) struct ShellOutput {
+    pub(crate) stdout: String,
+    pub(crate) stderr: String,
+    pub(crate) exit_code: i32,
+}
+
+/// Run a command through `bash -c` with timeout and shutdown handling.
+///
+/// `Err` is reserved for spawn failures and timeouts; a command that runs
+/// to completion returns `Ok` regardless of its exit code, so callers can
+/// interpret failure output themselves.
+pub(crate) fn run_shell_command(
+    command_text: &str,
+    timeout_secs: u64,
+    working_dir: Option<&Path>,
+) -> Result<ShellOutput, String> {
+    let mut command = Command::new("bash");
+    command
+        .arg("-c")
+        .arg(command_text)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(dir) = working_dir {
+        command.current_dir(dir);
+    }
+    // Fresh process group, so timeout and shutdown can signal the whole
+    // command tree rather than just bash itself
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+    let mut child = command
+        .spawn()
+        .map_err(|e| format!("Failed to execute command: {}", e))?;
+
+    // Visible to kill_running_children until this function returns
+    let _registration = ChildRegistration::new(child.id());
+
+    let stdout_reader = drain_pipe(child.stdout.take().expect("stdout is piped"));
+    let stderr_reader = drain_pipe(child.stderr.take().expect("stderr is piped"));
+
+    // Poll for exit until the deadline instead of blocking in wait(), so a
+    // runaway command cannot hold the REPL thread forever
+    let deadline = Instant::now() + Duration::from_secs(timeout_secs);
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break Some(status),
+            Ok(None) => {}
+            Err(e) => return Err(format!("Failed to wait for command: {}", e)),
+        }
+        if Instant::now() >= deadline {
+            break None;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    };
+
+    let Some(status) = status else {
+        // SIGTERM the process group first so the command can clean up,
+        // SIGKILL it after the grace period
+        signal_process_group(child.id(), "TERM");
+        let grace = Instant::now() + Duration::from_secs(BASH_KILL_GRACE_SECS);
+        loop {
+            if matches!(child.try_wait(), Ok(Some(_))) {
+                break;
+            }
+            if Instant::now() >= grace {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+        // Unconditional: bash exiting on SIGTERM says nothing about its
+        // descendants, and signaling an already-empty group is a no-op
+        signal_process_group(child.id(), "KILL");
+        let _ = child.kill();
+        let _ = child.wait();
+
+        let stdout = stdout_reader.join().unwrap_or_default();
+        let stderr = stderr_reader.join().unwrap_or_default();
+        return Err(format!(
+            "Command timed out after {}s\npartial stdout: {}\npartial stderr: {}",
+            timeout_secs,
+            String::from_utf8_lossy(&stdout).trim(),
+            String::from_utf8_lossy(&stderr).trim()
+        ));
+    };
+
+    let stdout = stdout_reader.join().unwrap_or_default();
+    let stderr = stderr_reader.join().unwrap_or_default();
+    Ok(ShellOutput {
+        stdout: String::from_utf8_lossy(&stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&stderr).into_owned(),
+        exit_code: status.code().unwrap_or(-1),
+    })
 }
 
 fn bash(input: Value) -> Result<String, String> {
@@ -248,20 +835,23 @@ fn bash(input: Value) -> Result<String, String> {
         }
     }
 
-    let output = Command::new("bash")
-        .arg("-c")
-        .arg(&input.command)
-        .output()
-        .map_err(|e| format!("Failed to execute command: {}", e))?;
+    let timeout_secs = input
+        .timeout_secs
+        .unwrap_or_else(|| BASH_TIMEOUT_SECS.load(Ordering::Relaxed));
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
+    // Vet the working directory before handing it to bash
+    let working_dir = match input.working_directory {
+        Some(ref dir) => Some(validate_working_directory(dir)?),
+        None => None,
+    };
+
+    let output = run_shell_command(&input.command, timeout_secs, working_dir.as_deref())?;
 
-    if output.status.success() {
-        let result = if stdout.is_empty() {
-            stderr.trim().to_string()
+    if output.exit_code == 0 {
+        let result = if output.stdout.is_empty() {
+            output.stderr.trim().to_string()
         } else {
-            stdout.trim().to_string()
+            output.stdout.trim().to_string()
         };
 
         // Truncate very long output
@@ -279,9 +869,9 @@ fn bash(input: Value) -> Result<String, String> {
     } else {
         Err(format!(
             "Command failed with exit code: {}\nstdout: {}\nstderr: {}",
-            output.status.code().unwrap_or(-1),
-            stdout.trim(),
-            stderr.trim()
+            output.exit_code,
+            output.stdout.trim(),
+            output.stderr.trim()
         ))
     }
 }
@@ -303,6 +893,27 @@ struct CodeSearchInput {
     /// Whether the search should be case sensitive (default: false).
     #[serde(default)]
     case_sensitive: bool,
+    /// Optional number of context lines to show before and after each
+    /// match (like grep -C). Useful for judging a match without a
+    /// follow-up read_file call.
+    #[serde(default)]
+    context_lines: Option<usize>,
+}
+
+/// Whether ripgrep is installed, probed once per process.
+static RIPGREP_AVAILABLE: OnceLock<bool> = OnceLock::new();
+
+fn ripgrep_available() -> bool {
+    *RIPGREP_AVAILABLE.get_or_init(|| Command::new("rg").arg("--version").output().is_ok())
+}
+
+/// Which search backend code_search uses, for display in /status.
+pub fn code_search_backend() -> &'static str {
+    if ripgrep_available() {
+        "ripgrep"
+    } else {
+        "built-in"
+    }
 }
 
 fn code_search(input: Value) -> Result<String, String> {
@@ -313,6 +924,14 @@ fn code_search(input: Value) -> Result<String, String> {
         return Err("pattern is required".to_string());
     }
 
+    if ripgrep_available() {
+        code_search_ripgrep(input)
+    } else {
+        code_search_builtin(input)
+    }
+}
+
+fn code_search_ripgrep(input: CodeSearchInput) -> Result<String, String> {
     // Build ripgrep command
     let mut args = vec![
         "--line-number".to_string(),
@@ -329,8 +948,31 @@ fn code_search(input: Value) -> Result<String, String> {
         args.push(file_type.clone());
     }
 
+    if let Some(context) = input.context_lines {
+        args.push("--context".to_string());
+        args.push(context.to_string());
+    }
+
+    // Without an explicit path, scope to the workspace member being
+    // worked in rather than searching the entire workspace
+    let search_path = input
+        .path
+        .or_else(crate::project::scoped_default_path)
+        .unwrap_or_else(|| ".".to_string());
+
+    if !RESPECT_GITIGNORE.load(Ordering::Relaxed) {
+        args.push("--no-ignore".to_string());
+    }
+
+    // Apply .agentignore on top of ripgrep's own gitignore handling
+    let agent_ignore = Path::new(&search_path).join(AGENT_IGNORE_FILE);
+    if agent_ignore.is_file() {
+        args.push("--ignore-file".to_string());
+        args.push(agent_ignore.display().to_string());
+    }
+
     args.push(input.pattern.clone());
-    args.push(input.path.unwrap_or_else(|| ".".to_string()));
+    args.push(search_path);
 
     let output = Command::new("rg")
         .args(&args)
@@ -347,72 +989,748 @@ fn code_search(input: Value) -> Result<String, String> {
     }
 
     let result = String::from_utf8_lossy(&output.stdout).trim().to_string();
-    let lines: Vec<&str> = result.lines().collect();
+    Ok(truncate_search_output(
+        &result,
+        input.context_lines.is_some(),
+    ))
+}
 
-    // Limit output to 50 matches
-    if lines.len() > 50 {
-        let truncated: String = lines[..50].join("\n");
-        Ok(format!(
-            "{}\n... (showing first 50 of {} matches)",
-            truncated,
-            lines.len()
-        ))
+/// Pure-Rust fallback used when ripgrep is not installed.
+///
+/// Mirrors the ripgrep invocation: same `file:line:match` output format,
+/// case-insensitive by default, ripgrep's file-type names, ignore-file
+/// handling, and the 50-match truncation.
+fn code_search_builtin(input: CodeSearchInput) -> Result<String, String> {
+    let regex = RegexBuilder::new(&input.pattern)
+        .case_insensitive(!input.case_sensitive)
+        .build()
+        .map_err(|e| format!("search failed: {}", e))?;
+
+    let search_path = input
+        .path
+        .or_else(crate::project::scoped_default_path)
+        .unwrap_or_else(|| ".".to_string());
+
+    // The ignore crate ships ripgrep's file-type definitions, so
+    // file_type: "rs" selects the same files as `rg --type rs`
+    let mut types_builder = TypesBuilder::new();
+    types_builder.add_defaults();
+    if let Some(ref file_type) = input.file_type {
+        types_builder.select(file_type);
+    }
+    let types = types_builder
+        .build()
+        .map_err(|e| format!("search failed: {}", e))?;
+
+    let respect_gitignore = RESPECT_GITIGNORE.load(Ordering::Relaxed);
+    let mut builder = WalkBuilder::new(&search_path);
+    builder
+        .ignore(false)
+        .git_global(false)
+        .require_git(false)
+        .git_ignore(respect_gitignore)
+        .git_exclude(respect_gitignore)
+        .add_custom_ignore_filename(AGENT_IGNORE_FILE)
+        .types(types);
+
+    let mut output_lines: Vec<String> = Vec::new();
+    for entry in builder.build().flatten() {
+        if !entry.file_type().is_some_and(|t| t.is_file()) {
+            continue;
+        }
+        // Skip binary and non-UTF-8 files, like ripgrep does by default
+        let Ok(content) = fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        let lines: Vec<&str> = content.lines().collect();
+        let match_indices: Vec<usize> = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| regex.is_match(line))
+            .map(|(index, _)| index)
+            .collect();
+
+        let path = entry.path().display();
+        match input.context_lines {
+            None => {
+                for index in match_indices {
+                    output_lines.push(format!("{}:{}:{}", path, index + 1, lines[index]));
+                }
+            }
+            Some(context) => {
+                // Mirror ripgrep's context output: `path:NN:` on matches,
+                // `path-NN-` on context lines, `--` between chunks
+                for (start, end) in context_chunks(&match_indices, context, lines.len()) {
+                    if !output_lines.is_empty() {
+                        output_lines.push("--".to_string());
+                    }
+                    for (index, line) in lines.iter().enumerate().take(end + 1).skip(start) {
+                        if match_indices.binary_search(&index).is_ok() {
+                            output_lines.push(format!("{}:{}:{}", path, index + 1, line));
+                        } else {
+                            output_lines.push(format!("{}-{}-{}", path, index + 1, line));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if output_lines.is_empty() {
+        return Ok("No matches found".to_string());
+    }
+
+    Ok(truncate_search_output(
+        &output_lines.join("\n"),
+        input.context_lines.is_some(),
+    ))
+}
+
+/// Merge match positions into line ranges padded by `context` lines on
+/// each side, coalescing ranges that touch or overlap like ripgrep does.
+/// Indices are zero-based; `total` is the file's line count.
+fn context_chunks(matches: &[usize], context: usize, total: usize) -> Vec<(usize, usize)> {
+    let mut chunks: Vec<(usize, usize)> = Vec::new();
+    for &index in matches {
+        let start = index.saturating_sub(context);
+        let end = (index + context).min(total.saturating_sub(1));
+        match chunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end + 1 => *last_end = (*last_end).max(end),
+            _ => chunks.push((start, end)),
+        }
+    }
+    chunks
+}
+
+/// Cap search output, identically for both backends: 50 matches normally,
+/// or 100 total lines when context is enabled (context lines and chunk
+/// separators count against the cap, so a handful of matches with wide
+/// context cannot flood the conversation).
+fn truncate_search_output(result: &str, context_enabled: bool) -> String {
+    let lines: Vec<&str> = result.lines().collect();
+    let (limit, unit) = if context_enabled {
+        (100, "lines")
+    } else {
+        (50, "matches")
+    };
+    if lines.len() > limit {
+        format!(
+            "{}\n... (showing first {} of {} {})",
+            lines[..limit].join("\n"),
+            limit,
+            lines.len(),
+            unit
+        )
     } else {
-        Ok(result)
+        result.to_string()
     }
 }
 
 // ============================================================================
-// Tool Definitions
+// DocSearch Tool
 // ============================================================================
 
-/// Create all tool definitions for the CLI.
-pub fn create_tool_definitions() -> Vec<ToolDefinition> {
-    vec![
-        ToolDefinition {
-            name: "read_file".to_string(),
-            description: "Read the contents of a file. Use this when you want to see what's inside a file. Do not use this with directory names.".to_string(),
-            input_schema: generate_schema::<ReadFileInput>(),
-            function: read_file,
-        },
-        ToolDefinition {
-            name: "write_file".to_string(),
-            description: "Create a new file or overwrite an existing file with the given content. Use this when you need to create a new file from scratch.".to_string(),
-            input_schema: generate_schema::<WriteFileInput>(),
-            function: write_file,
-        },
-        ToolDefinition {
-            name: "edit_file".to_string(),
-            description: r#"Make edits to a text file. Replaces 'old_str' with 'new_str' in the given file. 'old_str' and 'new_str' MUST be different from each other. The old_str must appear exactly once in the file. If the file doesn't exist and old_str is empty, the file will be created with new_str as content."#.to_string(),
-            input_schema: generate_schema::<EditFileInput>(),
-            function: edit_file,
-        },
-        ToolDefinition {
-            name: "list_files".to_string(),
-            description: "List files and directories at a given path. If no path is provided, lists files in the current directory. Useful for exploring the codebase structure.".to_string(),
-            input_schema: generate_schema::<ListFilesInput>(),
-            function: list_files,
-        },
-        ToolDefinition {
-            name: "bash".to_string(),
-            description: "Execute a bash command and return its output. Use this to run shell commands like 'cargo build', 'npm install', 'git status', etc.".to_string(),
-            input_schema: generate_schema::<BashInput>(),
-            function: bash,
-        },
-        ToolDefinition {
-            name: "code_search".to_string(),
-            description: r#"Search for code patterns using ripgrep (rg). Use this to find code patterns, function definitions, variable usage, or any text in the codebase. You can filter by file type (e.g., 'rs', 'js', 'py')."#.to_string(),
-            input_schema: generate_schema::<CodeSearchInput>(),
-            function: code_search,
-        },
-    ]
+/// Custom documentation roots from `tools.doc_paths`, keyed by language.
+///
+/// Threaded through a global like the bash timeout, because tool
+/// functions are plain `fn` pointers.
+static DOC_PATHS: Mutex<Option<HashMap<String, String>>> = Mutex::new(None);
+
+/// Set the custom documentation roots from `tools.doc_paths`.
+pub fn set_doc_paths(paths: HashMap<String, String>) {
+    *DOC_PATHS.lock().unwrap_or_else(|e| e.into_inner()) = Some(paths);
 }
 
-/// Convert tool definitions to the format expected by the Claude API.
-pub fn tool_definitions_to_api(definitions: &[ToolDefinition]) -> Vec<Tool> {
-    definitions
-        .iter()
-        .map(|def| Tool {
+#[derive(Debug, Deserialize, JsonSchema)]
+struct DocSearchInput {
+    /// The item to look up (e.g. "Vec", "Vec::push", "os.path", "express").
+    item: String,
+    /// The documentation set to search: "rust", "python", or "node".
+    language: String,
+}
+
+fn doc_search(input: Value) -> Result<String, String> {
+    let input: DocSearchInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+
+    if input.item.is_empty() {
+        return Err("item cannot be empty".to_string());
+    }
+
+    let custom_roots = DOC_PATHS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+        .unwrap_or_default();
+
+    super::doc_search::search(&input.item, &input.language, &custom_roots)
+}
+
+// ============================================================================
+// Cargo Tools
+// ============================================================================
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct CargoCheckInput {
+    /// Optional workspace package to check (defaults to the whole workspace).
+    #[serde(default)]
+    package: Option<String>,
+}
+
+fn cargo_check(input: Value) -> Result<String, String> {
+    let input: CargoCheckInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+    super::cargo_tools::cargo_check(input.package.as_deref())
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct CargoTestInput {
+    /// Optional test name filter, e.g. "tools::tests::test_bash". Only
+    /// letters, digits, '_', and '::' are allowed.
+    #[serde(default)]
+    filter: Option<String>,
+    /// Optional workspace package to test (defaults to the whole workspace).
+    #[serde(default)]
+    package: Option<String>,
+}
+
+fn cargo_test(input: Value) -> Result<String, String> {
+    let input: CargoTestInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+    super::cargo_tools::cargo_test(input.filter.as_deref(), input.package.as_deref())
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct CargoClippyInput {
+    /// Optional workspace package to lint (defaults to the whole workspace).
+    #[serde(default)]
+    package: Option<String>,
+}
+
+fn cargo_clippy(input: Value) -> Result<String, String> {
+    let input: CargoClippyInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+    super::cargo_tools::cargo_clippy(input.package.as_deref())
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct CargoFmtInput {
+    /// Optional workspace package to format (defaults to the whole workspace).
+    #[serde(default)]
+    package: Option<String>,
+}
+
+fn cargo_fmt(input: Value) -> Result<String, String> {
+    let input: CargoFmtInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+    super::cargo_tools::cargo_fmt(input.package.as_deref())
+}
+
+// ============================================================================
+// Async Tool Variants
+// ============================================================================
+
+/// Run a blocking tool implementation on tokio's blocking thread pool.
+fn run_on_blocking_pool(func: fn(Value) -> Result<String, String>, input: Value) -> ToolFuture {
+    Box::pin(async move {
+        tokio::task::spawn_blocking(move || func(input))
+            .await
+            .map_err(|e| format!("Tool task failed: {}", e))?
+    })
+}
+
+/// Async variant of `bash`: the subprocess wait runs on the blocking pool
+/// instead of stalling a runtime worker.
+pub fn bash_async(input: Value) -> ToolFuture {
+    run_on_blocking_pool(bash, input)
+}
+
+/// Async variant of `code_search` (ripgrep subprocess or built-in walk).
+pub fn code_search_async(input: Value) -> ToolFuture {
+    run_on_blocking_pool(code_search, input)
+}
+
+/// Async variant of `doc_search` (directory walks and the pydoc subprocess).
+pub fn doc_search_async(input: Value) -> ToolFuture {
+    run_on_blocking_pool(doc_search, input)
+}
+
+// ============================================================================
+// Dry-Run Simulation
+// ============================================================================
+
+/// Prefix marking simulated tool results so the model knows nothing ran.
+pub(crate) const DRY_RUN_PREFIX: &str = "[DRY RUN]";
+
+/// Describe what a mutating tool would do without doing it.
+///
+/// Returns `None` for read-only tools, which execute normally even in
+/// dry-run mode.
+pub(crate) fn simulate_tool_call(name: &str, input: &Value) -> Option<String> {
+    match name {
+        "bash" => {
+            let command = input.get("command").and_then(|c| c.as_str()).unwrap_or("");
+            Some(format!("{} would run: {}", DRY_RUN_PREFIX, command))
+        }
+        "write_file" => Some(simulate_write_file(input)),
+        "edit_file" => Some(simulate_edit_file(input)),
+        _ => None,
+    }
+}
+
+fn simulate_write_file(input: &Value) -> String {
+    let path = input.get("path").and_then(|p| p.as_str()).unwrap_or("");
+    let content = input.get("content").and_then(|c| c.as_str()).unwrap_or("");
+
+    match fs::read_to_string(path) {
+        Ok(existing) => format!(
+            "{} would overwrite {} ({} -> {}):\n{}",
+            DRY_RUN_PREFIX,
+            path,
+            format_size(existing.len() as u64),
+            format_size(content.len() as u64),
+            simulate_diff(&existing, content)
+        ),
+        Err(_) => format!(
+            "{} would create {} with {} ({} lines)",
+            DRY_RUN_PREFIX,
+            path,
+            format_size(content.len() as u64),
+            content.lines().count()
+        ),
+    }
+}
+
+fn simulate_edit_file(input: &Value) -> String {
+    let path = input.get("path").and_then(|p| p.as_str()).unwrap_or("");
+    let old_str = input.get("old_str").and_then(|s| s.as_str()).unwrap_or("");
+    let new_str = input.get("new_str").and_then(|s| s.as_str()).unwrap_or("");
+
+    // Report up front when the edit would not apply cleanly
+    match fs::read_to_string(path) {
+        Ok(existing) => match existing.matches(old_str).count() {
+            1 => {
+                let mut diff = String::new();
+                for line in old_str.lines() {
+                    diff.push_str(&format!("- {}\n", line));
+                }
+                for line in new_str.lines() {
+                    diff.push_str(&format!("+ {}\n", line));
+                }
+                format!(
+                    "{} would edit {}:\n{}",
+                    DRY_RUN_PREFIX,
+                    path,
+                    diff.trim_end()
+                )
+            }
+            0 => format!(
+                "{} edit_file would fail: old_str not found in {}",
+                DRY_RUN_PREFIX, path
+            ),
+            n => format!(
+                "{} edit_file would fail: old_str matches {} times in {}",
+                DRY_RUN_PREFIX, n, path
+            ),
+        },
+        Err(_) if old_str.is_empty() => format!(
+            "{} would create {} with {}",
+            DRY_RUN_PREFIX,
+            path,
+            format_size(new_str.len() as u64)
+        ),
+        Err(_) => format!(
+            "{} edit_file would fail: {} does not exist",
+            DRY_RUN_PREFIX, path
+        ),
+    }
+}
+
+/// Minimal line diff for dry-run previews: common prefix and suffix lines
+/// are trimmed and the changed middle is shown as -/+ blocks.
+fn simulate_diff(old: &str, new: &str) -> String {
+    const MAX_DIFF_LINES: usize = 40;
+
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+
+    let prefix = old_lines
+        .iter()
+        .zip(&new_lines)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old_lines[prefix..]
+        .iter()
+        .rev()
+        .zip(new_lines[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut lines = Vec::new();
+    for line in &old_lines[prefix..old_lines.len() - suffix] {
+        lines.push(format!("- {}", line));
+    }
+    for line in &new_lines[prefix..new_lines.len() - suffix] {
+        lines.push(format!("+ {}", line));
+    }
+
+    if lines.len() > MAX_DIFF_LINES {
+        let total = lines.len();
+        lines.truncate(MAX_DIFF_LINES);
+        lines.push(format!(
+            "... ({} more changed lines)",
+            total - MAX_DIFF_LINES
+        ));
+    }
+    lines.join("\n")
+}
+
+// ============================================================================
+// ProgressFile Tool
+// ============================================================================
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ProgressFileInput {
+    /// The progress action: "start", "complete", "skip", or "error".
+    action: String,
+    /// Stable identifier for the task (e.g. "add-config-flag").
+    task_id: String,
+    /// Human-readable description of the task or what happened.
+    description: String,
+    /// Optional structured data to attach to the entry.
+    #[serde(default)]
+    data: Option<Value>,
+}
+
+fn progress_file(input: Value) -> Result<String, String> {
+    let input: ProgressFileInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+
+    const ACTIONS: [&str; 4] = ["start", "complete", "skip", "error"];
+    if !ACTIONS.contains(&input.action.as_str()) {
+        return Err(format!(
+            "action must be one of {:?}, got '{}'",
+            ACTIONS, input.action
+        ));
+    }
+
+    if input.task_id.is_empty() {
+        return Err("task_id cannot be empty".to_string());
+    }
+
+    let session_id = ProgressFile::current_session_id();
+    let entry = ProgressEntry::new(
+        &input.action,
+        &input.task_id,
+        &input.description,
+        input.data,
+    );
+    ProgressFile::append(&session_id, &entry)
+        .map_err(|e| format!("Failed to write progress entry: {}", e))?;
+
+    Ok(format!(
+        "Recorded '{}' for task '{}'",
+        input.action, input.task_id
+    ))
+}
+
+// ============================================================================
+// SpawnTask Tool
+// ============================================================================
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct SpawnTaskInput {
+    /// A self-contained description of the task for the child agent. Include
+    /// all context it needs: the child does not see the parent conversation.
+    pub(crate) description: String,
+    /// Optional subset of tool names the child agent may use. Defaults to the
+    /// standard tools (read_file, write_file, edit_file, list_files, bash,
+    /// code_search). spawn_task is never available to children.
+    pub(crate) tools: Option<Vec<String>>,
+    /// Optional cap on the child agent's request/tool iterations.
+    pub(crate) max_iterations: Option<usize>,
+}
+
+fn spawn_task(input: Value) -> Result<String, String> {
+    // Validate the input so callers get a useful error, but the actual
+    // child agent needs live session state (API key, model, agent manager),
+    // so the REPL intercepts spawn_task calls before they reach this stub
+    let _input: SpawnTaskInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+    Err("spawn_task is only available in the interactive session".to_string())
+}
+
+// ============================================================================
+// UpdatePlan Tool
+// ============================================================================
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct UpdatePlanInput {
+    /// The 1-based number of the plan step to update, as shown in the
+    /// active plan checklist.
+    pub(crate) step: usize,
+    /// Whether the step is done. Defaults to true; pass false to untick a
+    /// step that turned out to need more work.
+    pub(crate) done: Option<bool>,
+}
+
+fn update_plan(input: Value) -> Result<String, String> {
+    // Validate the input so callers get a useful error, but the active
+    // plan lives in REPL state, so the REPL intercepts update_plan calls
+    // before they reach this stub
+    let _input: UpdatePlanInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+    Err("update_plan is only available in the interactive session".to_string())
+}
+
+// ============================================================================
+// TodoWrite Tool
+// ============================================================================
+
+/// Lifecycle of a todo item, constrained in the tool schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum TodoStatus {
+    Pending,
+    InProgress,
+    Done,
+}
+
+#[derive(Debug, Clone, Deserialize, JsonSchema)]
+pub(crate) struct TodoItem {
+    /// A short stable identifier for the item, reused across updates
+    /// (e.g. "parse-config").
+    pub(crate) id: String,
+    /// What the item is, phrased as a task (e.g. "Parse the config file").
+    pub(crate) content: String,
+    /// The item's current status: pending, in_progress, or done.
+    pub(crate) status: TodoStatus,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub(crate) struct TodoWriteInput {
+    /// The full todo list in order; each call replaces the previous
+    /// list, so carry over unchanged items.
+    pub(crate) todos: Vec<TodoItem>,
+}
+
+fn todo_write(input: Value) -> Result<String, String> {
+    // Validate the input so callers get a useful error, but the todo
+    // list lives in REPL state, so the REPL intercepts todo_write calls
+    // before they reach this stub
+    let _input: TodoWriteInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+    Err("todo_write is only available in the interactive session".to_string())
+}
+
+// ============================================================================
+// GitInteractiveRebase Tool
+// ============================================================================
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct RebaseOperationInput {
+    /// What to do with the commit: "pick", "squash", "fixup", "drop", or "reword".
+    action: String,
+    /// The commit to operate on (a hash or anything git rev-parse accepts).
+    commit_id: String,
+    /// Replacement commit message. Required for "reword"; optional for "squash",
+    /// which otherwise concatenates the two messages.
+    new_message: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct GitInteractiveRebaseInput {
+    /// The commit to rebase onto, e.g. "HEAD~3". Commits after it are rewritten.
+    base: String,
+    /// The new commit sequence, oldest first. Commits left out are dropped.
+    operations: Vec<RebaseOperationInput>,
+}
+
+fn git_interactive_rebase(input: Value) -> Result<String, String> {
+    use crate::integrations::{GitRepo, RebaseAction, RebaseOperation};
+
+    let input: GitInteractiveRebaseInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+
+    let operations = input
+        .operations
+        .iter()
+        .map(|op| {
+            let action = RebaseAction::from_name(&op.action).ok_or_else(|| {
+                format!(
+                    "unknown action '{}' (expected pick, squash, fixup, drop, or reword)",
+                    op.action
+                )
+            })?;
+            Ok(RebaseOperation {
+                action,
+                commit_id: op.commit_id.clone(),
+                new_message: op.new_message.clone(),
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let repo = GitRepo::open_cwd().map_err(|e| e.to_string())?;
+    let new_head = repo
+        .interactive_rebase(&input.base, &operations)
+        .map_err(|e| e.to_string())?;
+
+    let applied = operations
+        .iter()
+        .filter(|op| op.action != RebaseAction::Drop)
+        .count();
+    Ok(format!(
+        "Rebase complete: {} operation(s) applied onto {}. HEAD is now {}",
+        applied, input.base, new_head
+    ))
+}
+
+// ============================================================================
+// WorkspaceInfo Tool
+// ============================================================================
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct WorkspaceInfoInput {
+    /// Optional directory to inspect. Defaults to the current directory.
+    #[serde(default)]
+    path: Option<String>,
+}
+
+fn workspace_info(input: Value) -> Result<String, String> {
+    let input: WorkspaceInfoInput =
+        serde_json::from_value(input).map_err(|e| format!("Failed to parse input: {}", e))?;
+    let dir = input.path.unwrap_or_else(|| ".".to_string());
+
+    if !Path::new(&dir).is_dir() {
+        return Err(format!("{} is not a directory", dir));
+    }
+
+    match crate::project::workspace_info(Path::new(&dir)) {
+        Some(info) => Ok(info.render()),
+        None => Ok(format!(
+            "No recognized project markers (Cargo.toml, package.json, pyproject.toml, go.mod) in {}",
+            dir
+        )),
+    }
+}
+
+// ============================================================================
+// Tool Definitions
+// ============================================================================
+
+/// Create all tool definitions for the CLI.
+pub fn create_tool_definitions() -> Vec<ToolDefinition> {
+    vec![
+        ToolDefinition {
+            name: "read_file".to_string(),
+            description: "Read the contents of a file. Use this when you want to see what's inside a file. Do not use this with directory names. Binary files return a short descriptor instead of their contents; use offset and limit to read a slice of a large file.".to_string(),
+            input_schema: generate_schema::<ReadFileInput>(),
+            function: read_file,
+        },
+        ToolDefinition {
+            name: "write_file".to_string(),
+            description: "Create a new file or overwrite an existing file with the given content. Use this when you need to create a new file from scratch.".to_string(),
+            input_schema: generate_schema::<WriteFileInput>(),
+            function: write_file,
+        },
+        ToolDefinition {
+            name: "edit_file".to_string(),
+            description: r#"Make edits to a text file. Replaces 'old_str' with 'new_str' in the given file. 'old_str' and 'new_str' MUST be different from each other. The old_str must appear exactly once in the file. If the file doesn't exist and old_str is empty, the file will be created with new_str as content."#.to_string(),
+            input_schema: generate_schema::<EditFileInput>(),
+            function: edit_file,
+        },
+        ToolDefinition {
+            name: "list_files".to_string(),
+            description: "List files and directories at a given path. If no path is provided, lists files in the current directory. Useful for exploring the codebase structure.".to_string(),
+            input_schema: generate_schema::<ListFilesInput>(),
+            function: list_files,
+        },
+        ToolDefinition {
+            name: "bash".to_string(),
+            description: "Execute a bash command and return its output. Use this to run shell commands like 'cargo build', 'npm install', 'git status', etc.".to_string(),
+            input_schema: generate_schema::<BashInput>(),
+            function: bash,
+        },
+        ToolDefinition {
+            name: "progress_file".to_string(),
+            description: "Append a structured progress entry to the session's task log. Use action 'start' when beginning a subtask and 'complete', 'skip', or 'error' when finishing it. The log is shown by the /progress command. Useful for tracking long multi-step tasks.".to_string(),
+            input_schema: generate_schema::<ProgressFileInput>(),
+            function: progress_file,
+        },
+        ToolDefinition {
+            name: "spawn_task".to_string(),
+            description: "Spawn a child agent to work on a scoped sub-task with its own conversation and a restricted tool set. Use this for large tasks that would not fit in the current context (e.g. a sweeping refactor). Provide a self-contained task description; the child runs to completion and only its final summary comes back as the result.".to_string(),
+            input_schema: generate_schema::<SpawnTaskInput>(),
+            function: spawn_task,
+        },
+        ToolDefinition {
+            name: "update_plan".to_string(),
+            description: "Mark a step of the active plan as done (or not done). Use this immediately after completing a plan step so the checklist stays accurate. Steps are numbered as shown in the plan checklist. Only available when a plan has been approved.".to_string(),
+            input_schema: generate_schema::<UpdatePlanInput>(),
+            function: update_plan,
+        },
+        ToolDefinition {
+            name: "todo_write".to_string(),
+            description: "Replace the session's todo list to show the user your progress through multi-step work. Send the full list each time with statuses pending, in_progress, or done; keep item ids stable across updates. Use this at the start of a multi-step task and whenever an item's status changes.".to_string(),
+            input_schema: generate_schema::<TodoWriteInput>(),
+            function: todo_write,
+        },
+        ToolDefinition {
+            name: "doc_search".to_string(),
+            description: "Look up documentation for a function, type, or package from docs installed on this machine. Use language 'rust' (rustup std docs, item like 'Vec' or 'Vec::push'), 'python' (pydoc, item like 'os.path.join'), or 'node' (package READMEs in node_modules, item is the package name). Works offline; prefer this over guessing signatures or fetching the web.".to_string(),
+            input_schema: generate_schema::<DocSearchInput>(),
+            function: doc_search,
+        },
+        ToolDefinition {
+            name: "code_search".to_string(),
+            description: r#"Search for code patterns using ripgrep (rg). Use this to find code patterns, function definitions, variable usage, or any text in the codebase. You can filter by file type (e.g., 'rs', 'js', 'py')."#.to_string(),
+            input_schema: generate_schema::<CodeSearchInput>(),
+            function: code_search,
+        },
+        ToolDefinition {
+            name: "cargo_check".to_string(),
+            description: "Type-check the Rust workspace with 'cargo check'. Returns error/warning counts and the first few diagnostics, with a pointer to the full log. Pass 'package' to check a single workspace member. Prefer this over running cargo through bash.".to_string(),
+            input_schema: generate_schema::<CargoCheckInput>(),
+            function: cargo_check,
+        },
+        ToolDefinition {
+            name: "cargo_test".to_string(),
+            description: "Run the Rust workspace tests with 'cargo test'. Returns pass/fail totals and failing test names, with a pointer to the full log. Pass 'filter' to run a subset of tests by name and 'package' to test a single workspace member. Prefer this over running cargo through bash.".to_string(),
+            input_schema: generate_schema::<CargoTestInput>(),
+            function: cargo_test,
+        },
+        ToolDefinition {
+            name: "cargo_clippy".to_string(),
+            description: "Lint the Rust workspace with 'cargo clippy'. Returns error/warning counts and the first few diagnostics, with a pointer to the full log. Pass 'package' to lint a single workspace member. Prefer this over running cargo through bash.".to_string(),
+            input_schema: generate_schema::<CargoClippyInput>(),
+            function: cargo_clippy,
+        },
+        ToolDefinition {
+            name: "cargo_fmt".to_string(),
+            description: "Format the Rust workspace with 'cargo fmt'. Run this after editing Rust files instead of formatting by hand.".to_string(),
+            input_schema: generate_schema::<CargoFmtInput>(),
+            function: cargo_fmt,
+        },
+        ToolDefinition {
+            name: "git_interactive_rebase".to_string(),
+            description: "Rewrite the commits after 'base' like an interactive rebase, without an editor. List the new commit sequence oldest first with an action per commit: 'pick' re-applies it, 'reword' changes its message, 'squash'/'fixup' fold it into the previous commit (with/without combining messages), 'drop' removes it. Use this to clean up history before a PR. The working tree must be clean; on conflict nothing is changed.".to_string(),
+            input_schema: generate_schema::<GitInteractiveRebaseInput>(),
+            function: git_interactive_rebase,
+        },
+        ToolDefinition {
+            name: "workspace_info".to_string(),
+            description: "Summarize the project structure at a glance: detected language stack, declared toolchain version, build/test/lint commands, entry points, and Cargo workspace members. Use this before exploring an unfamiliar project instead of listing files manually.".to_string(),
+            input_schema: generate_schema::<WorkspaceInfoInput>(),
+            function: workspace_info,
+        },
+    ]
+}
+
+/// Convert tool definitions to the format expected by the Claude API.
+pub fn tool_definitions_to_api(definitions: &[ToolDefinition]) -> Vec<Tool> {
+    definitions
+        .iter()
+        .map(|def| Tool {
             name: def.name.clone(),
             description: def.description.clone(),
             input_schema: def.input_schema.clone(),
@@ -494,11 +1812,35 @@ pub fn execute_tool_with_permissions(
                 )),
             }
         }
+        "bash" => {
+            // A working_directory is a read of that location; the command
+            // itself stays a conscious decision and is not prompted for
+            if let Some(dir) = input.get("working_directory").and_then(|v| v.as_str()) {
+                let dir = dir.to_string();
+                match checker.check(Path::new(&dir), OperationType::Read) {
+                    PermissionDecision::Allowed => execute_tool(definitions, name, input),
+                    PermissionDecision::Denied => {
+                        Err(format!("Permission denied: Cannot run commands in {}", dir))
+                    }
+                    PermissionDecision::NeedsPrompt => Err(format!(
+                        "ErrorCategory::Permission|Running commands in {} requires confirmation",
+                        dir
+                    )),
+                }
+            } else {
+                execute_tool(definitions, name, input)
+            }
+        }
         // Other tools don't require permission checks
         // - read_file: reads are always allowed per spec
         // - list_files: only lists, doesn't modify
-        // - bash: executing commands is a conscious decision
         // - code_search: only searches, doesn't modify
+        // - progress_file: only appends to the agent's own journal
+        // - spawn_task: handled by the REPL before dispatch; the stub only errors
+        // - update_plan: handled by the REPL before dispatch; the stub only errors
+        // - todo_write: handled by the REPL before dispatch; the stub only errors
+        // - git_interactive_rebase: rewrites committed history only and
+        //   refuses to run on a dirty working tree
         _ => execute_tool(definitions, name, input),
     }
 }
@@ -513,7 +1855,7 @@ mod tests {
     #[test]
     fn test_tool_definitions_basic() {
         let definitions = create_tool_definitions();
-        assert_eq!(definitions.len(), 6);
+        assert_eq!(definitions.len(), 17);
 
         let names: Vec<&str> = definitions.iter().map(|d| d.name.as_str()).collect();
         assert!(names.contains(&"read_file"));
@@ -521,7 +1863,18 @@ mod tests {
         assert!(names.contains(&"edit_file"));
         assert!(names.contains(&"list_files"));
         assert!(names.contains(&"bash"));
+        assert!(names.contains(&"progress_file"));
+        assert!(names.contains(&"spawn_task"));
+        assert!(names.contains(&"update_plan"));
+        assert!(names.contains(&"todo_write"));
+        assert!(names.contains(&"doc_search"));
         assert!(names.contains(&"code_search"));
+        assert!(names.contains(&"cargo_check"));
+        assert!(names.contains(&"cargo_test"));
+        assert!(names.contains(&"cargo_clippy"));
+        assert!(names.contains(&"cargo_fmt"));
+        assert!(names.contains(&"git_interactive_rebase"));
+        assert!(names.contains(&"workspace_info"));
     }
 
     #[test]
@@ -544,25 +1897,745 @@ mod tests {
 
         let input = json!({ "path": file_path.to_str().unwrap() });
         let result = read_file(input);
-        
+
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "Hello, World!");
     }
 
     #[test]
-    fn test_write_file_basic_functionality() {
-        let dir = tempdir().unwrap();
-        let file_path = dir.path().join("output.txt");
+    fn test_bash_async_variant_runs_command() {
+        use super::super::ToolExecutor;
 
-        let input = json!({
-            "path": file_path.to_str().unwrap(),
-            "content": "Test content"
-        });
-        let result = write_file(input);
-        
-        assert!(result.is_ok());
-        let content = fs::read_to_string(&file_path).unwrap();
-        assert_eq!(content, "Test content");
+        let mut executor = ToolExecutor::with_defaults();
+        executor.register_async_tool("bash", std::sync::Arc::new(bash_async));
+
+        let result = executor.execute("call_1", "bash", json!({"command": "echo from-async"}));
+
+        assert!(result.result.unwrap().contains("from-async"));
+    }
+
+    #[test]
+    fn test_simulate_bash_reports_command() {
+        let result = simulate_tool_call("bash", &json!({"command": "rm -rf build"}));
+
+        assert_eq!(result.unwrap(), "[DRY RUN] would run: rm -rf build");
+    }
+
+    #[test]
+    fn test_simulate_read_only_tools_not_simulated() {
+        assert!(simulate_tool_call("read_file", &json!({"path": "x"})).is_none());
+        assert!(simulate_tool_call("list_files", &json!({})).is_none());
+        assert!(simulate_tool_call("code_search", &json!({})).is_none());
+    }
+
+    #[test]
+    fn test_simulate_write_file_new_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("new.txt");
+
+        let result = simulate_tool_call(
+            "write_file",
+            &json!({"path": path.to_str().unwrap(), "content": "a\nb\n"}),
+        )
+        .unwrap();
+
+        assert!(result.starts_with("[DRY RUN] would create"), "{}", result);
+        assert!(result.contains("2 lines"), "{}", result);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_simulate_write_file_overwrite_shows_diff() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "keep\nold line\nkeep too\n").unwrap();
+
+        let result = simulate_tool_call(
+            "write_file",
+            &json!({"path": path.to_str().unwrap(), "content": "keep\nnew line\nkeep too\n"}),
+        )
+        .unwrap();
+
+        assert!(result.contains("would overwrite"), "{}", result);
+        assert!(result.contains("- old line"), "{}", result);
+        assert!(result.contains("+ new line"), "{}", result);
+        // Unchanged lines are trimmed out of the diff
+        assert!(!result.contains("- keep"), "{}", result);
+        // The file itself is untouched
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "keep\nold line\nkeep too\n"
+        );
+    }
+
+    #[test]
+    fn test_simulate_edit_file_shows_replacement() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "fn main() {\n    old();\n}\n").unwrap();
+
+        let result = simulate_tool_call(
+            "edit_file",
+            &json!({
+                "path": path.to_str().unwrap(),
+                "old_str": "    old();",
+                "new_str": "    new();"
+            }),
+        )
+        .unwrap();
+
+        assert!(result.contains("would edit"), "{}", result);
+        assert!(result.contains("-     old();"), "{}", result);
+        assert!(result.contains("+     new();"), "{}", result);
+    }
+
+    #[test]
+    fn test_simulate_edit_file_reports_missing_match() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("file.txt");
+        fs::write(&path, "nothing to match\n").unwrap();
+
+        let result = simulate_tool_call(
+            "edit_file",
+            &json!({
+                "path": path.to_str().unwrap(),
+                "old_str": "absent",
+                "new_str": "replacement"
+            }),
+        )
+        .unwrap();
+
+        assert!(
+            result.contains("would fail: old_str not found"),
+            "{}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_read_file_binary_descriptor() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("logo.png");
+        let mut bytes = b"\x89PNG\r\n\x1a\n".to_vec();
+        bytes.extend_from_slice(&[0u8; 100]);
+        fs::write(&file_path, bytes).unwrap();
+
+        let input = json!({ "path": file_path.to_str().unwrap() });
+        let result = read_file(input).unwrap();
+
+        assert!(result.contains("Binary file (PNG image)"), "{}", result);
+        assert!(result.contains("contents not shown"), "{}", result);
+    }
+
+    #[test]
+    fn test_read_file_unknown_binary_descriptor() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("blob.bin");
+        fs::write(&file_path, [0u8, 1, 2, 3, 0, 5]).unwrap();
+
+        let input = json!({ "path": file_path.to_str().unwrap() });
+        let result = read_file(input).unwrap();
+
+        assert!(result.contains("Binary file (binary data)"), "{}", result);
+    }
+
+    #[test]
+    fn test_read_file_oversized_suggests_offset() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("big.txt");
+        fs::write(&file_path, "line one\nline two\nline three\n").unwrap();
+
+        let input = json!({ "path": file_path.to_str().unwrap() });
+        let error = read_file_with_max(input, 16).unwrap_err();
+
+        assert!(error.contains("exceeds the 16 bytes limit"), "{}", error);
+        assert!(error.contains("offset and limit"), "{}", error);
+    }
+
+    #[test]
+    fn test_read_file_offset_and_limit_slice() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("big.txt");
+        fs::write(&file_path, "one\ntwo\nthree\nfour\n").unwrap();
+
+        let input = json!({
+            "path": file_path.to_str().unwrap(),
+            "offset": 2,
+            "limit": 2
+        });
+        let result = read_file_with_max(input, 16).unwrap();
+
+        assert!(result.starts_with("two\nthree"), "{}", result);
+        assert!(result.contains("[Lines 2-3]"), "{}", result);
+    }
+
+    #[test]
+    fn test_read_file_offset_past_end() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("short.txt");
+        fs::write(&file_path, "only line\n").unwrap();
+
+        let input = json!({ "path": file_path.to_str().unwrap(), "offset": 10 });
+        let error = read_file(input).unwrap_err();
+
+        assert!(error.contains("past the end"), "{}", error);
+    }
+
+    #[test]
+    fn test_write_file_oversized_content_rejected() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("big.txt");
+
+        let input = json!({
+            "path": file_path.to_str().unwrap(),
+            "content": "x".repeat(64)
+        });
+        let error = write_file_with_max(input, 16).unwrap_err();
+
+        assert!(error.contains("exceeds the 16 bytes limit"), "{}", error);
+        assert!(!file_path.exists());
+    }
+
+    #[test]
+    fn test_sniff_file_type_magic_bytes() {
+        assert_eq!(sniff_file_type(b"\x89PNG\r\n\x1a\n"), "PNG image");
+        assert_eq!(sniff_file_type(b"%PDF-1.7"), "PDF document");
+        assert_eq!(sniff_file_type(b"\x7FELF\x02"), "ELF executable");
+        assert_eq!(sniff_file_type(b"\x00\x01\x02"), "binary data");
+    }
+
+    #[test]
+    fn test_format_size_units() {
+        assert_eq!(format_size(512), "512 bytes");
+        assert_eq!(format_size(48 * 1024), "48.0 KB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MB");
+    }
+
+    #[test]
+    fn test_is_binary_detection() {
+        assert!(is_binary(b"text with a null\0byte"));
+        assert!(is_binary(&[0xFF; 100]));
+        assert!(!is_binary(b"plain old text\n"));
+        assert!(!is_binary("café ☕".as_bytes()));
+    }
+
+    #[test]
+    fn test_write_file_basic_functionality() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("output.txt");
+
+        let input = json!({
+            "path": file_path.to_str().unwrap(),
+            "content": "Test content"
+        });
+        let result = write_file(input);
+
+        assert!(result.is_ok());
+        let content = fs::read_to_string(&file_path).unwrap();
+        assert_eq!(content, "Test content");
+    }
+
+    #[test]
+    fn test_write_file_blocks_hardcoded_secret() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("config.py");
+
+        let input = json!({
+            "path": file_path.to_str().unwrap(),
+            "content": "password = \"hunter2swordfish\"\n"
+        });
+        let error = write_file(input).unwrap_err();
+
+        assert!(error.contains("Write blocked"), "{}", error);
+        assert!(!file_path.exists());
+    }
+
+    #[test]
+    fn test_write_file_shebang_warning_rides_along() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("main.rs");
+
+        let input = json!({
+            "path": file_path.to_str().unwrap(),
+            "content": "#!/bin/bash\nfn main() {}\n"
+        });
+        let result = write_file(input).unwrap();
+
+        // Warning severity doesn't block the write
+        assert!(result.contains("Successfully wrote"));
+        assert!(result.contains("Warning:"), "{}", result);
+        assert!(file_path.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_file_preserves_permission_bits() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("script.sh");
+        fs::write(&file_path, "old").unwrap();
+        fs::set_permissions(&file_path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let input = json!({
+            "path": file_path.to_str().unwrap(),
+            "content": "new"
+        });
+        write_file(input).unwrap();
+
+        // The rewrite must not reset the executable bit to the umask default
+        let mode = fs::metadata(&file_path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755);
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), "new");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_file_through_trusted_symlink_keeps_link() {
+        let dir = tempdir().unwrap();
+        let target = dir.path().join("target.txt");
+        let link = dir.path().join("link.txt");
+        fs::write(&target, "original").unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        // The temp dir is outside the project root, so it has to be trusted
+        set_trusted_bash_dirs(vec![dir.path().to_string_lossy().to_string()]);
+        let input = json!({
+            "path": link.to_str().unwrap(),
+            "content": "updated"
+        });
+        let result = write_file(input);
+        set_trusted_bash_dirs(Vec::new());
+
+        // The write lands on the destination and the link survives
+        result.unwrap();
+        assert_eq!(fs::read_to_string(&target).unwrap(), "updated");
+        assert!(fs::symlink_metadata(&link)
+            .unwrap()
+            .file_type()
+            .is_symlink());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_file_refuses_symlink_escaping_project_root() {
+        let dir = tempdir().unwrap();
+        let target = dir.path().join("target.txt");
+        let link = dir.path().join("link.txt");
+        fs::write(&target, "original").unwrap();
+        std::os::unix::fs::symlink(&target, &link).unwrap();
+
+        let input = json!({
+            "path": link.to_str().unwrap(),
+            "content": "updated"
+        });
+        let error = write_file(input).unwrap_err();
+
+        assert!(error.contains("symlink"), "{}", error);
+        assert_eq!(fs::read_to_string(&target).unwrap(), "original");
+    }
+
+    #[test]
+    fn test_write_file_leaves_no_temp_file() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("output.txt");
+
+        let input = json!({
+            "path": file_path.to_str().unwrap(),
+            "content": "content"
+        });
+        write_file(input).unwrap();
+
+        // Only the target remains after the rename
+        let entries: Vec<_> = fs::read_dir(dir.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(entries, vec![std::ffi::OsString::from("output.txt")]);
+    }
+
+    /// Build a tree with nested ignore files:
+    /// .gitignore ignores dist/, sub/.agentignore ignores data.bin.
+    fn ignore_fixture() -> tempfile::TempDir {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join(".gitignore"), "dist/\n").unwrap();
+        fs::create_dir(dir.path().join("dist")).unwrap();
+        fs::write(dir.path().join("dist/bundle.js"), "bundled").unwrap();
+        fs::create_dir(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+        fs::create_dir(dir.path().join("sub")).unwrap();
+        fs::write(dir.path().join("sub/.agentignore"), "data.bin\n").unwrap();
+        fs::write(dir.path().join("sub/data.bin"), "blob").unwrap();
+        fs::write(dir.path().join("sub/notes.txt"), "notes").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_list_files_honors_nested_ignore_files() {
+        let dir = ignore_fixture();
+
+        let input = json!({ "path": dir.path().to_str().unwrap() });
+        let result = list_files(input).unwrap();
+
+        // .gitignore excludes dist/, .agentignore excludes sub/data.bin
+        assert!(!result.contains("bundle.js"));
+        assert!(!result.contains("data.bin"));
+        assert!(result.contains("src/main.rs"));
+        assert!(result.contains("sub/notes.txt"));
+    }
+
+    #[test]
+    fn test_list_files_respect_gitignore_escape_hatch() {
+        let dir = ignore_fixture();
+        let input = json!({ "path": dir.path().to_str().unwrap() });
+
+        set_respect_gitignore(false);
+        let result = list_files(input);
+        set_respect_gitignore(true);
+
+        // With tools.respect_gitignore = false, gitignored files are listed
+        assert!(result.unwrap().contains("bundle.js"));
+    }
+
+    #[test]
+    fn test_code_search_honors_agentignore() {
+        let dir = ignore_fixture();
+        fs::write(dir.path().join(".agentignore"), "notes.txt\n").unwrap();
+
+        let input = json!({
+            "pattern": "notes",
+            "path": dir.path().to_str().unwrap()
+        });
+        let result = code_search(input).unwrap();
+
+        assert_eq!(result, "No matches found");
+    }
+
+    fn builtin_input(pattern: &str, path: &Path) -> CodeSearchInput {
+        CodeSearchInput {
+            pattern: pattern.to_string(),
+            path: Some(path.display().to_string()),
+            file_type: None,
+            case_sensitive: false,
+            context_lines: None,
+        }
+    }
+
+    #[test]
+    fn test_builtin_search_output_format() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("greet.rs"),
+            "fn main() {\n    hello();\n}\n",
+        )
+        .unwrap();
+
+        let result = code_search_builtin(builtin_input("hello", dir.path())).unwrap();
+
+        // Same file:line:match shape as the ripgrep backend
+        assert_eq!(
+            result,
+            format!("{}:2:    hello();", dir.path().join("greet.rs").display())
+        );
+    }
+
+    #[test]
+    fn test_builtin_search_case_insensitive_by_default() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("note.txt"), "HELLO world\n").unwrap();
+
+        let insensitive = code_search_builtin(builtin_input("hello", dir.path())).unwrap();
+        assert!(insensitive.contains("HELLO world"));
+
+        let mut sensitive = builtin_input("hello", dir.path());
+        sensitive.case_sensitive = true;
+        let result = code_search_builtin(sensitive).unwrap();
+        assert_eq!(result, "No matches found");
+    }
+
+    #[test]
+    fn test_builtin_search_file_type_filter() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("lib.rs"), "target_word\n").unwrap();
+        fs::write(dir.path().join("notes.md"), "target_word\n").unwrap();
+
+        let mut input = builtin_input("target_word", dir.path());
+        input.file_type = Some("rust".to_string());
+        let result = code_search_builtin(input).unwrap();
+
+        assert!(result.contains("lib.rs"));
+        assert!(!result.contains("notes.md"));
+    }
+
+    #[test]
+    fn test_builtin_search_truncates_at_50_matches() {
+        let dir = tempdir().unwrap();
+        let content = "needle\n".repeat(60);
+        fs::write(dir.path().join("haystack.txt"), content).unwrap();
+
+        let result = code_search_builtin(builtin_input("needle", dir.path())).unwrap();
+
+        assert!(result.contains("... (showing first 50 of 60 matches)"));
+    }
+
+    #[test]
+    fn test_builtin_search_context_lines() {
+        // Arrange
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("app.rs"),
+            "fn setup() {}\n\nfn main() {\n    needle();\n}\n\nfn teardown() {}\n",
+        )
+        .unwrap();
+        let mut input = builtin_input("needle", dir.path());
+        input.context_lines = Some(1);
+
+        // Act
+        let result = code_search_builtin(input).unwrap();
+
+        // Assert: ripgrep shape — colons on the match, dashes on context
+        let path = dir.path().join("app.rs").display().to_string();
+        assert!(result.contains(&format!("{}-3-fn main() {{", path)));
+        assert!(result.contains(&format!("{}:4:    needle();", path)));
+        assert!(result.contains(&format!("{}-5-}}", path)));
+        assert!(!result.contains("teardown"));
+    }
+
+    #[test]
+    fn test_builtin_search_context_separates_chunks() {
+        // Arrange: two matches far enough apart for distinct chunks
+        let dir = tempdir().unwrap();
+        let mut content = String::from("needle one\n");
+        content.push_str(&"filler\n".repeat(10));
+        content.push_str("needle two\n");
+        fs::write(dir.path().join("far.txt"), content).unwrap();
+        let mut input = builtin_input("needle", dir.path());
+        input.context_lines = Some(1);
+
+        // Act
+        let result = code_search_builtin(input).unwrap();
+
+        // Assert
+        assert!(result.contains("\n--\n"));
+        assert!(result.contains("needle one"));
+        assert!(result.contains("needle two"));
+    }
+
+    #[test]
+    fn test_context_chunks_merges_overlapping_ranges() {
+        // Matches on lines 2 and 4 with one line of context overlap
+        assert_eq!(context_chunks(&[2, 4], 1, 10), vec![(1, 5)]);
+        // Far apart matches stay separate and clamp to file bounds
+        assert_eq!(context_chunks(&[0, 8], 2, 9), vec![(0, 2), (6, 8)]);
+    }
+
+    #[test]
+    fn test_builtin_search_context_caps_at_100_lines() {
+        // Arrange: every line matches, so context output is line-limited
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("haystack.txt"), "needle\n".repeat(120)).unwrap();
+        let mut input = builtin_input("needle", dir.path());
+        input.context_lines = Some(2);
+
+        // Act
+        let result = code_search_builtin(input).unwrap();
+
+        // Assert: capped by total lines, not by match count
+        assert!(result.contains("... (showing first 100 of 120 lines)"));
+        assert_eq!(result.lines().count(), 101);
+    }
+
+    #[test]
+    fn test_builtin_search_honors_agentignore() {
+        let dir = ignore_fixture();
+        fs::write(dir.path().join(".agentignore"), "notes.txt\n").unwrap();
+
+        let result = code_search_builtin(builtin_input("notes", dir.path())).unwrap();
+
+        assert_eq!(result, "No matches found");
+    }
+
+    #[test]
+    fn test_code_search_backend_reports_a_backend() {
+        let backend = code_search_backend();
+        assert!(backend == "ripgrep" || backend == "built-in");
+    }
+
+    #[test]
+    fn test_bash_completes_within_timeout() {
+        let input = json!({ "command": "echo done", "timeout_secs": 10 });
+
+        let result = bash(input);
+
+        assert_eq!(result, Ok("done".to_string()));
+    }
+
+    #[test]
+    fn test_bash_timeout_kills_command_and_reports_partial_output() {
+        let input = json!({ "command": "echo started; sleep 30", "timeout_secs": 1 });
+
+        let result = bash(input);
+
+        let error = result.unwrap_err();
+        assert!(error.contains("Command timed out after 1s"));
+        // Output produced before the timeout is reported
+        assert!(error.contains("started"));
+    }
+
+    #[test]
+    fn test_bash_timeout_kills_descendants_holding_pipes() {
+        // Arrange: both sleeps inherit the output pipes and outlive bash;
+        // if only bash itself were killed, draining stdout would block
+        // until the orphaned sleeps exited on their own
+        let input = json!({ "command": "sleep 30 & sleep 30", "timeout_secs": 1 });
+
+        let start = Instant::now();
+        let result = bash(input);
+
+        let error = result.unwrap_err();
+        assert!(error.contains("Command timed out after 1s"));
+        // Well under the 30s the orphans would otherwise hold the pipes
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_bash_working_directory_runs_command_there() {
+        let input = json!({ "command": "pwd", "working_directory": "src" });
+
+        let result = bash(input).expect("Command should run");
+
+        assert!(result.ends_with("/src"), "got: {}", result);
+    }
+
+    #[test]
+    fn test_bash_working_directory_must_exist() {
+        let input = json!({ "command": "pwd", "working_directory": "no/such/dir" });
+
+        let result = bash(input);
+
+        assert!(result.unwrap_err().contains("does not exist"));
+    }
+
+    #[test]
+    fn test_bash_working_directory_must_be_a_directory() {
+        let input = json!({ "command": "pwd", "working_directory": "Cargo.toml" });
+
+        let result = bash(input);
+
+        assert!(result.unwrap_err().contains("is not a directory"));
+    }
+
+    #[test]
+    fn test_bash_working_directory_outside_root_rejected() {
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        let input = json!({
+            "command": "pwd",
+            "working_directory": temp_dir.path().to_str().unwrap()
+        });
+
+        let result = bash(input);
+
+        assert!(result.unwrap_err().contains("outside the project root"));
+    }
+
+    #[test]
+    fn test_bash_working_directory_trusted_path_allowed() {
+        let temp_dir = tempdir().expect("Failed to create temp dir");
+        set_trusted_bash_dirs(vec![temp_dir.path().to_string_lossy().to_string()]);
+
+        let input = json!({
+            "command": "pwd",
+            "working_directory": temp_dir.path().to_str().unwrap()
+        });
+        let result = bash(input);
+
+        set_trusted_bash_dirs(Vec::new());
+        assert!(result.is_ok(), "got: {:?}", result);
+    }
+
+    #[test]
+    fn test_bash_permission_check_on_working_directory() {
+        use crate::permissions::TrustedPaths;
+
+        let definitions = create_tool_definitions();
+        // auto_read off and no trusted paths: the directory needs a prompt
+        let trusted = TrustedPaths::new(&[]).unwrap();
+        let checker = PermissionChecker::new(trusted, false);
+
+        let input = json!({ "command": "pwd", "working_directory": "src" });
+        let result = execute_tool_with_permissions(&definitions, "bash", input, Some(&checker));
+
+        let error = result.unwrap_err();
+        assert!(error.contains("ErrorCategory::Permission"));
+        assert!(error.contains("src"));
+    }
+
+    #[test]
+    fn test_spawn_task_stub_requires_interactive_session() {
+        let input = json!({ "description": "migrate error handling" });
+
+        let result = spawn_task(input);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("interactive session"));
+    }
+
+    #[test]
+    fn test_spawn_task_stub_rejects_malformed_input() {
+        let input = json!({ "tools": ["bash"] });
+
+        let result = spawn_task(input);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("Failed to parse input"));
+    }
+
+    #[test]
+    fn test_update_plan_stub_requires_interactive_session() {
+        let input = json!({ "step": 2, "done": true });
+
+        let result = update_plan(input);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("interactive session"));
+    }
+
+    #[test]
+    fn test_todo_write_stub_constrains_statuses() {
+        // A valid status parses but still defers to the REPL
+        let input = json!({ "todos": [{ "id": "a", "content": "Task", "status": "in_progress" }] });
+        let result = todo_write(input);
+        assert!(result.unwrap_err().contains("interactive session"));
+
+        // An unknown status is rejected by the schema-backed input type
+        let input = json!({ "todos": [{ "id": "a", "content": "Task", "status": "paused" }] });
+        let result = todo_write(input);
+        assert!(result.unwrap_err().contains("Failed to parse input"));
+    }
+
+    #[test]
+    fn test_progress_file_rejects_unknown_action() {
+        let input = json!({
+            "action": "pause",
+            "task_id": "task-1",
+            "description": "A task"
+        });
+        let result = progress_file(input);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("action must be one of"));
+    }
+
+    #[test]
+    fn test_progress_file_rejects_empty_task_id() {
+        let input = json!({
+            "action": "start",
+            "task_id": "",
+            "description": "A task"
+        });
+        let result = progress_file(input);
+
+        assert_eq!(result, Err("task_id cannot be empty".to_string()));
     }
 
     #[test]
@@ -575,4 +2648,4 @@ mod tests {
         let result = read_file(json!({ "invalid": "input" }));
         assert!(result.is_err());
     }
-}
\ No newline at end of file
+}
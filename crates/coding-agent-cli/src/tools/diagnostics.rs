@@ -84,6 +84,13 @@ impl Diagnostic {
     pub fn column(&self) -> Option<u32> {
         self.location.as_ref().and_then(|l| l.column)
     }
+
+    /// Get the first suggested shell command that would fix this diagnostic.
+    pub fn suggested_command(&self) -> Option<&str> {
+        self.suggestions
+            .iter()
+            .find_map(|s| s.suggested_command.as_deref())
+    }
 }
 
 /// Severity level of a diagnostic.
@@ -199,6 +206,9 @@ pub struct DiagnosticSuggestion {
 
     /// Whether this suggestion can be automatically applied.
     pub is_applicable: bool,
+
+    /// A shell command that would apply the fix (e.g., `cargo add serde`).
+    pub suggested_command: Option<String>,
 }
 
 impl DiagnosticSuggestion {
@@ -209,6 +219,7 @@ impl DiagnosticSuggestion {
             location: None,
             replacement: None,
             is_applicable: false,
+            suggested_command: None,
         }
     }
 
@@ -223,6 +234,18 @@ impl DiagnosticSuggestion {
             location: Some(location),
             replacement: Some(replacement.into()),
             is_applicable: true,
+            suggested_command: None,
+        }
+    }
+
+    /// Create a suggestion backed by a shell command that applies the fix.
+    pub fn with_command(message: impl Into<String>, command: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            location: None,
+            replacement: None,
+            is_applicable: true,
+            suggested_command: Some(command.into()),
         }
     }
 }
@@ -342,21 +365,112 @@ impl std::fmt::Display for CompilerType {
 /// }
 /// ```
 pub fn parse_compiler_output(output: &str) -> DiagnosticReport {
-    // Try to detect the compiler type
-    let compiler = detect_compiler(output);
+    parse_compiler_output_with_stack(output, None)
+}
+
+/// Parse compiler output with a project-stack hint.
+///
+/// Detection from the output itself still wins; the hint labels output
+/// that matches no known compiler with the stack's toolchain, and steers
+/// ambiguous fix suggestions — a missing module maps to `go get` in a Go
+/// repo rather than `npm install`.
+pub fn parse_compiler_output_with_stack(
+    output: &str,
+    stack: Option<crate::project::ProjectType>,
+) -> DiagnosticReport {
+    // Try to detect the compiler type from the output's shape
+    let detected = detect_compiler(output);
+    let compiler = match detected {
+        CompilerType::Unknown => stack
+            .map(CompilerType::from)
+            .unwrap_or(CompilerType::Unknown),
+        detected => detected,
+    };
 
     let mut report = DiagnosticReport::new(compiler);
 
-    match compiler {
+    match detected {
         CompilerType::Rust => parse_rust_output(output, &mut report),
         CompilerType::TypeScript => parse_typescript_output(output, &mut report),
         CompilerType::Go => parse_go_output(output, &mut report),
         CompilerType::Unknown => parse_generic_output(output, &mut report),
     }
 
+    // Attach runnable fix commands for known error patterns
+    for diagnostic in &mut report.diagnostics {
+        if diagnostic.suggested_command().is_none() {
+            if let Some(command) = suggest_fix_command(diagnostic, stack) {
+                diagnostic
+                    .suggestions
+                    .push(DiagnosticSuggestion::with_command(
+                        format!("run `{}`", command),
+                        command,
+                    ));
+            }
+        }
+    }
+
     report
 }
 
+impl From<crate::project::ProjectType> for CompilerType {
+    fn from(stack: crate::project::ProjectType) -> Self {
+        match stack {
+            crate::project::ProjectType::Rust => CompilerType::Rust,
+            crate::project::ProjectType::TypeScript => CompilerType::TypeScript,
+            crate::project::ProjectType::Go => CompilerType::Go,
+            // Python tracebacks have no dedicated parser yet
+            crate::project::ProjectType::Python => CompilerType::Unknown,
+        }
+    }
+}
+
+/// Build a shell command that would fix a diagnostic, for known error patterns.
+///
+/// Covers missing dependencies across ecosystems: a missing Rust crate maps to
+/// `cargo add`, a missing Python package to `pip install`, and a missing npm
+/// package to `npm install`.
+fn suggest_fix_command(
+    diagnostic: &Diagnostic,
+    stack: Option<crate::project::ProjectType>,
+) -> Option<String> {
+    let code = diagnostic.code.as_deref().unwrap_or("");
+    let message = &diagnostic.message;
+    let lower_message = message.to_lowercase();
+
+    // Missing Rust crate: error[E0463]: can't find crate for `serde`
+    if code == "E0463"
+        || lower_message.contains("can't find crate")
+        || lower_message.contains("cannot find crate")
+    {
+        let crate_name = extract_quoted_name(message)?;
+        return Some(format!("cargo add {}", crate_name));
+    }
+
+    // Missing Python package: ModuleNotFoundError: No module named 'requests'
+    if lower_message.contains("no module named") {
+        let package = extract_quoted_name(message)?;
+        return Some(format!("pip install {}", package));
+    }
+
+    // Missing npm package: error TS2307: Cannot find module 'lodash'
+    if code == "TS2307" || lower_message.contains("cannot find module") {
+        let module = extract_quoted_name(message)?;
+        // Relative paths are project files, not installable packages
+        if module.starts_with('.') || module.starts_with('/') {
+            return None;
+        }
+        // The go toolchain reports missing modules with the same words;
+        // without a TS error code, let the project stack decide
+        if code != "TS2307" && stack == Some(crate::project::ProjectType::Go) {
+            return Some(format!("go get {}", module));
+        }
+        return Some(format!("npm install {}", module));
+    }
+
+    None
+}
+
 /// Detect which compiler generated the output.
 fn detect_compiler(output: &str) -> CompilerType {
     // Rust: "error[E0xxx]:" or "warning[E0xxx]:" or "error: " with rustc patterns
@@ -500,8 +614,8 @@ fn parse_rust_diagnostic_line(line: &str) -> Option<Diagnostic> {
     }
 
     // "error: ..." without a code
-    if line.starts_with("error:") {
-        let message = line[6..].trim();
+    if let Some(rest) = line.strip_prefix("error:") {
+        let message = rest.trim();
         if !message.is_empty() {
             return Some(Diagnostic {
                 severity: DiagnosticSeverity::Error,
@@ -517,8 +631,8 @@ fn parse_rust_diagnostic_line(line: &str) -> Option<Diagnostic> {
     }
 
     // "warning: ..." without a code
-    if line.starts_with("warning:") {
-        let message = line[8..].trim();
+    if let Some(rest) = line.strip_prefix("warning:") {
+        let message = rest.trim();
         if !message.is_empty() {
             return Some(Diagnostic {
                 severity: DiagnosticSeverity::Warning,
@@ -750,7 +864,7 @@ pub fn extract_fix_info(diagnostic: &Diagnostic) -> Option<FixInfo> {
                     fix_type: FixType::AddDependency,
                     target_file: Some("Cargo.toml".to_string()),
                     target_item: Some(crate_name),
-                    suggested_change: format!("Add dependency to Cargo.toml"),
+                    suggested_change: "Add dependency to Cargo.toml".to_string(),
                 });
             }
         }
@@ -762,7 +876,7 @@ pub fn extract_fix_info(diagnostic: &Diagnostic) -> Option<FixInfo> {
                     fix_type: FixType::AddImport,
                     target_file: diagnostic.file_path().map(String::from),
                     target_item: Some(type_name),
-                    suggested_change: format!("Add import for type"),
+                    suggested_change: "Add import for type".to_string(),
                 });
             }
         }
@@ -834,6 +948,7 @@ pub struct FixInfo {
 
 /// Types of fixes that can be applied.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
 pub enum FixType {
     /// Add a missing import statement.
     AddImport,
@@ -1147,6 +1262,66 @@ src/b.ts(2,2): error TS2304: Cannot find name 'b'."#;
         assert_eq!(fix_info.fix_type, FixType::AddDependency);
     }
 
+    // ============== Suggested Command Tests ==============
+
+    #[test]
+    fn test_suggested_command_missing_rust_crate() {
+        let output = "error[E0463]: can't find crate for `serde`\n --> src/main.rs:1:1";
+        let report = parse_compiler_output(output);
+
+        let diag = &report.diagnostics[0];
+        assert_eq!(diag.suggested_command(), Some("cargo add serde"));
+    }
+
+    #[test]
+    fn test_suggested_command_missing_python_package() {
+        let output = "ModuleNotFoundError: No module named 'requests'";
+        let report = parse_compiler_output(output);
+
+        let diag = &report.diagnostics[0];
+        assert_eq!(diag.suggested_command(), Some("pip install requests"));
+    }
+
+    #[test]
+    fn test_suggested_command_missing_npm_package() {
+        let output = "src/index.ts(1,1): error TS2307: Cannot find module 'lodash'.";
+        let report = parse_compiler_output(output);
+
+        let diag = &report.diagnostics[0];
+        assert_eq!(diag.suggested_command(), Some("npm install lodash"));
+    }
+
+    #[test]
+    fn test_suggested_command_skips_relative_module() {
+        let output = "src/index.ts(1,1): error TS2307: Cannot find module './utils'.";
+        let report = parse_compiler_output(output);
+
+        let diag = &report.diagnostics[0];
+        assert_eq!(diag.suggested_command(), None);
+    }
+
+    #[test]
+    fn test_suggested_command_none_for_type_mismatch() {
+        let output = "error[E0308]: mismatched types\n --> src/main.rs:10:5";
+        let report = parse_compiler_output(output);
+
+        let diag = &report.diagnostics[0];
+        assert_eq!(diag.suggested_command(), None);
+    }
+
+    #[test]
+    fn test_suggestion_with_command() {
+        let suggestion =
+            DiagnosticSuggestion::with_command("run `cargo add serde`", "cargo add serde");
+
+        assert!(suggestion.is_applicable);
+        assert_eq!(
+            suggestion.suggested_command,
+            Some("cargo add serde".to_string())
+        );
+        assert!(suggestion.replacement.is_none());
+    }
+
     // ============== Helper Function Tests ==============
 
     #[test]
@@ -0,0 +1,353 @@
+//! Local documentation lookup for the doc_search tool.
+//!
+//! Resolves function, type, and package documentation from what is already
+//! installed on disk: Rust via the rustup std HTML docs, Python via `pydoc`,
+//! and Node via package READMEs in `node_modules`. Everything works offline,
+//! so common signature lookups never need a web fetch.
+
+use scraper::{ElementRef, Html, Selector};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use walkdir::WalkDir;
+
+/// Maximum length of a returned documentation excerpt.
+const MAX_EXCERPT: usize = 4_000;
+
+/// Rustdoc page prefixes to try when resolving a bare item name.
+const RUST_ITEM_KINDS: &[&str] = &[
+    "fn",
+    "struct",
+    "enum",
+    "trait",
+    "macro",
+    "constant",
+    "type",
+    "primitive",
+];
+
+/// Look up `item` in the documentation set for `language`.
+///
+/// `custom_roots` comes from `tools.doc_paths` and overrides the default
+/// location per language (e.g. `doc_paths.rust = "/opt/rust-docs/std"`).
+pub fn search(
+    item: &str,
+    language: &str,
+    custom_roots: &HashMap<String, String>,
+) -> Result<String, String> {
+    match language {
+        "rust" => search_rust(item, custom_roots.get("rust")),
+        "python" => search_python(item),
+        "node" | "javascript" | "js" => search_node(item, custom_roots.get("node")),
+        other => Err(format!(
+            "Unsupported language '{}': expected rust, python, or node",
+            other
+        )),
+    }
+}
+
+/// Find the rustup-installed std documentation root.
+fn rust_doc_root(custom: Option<&String>) -> Option<PathBuf> {
+    if let Some(root) = custom {
+        return Some(PathBuf::from(root));
+    }
+
+    let toolchains = dirs::home_dir()?.join(".rustup/toolchains");
+    for entry in fs::read_dir(&toolchains).ok()?.flatten() {
+        let candidate = entry.path().join("share/doc/rust/html/std");
+        if candidate.is_dir() {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+fn search_rust(item: &str, custom_root: Option<&String>) -> Result<String, String> {
+    let root = rust_doc_root(custom_root).ok_or_else(|| {
+        "Rust documentation not found: run `rustup component add rust-docs` \
+         or set tools.doc_paths.rust"
+            .to_string()
+    })?;
+
+    // "Vec::push" looks up the method section on the Vec page
+    let (type_name, method) = match item.split_once("::") {
+        Some((type_name, method)) => (type_name, Some(method)),
+        None => (item, None),
+    };
+
+    let page = find_rust_item_page(&root, type_name)
+        .ok_or_else(|| format!("No documentation page found for '{}'", type_name))?;
+    let html = fs::read_to_string(&page)
+        .map_err(|e| format!("Failed to read {}: {}", page.display(), e))?;
+
+    match method {
+        Some(method) => extract_rust_method(&html, item, method),
+        None => extract_rust_item(&html, type_name),
+    }
+}
+
+/// Walk the doc root for a page named `<kind>.<name>.html`.
+fn find_rust_item_page(root: &Path, name: &str) -> Option<PathBuf> {
+    let candidates: Vec<String> = RUST_ITEM_KINDS
+        .iter()
+        .map(|kind| format!("{}.{}.html", kind, name))
+        .collect();
+
+    WalkDir::new(root)
+        .into_iter()
+        .flatten()
+        .find(|entry| {
+            entry.file_type().is_file()
+                && entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|file| candidates.iter().any(|c| c == file))
+        })
+        .map(|entry| entry.into_path())
+}
+
+/// Concatenated text content of an element.
+fn text_of(element: ElementRef) -> String {
+    element.text().collect::<String>()
+}
+
+/// Pull the declaration and first docblock from a rustdoc item page.
+fn extract_rust_item(html: &str, item: &str) -> Result<String, String> {
+    let document = Html::parse_document(html);
+    let decl = Selector::parse("pre.item-decl, pre.rust").expect("selector is valid");
+    let docblock = Selector::parse(".docblock").expect("selector is valid");
+
+    let signature = document.select(&decl).next().map(text_of).ok_or_else(|| {
+        format!(
+            "No declaration found on the documentation page for '{}'",
+            item
+        )
+    })?;
+    let docs = document
+        .select(&docblock)
+        .next()
+        .map(text_of)
+        .unwrap_or_default();
+
+    Ok(excerpt(&format!("{}\n\n{}", signature.trim(), docs.trim())))
+}
+
+/// Pull a method signature and its docblock from a rustdoc type page.
+fn extract_rust_method(html: &str, item: &str, method: &str) -> Result<String, String> {
+    let document = Html::parse_document(html);
+    let section = Selector::parse(&format!(r#"[id="method.{}"]"#, method))
+        .map_err(|_| format!("Invalid method name '{}'", method))?;
+    let header = Selector::parse(".code-header").expect("selector is valid");
+    let docblock = Selector::parse(".docblock").expect("selector is valid");
+
+    let section_el = document
+        .select(&section)
+        .next()
+        .ok_or_else(|| format!("No method '{}' found on the page for '{}'", method, item))?;
+    let signature = section_el
+        .select(&header)
+        .next()
+        .map(text_of)
+        .unwrap_or_else(|| text_of(section_el));
+
+    // The docblock lives next to the section inside the enclosing <details>
+    let mut docs = String::new();
+    for ancestor in section_el.ancestors() {
+        if let Some(el) = ElementRef::wrap(ancestor) {
+            if el.value().name() == "details" {
+                if let Some(block) = el.select(&docblock).next() {
+                    docs = text_of(block);
+                }
+                break;
+            }
+        }
+    }
+
+    Ok(excerpt(&format!("{}\n\n{}", signature.trim(), docs.trim())))
+}
+
+fn search_python(item: &str) -> Result<String, String> {
+    let output = Command::new("python3")
+        .arg("-m")
+        .arg("pydoc")
+        .arg(item)
+        .output()
+        .map_err(|e| format!("Failed to run pydoc: {}", e))?;
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    // pydoc exits 0 even for unknown names and prints a notice instead
+    if !output.status.success() || text.contains("No Python documentation found") {
+        return Err(format!("pydoc found no documentation for '{}'", item));
+    }
+
+    Ok(excerpt(text.trim()))
+}
+
+fn search_node(package: &str, custom_root: Option<&String>) -> Result<String, String> {
+    let root = custom_root
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("node_modules"));
+    let package_dir = root.join(package);
+
+    if !package_dir.is_dir() {
+        return Err(format!(
+            "Package '{}' is not installed under {}",
+            package,
+            root.display()
+        ));
+    }
+
+    for name in ["README.md", "readme.md", "Readme.md"] {
+        if let Ok(content) = fs::read_to_string(package_dir.join(name)) {
+            return Ok(excerpt(content.trim()));
+        }
+    }
+
+    // Fall back to the first markdown file in the package's docs/ directory
+    let docs_dir = package_dir.join("docs");
+    if docs_dir.is_dir() {
+        let mut pages: Vec<PathBuf> = fs::read_dir(&docs_dir)
+            .map_err(|e| format!("Failed to read {}: {}", docs_dir.display(), e))?
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+            .collect();
+        pages.sort();
+        if let Some(page) = pages.first() {
+            if let Ok(content) = fs::read_to_string(page) {
+                return Ok(excerpt(content.trim()));
+            }
+        }
+    }
+
+    Err(format!(
+        "No README.md or docs/ found for package '{}'",
+        package
+    ))
+}
+
+/// Cap the excerpt length so one lookup cannot flood the context window.
+fn excerpt(text: &str) -> String {
+    if text.len() <= MAX_EXCERPT {
+        return text.to_string();
+    }
+
+    let mut end = MAX_EXCERPT;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    format!(
+        "{}\n\n... [Truncated: showing first {} characters of {} total]",
+        &text[..end],
+        end,
+        text.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn rust_root(custom: &Path) -> HashMap<String, String> {
+        let mut roots = HashMap::new();
+        roots.insert("rust".to_string(), custom.display().to_string());
+        roots
+    }
+
+    #[test]
+    fn test_rust_item_lookup() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("slice")).unwrap();
+        fs::write(
+            dir.path().join("slice/fn.sort.html"),
+            r#"<html><body>
+                <pre class="rust item-decl">pub fn sort(&amp;mut self)</pre>
+                <div class="docblock"><p>Sorts the slice.</p></div>
+            </body></html>"#,
+        )
+        .unwrap();
+
+        let result = search("sort", "rust", &rust_root(dir.path())).unwrap();
+
+        assert!(result.contains("pub fn sort(&mut self)"));
+        assert!(result.contains("Sorts the slice."));
+    }
+
+    #[test]
+    fn test_rust_method_lookup() {
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("struct.Vec.html"),
+            r#"<html><body><details>
+                <summary><section id="method.push" class="method">
+                    <h4 class="code-header">pub fn push(&amp;mut self, value: T)</h4>
+                </section></summary>
+                <div class="docblock"><p>Appends an element to the back.</p></div>
+            </details></body></html>"#,
+        )
+        .unwrap();
+
+        let result = search("Vec::push", "rust", &rust_root(dir.path())).unwrap();
+
+        assert!(result.contains("pub fn push(&mut self, value: T)"));
+        assert!(result.contains("Appends an element to the back."));
+    }
+
+    #[test]
+    fn test_rust_missing_item() {
+        let dir = tempdir().unwrap();
+
+        let result = search("NotAThing", "rust", &rust_root(dir.path()));
+
+        assert!(result
+            .unwrap_err()
+            .contains("No documentation page found for 'NotAThing'"));
+    }
+
+    #[test]
+    fn test_node_readme_lookup() {
+        let dir = tempdir().unwrap();
+        fs::create_dir(dir.path().join("leftpad")).unwrap();
+        fs::write(
+            dir.path().join("leftpad/README.md"),
+            "# leftpad\n\nPads a string on the left.",
+        )
+        .unwrap();
+        let mut roots = HashMap::new();
+        roots.insert("node".to_string(), dir.path().display().to_string());
+
+        let result = search("leftpad", "node", &roots).unwrap();
+
+        assert!(result.contains("Pads a string on the left."));
+    }
+
+    #[test]
+    fn test_node_missing_package() {
+        let dir = tempdir().unwrap();
+        let mut roots = HashMap::new();
+        roots.insert("node".to_string(), dir.path().display().to_string());
+
+        let result = search("ghost", "node", &roots);
+
+        assert!(result.unwrap_err().contains("not installed"));
+    }
+
+    #[test]
+    fn test_unsupported_language() {
+        let result = search("printf", "cobol", &HashMap::new());
+
+        assert!(result.unwrap_err().contains("Unsupported language"));
+    }
+
+    #[test]
+    fn test_excerpt_truncation() {
+        let long = "x".repeat(MAX_EXCERPT + 100);
+
+        let result = excerpt(&long);
+
+        assert!(result.contains("Truncated"));
+        assert!(result.len() < long.len() + 100);
+    }
+}
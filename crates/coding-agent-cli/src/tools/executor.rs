@@ -3,10 +3,37 @@
 //! Provides a structured way to execute tools and categorize their errors
 //! for potential recovery or retry.
 
+use crate::tools::hooks::HookRunner;
+use crate::tools::middleware::{ToolCallInfo, ToolMiddleware};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::ops::ControlFlow;
+use std::sync::{Arc, Mutex, OnceLock};
 use std::time::{Duration, Instant};
 
+/// Kinds of network failures, with transience derived from the kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkErrorKind {
+    /// Connection refused, reset, or dropped by the peer
+    ConnectionFailed,
+    /// Host or network unreachable
+    Unreachable,
+    /// The operation timed out
+    Timeout,
+    /// DNS or name resolution failed
+    Dns,
+}
+
+impl NetworkErrorKind {
+    /// Whether retrying the same operation is likely to help.
+    pub fn is_transient(&self) -> bool {
+        matches!(
+            self,
+            Self::ConnectionFailed | Self::Unreachable | Self::Timeout
+        )
+    }
+}
+
 /// Categories of errors that can occur during tool execution.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ErrorCategory {
@@ -17,6 +44,14 @@ pub enum ErrorCategory {
         error_type: String,
     },
 
+    /// A test run that ended with failing tests.
+    TestFailure {
+        /// Number of tests that failed
+        failed: u32,
+        /// Total number of tests that ran
+        total: u32,
+    },
+
     /// Permission errors (file access denied, operation not permitted)
     /// May require user intervention or permission grants.
     Permission {
@@ -25,10 +60,9 @@ pub enum ErrorCategory {
     },
 
     /// Network errors (connection refused, timeout, DNS failure)
-    /// Usually transient and can be retried.
     Network {
-        /// Whether the error is likely transient and retriable
-        is_transient: bool,
+        /// The kind of network failure
+        kind: NetworkErrorKind,
     },
 
     /// Resource errors (disk full, out of memory, file not found)
@@ -42,6 +76,22 @@ pub enum ErrorCategory {
     Unknown,
 }
 
+impl ErrorCategory {
+    /// Whether errors in this category are ever worth re-running.
+    ///
+    /// Transient network failures and temporary resource shortages can
+    /// clear on their own; Code, TestFailure, and Permission errors will
+    /// fail the same way every time, so retrying them just wastes the
+    /// model's turn.
+    pub fn allows_retry(&self) -> bool {
+        match self {
+            ErrorCategory::Network { kind } => kind.is_transient(),
+            ErrorCategory::Resource { resource_type } => resource_type == "temporary",
+            _ => false,
+        }
+    }
+}
+
 /// A structured error from tool execution.
 #[derive(Debug, Clone)]
 pub struct ToolError {
@@ -59,13 +109,23 @@ pub struct ToolError {
 
     /// Suggested fix, if any
     pub suggested_fix: Option<String>,
+
+    /// How sure the string-heuristic classifier is, from 0.0 to 1.0.
+    ///
+    /// Recovery flows (auto-fix, permission prompts) only trigger above
+    /// [`ToolError::CONFIDENCE_THRESHOLD`]; low-confidence matches still
+    /// get a category for display but are otherwise treated as unknown.
+    pub confidence: f32,
 }
 
 impl ToolError {
+    /// Minimum classification confidence for triggering recovery flows.
+    pub const CONFIDENCE_THRESHOLD: f32 = 0.6;
+
     /// Create a new tool error with automatic categorization.
     pub fn new(message: impl Into<String>) -> Self {
         let message = message.into();
-        let (category, retriable, suggested_fix) = categorize_error(&message);
+        let (category, retriable, suggested_fix, confidence) = categorize_error(&message);
 
         Self {
             message,
@@ -73,13 +133,71 @@ impl ToolError {
             raw_output: None,
             retriable,
             suggested_fix,
+            confidence,
+        }
+    }
+
+    /// Create a tool error with automatic categorization, informed by the
+    /// tool that produced it.
+    ///
+    /// For bash, only the first line describes the call itself (exit code,
+    /// timeout); patterns buried in captured stdout/stderr are the
+    /// command's output, not the agent's own failure, so those matches get
+    /// reduced confidence and never trigger retries. Failing test runs are
+    /// recognized anywhere in the output.
+    pub fn for_tool(tool_name: &str, message: impl Into<String>) -> Self {
+        let message = message.into();
+        if tool_name != "bash" {
+            return Self::new(message);
+        }
+
+        if let Some((failed, total)) = parse_test_failure(&message) {
+            return Self {
+                message,
+                category: ErrorCategory::TestFailure { failed, total },
+                raw_output: None,
+                retriable: false,
+                suggested_fix: Some(format!(
+                    "Fix the {} failing test{}",
+                    failed,
+                    if failed == 1 { "" } else { "s" }
+                )),
+                confidence: 0.9,
+            };
+        }
+
+        let first_line = message.lines().next().unwrap_or("");
+        let (category, retriable, suggested_fix, confidence) = categorize_error(first_line);
+        if category != ErrorCategory::Unknown {
+            return Self {
+                message,
+                category,
+                raw_output: None,
+                retriable,
+                suggested_fix,
+                confidence,
+            };
+        }
+
+        let (category, retriable, suggested_fix, confidence) = categorize_error(&message);
+        let confidence = confidence * 0.5;
+        Self {
+            message,
+            category,
+            raw_output: None,
+            retriable: retriable && confidence >= Self::CONFIDENCE_THRESHOLD,
+            suggested_fix,
+            confidence,
         }
     }
 
     /// Create a tool error with explicit category.
     pub fn with_category(message: impl Into<String>, category: ErrorCategory) -> Self {
         let message = message.into();
-        let retriable = matches!(category, ErrorCategory::Network { is_transient: true });
+        let retriable = matches!(
+            category,
+            ErrorCategory::Network { kind } if kind.is_transient()
+        );
 
         Self {
             message,
@@ -87,6 +205,7 @@ impl ToolError {
             raw_output: None,
             retriable,
             suggested_fix: None,
+            confidence: 1.0,
         }
     }
 
@@ -102,9 +221,22 @@ impl ToolError {
         self
     }
 
+    /// Check whether the classification is sure enough to act on.
+    pub fn is_confident(&self) -> bool {
+        self.confidence >= Self::CONFIDENCE_THRESHOLD
+    }
+
+    /// Check whether the executor should re-run the call for this error.
+    ///
+    /// Both gates must agree: the message-level heuristic marked the error
+    /// retriable, and the category is one where retrying can help.
+    pub fn should_retry(&self) -> bool {
+        self.retriable && self.category.allows_retry()
+    }
+
     /// Check if this error can potentially be auto-fixed.
     pub fn is_auto_fixable(&self) -> bool {
-        matches!(self.category, ErrorCategory::Code { .. })
+        matches!(self.category, ErrorCategory::Code { .. }) && self.is_confident()
     }
 }
 
@@ -133,6 +265,9 @@ pub struct ToolExecutionResult {
 
     /// Number of retries attempted
     pub retries: u32,
+
+    /// Failures from `post_tool_use` hooks, for display in the REPL
+    pub hook_failures: Vec<String>,
 }
 
 impl ToolExecutionResult {
@@ -156,8 +291,18 @@ impl ToolExecutionResult {
     }
 }
 
+/// Hook called before each tool function; returning `Err` aborts execution.
+pub type BeforeExecuteHook = Arc<dyn Fn(&str, &Value) -> Result<(), String> + Send + Sync>;
+
+/// Hook called unconditionally after each tool execution.
+pub type AfterExecuteHook = Arc<dyn Fn(&str, &ToolExecutionResult) + Send + Sync>;
+
+/// Called before each retry sleep with the tool name, attempt number,
+/// maximum retries, and the delay about to be waited.
+pub type RetryNotifier = Arc<dyn Fn(&str, u32, u32, Duration) + Send + Sync>;
+
 /// Configuration for the tool executor.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ToolExecutorConfig {
     /// Maximum number of retries for transient errors
     pub max_retries: u32,
@@ -173,6 +318,43 @@ pub struct ToolExecutorConfig {
 
     /// Maximum time to wait for a single tool execution (in milliseconds)
     pub execution_timeout_ms: u64,
+
+    /// Simulate mutating tools instead of executing them.
+    ///
+    /// In dry-run mode write_file, edit_file, and bash return a
+    /// `[DRY RUN]` description of what they would have done; read-only
+    /// tools execute normally.
+    pub dry_run: bool,
+
+    /// Called with the tool name and input before each tool function.
+    ///
+    /// This is the extension point for integrations that need to observe or
+    /// gate tool calls: logging to a file, metrics, rate limiting, or an
+    /// externally implemented dry-run mode. Returning `Err` aborts the
+    /// execution with that message; the tool function is never called.
+    pub before_execute: Option<BeforeExecuteHook>,
+
+    /// Called with the tool name and result after every execution, whether
+    /// it succeeded, failed, or was aborted by `before_execute`.
+    pub after_execute: Option<AfterExecuteHook>,
+
+    /// Called before each retry sleep, so the UI can show progress like
+    /// "retry 2/3 in 4s" instead of appearing hung.
+    pub on_retry: Option<RetryNotifier>,
+
+    /// User-defined lifecycle hooks from the `[hooks]` config section.
+    ///
+    /// `pre_tool_use` hooks run before each tool call and can block it;
+    /// `post_tool_use` hooks run after, with failures collected into
+    /// `ToolExecutionResult::hook_failures`.
+    pub hooks: HookRunner,
+
+    /// Consecutive failures before a tool's circuit breaker opens
+    pub circuit_failure_threshold: usize,
+
+    /// How long an open circuit stays open before allowing a trial call
+    /// (in milliseconds)
+    pub circuit_reset_timeout_ms: u64,
 }
 
 impl Default for ToolExecutorConfig {
@@ -183,13 +365,122 @@ impl Default for ToolExecutorConfig {
             max_retry_delay_ms: 10000,
             auto_fix_enabled: true,
             execution_timeout_ms: 300000, // 5 minutes
+            dry_run: false,
+            before_execute: None,
+            after_execute: None,
+            on_retry: None,
+            hooks: HookRunner::default(),
+            circuit_failure_threshold: 5,
+            circuit_reset_timeout_ms: 30000,
         }
     }
 }
 
+impl std::fmt::Debug for ToolExecutorConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToolExecutorConfig")
+            .field("max_retries", &self.max_retries)
+            .field("base_retry_delay_ms", &self.base_retry_delay_ms)
+            .field("max_retry_delay_ms", &self.max_retry_delay_ms)
+            .field("auto_fix_enabled", &self.auto_fix_enabled)
+            .field("execution_timeout_ms", &self.execution_timeout_ms)
+            .field("dry_run", &self.dry_run)
+            .field(
+                "before_execute",
+                &self.before_execute.as_ref().map(|_| ".."),
+            )
+            .field("after_execute", &self.after_execute.as_ref().map(|_| ".."))
+            .field("on_retry", &self.on_retry.as_ref().map(|_| ".."))
+            .field("hooks", &self.hooks)
+            .field("circuit_failure_threshold", &self.circuit_failure_threshold)
+            .field("circuit_reset_timeout_ms", &self.circuit_reset_timeout_ms)
+            .finish()
+    }
+}
+
 /// A function that executes a tool and returns its result.
 pub type ToolFunction = fn(Value) -> Result<String, String>;
 
+/// Boxed future returned by an async tool function.
+pub type ToolFuture = futures::future::BoxFuture<'static, Result<String, String>>;
+
+/// An async tool function, for tools that would otherwise block the tokio
+/// runtime on I/O (subprocess waits, HTTP fetches, large file reads).
+pub type AsyncToolFunction = Arc<dyn Fn(Value) -> ToolFuture + Send + Sync>;
+
+/// State of a tool's circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Calls flow normally
+    Closed,
+    /// Calls are rejected; holds the time the circuit opened
+    Open(Instant),
+    /// One trial call is allowed through to probe for recovery
+    HalfOpen,
+}
+
+/// Per-tool circuit breaker.
+///
+/// A tool that fails repeatedly (bash missing from PATH, a misconfigured
+/// shell) will fail the same way on every call; the breaker stops the
+/// model from burning turns on it. After `failure_threshold` consecutive
+/// failures the circuit opens and calls are rejected outright. Once
+/// `reset_timeout` has passed, one trial call is allowed through: success
+/// closes the circuit, another failure re-opens it.
+#[derive(Debug, Clone)]
+struct CircuitBreaker {
+    /// The current state
+    state: CircuitState,
+    /// Consecutive failures since the last success
+    failure_count: usize,
+    /// Failures needed to open the circuit
+    failure_threshold: usize,
+    /// How long the circuit stays open before a trial call
+    reset_timeout: Duration,
+}
+
+impl CircuitBreaker {
+    fn new(failure_threshold: usize, reset_timeout: Duration) -> Self {
+        Self {
+            state: CircuitState::Closed,
+            failure_count: 0,
+            failure_threshold,
+            reset_timeout,
+        }
+    }
+
+    /// Whether a call may proceed, transitioning Open → HalfOpen once the
+    /// reset timeout has elapsed.
+    fn allow_call(&mut self) -> bool {
+        match self.state {
+            CircuitState::Closed | CircuitState::HalfOpen => true,
+            CircuitState::Open(since) => {
+                if since.elapsed() >= self.reset_timeout {
+                    self.state = CircuitState::HalfOpen;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Record a successful call, closing the circuit.
+    fn record_success(&mut self) {
+        self.state = CircuitState::Closed;
+        self.failure_count = 0;
+    }
+
+    /// Record a failed call, opening the circuit at the threshold (or
+    /// immediately when the trial call of a half-open circuit fails).
+    fn record_failure(&mut self) {
+        self.failure_count += 1;
+        if self.state == CircuitState::HalfOpen || self.failure_count >= self.failure_threshold {
+            self.state = CircuitState::Open(Instant::now());
+        }
+    }
+}
+
 /// The tool executor manages tool execution with error handling and recovery.
 pub struct ToolExecutor {
     /// Configuration for the executor
@@ -197,6 +488,20 @@ pub struct ToolExecutor {
 
     /// Registered tool functions
     tools: HashMap<String, ToolFunction>,
+
+    /// Registered async tool functions; these take priority over a sync
+    /// registration of the same name
+    async_tools: HashMap<String, AsyncToolFunction>,
+
+    /// Middleware run in registration order around each tool call
+    middlewares: Vec<Arc<dyn ToolMiddleware>>,
+
+    /// The most recent execution error, cleared by the next success;
+    /// read back by [`ToolExecutor::explain_last_error`]
+    last_error: Mutex<Option<ToolError>>,
+
+    /// Per-tool circuit breakers, created lazily on first execution
+    circuits: Mutex<HashMap<String, CircuitBreaker>>,
 }
 
 impl ToolExecutor {
@@ -205,6 +510,10 @@ impl ToolExecutor {
         Self {
             config,
             tools: HashMap::new(),
+            async_tools: HashMap::new(),
+            middlewares: Vec::new(),
+            last_error: Mutex::new(None),
+            circuits: Mutex::new(HashMap::new()),
         }
     }
 
@@ -218,14 +527,48 @@ impl ToolExecutor {
         self.tools.insert(name.into(), func);
     }
 
+    /// Register an async tool function.
+    ///
+    /// The executor drives the returned future to completion itself, so
+    /// async tools go through the same retry, hook, and categorization
+    /// path as sync ones. An async registration shadows a sync one of the
+    /// same name.
+    pub fn register_async_tool(&mut self, name: impl Into<String>, func: AsyncToolFunction) {
+        self.async_tools.insert(name.into(), func);
+    }
+
+    /// Remove a tool registration (both sync and async).
+    ///
+    /// Calls to an unregistered tool fail with an unknown-tool error;
+    /// used by `/tools disable` to take a tool away mid-session.
+    pub fn unregister_tool(&mut self, name: &str) {
+        self.tools.remove(name);
+        self.async_tools.remove(name);
+    }
+
+    /// Register a middleware around tool execution.
+    ///
+    /// Middlewares run in registration order: `before` ahead of every
+    /// attempt (including retries), where it can abort the call, and
+    /// `after` once with the final result of each execution.
+    pub fn add_middleware(&mut self, middleware: Arc<dyn ToolMiddleware>) {
+        self.middlewares.push(middleware);
+    }
+
     /// Check if a tool is registered.
     pub fn has_tool(&self, name: &str) -> bool {
-        self.tools.contains_key(name)
+        self.tools.contains_key(name) || self.async_tools.contains_key(name)
     }
 
     /// Get the list of registered tool names.
     pub fn tool_names(&self) -> Vec<&str> {
-        self.tools.keys().map(|s| s.as_str()).collect()
+        let mut names: Vec<&str> = self.tools.keys().map(|s| s.as_str()).collect();
+        for name in self.async_tools.keys() {
+            if !self.tools.contains_key(name) {
+                names.push(name.as_str());
+            }
+        }
+        names
     }
 
     /// Execute a tool with the given input.
@@ -274,11 +617,13 @@ impl ToolExecutor {
         let tool_name = tool_name.into();
         let start = Instant::now();
 
-        // Look up the tool
-        let tool_func = match self.tools.get(&tool_name) {
-            Some(func) => func,
-            None => {
-                return ToolExecutionResult {
+        // Look up the tool; an async registration shadows a sync one
+        let async_func = self.async_tools.get(&tool_name).cloned();
+        let sync_func = self.tools.get(&tool_name).copied();
+        if async_func.is_none() && sync_func.is_none() {
+            return self.finish(
+                &input,
+                ToolExecutionResult {
                     tool_name: tool_name.clone(),
                     call_id,
                     result: Err(ToolError::with_category(
@@ -289,53 +634,297 @@ impl ToolExecutor {
                     )),
                     duration: start.elapsed(),
                     retries: 0,
-                };
+                    hook_failures: Vec::new(),
+                },
+            );
+        }
+
+        // A tripped circuit breaker rejects the call before anything runs
+        if !self.circuit_allows(&tool_name) {
+            return self.finish(
+                &input,
+                ToolExecutionResult {
+                    tool_name: tool_name.clone(),
+                    call_id,
+                    result: Err(ToolError::new("Tool disabled: circuit breaker open")),
+                    duration: start.elapsed(),
+                    retries: 0,
+                    hook_failures: Vec::new(),
+                },
+            );
+        }
+
+        // Give the before_execute hook a chance to abort the call
+        if let Some(hook) = &self.config.before_execute {
+            if let Err(message) = hook(&tool_name, &input) {
+                return self.finish(
+                    &input,
+                    ToolExecutionResult {
+                        tool_name: tool_name.clone(),
+                        call_id,
+                        result: Err(ToolError::with_category(message, ErrorCategory::Unknown)),
+                        duration: start.elapsed(),
+                        retries: 0,
+                        hook_failures: Vec::new(),
+                    },
+                );
             }
+        }
+
+        // User-configured pre_tool_use hooks can block the call
+        if let Err(message) = self.config.hooks.check_pre_tool_use(&tool_name, &input) {
+            return self.finish(
+                &input,
+                ToolExecutionResult {
+                    tool_name: tool_name.clone(),
+                    call_id,
+                    result: Err(ToolError::with_category(
+                        message,
+                        ErrorCategory::Permission {
+                            resource: tool_name.clone(),
+                        },
+                    )),
+                    duration: start.elapsed(),
+                    retries: 0,
+                    hook_failures: Vec::new(),
+                },
+            );
+        }
+
+        // Middleware can observe or abort the call; `before` runs again
+        // ahead of each retry attempt with the attempt number bumped
+        let mut call = ToolCallInfo {
+            call_id: call_id.clone(),
+            tool_name: tool_name.clone(),
+            input: input.clone(),
+            attempt: 0,
         };
+        if let Some(message) = self.run_before_middleware(&call) {
+            return self.finish(
+                &input,
+                ToolExecutionResult {
+                    tool_name: tool_name.clone(),
+                    call_id,
+                    result: Err(ToolError::with_category(message, ErrorCategory::Unknown)),
+                    duration: start.elapsed(),
+                    retries: 0,
+                    hook_failures: Vec::new(),
+                },
+            );
+        }
+
+        // Dry-run mode simulates mutating tools instead of executing them
+        if self.config.dry_run {
+            if let Some(simulated) = super::definitions::simulate_tool_call(&tool_name, &input) {
+                return self.finish(
+                    &input,
+                    ToolExecutionResult {
+                        tool_name: tool_name.clone(),
+                        call_id,
+                        result: Ok(simulated),
+                        duration: start.elapsed(),
+                        retries: 0,
+                        hook_failures: Vec::new(),
+                    },
+                );
+            }
+        }
 
         // Execute with retry logic
         let mut retries = 0;
         loop {
-            let result = tool_func(input.clone());
+            let result = match &async_func {
+                Some(func) => drive_tool_future(func(input.clone())),
+                None => sync_func.expect("checked above")(input.clone()),
+            };
 
             match result {
                 Ok(output) => {
-                    return ToolExecutionResult {
-                        tool_name: tool_name.clone(),
-                        call_id,
-                        result: Ok(output),
-                        duration: start.elapsed(),
-                        retries,
-                    };
+                    self.record_circuit_outcome(&tool_name, true);
+                    return self.finish(
+                        &input,
+                        ToolExecutionResult {
+                            tool_name: tool_name.clone(),
+                            call_id,
+                            result: Ok(output),
+                            duration: start.elapsed(),
+                            retries,
+                            hook_failures: Vec::new(),
+                        },
+                    );
                 }
                 Err(error_msg) => {
-                    let error = ToolError::new(&error_msg).with_raw_output(&error_msg);
+                    let error =
+                        ToolError::for_tool(&tool_name, &error_msg).with_raw_output(&error_msg);
 
-                    // Check if we should retry
-                    if error.retriable && retries < self.config.max_retries {
+                    // Category-aware retry: only transient categories are
+                    // re-run; Code and Permission errors fail immediately
+                    if error.should_retry() && retries < self.config.max_retries {
                         retries += 1;
                         let delay = self.calculate_retry_delay(retries);
+                        if let Some(notify) = &self.config.on_retry {
+                            notify(&tool_name, retries, self.config.max_retries, delay);
+                        }
                         std::thread::sleep(delay);
+
+                        // Middleware sees the retry attempt and can stop it
+                        call.attempt = retries;
+                        if let Some(message) = self.run_before_middleware(&call) {
+                            return self.finish(
+                                &input,
+                                ToolExecutionResult {
+                                    tool_name: tool_name.clone(),
+                                    call_id,
+                                    result: Err(ToolError::with_category(
+                                        message,
+                                        ErrorCategory::Unknown,
+                                    )),
+                                    duration: start.elapsed(),
+                                    retries,
+                                    hook_failures: Vec::new(),
+                                },
+                            );
+                        }
                         continue;
                     }
 
-                    return ToolExecutionResult {
-                        tool_name: tool_name.clone(),
-                        call_id,
-                        result: Err(error),
-                        duration: start.elapsed(),
-                        retries,
-                    };
+                    self.record_circuit_outcome(&tool_name, false);
+                    return self.finish(
+                        &input,
+                        ToolExecutionResult {
+                            tool_name: tool_name.clone(),
+                            call_id,
+                            result: Err(error),
+                            duration: start.elapsed(),
+                            retries,
+                            hook_failures: Vec::new(),
+                        },
+                    );
                 }
             }
         }
     }
 
-    /// Calculate retry delay with exponential backoff.
+    /// Run post_tool_use and after_execute hooks and pass the result through.
+    ///
+    /// Failures from user-configured `post_tool_use` hooks are collected into
+    /// the result rather than treated as errors.
+    fn finish(&self, input: &Value, mut result: ToolExecutionResult) -> ToolExecutionResult {
+        let outcome = match &result.result {
+            Ok(output) => Ok(output.clone()),
+            Err(error) => Err(error.message.clone()),
+        };
+        result.hook_failures =
+            self.config
+                .hooks
+                .report_post_tool_use(&result.tool_name, input, &outcome);
+
+        if let Some(hook) = &self.config.after_execute {
+            hook(&result.tool_name, &result);
+        }
+
+        // Middleware `after` sees every outcome, including aborted calls
+        let call = ToolCallInfo {
+            call_id: result.call_id.clone(),
+            tool_name: result.tool_name.clone(),
+            input: input.clone(),
+            attempt: result.retries,
+        };
+        for middleware in &self.middlewares {
+            middleware.after(&call, &result);
+        }
+
+        // Remember the failure for explain_last_error; a success clears it
+        *self.last_error.lock().unwrap() = result.result.as_ref().err().cloned();
+
+        result
+    }
+
+    /// Explain the most recent execution error in plain language.
+    ///
+    /// Where [`ToolError::suggested_fix`] is a one-line hint from the
+    /// categorization heuristics (and often absent), this composes a short
+    /// explanation from the diagnostic subsystem: Rust error codes are
+    /// looked up in a bundled table, permission errors name the restricted
+    /// path, and network errors point at connectivity and proxy settings.
+    /// Returns `None` when the last execution succeeded or the error
+    /// doesn't match anything worth explaining.
+    pub fn explain_last_error(&self) -> Option<String> {
+        let error = self.last_error.lock().unwrap().clone()?;
+        explain_error(&error)
+    }
+
+    /// Run each middleware's `before`, returning the first abort message.
+    fn run_before_middleware(&self, call: &ToolCallInfo) -> Option<String> {
+        for middleware in &self.middlewares {
+            if let ControlFlow::Break(message) = middleware.before(call) {
+                return Some(message);
+            }
+        }
+        None
+    }
+
+    /// Calculate retry delay with exponential backoff and jitter.
+    ///
+    /// Up to 25% jitter is added on top of the capped exponential delay so
+    /// concurrent agents hitting the same flaky endpoint don't retry in
+    /// lockstep. Time-based pseudo-randomness is enough for that and avoids
+    /// pulling in a rand dependency.
     fn calculate_retry_delay(&self, retry_count: u32) -> Duration {
         let delay_ms = self.config.base_retry_delay_ms * 2u64.pow(retry_count - 1);
         let capped_delay = delay_ms.min(self.config.max_retry_delay_ms);
-        Duration::from_millis(capped_delay)
+
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.subsec_nanos() as u64)
+            .unwrap_or(0);
+        let jitter = nanos % (capped_delay / 4 + 1);
+
+        Duration::from_millis(capped_delay + jitter)
+    }
+
+    /// Check the tool's circuit breaker, creating one on first use.
+    fn circuit_allows(&self, tool_name: &str) -> bool {
+        self.circuits
+            .lock()
+            .unwrap()
+            .entry(tool_name.to_string())
+            .or_insert_with(|| {
+                CircuitBreaker::new(
+                    self.config.circuit_failure_threshold,
+                    Duration::from_millis(self.config.circuit_reset_timeout_ms),
+                )
+            })
+            .allow_call()
+    }
+
+    /// Feed an execution outcome to the tool's circuit breaker.
+    fn record_circuit_outcome(&self, tool_name: &str, success: bool) {
+        let mut circuits = self.circuits.lock().unwrap();
+        let Some(breaker) = circuits.get_mut(tool_name) else {
+            return;
+        };
+        if success {
+            breaker.record_success();
+        } else {
+            breaker.record_failure();
+        }
+    }
+
+    /// The circuit breaker state of every tool that has been executed
+    /// (for the `/tools` listing).
+    pub fn circuit_status(&self) -> HashMap<String, CircuitState> {
+        self.circuits
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, breaker)| (name.clone(), breaker.state))
+            .collect()
+    }
+
+    /// Enable or disable dry-run simulation of mutating tools.
+    pub fn set_dry_run(&mut self, enabled: bool) {
+        self.config.dry_run = enabled;
     }
 
     /// Get the executor configuration.
@@ -350,6 +939,35 @@ impl Default for ToolExecutor {
     }
 }
 
+/// Drive an async tool future to completion from the executor's sync path.
+///
+/// On the multi-threaded runtime this uses `block_in_place` so the worker
+/// thread can block without starving other tasks. Outside a runtime (unit
+/// tests, direct calls) a throwaway current-thread runtime is built; inside
+/// a current-thread runtime the future runs on a separate thread, since
+/// `block_in_place` is not available there.
+fn drive_tool_future(future: ToolFuture) -> Result<String, String> {
+    use tokio::runtime::{Builder, Handle, RuntimeFlavor};
+
+    fn block_on_new_runtime(future: ToolFuture) -> Result<String, String> {
+        Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| format!("Failed to start async runtime: {}", e))?
+            .block_on(future)
+    }
+
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| handle.block_on(future))
+        }
+        Ok(_) => std::thread::spawn(move || block_on_new_runtime(future))
+            .join()
+            .map_err(|_| "Async tool panicked".to_string())?,
+        Err(_) => block_on_new_runtime(future),
+    }
+}
+
 /// Categorize an error message into an ErrorCategory.
 ///
 /// # Examples
@@ -364,9 +982,9 @@ impl Default for ToolExecutor {
 /// // => ErrorCategory::Permission { resource: "/etc/passwd" }
 ///
 /// let error = ToolError::new("Connection timed out");
-/// // => ErrorCategory::Network { is_transient: true }
+/// // => ErrorCategory::Network { kind: NetworkErrorKind::Timeout }
 /// ```
-fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
+fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>, f32) {
     let lower = message.to_lowercase();
 
     // Code errors - often fixable
@@ -383,6 +1001,7 @@ fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
             },
             false,
             Some("Add the missing dependency to Cargo.toml or package.json".to_string()),
+            0.9,
         );
     }
 
@@ -398,6 +1017,7 @@ fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
             },
             false,
             Some("Fix the type annotation or conversion".to_string()),
+            0.75,
         );
     }
 
@@ -415,6 +1035,7 @@ fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
             },
             false,
             Some("Fix the syntax error".to_string()),
+            0.7,
         );
     }
 
@@ -428,6 +1049,7 @@ fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
             },
             false,
             Some("Add the missing import statement".to_string()),
+            0.85,
         );
     }
 
@@ -443,19 +1065,46 @@ fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
             ErrorCategory::Permission { resource },
             false,
             Some("Check file permissions or request access".to_string()),
+            0.85,
+        );
+    }
+
+    // Bash tool timeouts are resource errors, not transient network failures:
+    // retrying the same command would just hit the same wall
+    if lower.starts_with("command timed out after") {
+        return (
+            ErrorCategory::Resource {
+                resource_type: "timeout".to_string(),
+            },
+            false,
+            Some(
+                "Re-run with a larger timeout_secs or split the command into smaller steps"
+                    .to_string(),
+            ),
+            0.95,
         );
     }
 
     // Network errors - often transient
-    if lower.contains("connection refused")
-        || lower.contains("connection reset")
-        || lower.contains("network unreachable")
-        || lower.contains("host unreachable")
-    {
+    if lower.contains("connection refused") || lower.contains("connection reset") {
+        return (
+            ErrorCategory::Network {
+                kind: NetworkErrorKind::ConnectionFailed,
+            },
+            true,
+            Some("Check network connectivity and retry".to_string()),
+            0.9,
+        );
+    }
+
+    if lower.contains("network unreachable") || lower.contains("host unreachable") {
         return (
-            ErrorCategory::Network { is_transient: true },
+            ErrorCategory::Network {
+                kind: NetworkErrorKind::Unreachable,
+            },
             true,
             Some("Check network connectivity and retry".to_string()),
+            0.85,
         );
     }
 
@@ -464,19 +1113,23 @@ fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
         || lower.contains("deadline exceeded")
     {
         return (
-            ErrorCategory::Network { is_transient: true },
+            ErrorCategory::Network {
+                kind: NetworkErrorKind::Timeout,
+            },
             true,
             Some("Operation timed out, will retry".to_string()),
+            0.7,
         );
     }
 
     if lower.contains("dns") || lower.contains("name resolution") || lower.contains("getaddrinfo") {
         return (
             ErrorCategory::Network {
-                is_transient: false,
+                kind: NetworkErrorKind::Dns,
             },
             false,
             Some("DNS resolution failed, check the hostname".to_string()),
+            0.8,
         );
     }
 
@@ -492,6 +1145,7 @@ fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
             },
             false,
             Some("Free up disk space".to_string()),
+            0.9,
         );
     }
 
@@ -505,6 +1159,7 @@ fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
             },
             false,
             Some("Reduce memory usage or increase available memory".to_string()),
+            0.9,
         );
     }
 
@@ -520,11 +1175,34 @@ fn categorize_error(message: &str) -> (ErrorCategory, bool, Option<String>) {
             },
             false,
             Some(format!("File or directory '{}' does not exist", resource)),
+            0.75,
         );
     }
 
     // Unknown error
-    (ErrorCategory::Unknown, false, None)
+    (ErrorCategory::Unknown, false, None, 0.1)
+}
+
+/// Parse a failing test summary like "3 passed; 2 failed" out of command
+/// output, returning (failed, total).
+fn parse_test_failure(message: &str) -> Option<(u32, u32)> {
+    let mut passed: Option<u32> = None;
+    let mut failed: Option<u32> = None;
+
+    let mut previous: Option<u32> = None;
+    for token in message.split_whitespace() {
+        if token.starts_with("passed") {
+            passed = passed.or(previous);
+        } else if token.starts_with("failed") {
+            failed = failed.or(previous);
+        }
+        previous = token.trim_end_matches([';', ',', '.']).parse().ok();
+    }
+
+    match (passed, failed) {
+        (Some(passed), Some(failed)) if failed > 0 => Some((failed, passed + failed)),
+        _ => None,
+    }
 }
 
 /// Try to extract a file path from an error message.
@@ -557,7 +1235,7 @@ fn extract_path_from_error(message: &str) -> Option<String> {
             || word.contains("/Users/")
         {
             // Clean up trailing punctuation
-            let cleaned = word.trim_end_matches(|c| c == ':' || c == ',' || c == '.' || c == ')');
+            let cleaned = word.trim_end_matches([':', ',', '.', ')']);
             return Some(cleaned.to_string());
         }
     }
@@ -565,6 +1243,132 @@ fn extract_path_from_error(message: &str) -> Option<String> {
     None
 }
 
+/// Explanations for common Rust error codes, bundled at build time.
+fn rust_error_explanation(code: &str) -> Option<&'static str> {
+    static TABLE: OnceLock<HashMap<String, String>> = OnceLock::new();
+    let table = TABLE.get_or_init(|| {
+        serde_json::from_str(include_str!("../../data/rust_errors.json"))
+            .expect("bundled rust_errors.json is valid")
+    });
+    table.get(code).map(|s| s.as_str())
+}
+
+/// Common error patterns that don't carry a compiler error code.
+const ERROR_PATTERNS: &[(&str, &str)] = &[
+    (
+        "command not found",
+        "The command isn't installed or isn't on PATH. Install it, or check the spelling and use the full path to the binary.",
+    ),
+    (
+        "no space left on device",
+        "The disk the tool wrote to is full. Free up space (build artifacts and caches are the usual suspects) and re-run the command.",
+    ),
+    (
+        "broken pipe",
+        "The process on the other end of the pipe exited before reading all the output. This is usually harmless; re-run the command without the downstream consumer to see the real result.",
+    ),
+    (
+        "is a directory",
+        "The path points at a directory where a file was expected. Point the tool at a file inside the directory instead.",
+    ),
+];
+
+/// Compose a short, human-readable explanation of a tool error.
+///
+/// Backs [`ToolExecutor::explain_last_error`]; split out so the category
+/// match stays readable.
+fn explain_error(error: &ToolError) -> Option<String> {
+    match &error.category {
+        ErrorCategory::Code { .. } => {
+            let source = error.raw_output.as_deref().unwrap_or(&error.message);
+            let report = super::diagnostics::parse_compiler_output_with_stack(
+                source,
+                crate::project::primary_type(),
+            );
+            if let Some(diagnostic) = report.errors().find(|d| d.has_code()) {
+                let code = diagnostic.code.as_deref().expect("filtered on has_code");
+                let mut explanation = match rust_error_explanation(code) {
+                    Some(text) => format!("{}: {}", code, text),
+                    None => format!("{}: {}.", code, diagnostic.message),
+                };
+                if let (Some(file), Some(line)) = (diagnostic.file_path(), diagnostic.line()) {
+                    explanation.push_str(&format!(" The error is at {}:{}.", file, line));
+                }
+                if let Some(command) = diagnostic.suggested_command() {
+                    explanation.push_str(&format!(" Running `{}` may fix it.", command));
+                }
+                return Some(explanation);
+            }
+            explain_pattern(&error.message).or_else(|| explain_deviation(&error.message))
+        }
+        ErrorCategory::TestFailure { failed, total } => Some(format!(
+            "{} of {} tests failed. The failures are usually in code this session changed; read the assertion output above and fix the code or the expectation it no longer matches.",
+            failed, total
+        )),
+        ErrorCategory::Permission { resource } => Some(format!(
+            "Access to {} was denied. The path is outside the directories this session is allowed to touch, or the file's permissions don't allow the operation; work inside the project directory or grant access when prompted.",
+            resource
+        )),
+        ErrorCategory::Network { kind } => {
+            let what = match kind {
+                NetworkErrorKind::ConnectionFailed => "The connection was refused or dropped",
+                NetworkErrorKind::Unreachable => "The host was unreachable",
+                NetworkErrorKind::Timeout => "The operation timed out",
+                NetworkErrorKind::Dns => "Name resolution failed",
+            };
+            Some(format!(
+                "{}. Check your network connectivity and any proxy settings (HTTP_PROXY/HTTPS_PROXY), then try again; transient failures are retried automatically.",
+                what
+            ))
+        }
+        ErrorCategory::Resource { resource_type } => match resource_type.as_str() {
+            "not_found" => Some(
+                "The file or directory doesn't exist. Check the path for typos, or list the parent directory to see what's actually there."
+                    .to_string(),
+            ),
+            "disk_full" => Some(
+                "The disk is full. Free up space (build artifacts and caches are the usual suspects) before retrying."
+                    .to_string(),
+            ),
+            "out_of_memory" => Some(
+                "The operation ran out of memory. Process the data in smaller chunks, or close other memory-heavy processes and retry."
+                    .to_string(),
+            ),
+            _ => explain_pattern(&error.message),
+        },
+        ErrorCategory::Unknown => {
+            explain_pattern(&error.message).or_else(|| explain_deviation(&error.message))
+        }
+    }
+}
+
+/// Look up an error message in the pattern table.
+fn explain_pattern(message: &str) -> Option<String> {
+    let lower = message.to_lowercase();
+    ERROR_PATTERNS
+        .iter()
+        .find(|(pattern, _)| lower.contains(pattern))
+        .map(|(_, explanation)| explanation.to_string())
+}
+
+/// Fall back to the fix-agent's deviation categories for a rough shape.
+///
+/// Only categories that say something actionable are surfaced; the
+/// catch-all `AgentCode` would just restate "there is an error".
+fn explain_deviation(message: &str) -> Option<String> {
+    match crate::agents::categorize_deviation(message) {
+        crate::agents::DeviationCategory::Dependency => Some(
+            "This looks like a missing dependency. Check that the crate, package, or module is declared in the project's manifest and spelled correctly."
+                .to_string(),
+        ),
+        crate::agents::DeviationCategory::TestLint => Some(
+            "This looks like a test or lint failure. Read the failure output above and fix the code or the expectation it no longer matches."
+                .to_string(),
+        ),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -599,7 +1403,9 @@ mod tests {
         let error = ToolError::new("Connection timed out");
         assert!(matches!(
             error.category,
-            ErrorCategory::Network { is_transient: true }
+            ErrorCategory::Network {
+                kind: NetworkErrorKind::Timeout
+            }
         ));
         assert!(error.retriable);
         assert!(!error.is_auto_fixable());
@@ -610,11 +1416,28 @@ mod tests {
         let error = ToolError::new("Connection refused: could not connect to localhost:5432");
         assert!(matches!(
             error.category,
-            ErrorCategory::Network { is_transient: true }
+            ErrorCategory::Network {
+                kind: NetworkErrorKind::ConnectionFailed
+            }
         ));
         assert!(error.retriable);
     }
 
+    #[test]
+    fn test_error_categorization_bash_timeout() {
+        let error =
+            ToolError::new("Command timed out after 120s\npartial stdout: \npartial stderr: ");
+        assert!(matches!(
+            error.category,
+            ErrorCategory::Resource {
+                resource_type: ref t
+            } if t == "timeout"
+        ));
+        // Not retriable: the same command would hit the same timeout
+        assert!(!error.retriable);
+        assert!(!error.is_auto_fixable());
+    }
+
     #[test]
     fn test_error_categorization_resource_not_found() {
         let error = ToolError::new("No such file or directory: '/tmp/missing.txt'");
@@ -685,39 +1508,294 @@ mod tests {
     }
 
     #[test]
-    fn test_tool_error_with_raw_output() {
-        let error = ToolError::new("error").with_raw_output("full compiler output here");
-        assert_eq!(
-            error.raw_output,
-            Some("full compiler output here".to_string())
+    fn test_for_tool_bash_output_patterns_low_confidence() {
+        // "permission denied" only appears inside the command's captured
+        // output, not in the tool's own failure line
+        let error = ToolError::for_tool(
+            "bash",
+            "Command failed with exit code: 1\nstdout: \nstderr: grep: /var/log/secure: Permission denied",
         );
-    }
 
-    #[test]
-    fn test_tool_error_with_suggested_fix() {
-        let error = ToolError::new("error").with_suggested_fix("try this");
-        assert_eq!(error.suggested_fix, Some("try this".to_string()));
+        assert!(matches!(error.category, ErrorCategory::Permission { .. }));
+        assert!(!error.is_confident());
+        assert!(!error.retriable);
     }
 
     #[test]
-    fn test_tool_executor_register_and_has_tool() {
-        let mut executor = ToolExecutor::with_defaults();
-
-        fn dummy_tool(_: Value) -> Result<String, String> {
-            Ok("ok".to_string())
-        }
+    fn test_for_tool_bash_first_line_full_confidence() {
+        let error = ToolError::for_tool(
+            "bash",
+            "Command timed out after 120s\npartial stdout: \npartial stderr: ",
+        );
 
-        assert!(!executor.has_tool("test_tool"));
-        executor.register_tool("test_tool", dummy_tool);
-        assert!(executor.has_tool("test_tool"));
+        assert!(matches!(
+            error.category,
+            ErrorCategory::Resource { resource_type: ref t } if t == "timeout"
+        ));
+        assert!(error.is_confident());
     }
 
     #[test]
-    fn test_tool_executor_tool_names() {
-        let mut executor = ToolExecutor::with_defaults();
+    fn test_for_tool_bash_detects_test_failures() {
+        let error = ToolError::for_tool(
+            "bash",
+            "Command failed with exit code: 101\nstdout: test result: FAILED. 40 passed; 2 failed; 1 ignored\nstderr: ",
+        );
 
-        fn tool_a(_: Value) -> Result<String, String> {
-            Ok("a".to_string())
+        assert_eq!(
+            error.category,
+            ErrorCategory::TestFailure {
+                failed: 2,
+                total: 42
+            }
+        );
+        assert!(error.is_confident());
+        assert!(!error.retriable);
+        assert!(error.suggested_fix.unwrap().contains("2 failing tests"));
+    }
+
+    #[test]
+    fn test_for_tool_non_bash_uses_generic_categorization() {
+        let error = ToolError::for_tool("read_file", "Permission denied: '/etc/shadow'");
+
+        assert!(matches!(error.category, ErrorCategory::Permission { .. }));
+        assert!(error.is_confident());
+    }
+
+    #[test]
+    fn test_parse_test_failure() {
+        assert_eq!(
+            parse_test_failure("test result: FAILED. 3 passed; 2 failed; 0 ignored"),
+            Some((2, 5))
+        );
+        assert_eq!(
+            parse_test_failure("test result: ok. 10 passed; 0 failed"),
+            None
+        );
+        assert_eq!(parse_test_failure("nothing test related"), None);
+    }
+
+    #[test]
+    fn test_low_confidence_code_error_not_auto_fixable() {
+        // The type error appears in command output, so confidence is halved
+        // below the threshold and auto-fix must not trigger
+        let error = ToolError::for_tool(
+            "bash",
+            "Command failed with exit code: 1\nstdout: \nstderr: error[E0308]: mismatched types: expected `&str`, found `String`",
+        );
+
+        assert!(matches!(error.category, ErrorCategory::Code { .. }));
+        assert!(!error.is_auto_fixable());
+    }
+
+    #[test]
+    fn test_categorization_regression_corpus() {
+        // Real captured error strings mapped to their expected categories
+        let corpus: &[(&str, ErrorCategory)] = &[
+            (
+                "error[E0463]: can't find crate for `tokio`",
+                ErrorCategory::Code {
+                    error_type: "missing_dependency".to_string(),
+                },
+            ),
+            (
+                "error[E0425]: cannot find function `spwan` in this scope",
+                ErrorCategory::Code {
+                    error_type: "missing_import".to_string(),
+                },
+            ),
+            (
+                "Permission denied: '/etc/hosts'",
+                ErrorCategory::Permission {
+                    resource: "/etc/hosts".to_string(),
+                },
+            ),
+            (
+                "Connection reset by peer (os error 104)",
+                ErrorCategory::Network {
+                    kind: NetworkErrorKind::ConnectionFailed,
+                },
+            ),
+            (
+                "curl: (6) Could not resolve host: api.example.com (getaddrinfo failed)",
+                ErrorCategory::Network {
+                    kind: NetworkErrorKind::Dns,
+                },
+            ),
+            (
+                "request to https://registry.example.com timed out",
+                ErrorCategory::Network {
+                    kind: NetworkErrorKind::Timeout,
+                },
+            ),
+            (
+                "write /tmp/build/output.o: no space left on device",
+                ErrorCategory::Resource {
+                    resource_type: "disk_full".to_string(),
+                },
+            ),
+            (
+                "cat: /tmp/missing.txt: No such file or directory",
+                ErrorCategory::Resource {
+                    resource_type: "not_found".to_string(),
+                },
+            ),
+            ("exit status 1", ErrorCategory::Unknown),
+        ];
+
+        for (message, expected) in corpus {
+            let error = ToolError::new(*message);
+            assert_eq!(&error.category, expected, "message: {:?}", message);
+        }
+    }
+
+    #[test]
+    fn test_tool_error_with_raw_output() {
+        let error = ToolError::new("error").with_raw_output("full compiler output here");
+        assert_eq!(
+            error.raw_output,
+            Some("full compiler output here".to_string())
+        );
+    }
+
+    #[test]
+    fn test_tool_error_with_suggested_fix() {
+        let error = ToolError::new("error").with_suggested_fix("try this");
+        assert_eq!(error.suggested_fix, Some("try this".to_string()));
+    }
+
+    #[test]
+    fn test_tool_executor_register_and_has_tool() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        fn dummy_tool(_: Value) -> Result<String, String> {
+            Ok("ok".to_string())
+        }
+
+        assert!(!executor.has_tool("test_tool"));
+        executor.register_tool("test_tool", dummy_tool);
+        assert!(executor.has_tool("test_tool"));
+    }
+
+    #[test]
+    fn test_tool_executor_unregister_tool() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        fn dummy_tool(_: Value) -> Result<String, String> {
+            Ok("ok".to_string())
+        }
+
+        executor.register_tool("test_tool", dummy_tool);
+        executor.unregister_tool("test_tool");
+
+        assert!(!executor.has_tool("test_tool"));
+        let result = executor.execute("call_1", "test_tool", serde_json::json!({}));
+        assert!(!result.is_success());
+    }
+
+    #[test]
+    fn test_explain_last_error_none_without_error() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        fn ok_tool(_: Value) -> Result<String, String> {
+            Ok("ok".to_string())
+        }
+        executor.register_tool("ok_tool", ok_tool);
+
+        // Nothing executed yet
+        assert_eq!(executor.explain_last_error(), None);
+
+        // A success leaves nothing to explain
+        executor.execute("call_1", "ok_tool", serde_json::json!({}));
+        assert_eq!(executor.explain_last_error(), None);
+    }
+
+    #[test]
+    fn test_explain_last_error_looks_up_rust_error_code() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        fn type_error_tool(_: Value) -> Result<String, String> {
+            Err("error[E0308]: mismatched types\n --> src/main.rs:4:5".to_string())
+        }
+        executor.register_tool("build", type_error_tool);
+
+        executor.execute("call_1", "build", serde_json::json!({}));
+
+        let explanation = executor.explain_last_error().expect("should explain E0308");
+        assert!(explanation.starts_with("E0308:"));
+        assert!(explanation.contains("types"));
+        assert!(explanation.contains("src/main.rs:4"));
+    }
+
+    #[test]
+    fn test_explain_last_error_names_denied_path() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        fn denied_tool(_: Value) -> Result<String, String> {
+            Err("Permission denied: '/etc/shadow'".to_string())
+        }
+        executor.register_tool("read_file", denied_tool);
+
+        executor.execute("call_1", "read_file", serde_json::json!({}));
+
+        let explanation = executor
+            .explain_last_error()
+            .expect("should explain denial");
+        assert!(explanation.contains("/etc/shadow"));
+        assert!(explanation.contains("denied"));
+    }
+
+    #[test]
+    fn test_explain_last_error_network_mentions_proxy() {
+        let error = ToolError::with_category(
+            "connection refused".to_string(),
+            ErrorCategory::Network {
+                kind: NetworkErrorKind::ConnectionFailed,
+            },
+        );
+
+        let explanation = explain_error(&error).expect("should explain network error");
+        assert!(explanation.contains("connectivity"));
+        assert!(explanation.contains("proxy"));
+    }
+
+    #[test]
+    fn test_explain_last_error_cleared_by_success() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        fn ok_tool(_: Value) -> Result<String, String> {
+            Ok("ok".to_string())
+        }
+        fn bad_tool(_: Value) -> Result<String, String> {
+            Err("error[E0308]: mismatched types".to_string())
+        }
+        executor.register_tool("ok_tool", ok_tool);
+        executor.register_tool("bad_tool", bad_tool);
+
+        executor.execute("call_1", "bad_tool", serde_json::json!({}));
+        assert!(executor.explain_last_error().is_some());
+
+        executor.execute("call_2", "ok_tool", serde_json::json!({}));
+        assert_eq!(executor.explain_last_error(), None);
+    }
+
+    #[test]
+    fn test_explain_error_pattern_table_fallback() {
+        let error = ToolError::with_category(
+            "bash: frobnicate: command not found".to_string(),
+            ErrorCategory::Unknown,
+        );
+
+        let explanation = explain_error(&error).expect("should match the pattern table");
+        assert!(explanation.contains("PATH"));
+    }
+
+    #[test]
+    fn test_tool_executor_tool_names() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        fn tool_a(_: Value) -> Result<String, String> {
+            Ok("a".to_string())
         }
         fn tool_b(_: Value) -> Result<String, String> {
             Ok("b".to_string())
@@ -784,9 +1862,222 @@ mod tests {
         assert!(matches!(error.category, ErrorCategory::Permission { .. }));
     }
 
+    #[test]
+    fn test_circuit_breaker_opens_after_repeated_failures() {
+        let config = ToolExecutorConfig {
+            circuit_failure_threshold: 3,
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+
+        fn failing_tool(_: Value) -> Result<String, String> {
+            Err("Permission denied: '/etc/shadow'".to_string())
+        }
+        executor.register_tool("fail", failing_tool);
+
+        for i in 0..3 {
+            let result = executor.execute(format!("call_{}", i), "fail", serde_json::json!({}));
+            assert!(!result.is_success());
+        }
+
+        // The circuit is now open; the tool function is no longer called
+        let result = executor.execute("call_4", "fail", serde_json::json!({}));
+        let error = result.error().unwrap();
+        assert!(error.message.contains("circuit breaker open"));
+        assert!(matches!(
+            executor.circuit_status().get("fail"),
+            Some(CircuitState::Open(_))
+        ));
+    }
+
+    #[test]
+    fn test_circuit_breaker_trial_call_closes_on_success() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        static RECOVERED: AtomicBool = AtomicBool::new(false);
+
+        fn flaky_tool(_: Value) -> Result<String, String> {
+            if RECOVERED.load(Ordering::SeqCst) {
+                Ok("recovered".to_string())
+            } else {
+                Err("Permission denied: '/etc/shadow'".to_string())
+            }
+        }
+
+        let config = ToolExecutorConfig {
+            circuit_failure_threshold: 1,
+            circuit_reset_timeout_ms: 0,
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("flaky", flaky_tool);
+
+        // The first failure opens the circuit
+        let result = executor.execute("call_1", "flaky", serde_json::json!({}));
+        assert!(!result.is_success());
+
+        // With a zero reset timeout the next call is a half-open trial;
+        // it fails too and re-opens the circuit
+        let result = executor.execute("call_2", "flaky", serde_json::json!({}));
+        assert!(!result.is_success());
+        assert!(matches!(
+            executor.circuit_status().get("flaky"),
+            Some(CircuitState::Open(_))
+        ));
+
+        // A successful trial closes the circuit again
+        RECOVERED.store(true, Ordering::SeqCst);
+        let result = executor.execute("call_3", "flaky", serde_json::json!({}));
+        assert!(result.is_success());
+        assert_eq!(
+            executor.circuit_status().get("flaky"),
+            Some(&CircuitState::Closed)
+        );
+    }
+
+    #[test]
+    fn test_register_async_tool_and_execute() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        let func: AsyncToolFunction = Arc::new(|input: Value| -> ToolFuture {
+            Box::pin(async move { Ok(format!("async echo: {}", input["msg"])) })
+        });
+
+        assert!(!executor.has_tool("async_echo"));
+        executor.register_async_tool("async_echo", func);
+        assert!(executor.has_tool("async_echo"));
+        assert!(executor.tool_names().contains(&"async_echo"));
+
+        let result = executor.execute("call_1", "async_echo", serde_json::json!({"msg": "hello"}));
+
+        assert!(result.is_success());
+        assert!(result.result.unwrap().contains("hello"));
+    }
+
+    #[test]
+    fn test_async_tool_error_categorized() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        let func: AsyncToolFunction = Arc::new(|_| -> ToolFuture {
+            Box::pin(async { Err("Permission denied: '/etc/shadow'".to_string()) })
+        });
+        executor.register_async_tool("async_fail", func);
+
+        let result = executor.execute("call_1", "async_fail", serde_json::json!({}));
+
+        assert!(!result.is_success());
+        assert!(matches!(
+            result.error().unwrap().category,
+            ErrorCategory::Permission { .. }
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_async_tool_inside_multi_thread_runtime() {
+        let mut executor = ToolExecutor::with_defaults();
+
+        let func: AsyncToolFunction =
+            Arc::new(|_| -> ToolFuture { Box::pin(async { Ok("driven".to_string()) }) });
+        executor.register_async_tool("async_ok", func);
+
+        let result = executor.execute("call_1", "async_ok", serde_json::json!({}));
+
+        assert!(result.is_success());
+        assert_eq!(result.result.unwrap(), "driven");
+    }
+
+    #[test]
+    fn test_async_registration_shadows_sync() {
+        fn sync_tool(_: Value) -> Result<String, String> {
+            Ok("sync".to_string())
+        }
+
+        let mut executor = ToolExecutor::with_defaults();
+        executor.register_tool("dual", sync_tool);
+        let func: AsyncToolFunction =
+            Arc::new(|_| -> ToolFuture { Box::pin(async { Ok("async".to_string()) }) });
+        executor.register_async_tool("dual", func);
+
+        let result = executor.execute("call_1", "dual", serde_json::json!({}));
+
+        assert_eq!(result.result.unwrap(), "async");
+        // The name is not listed twice
+        let count = executor
+            .tool_names()
+            .iter()
+            .filter(|name| **name == "dual")
+            .count();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_dry_run_simulates_mutating_tools() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static CALL_COUNT: AtomicU32 = AtomicU32::new(0);
+
+        fn counted_bash(_: Value) -> Result<String, String> {
+            CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+            Ok("ran".to_string())
+        }
+
+        CALL_COUNT.store(0, Ordering::SeqCst);
+
+        let config = ToolExecutorConfig {
+            dry_run: true,
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("bash", counted_bash);
+
+        let result = executor.execute("call_1", "bash", serde_json::json!({"command": "make"}));
+
+        assert!(result.is_success());
+        assert_eq!(result.result.unwrap(), "[DRY RUN] would run: make");
+        // The real tool function never ran
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_dry_run_read_only_tools_still_execute() {
+        fn reader(_: Value) -> Result<String, String> {
+            Ok("real contents".to_string())
+        }
+
+        let config = ToolExecutorConfig {
+            dry_run: true,
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("read_file", reader);
+
+        let result = executor.execute("call_1", "read_file", serde_json::json!({"path": "x"}));
+
+        assert_eq!(result.result.unwrap(), "real contents");
+    }
+
+    #[test]
+    fn test_set_dry_run_toggles_live() {
+        fn bash_stub(_: Value) -> Result<String, String> {
+            Ok("ran".to_string())
+        }
+
+        let mut executor = ToolExecutor::with_defaults();
+        executor.register_tool("bash", bash_stub);
+        assert!(!executor.config().dry_run);
+
+        executor.set_dry_run(true);
+        let simulated = executor.execute("call_1", "bash", serde_json::json!({"command": "ls"}));
+        assert!(simulated.result.unwrap().starts_with("[DRY RUN]"));
+
+        executor.set_dry_run(false);
+        let real = executor.execute("call_2", "bash", serde_json::json!({"command": "ls"}));
+        assert_eq!(real.result.unwrap(), "ran");
+    }
+
     #[test]
     fn test_tool_executor_config_defaults() {
         let config = ToolExecutorConfig::default();
+        assert!(!config.dry_run);
         assert_eq!(config.max_retries, 3);
         assert_eq!(config.base_retry_delay_ms, 1000);
         assert_eq!(config.max_retry_delay_ms, 10000);
@@ -802,6 +2093,7 @@ mod tests {
             max_retry_delay_ms: 5000,
             auto_fix_enabled: false,
             execution_timeout_ms: 60000,
+            ..Default::default()
         };
 
         let executor = ToolExecutor::new(config.clone());
@@ -818,6 +2110,7 @@ mod tests {
             result: Ok("output".to_string()),
             duration: Duration::from_millis(100),
             retries: 0,
+            hook_failures: Vec::new(),
         };
 
         assert!(success_result.is_success());
@@ -830,6 +2123,7 @@ mod tests {
             result: Err(ToolError::new("cannot find crate `foo`")),
             duration: Duration::from_millis(50),
             retries: 0,
+            hook_failures: Vec::new(),
         };
 
         assert!(!error_result.is_success());
@@ -882,41 +2176,460 @@ mod tests {
     }
 
     #[test]
-    fn test_retry_delay_calculation() {
+    fn test_category_allows_retry() {
+        assert!(ErrorCategory::Network {
+            kind: NetworkErrorKind::ConnectionFailed
+        }
+        .allows_retry());
+        assert!(!ErrorCategory::Network {
+            kind: NetworkErrorKind::Dns
+        }
+        .allows_retry());
+        assert!(ErrorCategory::Resource {
+            resource_type: "temporary".to_string()
+        }
+        .allows_retry());
+        assert!(!ErrorCategory::Resource {
+            resource_type: "disk_full".to_string()
+        }
+        .allows_retry());
+        assert!(!ErrorCategory::Code {
+            error_type: "type_error".to_string()
+        }
+        .allows_retry());
+        assert!(!ErrorCategory::Permission {
+            resource: "/etc".to_string()
+        }
+        .allows_retry());
+        assert!(!ErrorCategory::TestFailure {
+            failed: 1,
+            total: 2
+        }
+        .allows_retry());
+        assert!(!ErrorCategory::Unknown.allows_retry());
+    }
+
+    #[test]
+    fn test_retry_transient_network_error_then_success() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static FAILURES_LEFT: AtomicU32 = AtomicU32::new(0);
+
+        fn flaky_tool(_: Value) -> Result<String, String> {
+            if FAILURES_LEFT.fetch_sub(1, Ordering::SeqCst) > 0 {
+                Err("Connection refused: could not reach registry".to_string())
+            } else {
+                Ok("downloaded".to_string())
+            }
+        }
+
+        FAILURES_LEFT.store(2, Ordering::SeqCst);
+
         let config = ToolExecutorConfig {
-            base_retry_delay_ms: 1000,
-            max_retry_delay_ms: 10000,
+            base_retry_delay_ms: 1,
+            max_retry_delay_ms: 2,
             ..Default::default()
         };
-        let executor = ToolExecutor::new(config);
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("fetch", flaky_tool);
+
+        let result = executor.execute("call_1", "fetch", serde_json::json!({}));
+
+        assert!(result.is_success());
+        assert_eq!(result.retries, 2);
+    }
+
+    #[test]
+    fn test_permission_error_never_retried() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static CALL_COUNT: AtomicU32 = AtomicU32::new(0);
+
+        fn denied_tool(_: Value) -> Result<String, String> {
+            CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+            Err("Permission denied: '/etc/shadow'".to_string())
+        }
+
+        CALL_COUNT.store(0, Ordering::SeqCst);
+
+        let config = ToolExecutorConfig {
+            base_retry_delay_ms: 1,
+            max_retry_delay_ms: 2,
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("denied", denied_tool);
+
+        let result = executor.execute("call_1", "denied", serde_json::json!({}));
+
+        assert!(!result.is_success());
+        assert_eq!(result.retries, 0);
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_on_retry_notifier_receives_attempts() {
+        use std::sync::Mutex;
+
+        fn always_refused(_: Value) -> Result<String, String> {
+            Err("Connection refused".to_string())
+        }
+
+        let seen: Arc<Mutex<Vec<(String, u32, u32)>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen_hook = Arc::clone(&seen);
+
+        let config = ToolExecutorConfig {
+            max_retries: 2,
+            base_retry_delay_ms: 1,
+            max_retry_delay_ms: 2,
+            on_retry: Some(Arc::new(move |tool, attempt, max, delay| {
+                assert!(delay > Duration::ZERO);
+                seen_hook
+                    .lock()
+                    .unwrap()
+                    .push((tool.to_string(), attempt, max));
+            })),
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("flaky", always_refused);
+
+        let result = executor.execute("call_1", "flaky", serde_json::json!({}));
 
-        // retry 1: 1000 * 2^0 = 1000
+        assert!(!result.is_success());
+        assert_eq!(result.retries, 2);
         assert_eq!(
-            executor.calculate_retry_delay(1),
-            Duration::from_millis(1000)
+            *seen.lock().unwrap(),
+            vec![("flaky".to_string(), 1, 2), ("flaky".to_string(), 2, 2)]
         );
-        // retry 2: 1000 * 2^1 = 2000
+    }
+
+    #[test]
+    fn test_retry_delay_jitter_within_bounds() {
+        let executor = ToolExecutor::with_defaults();
+
+        // Second retry: capped exponential delay is 2000ms, jitter adds
+        // at most 25% on top
+        for _ in 0..10 {
+            let delay = executor.calculate_retry_delay(2);
+            assert!(delay >= Duration::from_millis(2000), "delay: {:?}", delay);
+            assert!(delay <= Duration::from_millis(2500), "delay: {:?}", delay);
+        }
+    }
+
+    #[test]
+    fn test_before_execute_hook_aborts_execution() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static CALL_COUNT: AtomicU32 = AtomicU32::new(0);
+
+        fn counted_tool(_: Value) -> Result<String, String> {
+            CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+            Ok("ok".to_string())
+        }
+
+        CALL_COUNT.store(0, Ordering::SeqCst);
+
+        let config = ToolExecutorConfig {
+            before_execute: Some(Arc::new(|name, _input| {
+                Err(format!("dry run: {} not executed", name))
+            })),
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("counted", counted_tool);
+
+        let result = executor.execute("call_1", "counted", serde_json::json!({}));
+
+        assert!(!result.is_success());
+        assert!(result
+            .error()
+            .unwrap()
+            .message
+            .contains("dry run: counted not executed"));
+        // The tool function was never called
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_before_execute_hook_allows_execution() {
+        fn ok_tool(_: Value) -> Result<String, String> {
+            Ok("ok".to_string())
+        }
+
+        let config = ToolExecutorConfig {
+            before_execute: Some(Arc::new(|_, _| Ok(()))),
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("ok_tool", ok_tool);
+
+        let result = executor.execute("call_1", "ok_tool", serde_json::json!({}));
+
+        assert!(result.is_success());
+    }
+
+    #[test]
+    fn test_after_execute_hook_runs_on_success_and_failure() {
+        use std::sync::Mutex;
+
+        fn ok_tool(_: Value) -> Result<String, String> {
+            Ok("ok".to_string())
+        }
+        fn failing_tool(_: Value) -> Result<String, String> {
+            Err("something went wrong".to_string())
+        }
+
+        let seen: Arc<Mutex<Vec<(String, bool)>>> = Arc::new(Mutex::new(Vec::new()));
+        let seen_hook = Arc::clone(&seen);
+
+        let config = ToolExecutorConfig {
+            after_execute: Some(Arc::new(move |name, result| {
+                seen_hook
+                    .lock()
+                    .unwrap()
+                    .push((name.to_string(), result.is_success()));
+            })),
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("ok_tool", ok_tool);
+        executor.register_tool("failing_tool", failing_tool);
+
+        executor.execute("call_1", "ok_tool", serde_json::json!({}));
+        executor.execute("call_2", "failing_tool", serde_json::json!({}));
+        executor.execute("call_3", "unknown_tool", serde_json::json!({}));
+
+        let seen = seen.lock().unwrap();
         assert_eq!(
-            executor.calculate_retry_delay(2),
-            Duration::from_millis(2000)
+            *seen,
+            vec![
+                ("ok_tool".to_string(), true),
+                ("failing_tool".to_string(), false),
+                ("unknown_tool".to_string(), false),
+            ]
         );
-        // retry 3: 1000 * 2^2 = 4000
+    }
+
+    #[test]
+    fn test_pre_tool_use_hook_blocks_execution() {
+        use crate::config::HooksConfig;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static CALL_COUNT: AtomicU32 = AtomicU32::new(0);
+
+        fn gated_tool(_: Value) -> Result<String, String> {
+            CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+            Ok("ok".to_string())
+        }
+
+        CALL_COUNT.store(0, Ordering::SeqCst);
+
+        let config = ToolExecutorConfig {
+            hooks: HookRunner::new(HooksConfig {
+                pre_tool_use: vec![
+                    "echo 'edits to generated files are blocked' >&2; exit 1".to_string()
+                ],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("gated", gated_tool);
+
+        let result = executor.execute("call_1", "gated", serde_json::json!({}));
+
+        assert!(!result.is_success());
+        let error = result.error().unwrap();
+        assert!(error.message.contains("Blocked by pre_tool_use hook"));
+        assert!(error.message.contains("generated files"));
+        assert!(matches!(error.category, ErrorCategory::Permission { .. }));
+        // The tool function was never called
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_post_tool_use_hook_failure_collected() {
+        use crate::config::HooksConfig;
+
+        fn ok_tool(_: Value) -> Result<String, String> {
+            Ok("ok".to_string())
+        }
+
+        let config = ToolExecutorConfig {
+            hooks: HookRunner::new(HooksConfig {
+                post_tool_use: vec!["exit 2".to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("ok_tool", ok_tool);
+
+        let result = executor.execute("call_1", "ok_tool", serde_json::json!({}));
+
+        // The tool call itself still succeeds; the failure is reported
+        assert!(result.is_success());
+        assert_eq!(result.hook_failures.len(), 1);
+        assert!(result.hook_failures[0].contains("exit code 2"));
+    }
+
+    #[test]
+    fn test_middleware_before_aborts_call() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static CALL_COUNT: AtomicU32 = AtomicU32::new(0);
+
+        fn counted_tool(_: Value) -> Result<String, String> {
+            CALL_COUNT.fetch_add(1, Ordering::SeqCst);
+            Ok("ok".to_string())
+        }
+
+        struct Blocker;
+        impl ToolMiddleware for Blocker {
+            fn before(&self, call: &ToolCallInfo) -> ControlFlow<String> {
+                ControlFlow::Break(format!("{} blocked by middleware", call.tool_name))
+            }
+        }
+
+        CALL_COUNT.store(0, Ordering::SeqCst);
+
+        let mut executor = ToolExecutor::with_defaults();
+        executor.register_tool("counted", counted_tool);
+        executor.add_middleware(Arc::new(Blocker));
+
+        let result = executor.execute("call_1", "counted", serde_json::json!({}));
+
+        assert!(!result.is_success());
+        assert!(result
+            .error()
+            .unwrap()
+            .message
+            .contains("counted blocked by middleware"));
+        // The tool function was never called
+        assert_eq!(CALL_COUNT.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_middleware_before_runs_per_attempt() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+        use std::sync::Mutex;
+
+        static CALL_COUNT: AtomicU32 = AtomicU32::new(0);
+
+        fn flaky_tool(_: Value) -> Result<String, String> {
+            if CALL_COUNT.fetch_add(1, Ordering::SeqCst) == 0 {
+                Err("Connection timed out".to_string())
+            } else {
+                Ok("ok".to_string())
+            }
+        }
+
+        struct AttemptRecorder {
+            attempts: Mutex<Vec<u32>>,
+        }
+        impl ToolMiddleware for AttemptRecorder {
+            fn before(&self, call: &ToolCallInfo) -> ControlFlow<String> {
+                self.attempts.lock().unwrap().push(call.attempt);
+                ControlFlow::Continue(())
+            }
+        }
+
+        CALL_COUNT.store(0, Ordering::SeqCst);
+
+        let config = ToolExecutorConfig {
+            base_retry_delay_ms: 1,
+            max_retry_delay_ms: 10,
+            ..Default::default()
+        };
+        let mut executor = ToolExecutor::new(config);
+        executor.register_tool("flaky", flaky_tool);
+        let recorder = Arc::new(AttemptRecorder {
+            attempts: Mutex::new(Vec::new()),
+        });
+        executor.add_middleware(Arc::clone(&recorder) as Arc<dyn ToolMiddleware>);
+
+        let result = executor.execute("call_1", "flaky", serde_json::json!({}));
+
+        assert!(result.is_success());
+        // One `before` per attempt: the first call and the retry
+        assert_eq!(*recorder.attempts.lock().unwrap(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_middleware_after_sees_every_outcome() {
+        use std::sync::Mutex;
+
+        fn ok_tool(_: Value) -> Result<String, String> {
+            Ok("ok".to_string())
+        }
+
+        struct Recorder {
+            seen: Mutex<Vec<(String, bool)>>,
+        }
+        impl ToolMiddleware for Recorder {
+            fn after(&self, call: &ToolCallInfo, result: &ToolExecutionResult) {
+                self.seen
+                    .lock()
+                    .unwrap()
+                    .push((call.tool_name.clone(), result.is_success()));
+            }
+        }
+
+        let mut executor = ToolExecutor::with_defaults();
+        executor.register_tool("ok_tool", ok_tool);
+        let recorder = Arc::new(Recorder {
+            seen: Mutex::new(Vec::new()),
+        });
+        executor.add_middleware(Arc::clone(&recorder) as Arc<dyn ToolMiddleware>);
+
+        executor.execute("call_1", "ok_tool", serde_json::json!({}));
+        executor.execute("call_2", "unknown_tool", serde_json::json!({}));
+
+        let seen = recorder.seen.lock().unwrap();
         assert_eq!(
-            executor.calculate_retry_delay(3),
-            Duration::from_millis(4000)
+            *seen,
+            vec![
+                ("ok_tool".to_string(), true),
+                ("unknown_tool".to_string(), false),
+            ]
         );
-        // retry 4: 1000 * 2^3 = 8000
-        assert_eq!(
-            executor.calculate_retry_delay(4),
-            Duration::from_millis(8000)
+    }
+
+    /// Assert a delay sits within [expected, expected + 25% jitter].
+    fn assert_delay_in_jitter_range(delay: Duration, expected_ms: u64) {
+        assert!(
+            delay >= Duration::from_millis(expected_ms),
+            "delay {:?} below base {}ms",
+            delay,
+            expected_ms
         );
-        // retry 5: 1000 * 2^4 = 16000, capped at 10000
-        assert_eq!(
-            executor.calculate_retry_delay(5),
-            Duration::from_millis(10000)
+        assert!(
+            delay <= Duration::from_millis(expected_ms + expected_ms / 4),
+            "delay {:?} above base {}ms plus jitter",
+            delay,
+            expected_ms
         );
     }
 
+    #[test]
+    fn test_retry_delay_calculation() {
+        let config = ToolExecutorConfig {
+            base_retry_delay_ms: 1000,
+            max_retry_delay_ms: 10000,
+            ..Default::default()
+        };
+        let executor = ToolExecutor::new(config);
+
+        // Exponential sequence 1000, 2000, 4000, 8000, capped at 10000,
+        // each with up to 25% jitter on top
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(1), 1000);
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(2), 2000);
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(3), 4000);
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(4), 8000);
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(5), 10000);
+    }
+
     #[test]
     fn test_retry_backoff_timing() {
         // Test that exponential backoff works correctly (mirrors spec requirement)
@@ -928,30 +2641,12 @@ mod tests {
         let executor = ToolExecutor::new(config);
 
         // Verify backoff sequence: 100, 200, 400, 800, capped at 1000
-        assert_eq!(
-            executor.calculate_retry_delay(1),
-            Duration::from_millis(100)
-        );
-        assert_eq!(
-            executor.calculate_retry_delay(2),
-            Duration::from_millis(200)
-        );
-        assert_eq!(
-            executor.calculate_retry_delay(3),
-            Duration::from_millis(400)
-        );
-        assert_eq!(
-            executor.calculate_retry_delay(4),
-            Duration::from_millis(800)
-        );
-        assert_eq!(
-            executor.calculate_retry_delay(5),
-            Duration::from_millis(1000)
-        ); // capped
-        assert_eq!(
-            executor.calculate_retry_delay(10),
-            Duration::from_millis(1000)
-        ); // still capped
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(1), 100);
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(2), 200);
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(3), 400);
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(4), 800);
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(5), 1000); // capped
+        assert_delay_in_jitter_range(executor.calculate_retry_delay(10), 1000); // still capped
     }
 
     #[test]
@@ -993,7 +2688,9 @@ mod tests {
         assert!(error.message.contains("Connection refused"));
         assert!(matches!(
             error.category,
-            ErrorCategory::Network { is_transient: true }
+            ErrorCategory::Network {
+                kind: NetworkErrorKind::ConnectionFailed
+            }
         ));
     }
 
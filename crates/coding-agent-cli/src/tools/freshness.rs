@@ -0,0 +1,156 @@
+//! Stale-file detection for write_file and edit_file.
+//!
+//! Tracks a content hash per file from the last time the agent read or
+//! wrote it. When a later write/edit targets a file whose content on
+//! disk no longer matches that hash — the user edited it in their own
+//! editor, a formatter ran — the tool fails with a "re-read before
+//! editing" error so the model refreshes its view instead of clobbering
+//! the external change. `tools.stale_check_strict = false` downgrades
+//! the failure to a warning on the tool result.
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Whether a stale file blocks the write (from `tools.stale_check_strict`).
+static STALE_CHECK_STRICT: AtomicBool = AtomicBool::new(true);
+
+/// Content hash of each file as of the agent's last read or write,
+/// keyed by canonical path.
+static FILE_SNAPSHOTS: Mutex<Option<HashMap<PathBuf, u64>>> = Mutex::new(None);
+
+/// Set whether stale files block writes, from `tools.stale_check_strict`.
+pub fn set_stale_check_strict(strict: bool) {
+    STALE_CHECK_STRICT.store(strict, Ordering::Relaxed);
+}
+
+/// The verdict on a write/edit target's freshness.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Freshness {
+    /// The file matches the agent's last view of it (or was never read).
+    Fresh,
+    /// The file changed outside the agent; strict mode blocked the write.
+    Stale(String),
+    /// The file changed outside the agent; warn-only mode let it through.
+    StaleWarning(String),
+}
+
+/// Record the file's current content hash after a read or write.
+///
+/// Missing or unreadable files clear any existing snapshot so a file
+/// deleted and recreated externally is not flagged forever.
+pub fn note_file_seen(path: &Path) {
+    let key = snapshot_key(path);
+    let mut snapshots = FILE_SNAPSHOTS.lock().unwrap();
+    let snapshots = snapshots.get_or_insert_with(HashMap::new);
+    match std::fs::read(path) {
+        Ok(bytes) => {
+            snapshots.insert(key, content_hash(&bytes));
+        }
+        Err(_) => {
+            snapshots.remove(&key);
+        }
+    }
+}
+
+/// Check whether a write/edit target changed outside the agent since
+/// the last read or write.
+///
+/// Files the agent has never seen pass unchecked — writing a file blind
+/// was always allowed and the model has no view to be stale.
+pub fn check_freshness(path: &Path) -> Freshness {
+    let recorded = {
+        let snapshots = FILE_SNAPSHOTS.lock().unwrap();
+        snapshots
+            .as_ref()
+            .and_then(|s| s.get(&snapshot_key(path)).copied())
+    };
+    let Some(recorded) = recorded else {
+        return Freshness::Fresh;
+    };
+    let Ok(bytes) = std::fs::read(path) else {
+        return Freshness::Fresh;
+    };
+    if content_hash(&bytes) == recorded {
+        return Freshness::Fresh;
+    }
+
+    let message = format!(
+        "{} was externally modified since it was last read; \
+         re-read the file before editing it",
+        path.display()
+    );
+    if STALE_CHECK_STRICT.load(Ordering::Relaxed) {
+        Freshness::Stale(message)
+    } else {
+        Freshness::StaleWarning(message)
+    }
+}
+
+/// Canonical key for a file so relative and absolute paths agree.
+fn snapshot_key(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Hash a file's bytes with the std hasher; collision resistance is
+/// irrelevant here, only "did the content change".
+fn content_hash(bytes: &[u8]) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unseen_file_is_fresh() {
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("never_read.txt");
+        std::fs::write(&path, "content").expect("Should write");
+
+        assert_eq!(check_freshness(&path), Freshness::Fresh);
+    }
+
+    #[test]
+    fn test_external_modification_detected() {
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("file.txt");
+        std::fs::write(&path, "original").expect("Should write");
+
+        note_file_seen(&path);
+        std::fs::write(&path, "changed behind the agent's back").expect("Should write");
+
+        match check_freshness(&path) {
+            Freshness::Stale(msg) => assert!(msg.contains("externally modified")),
+            other => panic!("Expected Stale, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_re_reading_clears_staleness() {
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("file.txt");
+        std::fs::write(&path, "original").expect("Should write");
+
+        note_file_seen(&path);
+        std::fs::write(&path, "changed").expect("Should write");
+        note_file_seen(&path);
+
+        assert_eq!(check_freshness(&path), Freshness::Fresh);
+    }
+
+    #[test]
+    fn test_unchanged_file_stays_fresh() {
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("file.txt");
+        std::fs::write(&path, "stable").expect("Should write");
+
+        note_file_seen(&path);
+
+        assert_eq!(check_freshness(&path), Freshness::Fresh);
+    }
+}
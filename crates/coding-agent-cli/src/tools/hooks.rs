@@ -0,0 +1,422 @@
+//! User-defined lifecycle hooks.
+//!
+//! Hooks are shell commands configured in the `[hooks]` config section that
+//! run at lifecycle events: before and after each tool call, after each
+//! conversation turn, and at session end. Each command receives a JSON
+//! payload on stdin describing the event. A `pre_tool_use` hook that exits
+//! non-zero blocks the tool call; failures of other hooks are reported but
+//! never abort the turn.
+//!
+//! Commands may use the `{formatter}` placeholder, which expands to the
+//! detected project stack's formatter (`cargo fmt`, `npx prettier --write
+//! .`, …) so one hook config works across Rust, TypeScript, Python, and
+//! Go repositories.
+
+use crate::config::HooksConfig;
+use serde_json::Value;
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Lifecycle events that can trigger hooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    /// Before a tool call; hooks can block the call.
+    PreToolUse,
+    /// After a tool call completes (success or failure).
+    PostToolUse,
+    /// After a conversation turn finishes.
+    PostTurn,
+    /// When the session ends.
+    SessionEnd,
+}
+
+impl HookEvent {
+    /// The event name as it appears in config and in the JSON payload.
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookEvent::PreToolUse => "pre_tool_use",
+            HookEvent::PostToolUse => "post_tool_use",
+            HookEvent::PostTurn => "post_turn",
+            HookEvent::SessionEnd => "session_end",
+        }
+    }
+}
+
+/// Outcome of running a single hook command.
+#[derive(Debug, Clone)]
+pub struct HookOutcome {
+    /// The shell command that was run.
+    pub command: String,
+
+    /// The exit code, if the command ran to completion.
+    pub exit_code: Option<i32>,
+
+    /// Captured stderr output.
+    pub stderr: String,
+
+    /// Whether the command was killed after exceeding the timeout.
+    pub timed_out: bool,
+}
+
+impl HookOutcome {
+    /// Whether the hook completed with exit code 0.
+    pub fn is_success(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+
+    /// Human-readable description of a failure for display in the REPL.
+    pub fn describe(&self) -> String {
+        if self.timed_out {
+            return format!("`{}` timed out", self.command);
+        }
+        let reason = match self.exit_code {
+            Some(code) => format!("exit code {}", code),
+            None => "failed to run".to_string(),
+        };
+        let stderr = self.stderr.trim();
+        if stderr.is_empty() {
+            format!("`{}`: {}", self.command, reason)
+        } else {
+            format!("`{}`: {} ({})", self.command, reason, stderr)
+        }
+    }
+}
+
+/// Runs configured hook commands for lifecycle events.
+#[derive(Debug, Clone, Default)]
+pub struct HookRunner {
+    config: HooksConfig,
+}
+
+impl HookRunner {
+    /// Create a runner from the `[hooks]` config section.
+    pub fn new(config: HooksConfig) -> Self {
+        Self { config }
+    }
+
+    /// Whether any hooks are configured for the given event.
+    pub fn has_hooks(&self, event: HookEvent) -> bool {
+        !self.commands(event).is_empty()
+    }
+
+    /// The commands configured for an event.
+    fn commands(&self, event: HookEvent) -> &[String] {
+        match event {
+            HookEvent::PreToolUse => &self.config.pre_tool_use,
+            HookEvent::PostToolUse => &self.config.post_tool_use,
+            HookEvent::PostTurn => &self.config.post_turn,
+            HookEvent::SessionEnd => &self.config.session_end,
+        }
+    }
+
+    /// Run all hooks for an event, passing the payload on stdin.
+    ///
+    /// Returns one outcome per configured command, in order.
+    pub fn run(&self, event: HookEvent, payload: &Value) -> Vec<HookOutcome> {
+        let commands = self.commands(event);
+        if commands.is_empty() {
+            return Vec::new();
+        }
+
+        let payload = payload.to_string();
+        commands
+            .iter()
+            .map(|command| self.run_one(command, &payload))
+            .collect()
+    }
+
+    /// Run the `pre_tool_use` hooks for a tool call.
+    ///
+    /// Returns `Err` with a description if any hook exits non-zero, which
+    /// blocks the call. Hooks that time out or fail to spawn do not block.
+    pub fn check_pre_tool_use(&self, tool_name: &str, input: &Value) -> Result<(), String> {
+        let payload = serde_json::json!({
+            "event": HookEvent::PreToolUse.name(),
+            "tool_name": tool_name,
+            "input": input,
+        });
+
+        for outcome in self.run(HookEvent::PreToolUse, &payload) {
+            if let Some(code) = outcome.exit_code {
+                if code != 0 {
+                    return Err(format!(
+                        "Blocked by pre_tool_use hook {}",
+                        outcome.describe()
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run the `post_tool_use` hooks for a completed tool call.
+    ///
+    /// Returns descriptions of any hook failures for display.
+    pub fn report_post_tool_use(
+        &self,
+        tool_name: &str,
+        input: &Value,
+        result: &Result<String, String>,
+    ) -> Vec<String> {
+        if !self.has_hooks(HookEvent::PostToolUse) {
+            return Vec::new();
+        }
+
+        let payload = serde_json::json!({
+            "event": HookEvent::PostToolUse.name(),
+            "tool_name": tool_name,
+            "input": input,
+            "result": match result {
+                Ok(output) => serde_json::json!({ "success": true, "output": output }),
+                Err(error) => serde_json::json!({ "success": false, "error": error }),
+            },
+        });
+
+        self.run(HookEvent::PostToolUse, &payload)
+            .into_iter()
+            .filter(|outcome| !outcome.is_success())
+            .map(|outcome| outcome.describe())
+            .collect()
+    }
+
+    /// Run a single hook command with the payload on stdin.
+    fn run_one(&self, command: &str, payload: &str) -> HookOutcome {
+        let command = &expand_placeholders(command, crate::project::primary_type());
+        let timeout = Duration::from_secs(self.config.timeout_secs);
+
+        let mut child = match Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(e) => {
+                return HookOutcome {
+                    command: command.to_string(),
+                    exit_code: None,
+                    stderr: e.to_string(),
+                    timed_out: false,
+                };
+            }
+        };
+
+        // Write the payload and close stdin so the hook sees EOF
+        if let Some(mut stdin) = child.stdin.take() {
+            let _ = stdin.write_all(payload.as_bytes());
+        }
+
+        // Drain stderr on a separate thread so a chatty hook can't block
+        let stderr_handle = child.stderr.take();
+        let stderr_reader = std::thread::spawn(move || {
+            let mut buf = String::new();
+            if let Some(mut stderr) = stderr_handle {
+                let _ = stderr.read_to_string(&mut buf);
+            }
+            buf
+        });
+
+        // Poll for completion, killing the hook if it exceeds the timeout
+        let deadline = Instant::now() + timeout;
+        let (exit_code, timed_out) = loop {
+            match child.try_wait() {
+                Ok(Some(status)) => break (status.code(), false),
+                Ok(None) => {
+                    if Instant::now() >= deadline {
+                        let _ = child.kill();
+                        let _ = child.wait();
+                        break (None, true);
+                    }
+                    std::thread::sleep(Duration::from_millis(10));
+                }
+                Err(_) => break (None, false),
+            }
+        };
+
+        // A killed shell can leave grandchildren holding the stderr pipe,
+        // which would block the reader until they exit — don't wait for it.
+        let stderr = if timed_out {
+            String::new()
+        } else {
+            stderr_reader.join().unwrap_or_default()
+        };
+
+        HookOutcome {
+            command: command.to_string(),
+            exit_code,
+            stderr,
+            timed_out,
+        }
+    }
+}
+
+/// Expand the `{formatter}` placeholder to the stack's formatter command.
+///
+/// Without a detected stack the placeholder becomes the shell no-op
+/// `true`, so a shared hook config does not fail in unrecognized repos.
+fn expand_placeholders(command: &str, stack: Option<crate::project::ProjectType>) -> String {
+    if !command.contains("{formatter}") {
+        return command.to_string();
+    }
+    let formatter = stack.map(|s| s.formatter_command()).unwrap_or("true");
+    command.replace("{formatter}", formatter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn runner(config: HooksConfig) -> HookRunner {
+        HookRunner::new(config)
+    }
+
+    #[test]
+    fn test_expand_formatter_placeholder() {
+        // Arrange & Act & Assert: stack-specific expansion
+        assert_eq!(
+            expand_placeholders("{formatter}", Some(crate::project::ProjectType::TypeScript)),
+            "npx prettier --write ."
+        );
+        // No stack falls back to a shell no-op
+        assert_eq!(expand_placeholders("{formatter}", None), "true");
+        // Commands without the placeholder pass through untouched
+        assert_eq!(
+            expand_placeholders("cargo fmt", Some(crate::project::ProjectType::Python)),
+            "cargo fmt"
+        );
+    }
+
+    #[test]
+    fn test_no_hooks_configured() {
+        let runner = runner(HooksConfig::default());
+
+        assert!(!runner.has_hooks(HookEvent::PreToolUse));
+        assert!(runner
+            .check_pre_tool_use("bash", &serde_json::json!({}))
+            .is_ok());
+        assert!(runner
+            .run(HookEvent::PostTurn, &serde_json::json!({}))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_pre_tool_use_hook_allows() {
+        let runner = runner(HooksConfig {
+            pre_tool_use: vec!["exit 0".to_string()],
+            ..Default::default()
+        });
+
+        let result = runner.check_pre_tool_use("edit_file", &serde_json::json!({}));
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_pre_tool_use_hook_denies() {
+        let runner = runner(HooksConfig {
+            pre_tool_use: vec!["echo 'generated file' >&2; exit 1".to_string()],
+            ..Default::default()
+        });
+
+        let result = runner.check_pre_tool_use("edit_file", &serde_json::json!({}));
+
+        assert!(result.is_err());
+        let err = result.unwrap_err();
+        assert!(err.contains("Blocked by pre_tool_use hook"));
+        assert!(err.contains("generated file"));
+    }
+
+    #[test]
+    fn test_hook_receives_payload_on_stdin() {
+        // The hook greps stdin for the tool name; exit 1 (deny) when found
+        let runner = runner(HooksConfig {
+            pre_tool_use: vec!["grep -q edit_file && exit 1 || exit 0".to_string()],
+            ..Default::default()
+        });
+
+        let denied = runner.check_pre_tool_use("edit_file", &serde_json::json!({}));
+        let allowed = runner.check_pre_tool_use("read_file", &serde_json::json!({}));
+
+        assert!(denied.is_err());
+        assert!(allowed.is_ok());
+    }
+
+    #[test]
+    fn test_hook_timeout_kills_command() {
+        let runner = runner(HooksConfig {
+            post_tool_use: vec!["sleep 30".to_string()],
+            timeout_secs: 1,
+            ..Default::default()
+        });
+
+        let start = Instant::now();
+        let outcomes = runner.run(HookEvent::PostToolUse, &serde_json::json!({}));
+
+        assert_eq!(outcomes.len(), 1);
+        assert!(outcomes[0].timed_out);
+        assert!(!outcomes[0].is_success());
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_report_post_tool_use_collects_failures() {
+        let runner = runner(HooksConfig {
+            post_tool_use: vec!["exit 0".to_string(), "exit 3".to_string()],
+            ..Default::default()
+        });
+
+        let failures =
+            runner.report_post_tool_use("bash", &serde_json::json!({}), &Ok("done".to_string()));
+
+        assert_eq!(failures.len(), 1);
+        assert!(failures[0].contains("exit code 3"));
+    }
+
+    #[test]
+    fn test_hooks_run_in_order() {
+        let runner = runner(HooksConfig {
+            post_turn: vec!["exit 0".to_string(), "exit 1".to_string()],
+            ..Default::default()
+        });
+
+        let outcomes = runner.run(HookEvent::PostTurn, &serde_json::json!({}));
+
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes[0].is_success());
+        assert!(!outcomes[1].is_success());
+    }
+
+    #[test]
+    fn test_outcome_describe() {
+        let outcome = HookOutcome {
+            command: "cargo fmt".to_string(),
+            exit_code: Some(1),
+            stderr: "rustfmt failed\n".to_string(),
+            timed_out: false,
+        };
+        assert_eq!(
+            outcome.describe(),
+            "`cargo fmt`: exit code 1 (rustfmt failed)"
+        );
+
+        let timed_out = HookOutcome {
+            command: "slow".to_string(),
+            exit_code: None,
+            stderr: String::new(),
+            timed_out: true,
+        };
+        assert_eq!(timed_out.describe(), "`slow` timed out");
+    }
+
+    #[test]
+    fn test_event_names() {
+        assert_eq!(HookEvent::PreToolUse.name(), "pre_tool_use");
+        assert_eq!(HookEvent::PostToolUse.name(), "post_tool_use");
+        assert_eq!(HookEvent::PostTurn.name(), "post_turn");
+        assert_eq!(HookEvent::SessionEnd.name(), "session_end");
+    }
+}
@@ -0,0 +1,298 @@
+//! Middleware around tool execution
+//!
+//! `ToolMiddleware` is the instrumentation extension point for the
+//! executor: registered middlewares run in order around every tool call,
+//! can observe inputs and results, and can abort a call from `before`.
+//! Two built-ins ship with the CLI: [`JsonlLogger`] appends one JSON
+//! record per call to a file, and [`StatsCollector`] accumulates the
+//! per-tool numbers behind the `/stats` command.
+
+use super::executor::ToolExecutionResult;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Everything a middleware gets to see about a tool call
+#[derive(Debug, Clone)]
+pub struct ToolCallInfo {
+    /// The call ID for this execution
+    pub call_id: String,
+    /// The tool being invoked
+    pub tool_name: String,
+    /// The tool input
+    pub input: Value,
+    /// Which attempt this is (0 for the first call, 1+ for retries)
+    pub attempt: u32,
+}
+
+/// Middleware invoked around each tool execution
+///
+/// `before` runs before every attempt, including retries; returning
+/// `ControlFlow::Break(message)` aborts the call with that error.
+/// `after` runs once per execution with the final result, whether the
+/// call succeeded, failed, or was aborted.
+pub trait ToolMiddleware: Send + Sync {
+    /// Observe or gate a tool call before it runs
+    fn before(&self, _call: &ToolCallInfo) -> ControlFlow<String> {
+        ControlFlow::Continue(())
+    }
+
+    /// Observe the final result of a tool call
+    fn after(&self, _call: &ToolCallInfo, _result: &ToolExecutionResult) {}
+}
+
+/// Middleware that appends one JSON record per tool call to a file
+///
+/// Records carry the timestamp, tool name, call ID, outcome, duration,
+/// and retry count. Write failures are logged and never fail the call.
+pub struct JsonlLogger {
+    path: PathBuf,
+}
+
+impl JsonlLogger {
+    /// Create a logger that appends to the given file
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl ToolMiddleware for JsonlLogger {
+    fn after(&self, call: &ToolCallInfo, result: &ToolExecutionResult) {
+        let record = serde_json::json!({
+            "timestamp": crate::integrations::specstory::chrono_now(),
+            "tool": call.tool_name,
+            "call_id": call.call_id,
+            "success": result.is_success(),
+            "duration_ms": result.duration.as_millis() as u64,
+            "retries": result.retries,
+            "error": result.error().map(|e| e.message.clone()),
+        });
+
+        let line = format!("{}\n", record);
+        let written = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| std::io::Write::write_all(&mut file, line.as_bytes()));
+        if let Err(e) = written {
+            tracing::warn!(path = %self.path.display(), error = %e, "Failed to write tool log");
+        }
+    }
+}
+
+/// Per-tool numbers accumulated by the stats collector
+#[derive(Debug, Clone, Default)]
+struct ToolStats {
+    /// Total executions
+    calls: u64,
+    /// Executions that ended in an error
+    errors: u64,
+    /// Retry attempts across all executions
+    retries: u64,
+    /// Duration of each execution, in milliseconds
+    durations_ms: Vec<u64>,
+}
+
+/// Middleware that accumulates in-memory per-tool call statistics
+///
+/// Shared between the executor and the `/stats` command via `Arc`;
+/// `render` produces the command's output.
+#[derive(Default)]
+pub struct StatsCollector {
+    stats: Mutex<HashMap<String, ToolStats>>,
+}
+
+impl StatsCollector {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Render per-tool call counts, error rates, and duration percentiles
+    pub fn render(&self) -> String {
+        let stats = self.stats.lock().unwrap();
+        if stats.is_empty() {
+            return "No tool calls recorded yet.".to_string();
+        }
+
+        let mut names: Vec<&String> = stats.keys().collect();
+        names.sort();
+
+        let mut output = String::from("Tool Call Stats\n");
+        output.push_str(&"─".repeat(50));
+        output.push('\n');
+
+        let mut total = 0;
+        for name in names {
+            let tool = &stats[name];
+            total += tool.calls;
+            let error_rate = (tool.errors as f64 / tool.calls as f64) * 100.0;
+
+            let mut sorted = tool.durations_ms.clone();
+            sorted.sort_unstable();
+
+            let retries = if tool.retries > 0 {
+                format!(
+                    " · {} retr{}",
+                    tool.retries,
+                    if tool.retries == 1 { "y" } else { "ies" }
+                )
+            } else {
+                String::new()
+            };
+
+            output.push_str(&format!(
+                "  {:<14} {} call{} · {} error{} ({:.1}%){} · p50 {}ms · p95 {}ms\n",
+                name,
+                tool.calls,
+                if tool.calls == 1 { "" } else { "s" },
+                tool.errors,
+                if tool.errors == 1 { "" } else { "s" },
+                error_rate,
+                retries,
+                percentile(&sorted, 0.50),
+                percentile(&sorted, 0.95),
+            ));
+        }
+
+        output.push_str(&"─".repeat(50));
+        output.push_str(&format!("\nTotal: {} calls", total));
+        output
+    }
+}
+
+impl ToolMiddleware for StatsCollector {
+    fn after(&self, call: &ToolCallInfo, result: &ToolExecutionResult) {
+        let mut stats = self.stats.lock().unwrap();
+        let tool = stats.entry(call.tool_name.clone()).or_default();
+        tool.calls += 1;
+        if !result.is_success() {
+            tool.errors += 1;
+        }
+        tool.retries += u64::from(result.retries);
+        tool.durations_ms.push(result.duration.as_millis() as u64);
+    }
+}
+
+/// Nearest-rank percentile over an already-sorted slice
+fn percentile(sorted: &[u64], p: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = ((sorted.len() as f64) * p).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::executor::ToolError;
+    use std::time::Duration;
+
+    fn call_info(tool: &str) -> ToolCallInfo {
+        ToolCallInfo {
+            call_id: "call_1".to_string(),
+            tool_name: tool.to_string(),
+            input: serde_json::json!({}),
+            attempt: 0,
+        }
+    }
+
+    fn result_with(tool: &str, result: Result<String, ToolError>, ms: u64) -> ToolExecutionResult {
+        ToolExecutionResult {
+            tool_name: tool.to_string(),
+            call_id: "call_1".to_string(),
+            result,
+            duration: Duration::from_millis(ms),
+            retries: 0,
+            hook_failures: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_percentile_nearest_rank() {
+        let sorted = vec![10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
+        assert_eq!(percentile(&sorted, 0.50), 50);
+        assert_eq!(percentile(&sorted, 0.95), 100);
+        assert_eq!(percentile(&[], 0.50), 0);
+        assert_eq!(percentile(&[42], 0.95), 42);
+    }
+
+    #[test]
+    fn test_stats_collector_counts_and_error_rate() {
+        let collector = StatsCollector::new();
+        let call = call_info("bash");
+
+        collector.after(&call, &result_with("bash", Ok("ok".to_string()), 100));
+        collector.after(&call, &result_with("bash", Ok("ok".to_string()), 300));
+        collector.after(
+            &call,
+            &result_with("bash", Err(ToolError::new("boom")), 200),
+        );
+
+        let output = collector.render();
+        assert!(output.contains("bash"));
+        assert!(output.contains("3 calls"));
+        assert!(output.contains("1 error (33.3%)"));
+        assert!(output.contains("p50 200ms"));
+        assert!(output.contains("Total: 3 calls"));
+    }
+
+    #[test]
+    fn test_stats_collector_tracks_retries() {
+        let collector = StatsCollector::new();
+        let call = call_info("fetch_url");
+
+        let mut retried = result_with("fetch_url", Ok("ok".to_string()), 100);
+        retried.retries = 2;
+        collector.after(&call, &retried);
+        collector.after(&call, &result_with("fetch_url", Ok("ok".to_string()), 100));
+
+        let output = collector.render();
+        assert!(output.contains("2 retries"), "got: {}", output);
+    }
+
+    #[test]
+    fn test_stats_collector_empty_render() {
+        let collector = StatsCollector::new();
+        assert_eq!(collector.render(), "No tool calls recorded yet.");
+    }
+
+    #[test]
+    fn test_jsonl_logger_appends_records() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = temp_dir.path().join("tools.jsonl");
+        let logger = JsonlLogger::new(path.clone());
+
+        let call = call_info("read_file");
+        logger.after(&call, &result_with("read_file", Ok("ok".to_string()), 5));
+        logger.after(
+            &call,
+            &result_with("read_file", Err(ToolError::new("no")), 7),
+        );
+
+        let content = std::fs::read_to_string(&path).expect("Should read log");
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2);
+
+        let first: Value = serde_json::from_str(lines[0]).expect("Valid JSON");
+        assert_eq!(first["tool"], "read_file");
+        assert_eq!(first["success"], true);
+        let second: Value = serde_json::from_str(lines[1]).expect("Valid JSON");
+        assert_eq!(second["success"], false);
+        assert_eq!(second["error"], "no");
+    }
+
+    #[test]
+    fn test_default_middleware_continues() {
+        struct Noop;
+        impl ToolMiddleware for Noop {}
+
+        let flow = Noop.before(&call_info("bash"));
+        assert!(matches!(flow, ControlFlow::Continue(())));
+    }
+}
@@ -10,15 +10,42 @@
 //! - Regression test generation to prevent fix reversions
 
 mod auto_fix;
+mod cargo_tools;
 mod definitions;
 mod diagnostics;
+mod doc_search;
 mod executor;
+mod freshness;
+mod hooks;
+mod middleware;
+mod progress;
 mod regression_tests;
+mod write_validator;
 
-pub use auto_fix::FixApplicationResult;
-pub use definitions::{create_tool_definitions, execute_tool, tool_definitions_to_api};
-pub use diagnostics::{extract_fix_info, parse_compiler_output, Diagnostic, FixInfo, FixType};
+pub use auto_fix::{compute_file_change, FileChange, FixApplicationResult};
+pub use definitions::{
+    bash_async, code_search_async, code_search_backend, create_tool_definitions, doc_search_async,
+    execute_tool, kill_running_children, set_bash_timeout_secs, set_doc_paths,
+    set_max_file_size_bytes, set_respect_gitignore, set_trusted_bash_dirs, tool_definitions_to_api,
+};
+pub(crate) use definitions::{
+    SpawnTaskInput, TodoItem, TodoStatus, TodoWriteInput, UpdatePlanInput,
+};
+pub use diagnostics::{
+    extract_fix_info, parse_compiler_output, parse_compiler_output_with_stack, Diagnostic, FixInfo,
+    FixType,
+};
 pub use executor::{
-    ErrorCategory, ToolError, ToolExecutionResult, ToolExecutor, ToolExecutorConfig,
+    AfterExecuteHook, AsyncToolFunction, BeforeExecuteHook, CircuitState, ErrorCategory,
+    NetworkErrorKind, RetryNotifier, ToolError, ToolExecutionResult, ToolExecutor,
+    ToolExecutorConfig, ToolFuture,
 };
+pub use freshness::set_stale_check_strict;
+pub use hooks::{HookEvent, HookOutcome, HookRunner};
+pub use middleware::{JsonlLogger, StatsCollector, ToolCallInfo, ToolMiddleware};
+pub use progress::{ProgressEntry, ProgressFile};
 pub use regression_tests::{generate_regression_test, RegressionTest, RegressionTestConfig};
+pub use write_validator::{
+    set_secret_patterns, set_write_validation, ValidationSeverity, ValidationWarning,
+    WriteFileValidator,
+};
@@ -0,0 +1,192 @@
+//! Structured progress journal for long-running tasks.
+//!
+//! Entries are appended to a JSONL file at `.coding-agent/progress/{session_id}.jsonl`
+//! by the `progress_file` tool, and read back by the `/progress` command to
+//! render a checklist of completed vs pending subtasks.
+
+use crate::integrations::specstory::chrono_now;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// The session id that progress entries are recorded against.
+///
+/// Set by the REPL whenever a session is created or loaded; tool functions
+/// have no session context of their own.
+static CURRENT_SESSION_ID: Mutex<Option<String>> = Mutex::new(None);
+
+/// A single entry in the progress journal
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProgressEntry {
+    /// When the entry was recorded (ISO 8601 format)
+    pub timestamp: String,
+    /// The action: "start", "complete", "skip", or "error"
+    pub action: String,
+    /// Stable identifier for the task this entry belongs to
+    pub task_id: String,
+    /// Human-readable description of the task or what happened
+    pub description: String,
+    /// Optional structured data attached to the entry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl ProgressEntry {
+    /// Create an entry timestamped with the current time
+    pub fn new(action: &str, task_id: &str, description: &str, data: Option<Value>) -> Self {
+        Self {
+            timestamp: chrono_now(),
+            action: action.to_string(),
+            task_id: task_id.to_string(),
+            description: description.to_string(),
+            data,
+        }
+    }
+}
+
+/// Append-only JSONL progress log, one file per session
+pub struct ProgressFile;
+
+impl ProgressFile {
+    /// Set the session id that subsequent progress entries are recorded against
+    pub fn set_session_id(id: &str) {
+        *CURRENT_SESSION_ID.lock().unwrap() = Some(id.to_string());
+    }
+
+    /// Get the current session id, falling back to "default" if none is set
+    pub fn current_session_id() -> String {
+        CURRENT_SESSION_ID
+            .lock()
+            .unwrap()
+            .clone()
+            .unwrap_or_else(|| "default".to_string())
+    }
+
+    /// Path of the progress log for the given session
+    pub fn path_for(session_id: &str) -> PathBuf {
+        PathBuf::from(".coding-agent")
+            .join("progress")
+            .join(format!("{}.jsonl", session_id))
+    }
+
+    /// Append an entry to the session's progress log, creating the file
+    /// (and parent directories) if needed
+    pub fn append(session_id: &str, entry: &ProgressEntry) -> Result<(), io::Error> {
+        let path = Self::path_for(session_id);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let line = serde_json::to_string(entry)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{}", line)
+    }
+
+    /// Read back all entries for the given session
+    ///
+    /// Returns an empty vector if no progress has been recorded yet.
+    pub fn read(session_id: &str) -> Result<Vec<ProgressEntry>, io::Error> {
+        let path = Self::path_for(session_id);
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        content
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    // Tests change the current directory, so they must run serially
+    static TEST_MUTEX: Mutex<()> = Mutex::new(());
+
+    fn in_temp_dir<F: FnOnce()>(f: F) {
+        let _guard = TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner());
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let original = std::env::current_dir().expect("Failed to get current dir");
+        std::env::set_current_dir(temp_dir.path()).expect("Failed to change dir");
+
+        f();
+
+        std::env::set_current_dir(original).expect("Failed to restore dir");
+    }
+
+    #[test]
+    fn test_append_and_read_roundtrip() {
+        in_temp_dir(|| {
+            let entry1 = ProgressEntry::new("start", "task-1", "First task", None);
+            let entry2 = ProgressEntry::new(
+                "complete",
+                "task-1",
+                "First task",
+                Some(json!({"files": 3})),
+            );
+
+            ProgressFile::append("test-session", &entry1).expect("Should append");
+            ProgressFile::append("test-session", &entry2).expect("Should append");
+
+            let entries = ProgressFile::read("test-session").expect("Should read");
+            assert_eq!(entries.len(), 2);
+            assert_eq!(entries[0], entry1);
+            assert_eq!(entries[1], entry2);
+        });
+    }
+
+    #[test]
+    fn test_read_missing_file_is_empty() {
+        in_temp_dir(|| {
+            let entries = ProgressFile::read("no-such-session").expect("Should not error");
+            assert!(entries.is_empty());
+        });
+    }
+
+    #[test]
+    fn test_read_corrupted_line() {
+        in_temp_dir(|| {
+            let path = ProgressFile::path_for("corrupted");
+            fs::create_dir_all(path.parent().unwrap()).expect("Failed to create dir");
+            fs::write(&path, "not json\n").expect("Failed to write");
+
+            let result = ProgressFile::read("corrupted");
+            assert!(result.is_err());
+        });
+    }
+
+    #[test]
+    fn test_session_id_fallback() {
+        // Not run in a temp dir: only touches the in-memory session id
+        ProgressFile::set_session_id("2024-01-15_10-30-00");
+        assert_eq!(ProgressFile::current_session_id(), "2024-01-15_10-30-00");
+    }
+
+    #[test]
+    fn test_path_for() {
+        let path = ProgressFile::path_for("2024-01-15_10-30-00");
+        assert_eq!(
+            path,
+            PathBuf::from(".coding-agent/progress/2024-01-15_10-30-00.jsonl")
+        );
+    }
+}
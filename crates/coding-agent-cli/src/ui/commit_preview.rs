@@ -31,6 +31,8 @@ pub struct CommitPreview {
     message: String,
     /// Files that will be committed
     files: Vec<String>,
+    /// Per-file diff summary lines (e.g. "+12 -3 src/main.rs")
+    diff_stats: Vec<String>,
     /// Theme for styling
     theme: Theme,
 }
@@ -41,6 +43,7 @@ impl CommitPreview {
         Self {
             message,
             files,
+            diff_stats: Vec::new(),
             theme: Theme::default(),
         }
     }
@@ -51,6 +54,12 @@ impl CommitPreview {
         self
     }
 
+    /// Attach per-file diff summary lines shown below the file list
+    pub fn with_diff_stats(mut self, diff_stats: Vec<String>) -> Self {
+        self.diff_stats = diff_stats;
+        self
+    }
+
     /// Get the current message
     pub fn message(&self) -> &str {
         &self.message
@@ -152,6 +161,22 @@ impl CommitPreview {
             lines += 1;
         }
 
+        // Render diff summary if available
+        if !self.diff_stats.is_empty() {
+            execute!(stdout, Print("\n"))?;
+            lines += 1;
+
+            let stats_header = self.theme.apply(Color::Muted, "Diff summary:");
+            execute!(stdout, Print(format!("{}\n", stats_header)))?;
+            lines += 1;
+
+            for stat in &self.diff_stats {
+                let styled = self.theme.apply(Color::Muted, &format!("  {}", stat));
+                execute!(stdout, Print(format!("{}\n", styled)))?;
+                lines += 1;
+            }
+        }
+
         // Blank line
         execute!(stdout, Print("\n"))?;
         lines += 1;
@@ -299,6 +324,14 @@ mod tests {
         assert_ne!(CommitPreviewResult::Edit, CommitPreviewResult::Cancelled);
     }
 
+    #[test]
+    fn test_commit_preview_with_diff_stats() {
+        let preview = CommitPreview::new("Test".to_string(), vec!["src/main.rs".to_string()])
+            .with_diff_stats(vec!["+12 -3 src/main.rs".to_string()]);
+        assert_eq!(preview.diff_stats.len(), 1);
+        assert_eq!(preview.diff_stats[0], "+12 -3 src/main.rs");
+    }
+
     #[test]
     fn test_commit_preview_multiple_files() {
         let files = vec![
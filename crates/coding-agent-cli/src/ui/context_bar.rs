@@ -11,6 +11,7 @@
 //! - Red: 85%+ usage
 
 use crate::ui::theme::{Color, Theme};
+use coding_agent_core::{ContentBlock, Message};
 use std::io::{self, Write};
 
 /// Threshold percentages for color changes.
@@ -24,6 +25,42 @@ pub const SMART_ZONE_CRITICAL: f64 = 70.0;
 /// Default context bar width (in characters).
 const DEFAULT_BAR_WIDTH: usize = 30;
 
+/// Context usage percentage above which trim suggestions are shown.
+pub const SUGGESTION_THRESHOLD: u64 = 90;
+
+/// A concrete way to free context, applied via `/trim`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrimAction {
+    /// Drop the N oldest messages from the conversation.
+    RemoveOldestMessages(usize),
+    /// Replace the messages in `[start, end)` with a short summary.
+    SummarizeRange(usize, usize),
+    /// Truncate the tool result with this tool_use_id.
+    TruncateLargeToolResult(String),
+}
+
+/// A recommendation for freeing context, with its estimated payoff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrimSuggestion {
+    /// Human-readable description shown below the context bar.
+    pub description: String,
+    /// Estimated tokens freed by applying the suggestion.
+    pub tokens_saved: u64,
+    /// What `/trim` does to the conversation for this suggestion.
+    pub action: TrimAction,
+}
+
+/// A labelled slice of the context window, for the segment breakdown.
+#[derive(Debug, Clone)]
+pub struct ContextSegment {
+    /// What this slice of context holds (e.g. "recent messages").
+    pub label: String,
+    /// Tokens attributed to this segment.
+    pub tokens: u64,
+    /// Color used when rendering this segment in the bar.
+    pub color: Color,
+}
+
 /// Context bar state and rendering.
 #[derive(Debug, Clone)]
 pub struct ContextBar {
@@ -35,6 +72,8 @@ pub struct ContextBar {
     bar_width: usize,
     /// Theme for styling.
     theme: Theme,
+    /// Per-segment breakdown of what is filling the context window.
+    breakdown: Vec<ContextSegment>,
 }
 
 impl ContextBar {
@@ -49,6 +88,7 @@ impl ContextBar {
             max_tokens,
             bar_width: DEFAULT_BAR_WIDTH,
             theme: Theme::default(),
+            breakdown: Vec::new(),
         }
     }
 
@@ -59,6 +99,7 @@ impl ContextBar {
             max_tokens,
             bar_width: DEFAULT_BAR_WIDTH,
             theme,
+            breakdown: Vec::new(),
         }
     }
 
@@ -77,9 +118,49 @@ impl ContextBar {
         self.current_tokens = self.current_tokens.saturating_add(tokens);
     }
 
-    /// Reset the token count to zero.
+    /// Reset the token count and segment breakdown to zero.
     pub fn reset(&mut self) {
         self.current_tokens = 0;
+        self.breakdown.clear();
+    }
+
+    /// Attribute tokens to a named segment of the context window.
+    ///
+    /// Tokens for an existing label accumulate into that segment, so
+    /// callers can report incrementally as the conversation grows.
+    pub fn add_segment(&mut self, label: &str, tokens: u64, color: Color) {
+        if let Some(segment) = self.breakdown.iter_mut().find(|s| s.label == label) {
+            segment.tokens = segment.tokens.saturating_add(tokens);
+        } else {
+            self.breakdown.push(ContextSegment {
+                label: label.to_string(),
+                tokens,
+                color,
+            });
+        }
+    }
+
+    /// Release tokens previously attributed to a segment.
+    ///
+    /// Used when content is removed from the conversation again (e.g.
+    /// old tool results trimmed out); both the segment and the current
+    /// total shrink, saturating at zero.
+    pub fn remove_segment_tokens(&mut self, label: &str, tokens: u64) {
+        if let Some(segment) = self.breakdown.iter_mut().find(|s| s.label == label) {
+            segment.tokens = segment.tokens.saturating_sub(tokens);
+        }
+        self.current_tokens = self.current_tokens.saturating_sub(tokens);
+    }
+
+    /// Get the per-segment breakdown.
+    pub fn breakdown(&self) -> &[ContextSegment] {
+        &self.breakdown
+    }
+
+    /// Get the largest segment — the best compression target when the
+    /// context window is filling up.
+    pub fn largest_segment(&self) -> Option<&ContextSegment> {
+        self.breakdown.iter().max_by_key(|s| s.tokens)
     }
 
     /// Get the current token count.
@@ -145,6 +226,32 @@ impl ContextBar {
         )
     }
 
+    /// Render the bar as color-coded per-segment runs (like "▓▓▓░░░").
+    ///
+    /// Each segment's run is styled with its own color; the unused part
+    /// of the window is drawn as "░".
+    fn render_segment_bar(&self) -> String {
+        let mut out = String::new();
+        let mut used_cells = 0usize;
+
+        if self.max_tokens > 0 {
+            for segment in &self.breakdown {
+                let cells = ((segment.tokens as f64 / self.max_tokens as f64)
+                    * self.bar_width as f64)
+                    .round() as usize;
+                let cells = cells.min(self.bar_width - used_cells);
+                if cells == 0 {
+                    continue;
+                }
+                out.push_str(&self.theme.apply(segment.color, &"▓".repeat(cells)));
+                used_cells += cells;
+            }
+        }
+
+        out.push_str(&"░".repeat(self.bar_width - used_cells));
+        out
+    }
+
     /// Format token count for display (e.g., "76k" or "200k").
     fn format_tokens(tokens: u64) -> String {
         if tokens >= 1000 {
@@ -177,14 +284,18 @@ impl ContextBar {
     /// }
     /// ```
     pub fn render(&self) -> String {
-        let bar = self.render_bar();
-        let color = self.usage_color();
         let pct = self.percent();
 
         let current_str = Self::format_tokens(self.current_tokens);
         let max_str = Self::format_tokens(self.max_tokens);
 
-        let bar_styled = self.theme.apply(color, &bar);
+        // With a breakdown, draw color-coded segment runs; otherwise fall
+        // back to the plain single-color bar
+        let bar_styled = if self.breakdown.is_empty() {
+            self.theme.apply(self.usage_color(), &self.render_bar())
+        } else {
+            self.render_segment_bar()
+        };
 
         format!(
             "Context: [{}] {:>3}% used | {} / {} tokens",
@@ -237,6 +348,101 @@ impl ContextBar {
         }
     }
 
+    /// Recommend what to trim from the conversation to free context.
+    ///
+    /// Analyzes the conversation for the three moves `/trim` can make:
+    /// dropping the oldest messages, summarizing the older half, and
+    /// truncating the largest tool result. Savings are estimated with the
+    /// same chars/4 heuristic as [`estimate_tokens`], and suggestions come
+    /// back sorted by payoff. Callers show them once [`ContextBar::percent`]
+    /// passes [`SUGGESTION_THRESHOLD`].
+    pub fn suggestions(&self, conversation: &[Message]) -> Vec<TrimSuggestion> {
+        let mut suggestions = Vec::new();
+
+        // Oldest quarter of the conversation (at least two messages):
+        // these compress best and are least likely to still matter
+        let oldest = (conversation.len() / 4).max(2);
+        if conversation.len() > oldest {
+            let tokens: u64 = conversation[..oldest].iter().map(message_tokens).sum();
+            if tokens > 0 {
+                suggestions.push(TrimSuggestion {
+                    description: format!("Remove the {} oldest messages", oldest),
+                    tokens_saved: tokens,
+                    action: TrimAction::RemoveOldestMessages(oldest),
+                });
+            }
+        }
+
+        // Summarizing the older half keeps the gist at ~20% of the size
+        let half = conversation.len() / 2;
+        if half >= 2 {
+            let tokens: u64 = conversation[..half].iter().map(message_tokens).sum();
+            let saved = tokens.saturating_sub(tokens / 5);
+            if saved > 0 {
+                suggestions.push(TrimSuggestion {
+                    description: format!("Summarize messages 1-{} into a short note", half),
+                    tokens_saved: saved,
+                    action: TrimAction::SummarizeRange(0, half),
+                });
+            }
+        }
+
+        // The single largest tool result, if it is big enough to matter
+        let mut largest: Option<(&str, &str, usize)> = None;
+        for msg in conversation {
+            for block in &msg.content {
+                if let ContentBlock::ToolResult {
+                    tool_use_id,
+                    content,
+                    ..
+                } = block
+                {
+                    if content.len() >= MIN_TRIMMABLE_RESULT_BYTES
+                        && largest.is_none_or(|(_, _, len)| content.len() > len)
+                    {
+                        largest = Some((tool_use_id, content, content.len()));
+                    }
+                }
+            }
+        }
+        if let Some((tool_use_id, content, len)) = largest {
+            let saved =
+                estimate_tokens(content).saturating_sub(TRUNCATED_RESULT_KEEP_BYTES as u64 / 4);
+            suggestions.push(TrimSuggestion {
+                description: format!("Truncate a {}-byte tool result", len),
+                tokens_saved: saved,
+                action: TrimAction::TruncateLargeToolResult(tool_use_id.to_string()),
+            });
+        }
+
+        suggestions.sort_by_key(|s| std::cmp::Reverse(s.tokens_saved));
+        suggestions
+    }
+
+    /// Render trim suggestions as a numbered box for display below the bar.
+    pub fn render_suggestions(&self, suggestions: &[TrimSuggestion]) -> String {
+        if suggestions.is_empty() {
+            return String::new();
+        }
+
+        let mut lines = vec!["┌─ Context nearly full — /trim <n> to free space".to_string()];
+        for (index, suggestion) in suggestions.iter().enumerate() {
+            lines.push(format!(
+                "│ {}. {} (~{} tokens)",
+                index + 1,
+                suggestion.description,
+                Self::format_tokens(suggestion.tokens_saved)
+            ));
+        }
+        lines.push("└─".to_string());
+
+        lines
+            .into_iter()
+            .map(|line| self.theme.apply(Color::ContextYellow, &line))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Render the context bar with an optional warning line below.
     ///
     /// Output format when in warning zone:
@@ -259,6 +465,38 @@ impl ContextBar {
         }
     }
 
+    /// Render the per-segment breakdown as display lines (for `/cost`).
+    ///
+    /// When usage is in the warning zone, the largest segment is flagged
+    /// as the best compression target.
+    pub fn render_breakdown(&self) -> String {
+        if self.breakdown.is_empty() {
+            return "No context breakdown recorded yet.".to_string();
+        }
+
+        let highlight_largest = self.percent() as f64 >= SMART_ZONE_WARNING;
+        let largest_tokens = self.largest_segment().map(|s| s.tokens).unwrap_or(0);
+
+        let mut lines = vec!["Context breakdown:".to_string()];
+        for segment in &self.breakdown {
+            let pct = (segment.tokens * 100)
+                .checked_div(self.max_tokens)
+                .unwrap_or(0);
+            let mut line = format!(
+                "  {:<16} {:>6} tokens ({:>2}%)",
+                segment.label,
+                Self::format_tokens(segment.tokens),
+                pct
+            );
+            if highlight_largest && segment.tokens == largest_tokens {
+                line.push_str("  ← largest; best compression target");
+            }
+            lines.push(self.theme.apply(segment.color, &line));
+        }
+
+        lines.join("\n")
+    }
+
     /// Print the context bar with warning to stdout.
     pub fn print_with_warning(&self) -> io::Result<()> {
         let mut stdout = io::stdout();
@@ -274,6 +512,35 @@ impl Default for ContextBar {
     }
 }
 
+/// Minimum size a tool result must have before truncating it is worth
+/// suggesting.
+const MIN_TRIMMABLE_RESULT_BYTES: usize = 1024;
+
+/// How much of a truncated tool result `/trim` keeps.
+pub const TRUNCATED_RESULT_KEEP_BYTES: usize = 400;
+
+/// Rough token estimate for suggestion payoffs (~4 chars per token).
+///
+/// The context bar has no tokenizer; suggestions only need to rank and
+/// ballpark savings, not bill for them.
+pub fn estimate_tokens(text: &str) -> u64 {
+    (text.len() / 4) as u64
+}
+
+/// Estimated tokens held by one conversation message.
+fn message_tokens(message: &Message) -> u64 {
+    message
+        .content
+        .iter()
+        .map(|block| match block {
+            ContentBlock::Text { text } => estimate_tokens(text),
+            ContentBlock::ToolResult { content, .. } => estimate_tokens(content),
+            ContentBlock::ToolUse { input, .. } => estimate_tokens(&input.to_string()),
+            ContentBlock::Image { source } => estimate_tokens(&source.data),
+        })
+        .sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -411,6 +678,23 @@ mod tests {
         assert_eq!(rendered.chars().filter(|&c| c == '-').count(), 0);
     }
 
+    #[test]
+    fn test_remove_segment_tokens() {
+        let mut bar = ContextBar::new(1000);
+        bar.add_tokens(500);
+        bar.add_segment("tool results", 300, Color::Tool);
+
+        bar.remove_segment_tokens("tool results", 200);
+
+        assert_eq!(bar.current_tokens(), 300);
+        assert_eq!(bar.breakdown()[0].tokens, 100);
+
+        // Saturates at zero rather than underflowing
+        bar.remove_segment_tokens("tool results", 9_999);
+        assert_eq!(bar.current_tokens(), 0);
+        assert_eq!(bar.breakdown()[0].tokens, 0);
+    }
+
     #[test]
     fn test_context_bar_format_tokens() {
         assert_eq!(ContextBar::format_tokens(500), "500");
@@ -567,8 +851,177 @@ mod tests {
         bar.set_tokens(50);
         let rendered = bar.render_bar();
         assert_eq!(bar.percent(), 50);
-        assert_eq!(rendered.chars().filter(|&c| c == '=').count(), 5);  // Half filled
-        assert_eq!(rendered.chars().filter(|&c| c == '-').count(), 5);  // Half empty
+        assert_eq!(rendered.chars().filter(|&c| c == '=').count(), 5); // Half filled
+        assert_eq!(rendered.chars().filter(|&c| c == '-').count(), 5); // Half empty
+    }
+
+    #[test]
+    fn test_context_bar_add_segment_accumulates_by_label() {
+        let mut bar = ContextBar::new(200_000);
+
+        bar.add_segment("recent messages", 1_000, Color::UserInput);
+        bar.add_segment("tool results", 500, Color::Tool);
+        bar.add_segment("recent messages", 2_000, Color::UserInput);
+
+        assert_eq!(bar.breakdown().len(), 2);
+        assert_eq!(bar.breakdown()[0].tokens, 3_000);
+        assert_eq!(bar.breakdown()[1].tokens, 500);
+    }
+
+    #[test]
+    fn test_context_bar_largest_segment() {
+        let mut bar = ContextBar::new(200_000);
+        assert!(bar.largest_segment().is_none());
+
+        bar.add_segment("system prompt", 800, Color::Muted);
+        bar.add_segment("tool results", 5_000, Color::Tool);
+        bar.add_segment("recent messages", 2_000, Color::UserInput);
+
+        let largest = bar.largest_segment().unwrap();
+        assert_eq!(largest.label, "tool results");
+        assert_eq!(largest.tokens, 5_000);
+    }
+
+    #[test]
+    fn test_context_bar_reset_clears_breakdown() {
+        let mut bar = ContextBar::new(200_000);
+        bar.add_tokens(1_000);
+        bar.add_segment("recent messages", 1_000, Color::UserInput);
+
+        bar.reset();
+
+        assert_eq!(bar.current_tokens(), 0);
+        assert!(bar.breakdown().is_empty());
+    }
+
+    #[test]
+    fn test_context_bar_render_uses_segment_bar_with_breakdown() {
+        let mut bar = ContextBar::new(100);
+        bar.set_bar_width(10);
+        bar.add_tokens(50);
+        bar.add_segment("recent messages", 50, Color::UserInput);
+
+        let rendered = bar.render();
+        // Half the bar is segment cells, the rest is unused
+        assert_eq!(rendered.matches('▓').count(), 5);
+        assert_eq!(rendered.matches('░').count(), 5);
+    }
+
+    #[test]
+    fn test_context_bar_render_breakdown_empty() {
+        let bar = ContextBar::new(200_000);
+        assert_eq!(bar.render_breakdown(), "No context breakdown recorded yet.");
+    }
+
+    #[test]
+    fn test_context_bar_render_breakdown_lists_segments() {
+        let mut bar = ContextBar::new(200_000);
+        bar.add_tokens(10_000);
+        bar.add_segment("system prompt", 2_000, Color::Muted);
+        bar.add_segment("recent messages", 8_000, Color::UserInput);
+
+        let output = bar.render_breakdown();
+        assert!(output.contains("Context breakdown:"));
+        assert!(output.contains("system prompt"));
+        assert!(output.contains("recent messages"));
+        // Below the warning zone no segment is flagged for compression
+        assert!(!output.contains("compression target"));
+    }
+
+    #[test]
+    fn test_context_bar_render_breakdown_highlights_largest_near_limit() {
+        let mut bar = ContextBar::new(100);
+        bar.add_tokens(65); // 65% - warning zone
+        bar.add_segment("system prompt", 5, Color::Muted);
+        bar.add_segment("tool results", 60, Color::Tool);
+
+        let output = bar.render_breakdown();
+        assert!(output.contains("compression target"));
+        let flagged_line = output
+            .lines()
+            .find(|l| l.contains("compression target"))
+            .unwrap();
+        assert!(flagged_line.contains("tool results"));
+    }
+
+    #[test]
+    fn test_suggestions_empty_conversation() {
+        let bar = ContextBar::new(100);
+
+        assert!(bar.suggestions(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_suggestions_recommend_oldest_and_summary() {
+        let bar = ContextBar::new(100);
+        let conversation: Vec<Message> = (0..8)
+            .map(|i| Message::user(format!("message number {} with some content", i)))
+            .collect();
+
+        let suggestions = bar.suggestions(&conversation);
+
+        assert!(suggestions
+            .iter()
+            .any(|s| s.action == TrimAction::RemoveOldestMessages(2)));
+        assert!(suggestions
+            .iter()
+            .any(|s| s.action == TrimAction::SummarizeRange(0, 4)));
+        // Sorted by payoff, largest first
+        assert!(suggestions
+            .windows(2)
+            .all(|w| w[0].tokens_saved >= w[1].tokens_saved));
+    }
+
+    #[test]
+    fn test_suggestions_flag_largest_tool_result() {
+        let bar = ContextBar::new(100);
+        let conversation = vec![
+            Message::user("run the tests"),
+            Message {
+                role: "user".to_string(),
+                content: vec![
+                    ContentBlock::ToolResult {
+                        tool_use_id: "small".to_string(),
+                        content: "ok".to_string(),
+                        is_error: None,
+                    },
+                    ContentBlock::ToolResult {
+                        tool_use_id: "big".to_string(),
+                        content: "x".repeat(4_000),
+                        is_error: None,
+                    },
+                ],
+            },
+        ];
+
+        let suggestions = bar.suggestions(&conversation);
+
+        let truncate = suggestions
+            .iter()
+            .find(|s| matches!(s.action, TrimAction::TruncateLargeToolResult(_)))
+            .expect("Should suggest truncating the large result");
+        assert_eq!(
+            truncate.action,
+            TrimAction::TruncateLargeToolResult("big".to_string())
+        );
+        assert!(truncate.tokens_saved > 0);
+    }
+
+    #[test]
+    fn test_render_suggestions_numbers_entries() {
+        let bar = ContextBar::with_theme(100, Theme::new(ThemeStyle::Monochrome));
+        let suggestions = vec![TrimSuggestion {
+            description: "Remove the 2 oldest messages".to_string(),
+            tokens_saved: 1_500,
+            action: TrimAction::RemoveOldestMessages(2),
+        }];
+
+        let rendered = bar.render_suggestions(&suggestions);
+
+        assert!(rendered.contains("/trim"));
+        assert!(rendered.contains("1. Remove the 2 oldest messages"));
+        assert!(rendered.contains("~1k tokens"));
+        assert_eq!(bar.render_suggestions(&[]), "");
     }
 
     #[test]
@@ -585,8 +1038,14 @@ mod tests {
         eprintln!("Percent: {}%", percent);
         eprintln!("Bar string: '{}'", bar_str);
         eprintln!("Bar length: {}", bar_str.len());
-        eprintln!("Filled = count: {}", bar_str.chars().filter(|&c| c == '=').count());
-        eprintln!("Empty - count: {}", bar_str.chars().filter(|&c| c == '-').count());
+        eprintln!(
+            "Filled = count: {}",
+            bar_str.chars().filter(|&c| c == '=').count()
+        );
+        eprintln!(
+            "Empty - count: {}",
+            bar_str.chars().filter(|&c| c == '-').count()
+        );
         eprintln!("Full render: {}", full_render);
         eprintln!("================\n");
 
@@ -0,0 +1,389 @@
+//! Fuzzy file finder overlay for inserting `@path` references
+//!
+//! Opened from the prompt via `/files` or the `file-finder` keybinding
+//! (Ctrl+T by default). The project file list is indexed once per
+//! session — the walk respects .gitignore — and filtered incrementally
+//! per keystroke, so typing stays responsive even in trees with
+//! thousands of files. Matches are ranked by fuzzy match score with
+//! recency of modification breaking ties, and the matched characters
+//! are highlighted. Enter hands the selected path back to the caller;
+//! Esc cancels and the caller redraws the prompt untouched.
+
+use super::theme::{Color, Theme};
+use crossterm::{
+    cursor::{Hide, MoveToColumn, MoveUp, Show},
+    event::{self, Event, KeyCode, KeyModifiers},
+    execute,
+    style::Print,
+    terminal::{Clear, ClearType},
+};
+use std::io::{stdout, Write};
+use std::path::Path;
+use std::time::SystemTime;
+
+/// Cap on indexed files, bounding both walk time and per-key filtering
+const MAX_INDEXED_FILES: usize = 10_000;
+
+/// Rows of matches shown in the overlay
+const MAX_VISIBLE: usize = 10;
+
+/// One file in the index
+#[derive(Debug, Clone)]
+pub struct IndexedFile {
+    /// Path relative to the index root
+    pub path: String,
+    /// Last modification time, for recency ranking
+    modified: SystemTime,
+}
+
+/// A pre-built, ignore-aware list of project files
+pub struct FileIndex {
+    /// Indexed files, most recently modified first
+    files: Vec<IndexedFile>,
+}
+
+impl FileIndex {
+    /// Walk `root` (respecting .gitignore) and index up to
+    /// [`MAX_INDEXED_FILES`] files
+    pub fn build(root: &Path) -> Self {
+        let mut files = Vec::new();
+        for entry in ignore::WalkBuilder::new(root).build().flatten() {
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
+            let path = entry.path().strip_prefix(root).unwrap_or(entry.path());
+            let Some(path) = path.to_str() else {
+                continue;
+            };
+            let modified = entry
+                .metadata()
+                .ok()
+                .and_then(|m| m.modified().ok())
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            files.push(IndexedFile {
+                path: path.to_string(),
+                modified,
+            });
+            if files.len() >= MAX_INDEXED_FILES {
+                break;
+            }
+        }
+
+        // Recency order doubles as the listing shown before any typing
+        files.sort_by_key(|f| std::cmp::Reverse(f.modified));
+        Self { files }
+    }
+
+    /// Number of indexed files
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Whether the index holds no files
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Rank files against the query: best score first, more recently
+    /// modified files breaking ties. Each match carries the matched
+    /// character positions for highlighting. An empty query lists
+    /// everything in recency order.
+    pub fn matches(&self, query: &str) -> Vec<(&IndexedFile, Vec<usize>)> {
+        if query.is_empty() {
+            return self.files.iter().map(|f| (f, Vec::new())).collect();
+        }
+
+        let mut scored: Vec<(i32, &IndexedFile, Vec<usize>)> = self
+            .files
+            .iter()
+            .filter_map(|f| {
+                match_score(query, &f.path).map(|(score, positions)| (score, f, positions))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.modified.cmp(&a.1.modified)));
+        scored
+            .into_iter()
+            .map(|(_, f, positions)| (f, positions))
+            .collect()
+    }
+}
+
+/// Score a case-insensitive fuzzy match of `query` against `path`.
+///
+/// Every query character must appear in the path in order. Runs of
+/// adjacent matches and matches at the start of a path component score
+/// higher; longer paths score slightly lower so `src/main.rs` beats
+/// `examples/archive/main_backup.rs` for "main". Returns the score and
+/// the matched character positions, or None when the query does not
+/// match.
+pub fn match_score(query: &str, path: &str) -> Option<(i32, Vec<usize>)> {
+    let chars: Vec<char> = path.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .filter_map(|c| c.to_lowercase().next())
+        .collect();
+
+    let mut positions = Vec::new();
+    let mut score = 0i32;
+    let mut index = 0usize;
+    for q in query.chars().filter_map(|c| c.to_lowercase().next()) {
+        let offset = lower.get(index..)?.iter().position(|&c| c == q)?;
+        let at = index + offset;
+        if positions.last() == Some(&at.wrapping_sub(1)) {
+            score += 3; // adjacent to the previous match
+        }
+        if at == 0 || matches!(chars[at - 1], '/' | '_' | '-' | '.') {
+            score += 5; // start of a path component or word
+        }
+        positions.push(at);
+        index = at + 1;
+    }
+
+    // Mild preference for shorter paths among equal matches
+    score -= (chars.len() / 10) as i32;
+    Some((score, positions))
+}
+
+/// Interactive fuzzy finder overlay over a [`FileIndex`]
+///
+/// Assumes raw mode is already enabled (it is while the prompt is being
+/// edited). The overlay draws below the cursor line and clears itself
+/// on exit, leaving the caller to redraw the prompt.
+pub struct FileFinder<'a> {
+    /// The pre-built file list to filter
+    index: &'a FileIndex,
+    /// Theme for styling
+    theme: Theme,
+    /// The filter typed so far
+    filter: String,
+    /// Highlighted row within the visible matches
+    selected: usize,
+}
+
+impl<'a> FileFinder<'a> {
+    /// Create a finder over a pre-built index
+    pub fn new(index: &'a FileIndex) -> Self {
+        Self {
+            index,
+            theme: Theme::default(),
+            filter: String::new(),
+            selected: 0,
+        }
+    }
+
+    /// Set the theme
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Run the overlay; returns the chosen path, or None on cancel
+    pub fn run(&mut self) -> std::io::Result<Option<String>> {
+        if self.index.is_empty() {
+            return Ok(None);
+        }
+
+        let mut stdout = stdout();
+        execute!(stdout, Hide)?;
+        let result = self.run_inner(&mut stdout);
+        execute!(stdout, Show)?;
+        result
+    }
+
+    fn run_inner(&mut self, stdout: &mut impl Write) -> std::io::Result<Option<String>> {
+        loop {
+            let matches = self.index.matches(&self.filter);
+            if self.selected >= matches.len() {
+                self.selected = matches.len().saturating_sub(1);
+            }
+            self.render(stdout, &matches)?;
+
+            if let Event::Key(key_event) = event::read()? {
+                let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+                match key_event.code {
+                    KeyCode::Char('c') | KeyCode::Char('d') if ctrl => {
+                        self.clear_display(stdout)?;
+                        return Ok(None);
+                    }
+                    _ if ctrl => {}
+                    KeyCode::Up => self.selected = self.selected.saturating_sub(1),
+                    KeyCode::Down if self.selected + 1 < matches.len().min(MAX_VISIBLE) => {
+                        self.selected += 1;
+                    }
+                    KeyCode::Enter => {
+                        let picked = matches.get(self.selected).map(|(f, _)| f.path.clone());
+                        self.clear_display(stdout)?;
+                        return Ok(picked);
+                    }
+                    KeyCode::Esc => {
+                        self.clear_display(stdout)?;
+                        return Ok(None);
+                    }
+                    KeyCode::Backspace => {
+                        self.filter.pop();
+                    }
+                    KeyCode::Char(c) => self.filter.push(c),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Lines the overlay occupies: query, matches, footer
+    fn overlay_height(&self) -> usize {
+        MAX_VISIBLE + 2
+    }
+
+    /// Render the overlay in place and park the cursor back at its top
+    fn render(
+        &self,
+        stdout: &mut impl Write,
+        matches: &[(&IndexedFile, Vec<usize>)],
+    ) -> std::io::Result<()> {
+        execute!(stdout, MoveToColumn(0), Clear(ClearType::FromCursorDown))?;
+
+        let query = self
+            .theme
+            .apply(Color::UserInput, &format!("Find file: {}▏", self.filter));
+        execute!(stdout, Print(format!("{}\r\n", query)))?;
+
+        for (row, (file, positions)) in matches.iter().take(MAX_VISIBLE).enumerate() {
+            let marker = if row == self.selected { "❯ " } else { "  " };
+            execute!(
+                stdout,
+                Print(format!(
+                    "{}{}\r\n",
+                    self.theme.apply(Color::Agent, marker),
+                    self.highlight(&file.path, positions, row == self.selected)
+                ))
+            )?;
+        }
+        for _ in matches.len().min(MAX_VISIBLE)..MAX_VISIBLE {
+            execute!(stdout, Print("\r\n"))?;
+        }
+
+        let footer = if matches.is_empty() {
+            format!("No files match ({} indexed)", self.index.len())
+        } else {
+            format!(
+                "{} of {} files • [↑/↓] navigate • [Enter] insert • [Esc] cancel",
+                matches.len().min(MAX_VISIBLE),
+                matches.len()
+            )
+        };
+        execute!(stdout, Print(self.theme.apply(Color::Muted, &footer)))?;
+
+        // Park the cursor back at the overlay's top-left for the next
+        // render (or the caller's cleanup)
+        execute!(
+            stdout,
+            MoveUp(self.overlay_height() as u16 - 1),
+            MoveToColumn(0)
+        )?;
+        stdout.flush()
+    }
+
+    /// Render a path with the matched characters emphasized
+    fn highlight(&self, path: &str, positions: &[usize], selected: bool) -> String {
+        let base = if selected {
+            Color::UserInput
+        } else {
+            Color::Muted
+        };
+        let mut out = String::new();
+        for (i, c) in path.chars().enumerate() {
+            if positions.contains(&i) {
+                out.push_str(
+                    &self
+                        .theme
+                        .bold(Color::Agent)
+                        .apply_to(c.to_string())
+                        .to_string(),
+                );
+            } else {
+                out.push_str(&self.theme.apply(base, &c.to_string()));
+            }
+        }
+        out
+    }
+
+    /// Erase the overlay, leaving the cursor where it started
+    fn clear_display(&self, stdout: &mut impl Write) -> std::io::Result<()> {
+        execute!(stdout, MoveToColumn(0), Clear(ClearType::FromCursorDown))?;
+        stdout.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn index_of(paths: &[(&str, u64)]) -> FileIndex {
+        let mut files: Vec<IndexedFile> = paths
+            .iter()
+            .map(|(path, age_secs)| IndexedFile {
+                path: path.to_string(),
+                modified: SystemTime::now() - Duration::from_secs(*age_secs),
+            })
+            .collect();
+        files.sort_by_key(|f| std::cmp::Reverse(f.modified));
+        FileIndex { files }
+    }
+
+    #[test]
+    fn test_match_score_requires_subsequence() {
+        assert!(match_score("mrs", "src/main.rs").is_some());
+        assert!(match_score("xyz", "src/main.rs").is_none());
+        // Case-insensitive
+        assert!(match_score("MAIN", "src/main.rs").is_some());
+    }
+
+    #[test]
+    fn test_match_score_positions_for_highlighting() {
+        let (_, positions) = match_score("main", "src/main.rs").expect("Should match");
+        assert_eq!(positions, vec![4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_match_score_prefers_component_starts() {
+        let (at_start, _) = match_score("main", "src/main.rs").unwrap();
+        let (mid_word, _) = match_score("main", "src/domain_x.rs").unwrap();
+        assert!(at_start > mid_word);
+    }
+
+    #[test]
+    fn test_matches_ranked_by_score_then_recency() {
+        let index = index_of(&[
+            ("src/stale.rs", 1_000_000),
+            ("src/fresh.rs", 10),
+            ("docs/notes.md", 500),
+        ]);
+
+        // Equal scores: the fresher file wins
+        let matches = index.matches("src");
+        assert_eq!(matches[0].0.path, "src/fresh.rs");
+        assert_eq!(matches[1].0.path, "src/stale.rs");
+
+        // Empty query lists everything, most recent first
+        let all = index.matches("");
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].0.path, "src/fresh.rs");
+    }
+
+    #[test]
+    fn test_build_respects_gitignore() {
+        let temp_dir = tempfile::TempDir::new().expect("Failed to create temp dir");
+        std::fs::create_dir(temp_dir.path().join(".git")).expect("Should create");
+        std::fs::write(temp_dir.path().join(".gitignore"), "target/\n").expect("Should write");
+        std::fs::write(temp_dir.path().join("main.rs"), "fn main() {}").expect("Should write");
+        std::fs::create_dir(temp_dir.path().join("target")).expect("Should create");
+        std::fs::write(temp_dir.path().join("target/out.rs"), "").expect("Should write");
+
+        let index = FileIndex::build(temp_dir.path());
+
+        let paths: Vec<&str> = index.files.iter().map(|f| f.path.as_str()).collect();
+        assert!(paths.contains(&"main.rs"));
+        assert!(!paths.iter().any(|p| p.starts_with("target")));
+    }
+}
@@ -50,6 +50,13 @@ impl FileEntry {
 pub enum FilePickerResult {
     /// User confirmed selection
     Selected(Vec<String>),
+    /// User confirmed selection and typed a commit message via `[m]`
+    SelectedWithMessage {
+        /// The selected file paths
+        paths: Vec<String>,
+        /// The one-line message entered in the picker
+        message: String,
+    },
     /// User cancelled
     Cancelled,
 }
@@ -66,6 +73,10 @@ pub struct FilePicker {
     header: String,
     /// Footer help text
     footer: String,
+    /// One-line commit message entered via `[m]`
+    message: String,
+    /// Whether keystrokes currently go to the message input
+    entering_message: bool,
 }
 
 impl FilePicker {
@@ -76,7 +87,9 @@ impl FilePicker {
             cursor: 0,
             theme: Theme::default(),
             header: "Select files to commit:".to_string(),
-            footer: "[Space] toggle • [↑/↓] navigate • [Enter] confirm • [Esc] cancel • [a] all • [n] none".to_string(),
+            footer: "[Space] toggle • [↑/↓] navigate • [Enter] confirm • [Esc] cancel • [a] all • [n] none • [m] message".to_string(),
+            message: String::new(),
+            entering_message: false,
         }
     }
 
@@ -131,16 +144,39 @@ impl FilePicker {
         loop {
             if event::poll(std::time::Duration::from_millis(100))? {
                 if let Event::Key(key_event) = event::read()? {
-                    match key_event.code {
-                        KeyCode::Up | KeyCode::Char('k') => {
-                            if self.cursor > 0 {
-                                self.cursor -= 1;
+                    // While the message input is open, keystrokes edit the
+                    // message instead of moving the selection
+                    if self.entering_message {
+                        match key_event.code {
+                            KeyCode::Enter => self.entering_message = false,
+                            KeyCode::Esc => {
+                                self.message.clear();
+                                self.entering_message = false;
                             }
-                        }
-                        KeyCode::Down | KeyCode::Char('j') => {
-                            if self.cursor < self.entries.len() - 1 {
-                                self.cursor += 1;
+                            KeyCode::Backspace => {
+                                self.message.pop();
+                            }
+                            KeyCode::Char('c')
+                                if key_event.modifiers.contains(KeyModifiers::CONTROL) =>
+                            {
+                                self.clear_display(&mut stdout)?;
+                                return Ok(FilePickerResult::Cancelled);
                             }
+                            KeyCode::Char(c) => self.message.push(c),
+                            _ => {}
+                        }
+                        self.render(&mut stdout)?;
+                        continue;
+                    }
+
+                    match key_event.code {
+                        KeyCode::Up | KeyCode::Char('k') if self.cursor > 0 => {
+                            self.cursor -= 1;
+                        }
+                        KeyCode::Down | KeyCode::Char('j')
+                            if self.cursor < self.entries.len() - 1 =>
+                        {
+                            self.cursor += 1;
                         }
                         KeyCode::Char(' ') => {
                             // Toggle selection
@@ -159,18 +195,14 @@ impl FilePicker {
                                 entry.selected = false;
                             }
                         }
+                        KeyCode::Char('m') => {
+                            // Open the one-line message input
+                            self.entering_message = true;
+                        }
                         KeyCode::Enter => {
                             // Clear the picker display
                             self.clear_display(&mut stdout)?;
-
-                            // Return selected files
-                            let selected: Vec<String> = self
-                                .entries
-                                .iter()
-                                .filter(|e| e.selected)
-                                .map(|e| e.path.clone())
-                                .collect();
-                            return Ok(FilePickerResult::Selected(selected));
+                            return Ok(self.confirm_result());
                         }
                         KeyCode::Esc => {
                             // Clear the picker display
@@ -194,12 +226,33 @@ impl FilePicker {
         }
     }
 
+    /// Build the result for a confirmed selection, attaching the message
+    /// entered via `[m]` when there is one
+    fn confirm_result(&self) -> FilePickerResult {
+        let paths: Vec<String> = self
+            .entries
+            .iter()
+            .filter(|e| e.selected)
+            .map(|e| e.path.clone())
+            .collect();
+
+        let message = self.message.trim();
+        if message.is_empty() {
+            FilePickerResult::Selected(paths)
+        } else {
+            FilePickerResult::SelectedWithMessage {
+                paths,
+                message: message.to_string(),
+            }
+        }
+    }
+
     fn render(&self, stdout: &mut impl Write) -> std::io::Result<()> {
         // Move cursor to beginning and clear
         execute!(stdout, MoveToColumn(0))?;
 
-        // Calculate lines to clear (header + entries + status + footer + spacing)
-        let total_lines = self.entries.len() + 4;
+        // Calculate lines to clear (header + entries + status + message + footer + spacing)
+        let total_lines = self.entries.len() + 5;
         for _ in 0..total_lines {
             execute!(stdout, Clear(ClearType::CurrentLine), MoveDown(1))?;
         }
@@ -254,6 +307,19 @@ impl FilePicker {
         let status = self.theme.apply(Color::Muted, &status_text);
         execute!(stdout, Print(format!("{}\n", status)))?;
 
+        // Message input line: shows the live input while typing, the
+        // saved message afterwards, and stays blank otherwise
+        let message_line = if self.entering_message {
+            self.theme
+                .apply(Color::UserInput, &format!("Message: {}▏", self.message))
+        } else if !self.message.trim().is_empty() {
+            self.theme
+                .apply(Color::Muted, &format!("Message: {}", self.message))
+        } else {
+            String::new()
+        };
+        execute!(stdout, Print(format!("{}\n", message_line)))?;
+
         // Footer
         let footer = self.theme.apply(Color::Muted, &self.footer);
         execute!(stdout, Print(footer))?;
@@ -267,7 +333,7 @@ impl FilePicker {
         execute!(stdout, MoveToColumn(0))?;
 
         // Clear all lines we rendered
-        let total_lines = self.entries.len() + 4;
+        let total_lines = self.entries.len() + 5;
         for _ in 0..total_lines {
             execute!(stdout, Clear(ClearType::CurrentLine), MoveDown(1))?;
         }
@@ -331,10 +397,57 @@ mod tests {
         assert!(result.is_ok());
         match result.unwrap() {
             FilePickerResult::Selected(files) => assert!(files.is_empty()),
-            FilePickerResult::Cancelled => panic!("Expected Selected, got Cancelled"),
+            other => panic!("Expected Selected, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_confirm_result_without_message() {
+        let entries = vec![
+            FileEntry::new_selected("a.rs".to_string(), " M".to_string()),
+            FileEntry::new("b.rs".to_string(), " M".to_string()),
+        ];
+        let picker = FilePicker::new(entries);
+
+        match picker.confirm_result() {
+            FilePickerResult::Selected(paths) => assert_eq!(paths, vec!["a.rs".to_string()]),
+            other => panic!("Expected Selected, got {:?}", other),
         }
     }
 
+    #[test]
+    fn test_confirm_result_with_message() {
+        let entries = vec![
+            FileEntry::new_selected("a.rs".to_string(), " M".to_string()),
+            FileEntry::new_selected("b.rs".to_string(), "??".to_string()),
+        ];
+        let mut picker = FilePicker::new(entries);
+        picker.message = "Group auth fixes together".to_string();
+
+        match picker.confirm_result() {
+            FilePickerResult::SelectedWithMessage { paths, message } => {
+                assert_eq!(paths, vec!["a.rs".to_string(), "b.rs".to_string()]);
+                assert_eq!(message, "Group auth fixes together");
+            }
+            other => panic!("Expected SelectedWithMessage, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_confirm_result_whitespace_message_is_dropped() {
+        let entries = vec![FileEntry::new_selected(
+            "a.rs".to_string(),
+            " M".to_string(),
+        )];
+        let mut picker = FilePicker::new(entries);
+        picker.message = "   ".to_string();
+
+        assert!(matches!(
+            picker.confirm_result(),
+            FilePickerResult::Selected(_)
+        ));
+    }
+
     #[test]
     fn test_pick_mode_ui_construction() {
         // Test that the picker can be constructed with various file statuses
@@ -380,7 +380,7 @@ impl FunFactClient {
         // Cache is fresh, use it
         self.cache
             .get_random()
-            .unwrap_or_else(|| Self::get_fallback_fact())
+            .unwrap_or_else(Self::get_fallback_fact)
     }
 
     /// Get a fun fact synchronously from cache or fallback only (no API calls)
@@ -390,7 +390,7 @@ impl FunFactClient {
     pub fn get_fact_sync(&self) -> FunFact {
         self.cache
             .get_random()
-            .unwrap_or_else(|| Self::get_fallback_fact())
+            .unwrap_or_else(Self::get_fallback_fact)
     }
 
     /// Preload cache with multiple facts from various sources
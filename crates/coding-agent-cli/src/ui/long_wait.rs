@@ -4,11 +4,82 @@
 //! longer than expected, which can be used to trigger additional UI feedback
 //! like fun facts or progress indicators.
 
+use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
 /// Default threshold for considering a wait "long" (10 seconds)
 pub const DEFAULT_LONG_WAIT_THRESHOLD: Duration = Duration::from_secs(10);
 
+/// Default number of response-time samples to retain
+pub const DEFAULT_MAX_SAMPLES: usize = 20;
+
+/// A rolling window of API response times, used to estimate wait times
+#[derive(Debug, Clone)]
+pub struct ResponseTimeSampler {
+    /// Recorded durations, oldest first
+    samples: VecDeque<Duration>,
+    /// Maximum number of retained samples
+    max_samples: usize,
+}
+
+impl ResponseTimeSampler {
+    /// Create a sampler retaining up to `max_samples` durations
+    pub fn new(max_samples: usize) -> Self {
+        Self {
+            samples: VecDeque::new(),
+            max_samples: max_samples.max(1),
+        }
+    }
+
+    /// Record a response time, evicting the oldest sample when full
+    pub fn record(&mut self, duration: Duration) {
+        self.samples.push_back(duration);
+        while self.samples.len() > self.max_samples {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Get the median response time, if any samples exist
+    pub fn median(&self) -> Option<Duration> {
+        if self.samples.is_empty() {
+            None
+        } else {
+            Some(self.percentile(0.5))
+        }
+    }
+
+    /// Get the response time at percentile `p` (0.0 to 1.0, e.g. 0.9 for p90)
+    ///
+    /// Returns `Duration::ZERO` when no samples have been recorded.
+    pub fn percentile(&self, p: f64) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+
+        let mut sorted: Vec<Duration> = self.samples.iter().copied().collect();
+        sorted.sort();
+
+        let index = (p.clamp(0.0, 1.0) * (sorted.len() - 1) as f64).round() as usize;
+        sorted[index]
+    }
+
+    /// Number of recorded samples
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Check if no samples have been recorded
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+}
+
+impl Default for ResponseTimeSampler {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_SAMPLES)
+    }
+}
+
 /// A timer that detects when operations take longer than a threshold
 #[derive(Debug, Clone)]
 pub struct LongWaitDetector {
@@ -18,6 +89,8 @@ pub struct LongWaitDetector {
     threshold: Duration,
     /// Whether we've already triggered the long wait callback
     triggered: bool,
+    /// Historical response times used for ETA estimates
+    sampler: ResponseTimeSampler,
 }
 
 impl LongWaitDetector {
@@ -32,6 +105,26 @@ impl LongWaitDetector {
             start_time: None,
             threshold,
             triggered: false,
+            sampler: ResponseTimeSampler::default(),
+        }
+    }
+
+    /// Use the given response-time history for ETA estimates
+    pub fn set_sampler(&mut self, sampler: ResponseTimeSampler) {
+        self.sampler = sampler;
+    }
+
+    /// Estimate how much longer the operation will take
+    ///
+    /// Compares `elapsed` against the median historical response time.
+    /// Returns None when no history exists or the median has already passed
+    /// (at which point any estimate would be a guess).
+    pub fn estimated_time_remaining(&self, elapsed: Duration) -> Option<Duration> {
+        let median = self.sampler.median()?;
+        if elapsed < median {
+            Some(median - elapsed)
+        } else {
+            None
         }
     }
 
@@ -323,6 +416,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sampler_record_evicts_oldest() {
+        let mut sampler = ResponseTimeSampler::new(3);
+
+        for secs in 1..=5 {
+            sampler.record(Duration::from_secs(secs));
+        }
+
+        assert_eq!(sampler.len(), 3);
+        // Oldest samples (1s, 2s) were evicted
+        assert_eq!(sampler.percentile(0.0), Duration::from_secs(3));
+        assert_eq!(sampler.percentile(1.0), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_sampler_median() {
+        let mut sampler = ResponseTimeSampler::default();
+        assert_eq!(sampler.median(), None);
+
+        sampler.record(Duration::from_secs(2));
+        sampler.record(Duration::from_secs(10));
+        sampler.record(Duration::from_secs(4));
+
+        assert_eq!(sampler.median(), Some(Duration::from_secs(4)));
+    }
+
+    #[test]
+    fn test_sampler_percentile() {
+        let mut sampler = ResponseTimeSampler::default();
+        assert_eq!(sampler.percentile(0.5), Duration::ZERO);
+
+        for secs in 1..=10 {
+            sampler.record(Duration::from_secs(secs));
+        }
+
+        assert_eq!(sampler.percentile(0.5), Duration::from_secs(6));
+        assert_eq!(sampler.percentile(0.9), Duration::from_secs(9));
+    }
+
+    #[test]
+    fn test_estimated_time_remaining() {
+        let mut sampler = ResponseTimeSampler::default();
+        sampler.record(Duration::from_secs(10));
+
+        let mut detector = LongWaitDetector::new();
+        detector.set_sampler(sampler);
+
+        assert_eq!(
+            detector.estimated_time_remaining(Duration::from_secs(3)),
+            Some(Duration::from_secs(7))
+        );
+        // Past the median there is no meaningful estimate
+        assert_eq!(
+            detector.estimated_time_remaining(Duration::from_secs(12)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_estimated_time_remaining_without_history() {
+        let detector = LongWaitDetector::new();
+        assert_eq!(
+            detector.estimated_time_remaining(Duration::from_secs(1)),
+            None
+        );
+    }
+
     #[test]
     fn test_threshold_boundary() {
         let threshold = Duration::from_millis(100);
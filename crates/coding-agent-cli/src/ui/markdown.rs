@@ -18,6 +18,8 @@ pub struct MarkdownRenderer {
     skin: MadSkin,
     syntax_set: SyntaxSet,
     theme_set: ThemeSet,
+    /// Whether to emit ANSI color codes (false for NO_COLOR / piped output)
+    colors: bool,
 }
 
 impl Default for MarkdownRenderer {
@@ -70,11 +72,30 @@ impl MarkdownRenderer {
             skin,
             syntax_set,
             theme_set,
+            colors: true,
+        }
+    }
+
+    /// Create a renderer that emits no colors or text styling.
+    ///
+    /// Used when colors are disabled (NO_COLOR, `--no-color`, or piped
+    /// output): markdown structure is still rendered, but the output
+    /// contains no ANSI escape codes.
+    pub fn plain() -> Self {
+        Self {
+            skin: MadSkin::no_style(),
+            syntax_set: SyntaxSet::load_defaults_newlines(),
+            theme_set: ThemeSet::load_defaults(),
+            colors: false,
         }
     }
 
     /// Highlight code with syntax-aware coloring
     fn highlight_code(&self, code: &str, language: &str) -> String {
+        if !self.colors {
+            return code.to_string();
+        }
+
         // Try to find syntax definition for the language
         let syntax = self
             .syntax_set
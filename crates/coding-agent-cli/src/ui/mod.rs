@@ -5,14 +5,17 @@
 pub mod commit_preview;
 pub mod components;
 pub mod context_bar;
+pub mod file_finder;
 pub mod file_picker;
 pub mod fun_facts;
 pub mod long_wait;
 pub mod markdown;
+pub mod notifications;
 pub mod output;
 pub mod progress;
 pub mod spinner;
 pub mod status_bar;
+pub mod status_line;
 pub mod syntax;
 pub mod theme;
 pub mod thinking;
@@ -20,13 +23,16 @@ pub mod tool_result;
 pub mod tool_spinner;
 
 pub use commit_preview::{edit_commit_message, CommitPreview, CommitPreviewResult};
-pub use context_bar::ContextBar;
+pub use context_bar::{ContextBar, ContextSegment, TrimAction, TrimSuggestion};
+pub use file_finder::{FileFinder, FileIndex};
 pub use file_picker::{FileEntry, FilePicker, FilePickerResult};
 pub use fun_facts::{FunFact, FunFactCache, FunFactClient};
-pub use long_wait::LongWaitDetector;
+pub use long_wait::{LongWaitDetector, ResponseTimeSampler};
 pub use markdown::MarkdownRenderer;
+pub use notifications::Notifier;
 pub use status_bar::StatusBar;
+pub use status_line::StatusLine;
 pub use theme::{Color, Theme};
 pub use thinking::ThinkingMessages;
-pub use tool_result::{FormattedResult, ToolResultFormatter};
-pub use tool_spinner::ToolExecutionSpinner;
+pub use tool_result::{ContentFormat, FormattedResult, ToolResultConfig, ToolResultFormatter};
+pub use tool_spinner::{SpinnerRetryHandle, ToolExecutionSpinner};
@@ -0,0 +1,122 @@
+//! Terminal and desktop notifications for long-running operations
+//!
+//! When a turn (or fix-agent) takes longer than a configurable threshold,
+//! the user has probably alt-tabbed away. This module emits a terminal bell
+//! and, where supported, an OSC 9 / notify-send desktop notification so they
+//! know the agent is ready or waiting for input.
+
+use std::io::{IsTerminal, Write};
+use std::time::Duration;
+
+/// Emits notifications when long-running operations finish or need input.
+///
+/// Gated behind the `behavior.notifications` config setting. Nothing is
+/// emitted for fast operations or when stdout is not a TTY.
+pub struct Notifier {
+    /// Whether notifications are enabled (behavior.notifications)
+    enabled: bool,
+    /// Minimum operation duration before a notification is emitted
+    threshold: Duration,
+}
+
+impl Notifier {
+    /// Create a notifier with the given enabled flag and threshold in seconds
+    pub fn new(enabled: bool, threshold_secs: u64) -> Self {
+        Self {
+            enabled,
+            threshold: Duration::from_secs(threshold_secs),
+        }
+    }
+
+    /// Create a notifier that never emits anything
+    pub fn disabled() -> Self {
+        Self::new(false, 0)
+    }
+
+    /// Whether a notification should be emitted for an operation that took
+    /// `elapsed` time
+    pub fn should_notify(&self, elapsed: Duration) -> bool {
+        self.enabled && elapsed >= self.threshold
+    }
+
+    /// Notify that a long operation finished, if it exceeded the threshold
+    pub fn notify_if_long(&self, elapsed: Duration, message: &str) {
+        if self.should_notify(elapsed) {
+            self.emit(message);
+        }
+    }
+
+    /// Notify that the agent is waiting for input (e.g. a permission prompt)
+    pub fn notify_waiting(&self, message: &str) {
+        if self.enabled {
+            self.emit(message);
+        }
+    }
+
+    /// Emit the notification: terminal bell, OSC 9, and a desktop
+    /// notification where supported. No-op when stdout is not a TTY.
+    fn emit(&self, message: &str) {
+        if !std::io::stdout().is_terminal() {
+            return;
+        }
+
+        // Terminal bell plus OSC 9 notification (iTerm2, kitty, WezTerm, ...)
+        print!("\x07\x1b]9;coding-agent: {}\x07", message);
+        let _ = std::io::stdout().flush();
+
+        Self::desktop_notify(message);
+    }
+
+    /// Send a desktop notification via notify-send, ignoring failures
+    #[cfg(target_os = "linux")]
+    fn desktop_notify(message: &str) {
+        use std::process::{Command, Stdio};
+
+        let _ = Command::new("notify-send")
+            .arg("coding-agent")
+            .arg(message)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn();
+    }
+
+    /// Desktop notifications are only wired up on Linux for now
+    #[cfg(not(target_os = "linux"))]
+    fn desktop_notify(_message: &str) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_notify_above_threshold() {
+        let notifier = Notifier::new(true, 10);
+
+        assert!(notifier.should_notify(Duration::from_secs(10)));
+        assert!(notifier.should_notify(Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_should_not_notify_below_threshold() {
+        let notifier = Notifier::new(true, 10);
+
+        assert!(!notifier.should_notify(Duration::from_secs(0)));
+        assert!(!notifier.should_notify(Duration::from_secs(9)));
+    }
+
+    #[test]
+    fn test_disabled_notifier_never_notifies() {
+        let notifier = Notifier::disabled();
+
+        assert!(!notifier.should_notify(Duration::from_secs(0)));
+        assert!(!notifier.should_notify(Duration::from_secs(3600)));
+    }
+
+    #[test]
+    fn test_zero_threshold_notifies_immediately() {
+        let notifier = Notifier::new(true, 0);
+
+        assert!(notifier.should_notify(Duration::from_secs(0)));
+    }
+}
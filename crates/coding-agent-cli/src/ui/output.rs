@@ -123,8 +123,10 @@ mod tests {
 
     #[test]
     fn test_styled_output_creation() {
+        // Color availability depends on the environment (e.g. NO_COLOR in CI);
+        // just make sure construction works and the theme is queryable.
         let output = StyledOutput::default_theme();
-        assert!(output.theme().colors_enabled() || true); // May be disabled in CI
+        let _ = output.theme().colors_enabled();
     }
 
     #[test]
@@ -1,6 +1,7 @@
 //! Spinner component for showing progress during operations
 
 use indicatif::{ProgressBar as IndicatifBar, ProgressStyle};
+use std::io::IsTerminal;
 use std::time::Duration;
 
 /// Spinner for showing activity during long-running operations
@@ -8,6 +9,14 @@ pub struct Spinner {
     bar: IndicatifBar,
     messages: Vec<&'static str>,
     message_index: usize,
+    /// Whether stdout is a TTY; when false the spinner degrades to
+    /// plain single-line progress text (no animation or escape codes)
+    interactive: bool,
+}
+
+/// Check whether stdout is attached to a terminal
+pub(crate) fn stdout_is_tty() -> bool {
+    std::io::stdout().is_terminal()
 }
 
 /// Default thinking messages
@@ -27,24 +36,22 @@ impl Spinner {
 
     /// Create a spinner with custom messages
     pub fn with_messages(messages: Vec<&'static str>) -> Self {
-        let bar = IndicatifBar::new_spinner();
-        bar.set_style(
-            ProgressStyle::default_spinner()
-                .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏")
-                .template("{spinner:.yellow} {msg}")
-                .unwrap(),
-        );
-        bar.enable_steady_tick(Duration::from_millis(80));
+        let interactive = stdout_is_tty();
+        let bar = Self::new_bar(interactive);
 
         let spinner = Self {
             bar,
             messages,
             message_index: 0,
+            interactive,
         };
 
         // Set initial message
         if !spinner.messages.is_empty() {
             spinner.bar.set_message(spinner.messages[0]);
+            if !interactive {
+                println!("{}", spinner.messages[0]);
+            }
         }
 
         spinner
@@ -52,6 +59,30 @@ impl Spinner {
 
     /// Create a spinner with a single static message
     pub fn with_message(message: &'static str) -> Self {
+        let interactive = stdout_is_tty();
+        let bar = Self::new_bar(interactive);
+        bar.set_message(message);
+        if !interactive {
+            println!("{}", message);
+        }
+
+        Self {
+            bar,
+            messages: vec![message],
+            message_index: 0,
+            interactive,
+        }
+    }
+
+    /// Create the underlying indicatif bar.
+    ///
+    /// When stdout is not a TTY the bar is hidden and callers print plain
+    /// progress lines instead, so piped output contains no escape codes.
+    fn new_bar(interactive: bool) -> IndicatifBar {
+        if !interactive {
+            return IndicatifBar::hidden();
+        }
+
         let bar = IndicatifBar::new_spinner();
         bar.set_style(
             ProgressStyle::default_spinner()
@@ -59,14 +90,8 @@ impl Spinner {
                 .template("{spinner:.yellow} {msg}")
                 .unwrap(),
         );
-        bar.set_message(message);
         bar.enable_steady_tick(Duration::from_millis(80));
-
-        Self {
-            bar,
-            messages: vec![message],
-            message_index: 0,
-        }
+        bar
     }
 
     /// Set the current message
@@ -94,7 +119,12 @@ impl Spinner {
 
     /// Stop the spinner with a success message
     pub fn finish_with_message(&self, message: &str) {
-        self.bar.finish_with_message(message.to_string());
+        if self.interactive {
+            self.bar.finish_with_message(message.to_string());
+        } else {
+            self.bar.finish();
+            println!("{}", message);
+        }
     }
 
     /// Stop the spinner and clear it from the terminal
@@ -208,7 +208,11 @@ impl StatusBar {
     ///
     /// Call this before re-rendering to avoid accumulating output.
     pub fn clear(&self, line_count: usize) -> io::Result<()> {
-        if line_count == 0 {
+        use std::io::IsTerminal;
+
+        // Cursor movement only makes sense on a real terminal; when piping
+        // we leave the previously rendered lines in place.
+        if line_count == 0 || !io::stdout().is_terminal() {
             return Ok(());
         }
 
@@ -0,0 +1,92 @@
+//! One-line session status shown above the prompt
+//!
+//! Renders `branch* | model | mode | ctx 42%` so the user always knows which
+//! branch, model, and mode they are in. The line is printed fresh on each
+//! loop iteration (it scrolls away with the prompt), so it never needs the
+//! cursor gymnastics the agent StatusBar does and cannot flicker.
+
+use crate::ui::theme::{Color, Theme};
+
+/// Formats the per-prompt session status line
+pub struct StatusLine {
+    theme: Theme,
+}
+
+impl StatusLine {
+    /// Create a status line formatter with the given theme
+    pub fn new(theme: Theme) -> Self {
+        Self { theme }
+    }
+
+    /// Format the status line from its parts
+    ///
+    /// `branch` carries the branch name and whether the worktree is dirty
+    /// (rendered as a `*` suffix). `mode` is the mode indicator, omitted for
+    /// normal mode. Parts that are unavailable are simply left out.
+    pub fn format(
+        &self,
+        branch: Option<(&str, bool)>,
+        model: &str,
+        mode: Option<&str>,
+        ctx_percent: u64,
+    ) -> String {
+        let mut parts: Vec<String> = Vec::new();
+
+        if let Some((name, dirty)) = branch {
+            if dirty {
+                parts.push(format!("{}*", name));
+            } else {
+                parts.push(name.to_string());
+            }
+        }
+
+        parts.push(model.to_string());
+
+        if let Some(mode) = mode {
+            parts.push(mode.to_string());
+        }
+
+        parts.push(format!("ctx {}%", ctx_percent));
+
+        self.theme.apply(Color::Muted, &parts.join(" | "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::theme::ThemeStyle;
+
+    fn plain_status_line() -> StatusLine {
+        StatusLine::new(Theme::new(ThemeStyle::Monochrome))
+    }
+
+    #[test]
+    fn test_format_all_parts() {
+        let line = plain_status_line().format(
+            Some(("main", true)),
+            "claude-3-opus",
+            Some("📋 Planning: spec.md"),
+            42,
+        );
+
+        assert_eq!(
+            line,
+            "main* | claude-3-opus | 📋 Planning: spec.md | ctx 42%"
+        );
+    }
+
+    #[test]
+    fn test_format_clean_branch_has_no_star() {
+        let line = plain_status_line().format(Some(("main", false)), "claude-3-opus", None, 0);
+
+        assert_eq!(line, "main | claude-3-opus | ctx 0%");
+    }
+
+    #[test]
+    fn test_format_without_branch_or_mode() {
+        let line = plain_status_line().format(None, "claude-3-opus", None, 7);
+
+        assert_eq!(line, "claude-3-opus | ctx 7%");
+    }
+}
@@ -67,6 +67,44 @@ impl SyntaxHighlighter {
         }
     }
 
+    /// Highlight code as HTML `<span>`s with inline color styles.
+    ///
+    /// Used by the HTML session export, which must be self-contained;
+    /// inline styles keep the output independent of any stylesheet or
+    /// script. Unknown languages come back as escaped plain text.
+    pub fn highlight_html(&self, code: &str, language: &str) -> String {
+        let syntax = self
+            .syntax_set
+            .find_syntax_by_extension(language)
+            .or_else(|| self.syntax_set.find_syntax_by_name(language))
+            .or_else(|| self.syntax_set.find_syntax_by_token(language));
+
+        let Some(syntax) = syntax else {
+            return escape_html(code);
+        };
+
+        let theme = &self.theme_set.themes[&self.theme_name];
+        let mut highlighter = HighlightLines::new(syntax, theme);
+        let mut output = String::new();
+
+        for line in LinesWithEndings::from(code) {
+            let ranges: Vec<(Style, &str)> =
+                highlighter.highlight_line(line, &self.syntax_set).unwrap();
+            for (style, text) in ranges {
+                let color = style.foreground;
+                output.push_str(&format!(
+                    "<span style=\"color:#{:02x}{:02x}{:02x}\">{}</span>",
+                    color.r,
+                    color.g,
+                    color.b,
+                    escape_html(text)
+                ));
+            }
+        }
+
+        output
+    }
+
     /// Check if a language is supported
     pub fn is_supported(&self, language: &str) -> bool {
         self.syntax_set.find_syntax_by_extension(language).is_some()
@@ -91,6 +129,14 @@ impl Default for SyntaxHighlighter {
     }
 }
 
+/// Escape text for embedding in HTML element content or attributes
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -161,6 +207,36 @@ mod tests {
         assert_eq!(highlighter.theme_name, "base16-mocha.dark");
     }
 
+    #[test]
+    fn test_highlight_html_emits_styled_spans() {
+        let highlighter = SyntaxHighlighter::new();
+        let code = "fn main() { println!(\"<hi>\"); }";
+
+        let html = highlighter.highlight_html(code, "rs");
+
+        // Colored spans with inline styles, no raw markup from the source
+        assert!(html.contains("<span style=\"color:#"));
+        assert!(html.contains("&lt;hi&gt;"));
+        assert!(!html.contains("<hi>"));
+    }
+
+    #[test]
+    fn test_highlight_html_unknown_language_escapes_plain() {
+        let highlighter = SyntaxHighlighter::new();
+
+        let html = highlighter.highlight_html("a < b && c > d", "unknown_language_xyz");
+
+        assert_eq!(html, "a &lt; b &amp;&amp; c &gt; d");
+    }
+
+    #[test]
+    fn test_escape_html() {
+        assert_eq!(
+            escape_html("<a href=\"x\">&</a>"),
+            "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;"
+        );
+    }
+
     #[test]
     fn test_highlight_multiline() {
         let highlighter = SyntaxHighlighter::new();
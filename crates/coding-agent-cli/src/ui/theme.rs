@@ -4,6 +4,10 @@
 
 use console::Style;
 use std::env;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Process-wide color kill switch, set by `--no-color` or `theme.color = false`.
+static COLORS_FORCED_OFF: AtomicBool = AtomicBool::new(false);
 
 /// Color definitions for different UI elements
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -39,11 +43,41 @@ pub struct Theme {
     pub style: ThemeStyle,
     /// Whether colors are enabled (respects NO_COLOR)
     colors_enabled: bool,
+    /// Per-role color overrides from config, merged over the base style
+    overrides: ColorOverrides,
+}
+
+/// Parsed per-role color overrides
+#[derive(Debug, Clone, Default)]
+struct ColorOverrides {
+    user_input: Option<console::Color>,
+    agent: Option<console::Color>,
+    tool: Option<console::Color>,
+    error: Option<console::Color>,
+    success: Option<console::Color>,
+    muted: Option<console::Color>,
+}
+
+impl ColorOverrides {
+    /// Look up the override for a semantic color, if one was configured
+    fn get(&self, color: Color) -> Option<console::Color> {
+        match color {
+            Color::UserInput => self.user_input,
+            Color::Agent => self.agent,
+            Color::Tool => self.tool,
+            Color::Error => self.error,
+            Color::Success => self.success,
+            Color::Muted => self.muted,
+            _ => None,
+        }
+    }
 }
 
 /// Available theme styles
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ThemeStyle {
+    /// Pick a style matching the terminal's color capabilities
+    Auto,
     /// Minimal color usage
     Minimal,
     /// Full color support
@@ -60,14 +94,108 @@ impl Default for Theme {
 
 impl Theme {
     /// Create a new theme with the given style
+    ///
+    /// `Auto` resolves to a concrete style via terminal detection, so
+    /// `theme.style` always names the style actually in effect.
     pub fn new(style: ThemeStyle) -> Self {
-        let colors_enabled = !Self::no_color_env() && style != ThemeStyle::Monochrome;
+        let style = if style == ThemeStyle::Auto {
+            Self::detect_style()
+        } else {
+            style
+        };
+        let colors_enabled =
+            !Self::no_color_env() && !Self::colors_forced_off() && style != ThemeStyle::Monochrome;
         Self {
             style,
             colors_enabled,
+            overrides: ColorOverrides::default(),
         }
     }
 
+    /// Create a theme matching the terminal's color capabilities
+    ///
+    /// Equivalent to `Theme::new(ThemeStyle::Auto)`.
+    pub fn detect_terminal() -> Self {
+        Self::new(ThemeStyle::Auto)
+    }
+
+    /// Pick a style from `$TERM`, `$COLORTERM`, and the color count the
+    /// terminal reports
+    ///
+    /// Truecolor and 256-color terminals get `Colorful`, plain ANSI
+    /// terminals get `Minimal`, and dumb or colorless terminals (or
+    /// `NO_COLOR` per no-color.org) get `Monochrome`.
+    fn detect_style() -> ThemeStyle {
+        if Self::no_color_env() || Self::colors_forced_off() {
+            return ThemeStyle::Monochrome;
+        }
+
+        let term = env::var("TERM").unwrap_or_default();
+        if term.is_empty() || term == "dumb" {
+            return ThemeStyle::Monochrome;
+        }
+
+        let colorterm = env::var("COLORTERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" || term.contains("256color") {
+            return ThemeStyle::Colorful;
+        }
+
+        match crossterm::style::available_color_count() {
+            0..=7 => ThemeStyle::Monochrome,
+            8..=255 => ThemeStyle::Minimal,
+            _ => ThemeStyle::Colorful,
+        }
+    }
+
+    /// Create a theme with per-role color overrides merged over the style
+    ///
+    /// `overrides` holds (role, value) pairs from the `[theme.colors]` config
+    /// table. Returns the theme plus a warning for each entry that could not
+    /// be applied; invalid values fall back to the style's default color.
+    pub fn with_overrides(style: ThemeStyle, overrides: &[(&str, &str)]) -> (Self, Vec<String>) {
+        let mut theme = Self::new(style);
+        let mut warnings = Vec::new();
+
+        for (role, value) in overrides {
+            let Some(color) = parse_color(value) else {
+                warnings.push(format!(
+                    "Invalid color '{}' for theme role '{}', using default",
+                    value, role
+                ));
+                continue;
+            };
+
+            match *role {
+                "prompt" => theme.overrides.user_input = Some(color),
+                "assistant" => theme.overrides.agent = Some(color),
+                "tool" => theme.overrides.tool = Some(color),
+                "error" => theme.overrides.error = Some(color),
+                "success" => theme.overrides.success = Some(color),
+                "dim" => theme.overrides.muted = Some(color),
+                _ => warnings.push(format!("Unknown theme role '{}'", role)),
+            }
+        }
+
+        (theme, warnings)
+    }
+
+    /// Disable colors for the entire process.
+    ///
+    /// Used by the `--no-color` CLI flag and `theme.color = false` config.
+    /// Every theme created after this call behaves like `Monochrome`, and the
+    /// `console` crate's own styling is disabled as well so no escape codes
+    /// reach stdout/stderr.
+    pub fn force_no_color() {
+        COLORS_FORCED_OFF.store(true, Ordering::Relaxed);
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+
+    /// Check if colors have been force-disabled process-wide
+    fn colors_forced_off() -> bool {
+        COLORS_FORCED_OFF.load(Ordering::Relaxed)
+    }
+
     /// Check if NO_COLOR environment variable is set
     fn no_color_env() -> bool {
         env::var("NO_COLOR").is_ok()
@@ -84,6 +212,16 @@ impl Theme {
             return Style::new();
         }
 
+        // Config overrides replace the foreground color but keep the
+        // emphasis of the role (errors stay bold)
+        if let Some(fg) = self.overrides.get(color) {
+            let style = Style::new().fg(fg);
+            return match color {
+                Color::Error | Color::Warning => style.bold(),
+                _ => style,
+            };
+        }
+
         match color {
             Color::UserInput => Style::new().white(),
             Color::Agent => Style::new().cyan(),
@@ -117,8 +255,10 @@ impl Theme {
 
 impl ThemeStyle {
     /// Parse a theme style from a string
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
+            "auto" => Some(Self::Auto),
             "minimal" => Some(Self::Minimal),
             "colorful" => Some(Self::Colorful),
             "monochrome" | "mono" | "none" => Some(Self::Monochrome),
@@ -127,6 +267,54 @@ impl ThemeStyle {
     }
 }
 
+/// Parse a color value from config: a named ANSI color, an xterm-256 index,
+/// or a "#rrggbb" hex color (mapped to the closest 256-color palette entry)
+fn parse_color(s: &str) -> Option<console::Color> {
+    use console::Color as C;
+
+    let s = s.trim().to_lowercase();
+
+    if let Some(hex) = s.strip_prefix('#') {
+        if hex.len() != 6 {
+            return None;
+        }
+        let r = u8::from_str_radix(&hex[0..2], 16).ok()?;
+        let g = u8::from_str_radix(&hex[2..4], 16).ok()?;
+        let b = u8::from_str_radix(&hex[4..6], 16).ok()?;
+        return Some(C::Color256(rgb_to_ansi256(r, g, b)));
+    }
+
+    match s.as_str() {
+        "black" => Some(C::Black),
+        "red" => Some(C::Red),
+        "green" => Some(C::Green),
+        "yellow" => Some(C::Yellow),
+        "blue" => Some(C::Blue),
+        "magenta" => Some(C::Magenta),
+        "cyan" => Some(C::Cyan),
+        "white" => Some(C::White),
+        _ => s.parse::<u8>().ok().map(C::Color256),
+    }
+}
+
+/// Map an RGB color to the closest xterm-256 palette index
+fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    // Use the greyscale ramp when all components match
+    if r == g && g == b {
+        if r < 8 {
+            return 16;
+        }
+        if r > 248 {
+            return 231;
+        }
+        return 232 + ((r as u16 - 8) / 10) as u8;
+    }
+
+    // Otherwise snap each component to the 6x6x6 color cube
+    let scale = |c: u8| (c as u16 * 5 + 127) / 255;
+    (16 + 36 * scale(r) + 6 * scale(g) + scale(b)) as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -182,9 +370,55 @@ mod tests {
         );
         assert_eq!(ThemeStyle::from_str("mono"), Some(ThemeStyle::Monochrome));
         assert_eq!(ThemeStyle::from_str("none"), Some(ThemeStyle::Monochrome));
+        assert_eq!(ThemeStyle::from_str("auto"), Some(ThemeStyle::Auto));
         assert_eq!(ThemeStyle::from_str("invalid"), None);
     }
 
+    #[test]
+    fn test_auto_resolves_to_concrete_style() {
+        // Whatever the environment looks like, Auto never survives
+        // construction; detection picks one of the real styles
+        let detected = Theme::detect_terminal();
+        assert_ne!(detected.style, ThemeStyle::Auto);
+
+        let via_new = Theme::new(ThemeStyle::Auto);
+        assert_ne!(via_new.style, ThemeStyle::Auto);
+    }
+
+    #[test]
+    fn test_parse_color_values() {
+        assert_eq!(parse_color("red"), Some(console::Color::Red));
+        assert_eq!(parse_color("CYAN"), Some(console::Color::Cyan));
+        assert_eq!(parse_color("208"), Some(console::Color::Color256(208)));
+        assert_eq!(parse_color("#000000"), Some(console::Color::Color256(16)));
+        assert_eq!(parse_color("#ffffff"), Some(console::Color::Color256(231)));
+        assert_eq!(parse_color("not-a-color"), None);
+        assert_eq!(parse_color("#ff00"), None);
+        assert_eq!(parse_color("#zzzzzz"), None);
+    }
+
+    #[test]
+    fn test_theme_with_overrides() {
+        let overrides = [("assistant", "magenta"), ("dim", "240")];
+        let (theme, warnings) = Theme::with_overrides(ThemeStyle::Minimal, &overrides);
+
+        assert!(warnings.is_empty());
+        assert_eq!(theme.overrides.agent, Some(console::Color::Magenta));
+        assert_eq!(theme.overrides.muted, Some(console::Color::Color256(240)));
+        assert_eq!(theme.overrides.tool, None);
+    }
+
+    #[test]
+    fn test_theme_with_invalid_override_warns() {
+        let overrides = [("error", "chartreuse-ish"), ("footer", "red")];
+        let (theme, warnings) = Theme::with_overrides(ThemeStyle::Minimal, &overrides);
+
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("Invalid color"));
+        assert!(warnings[1].contains("Unknown theme role"));
+        assert_eq!(theme.overrides.error, None);
+    }
+
     #[test]
     fn test_theme_apply() {
         let theme = Theme::new(ThemeStyle::Minimal);
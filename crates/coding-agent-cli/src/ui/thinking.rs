@@ -66,6 +66,7 @@ impl ThinkingMessages {
     }
 
     /// Force advance to the next message
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> &'static str {
         if !self.messages.is_empty() {
             self.current_index = (self.current_index + 1) % self.messages.len();
@@ -100,7 +101,7 @@ mod tests {
     #[test]
     fn test_default_messages() {
         let thinking = ThinkingMessages::new();
-        let expected = vec![
+        let expected = [
             "Pondering...",
             "Percolating...",
             "Cogitating...",
@@ -17,6 +17,11 @@ pub struct ToolResultConfig {
     pub show_line_numbers: bool,
     /// Threshold for collapsing results (0 = never collapse)
     pub collapse_threshold: usize,
+    /// Whether tables use box-drawing borders (ASCII `+--+` when false)
+    pub unicode_tables: bool,
+    /// Whether plain-text output gets a content-detection pass
+    /// (JSON, diffs, error messages render with structure-aware styling)
+    pub auto_format: bool,
 }
 
 impl Default for ToolResultConfig {
@@ -26,10 +31,24 @@ impl Default for ToolResultConfig {
             enable_highlighting: true,
             show_line_numbers: false,
             collapse_threshold: 5,
+            unicode_tables: true,
+            auto_format: true,
         }
     }
 }
 
+/// Content type detected in otherwise plain-text tool output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentFormat {
+    Json,
+    Yaml,
+    Csv,
+    UnifiedDiff,
+    Markdown,
+    ErrorMessage,
+    PlainText,
+}
+
 /// Result of formatting a tool output, potentially collapsible
 #[derive(Debug, Clone)]
 pub struct FormattedResult {
@@ -60,6 +79,15 @@ impl ToolResultFormatter {
         }
     }
 
+    /// Create a formatter with a custom theme
+    pub fn with_theme(theme: Theme) -> Self {
+        Self {
+            highlighter: SyntaxHighlighter::new(),
+            theme,
+            config: ToolResultConfig::default(),
+        }
+    }
+
     /// Create a formatter with custom configuration
     pub fn with_config(config: ToolResultConfig) -> Self {
         Self {
@@ -90,16 +118,40 @@ impl ToolResultFormatter {
                 collapsed_count: 0,
                 tool_name: tool_name.to_string(),
             },
-            "list_files" => self.format_file_list_collapsible(output, tool_name),
+            // Structured listings render as a table when the output is a
+            // JSON array of objects; plain string arrays keep the old list
+            "list_files" => match self.try_format_table(output) {
+                Some(table) => FormattedResult {
+                    display: table,
+                    collapsed_content: None,
+                    collapsed_count: 0,
+                    tool_name: tool_name.to_string(),
+                },
+                None => self.format_file_list_collapsible(output, tool_name),
+            },
+            "list_processes" => FormattedResult {
+                display: self
+                    .try_format_table(output)
+                    .unwrap_or_else(|| self.format_generic(output)),
+                collapsed_content: None,
+                collapsed_count: 0,
+                tool_name: tool_name.to_string(),
+            },
+            // Plain-text branches get a secondary content-detection pass:
+            // bash that prints JSON renders as JSON, not a code block
             "bash" => FormattedResult {
-                display: self.format_bash_output(output),
+                display: self
+                    .try_auto_format(output)
+                    .unwrap_or_else(|| self.format_bash_output(output)),
                 collapsed_content: None,
                 collapsed_count: 0,
                 tool_name: tool_name.to_string(),
             },
             "code_search" => self.format_search_results_collapsible(output, tool_name),
             _ => FormattedResult {
-                display: self.format_generic(output),
+                display: self
+                    .try_auto_format(output)
+                    .unwrap_or_else(|| self.format_generic(output)),
                 collapsed_content: None,
                 collapsed_count: 0,
                 tool_name: tool_name.to_string(),
@@ -139,8 +191,10 @@ impl ToolResultFormatter {
         };
 
         // Display content
-        if self.config.enable_highlighting && language.is_some() {
-            let highlighted = self.highlighter.highlight(content, language.unwrap());
+        if let Some(language) =
+            language.filter(|_| self.config.enable_highlighting && self.theme.colors_enabled())
+        {
+            let highlighted = self.highlighter.highlight(content, language);
             for (idx, line) in highlighted
                 .lines()
                 .take(self.config.max_display_lines)
@@ -341,19 +395,7 @@ impl ToolResultFormatter {
 
         let display_count = self.config.max_display_lines.min(total_lines);
         for line in &lines[..display_count] {
-            // Highlight file paths
-            if line.contains(':') {
-                let parts: Vec<&str> = line.splitn(2, ':').collect();
-                if parts.len() == 2 {
-                    result.push_str(&format!(
-                        "  {}: {}\r\n",
-                        self.theme.apply(Color::Agent, parts[0]),
-                        parts[1]
-                    ));
-                    continue;
-                }
-            }
-            result.push_str(&format!("  {}\r\n", line));
+            result.push_str(&self.render_search_line(line));
         }
 
         if total_lines > display_count {
@@ -369,6 +411,55 @@ impl ToolResultFormatter {
         result
     }
 
+    /// Render one line of search output: match lines keep the highlighted
+    /// file path, while the context lines and chunk separators present
+    /// when code_search runs with context_lines are dimmed so the matches
+    /// stand out.
+    fn render_search_line(&self, line: &str) -> String {
+        if Self::is_search_context_line(line) {
+            return format!("  {}\r\n", self.theme.apply(Color::Muted, line));
+        }
+        if line.contains(':') {
+            let parts: Vec<&str> = line.splitn(2, ':').collect();
+            if parts.len() == 2 {
+                return format!(
+                    "  {}: {}\r\n",
+                    self.theme.apply(Color::Agent, parts[0]),
+                    parts[1]
+                );
+            }
+        }
+        format!("  {}\r\n", line)
+    }
+
+    /// True for the extra lines ripgrep emits when context is enabled:
+    /// `--` chunk separators and `path-NN-text` context lines. Match
+    /// lines use colons (`path:NN:text`) and are checked first so a
+    /// match whose text contains dashes is never dimmed.
+    fn is_search_context_line(line: &str) -> bool {
+        if line == "--" {
+            return true;
+        }
+        if let Some((_, rest)) = line.split_once(':') {
+            let number = rest.split(':').next().unwrap_or("");
+            if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+                return false;
+            }
+        }
+        // Look for a dash-delimited line number anywhere in the line,
+        // since the file path itself may contain dashes
+        let mut rest = line;
+        while let Some(pos) = rest.find('-') {
+            let after = &rest[pos + 1..];
+            let digits = after.chars().take_while(|c| c.is_ascii_digit()).count();
+            if digits > 0 && after[digits..].starts_with('-') {
+                return true;
+            }
+            rest = after;
+        }
+        false
+    }
+
     /// Format generic output
     fn format_generic(&self, output: &str) -> String {
         let lines: Vec<&str> = output.lines().collect();
@@ -394,6 +485,140 @@ impl ToolResultFormatter {
         result
     }
 
+    /// Detect the content type of otherwise plain-text output
+    ///
+    /// Error prefixes are checked first so compiler-style `error: ...`
+    /// lines never read as YAML key/value pairs; after that the order is
+    /// JSON parse, YAML-ish key/value lines, unified diff headers,
+    /// consistent comma counts for CSV, then markdown headers.
+    pub fn auto_detect_format(content: &str) -> ContentFormat {
+        let trimmed = content.trim();
+        if trimmed.is_empty() {
+            return ContentFormat::PlainText;
+        }
+
+        let lines: Vec<&str> = trimmed.lines().collect();
+        let first_lower = lines[0].to_lowercase();
+        if first_lower.starts_with("error:") || first_lower.starts_with("error[") {
+            return ContentFormat::ErrorMessage;
+        }
+
+        if (trimmed.starts_with('{') || trimmed.starts_with('['))
+            && serde_json::from_str::<serde_json::Value>(trimmed).is_ok()
+        {
+            return ContentFormat::Json;
+        }
+        if Self::looks_like_yaml(&lines) {
+            return ContentFormat::Yaml;
+        }
+        if lines.iter().any(|line| line.starts_with("--- a/"))
+            && lines.iter().any(|line| line.starts_with("+++ b/"))
+        {
+            return ContentFormat::UnifiedDiff;
+        }
+        if Self::looks_like_csv(&lines) {
+            return ContentFormat::Csv;
+        }
+        if lines
+            .iter()
+            .any(|line| line.starts_with("# ") || line.starts_with("## "))
+        {
+            return ContentFormat::Markdown;
+        }
+
+        ContentFormat::PlainText
+    }
+
+    /// Whether every line reads as a YAML mapping entry or list item
+    ///
+    /// Heuristic only (there is no YAML dependency): at least two
+    /// `key: value` lines with simple keys, and nothing that is neither
+    /// a mapping entry, a list item, nor an indented continuation.
+    fn looks_like_yaml(lines: &[&str]) -> bool {
+        let is_key_value = |line: &str| {
+            line.split_once(':').is_some_and(|(key, value)| {
+                !key.is_empty()
+                    && key
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+                    && (value.is_empty() || value.starts_with(' '))
+            })
+        };
+
+        let key_values = lines.iter().filter(|line| is_key_value(line)).count();
+        key_values >= 2
+            && lines.iter().all(|line| {
+                line.trim().is_empty()
+                    || line.starts_with(' ')
+                    || line.starts_with("- ")
+                    || is_key_value(line)
+            })
+    }
+
+    /// Whether every line carries the same nonzero number of commas
+    fn looks_like_csv(lines: &[&str]) -> bool {
+        if lines.len() < 2 {
+            return false;
+        }
+        let commas = lines[0].matches(',').count();
+        commas >= 1 && lines.iter().all(|line| line.matches(',').count() == commas)
+    }
+
+    /// Structure-aware rendering for plain-text output, when detection
+    /// finds a format we can improve on
+    ///
+    /// JSON is pretty-printed, unified diffs get +/- coloring, and error
+    /// messages render in the error color. YAML, CSV, and markdown read
+    /// fine as plain text, so those (and `PlainText`) return `None` and
+    /// the caller keeps its tool-name-based rendering.
+    fn try_auto_format(&self, output: &str) -> Option<String> {
+        if !self.config.auto_format {
+            return None;
+        }
+        match Self::auto_detect_format(output) {
+            ContentFormat::Json => {
+                let value: serde_json::Value = serde_json::from_str(output.trim()).ok()?;
+                let pretty = serde_json::to_string_pretty(&value).ok()?;
+                Some(self.format_generic(&pretty))
+            }
+            ContentFormat::UnifiedDiff => {
+                let styled = output
+                    .trim_end()
+                    .lines()
+                    .map(|line| {
+                        if line.starts_with("--- ")
+                            || line.starts_with("+++ ")
+                            || line.starts_with("@@")
+                        {
+                            self.theme.apply(Color::Muted, line)
+                        } else if line.starts_with('+') {
+                            self.theme.apply(Color::Success, line)
+                        } else if line.starts_with('-') {
+                            self.theme.apply(Color::Error, line)
+                        } else {
+                            line.to_string()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Some(self.format_generic(&styled))
+            }
+            ContentFormat::ErrorMessage => {
+                let styled = output
+                    .trim_end()
+                    .lines()
+                    .map(|line| self.theme.apply(Color::Error, line))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                Some(self.format_generic(&styled))
+            }
+            ContentFormat::Yaml
+            | ContentFormat::Csv
+            | ContentFormat::Markdown
+            | ContentFormat::PlainText => None,
+        }
+    }
+
     /// Format file list with collapsible support
     fn format_file_list_collapsible(&self, output: &str, tool_name: &str) -> FormattedResult {
         if let Ok(files) = serde_json::from_str::<Vec<String>>(output) {
@@ -497,18 +722,7 @@ impl ToolResultFormatter {
         ));
 
         for line in &lines {
-            if line.contains(':') {
-                let parts: Vec<&str> = line.splitn(2, ':').collect();
-                if parts.len() == 2 {
-                    full_output.push_str(&format!(
-                        "  {}: {}\r\n",
-                        self.theme.apply(Color::Agent, parts[0]),
-                        parts[1]
-                    ));
-                    continue;
-                }
-            }
-            full_output.push_str(&format!("  {}\r\n", line));
+            full_output.push_str(&self.render_search_line(line));
         }
 
         FormattedResult {
@@ -519,6 +733,119 @@ impl ToolResultFormatter {
         }
     }
 
+    /// Field order for table headers derived from JSON objects: known
+    /// fields first, anything else after them alphabetically
+    const KNOWN_TABLE_FIELDS: &'static [&'static str] = &[
+        "name", "path", "pid", "command", "type", "size", "modified", "cpu", "mem",
+    ];
+
+    /// Try to render a tool result as a table
+    ///
+    /// Succeeds when the output parses as a non-empty JSON array of
+    /// objects; headers come from the first object's keys.
+    fn try_format_table(&self, output: &str) -> Option<String> {
+        let values: Vec<serde_json::Value> = serde_json::from_str(output).ok()?;
+        let objects: Vec<&serde_json::Map<String, serde_json::Value>> = values
+            .iter()
+            .map(|v| v.as_object())
+            .collect::<Option<_>>()?;
+        let first = objects.first()?;
+
+        let mut headers: Vec<&str> = Self::KNOWN_TABLE_FIELDS
+            .iter()
+            .copied()
+            .filter(|field| first.contains_key(*field))
+            .collect();
+        // serde_json::Map iterates keys in sorted order
+        headers.extend(
+            first
+                .keys()
+                .map(String::as_str)
+                .filter(|key| !Self::KNOWN_TABLE_FIELDS.contains(key)),
+        );
+
+        let rows: Vec<Vec<String>> = objects
+            .iter()
+            .map(|object| {
+                headers
+                    .iter()
+                    .map(|header| match object.get(*header) {
+                        Some(serde_json::Value::String(s)) => s.clone(),
+                        Some(serde_json::Value::Null) | None => String::new(),
+                        Some(value) => value.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Some(self.format_table(&headers, &rows))
+    }
+
+    /// Render rows as an aligned table with bordered columns
+    ///
+    /// Column widths fit the widest cell. Borders use box-drawing
+    /// characters, or ASCII `+--+` when `unicode_tables` is off; the
+    /// header row and first column are color-coded via the theme.
+    pub fn format_table(&self, headers: &[&str], rows: &[Vec<String>]) -> String {
+        let cols = headers.len();
+        let mut widths: Vec<usize> = headers.iter().map(|h| h.chars().count()).collect();
+        for row in rows {
+            for (i, cell) in row.iter().take(cols).enumerate() {
+                widths[i] = widths[i].max(cell.chars().count());
+            }
+        }
+
+        // Corner/junction sets for the top, separator, and bottom borders
+        let (horizontal, vertical, top, mid, bottom) = if self.config.unicode_tables {
+            ('─', '│', ['┌', '┬', '┐'], ['├', '┼', '┤'], ['└', '┴', '┘'])
+        } else {
+            ('-', '|', ['+', '+', '+'], ['+', '+', '+'], ['+', '+', '+'])
+        };
+
+        let border = |ends: [char; 3]| -> String {
+            let mut line = String::new();
+            line.push(ends[0]);
+            for (i, width) in widths.iter().enumerate() {
+                line.push_str(&horizontal.to_string().repeat(width + 2));
+                line.push(if i + 1 == cols { ends[2] } else { ends[1] });
+            }
+            format!("  {}\r\n", self.theme.apply(Color::Muted, &line))
+        };
+        let divider = self.theme.apply(Color::Muted, &vertical.to_string());
+
+        let mut result = String::new();
+        result.push_str(&border(top));
+
+        // Header row, padded before coloring so widths stay aligned
+        let mut line = divider.clone();
+        for (i, header) in headers.iter().enumerate() {
+            let padded = format!(" {:<width$} ", header, width = widths[i]);
+            line.push_str(&self.theme.apply(Color::Tool, &padded));
+            line.push_str(&divider);
+        }
+        result.push_str(&format!("  {}\r\n", line));
+        result.push_str(&border(mid));
+
+        for row in rows {
+            let mut line = divider.clone();
+            for (i, width) in widths.iter().enumerate() {
+                let cell = row.get(i).map(String::as_str).unwrap_or("");
+                let padded = format!(" {:<width$} ", cell, width = width);
+                // The first column names the entry, like paths elsewhere
+                if i == 0 {
+                    line.push_str(&self.theme.apply(Color::Agent, &padded));
+                } else {
+                    line.push_str(&padded);
+                }
+                line.push_str(&divider);
+            }
+            result.push_str(&format!("  {}\r\n", line));
+        }
+
+        result.push_str(&border(bottom));
+        result
+    }
+
     /// Detect programming language from content (simple heuristics)
     fn detect_language(&self, content: &str) -> Option<&str> {
         // Check for common language markers
@@ -572,8 +899,10 @@ mod tests {
 
     #[test]
     fn test_format_file_content_truncated() {
-        let mut config = ToolResultConfig::default();
-        config.max_display_lines = 3;
+        let config = ToolResultConfig {
+            max_display_lines: 3,
+            ..Default::default()
+        };
         let formatter = ToolResultFormatter::with_config(config);
 
         let content = (0..10)
@@ -616,8 +945,10 @@ mod tests {
 
     #[test]
     fn test_format_file_list_truncated() {
-        let mut config = ToolResultConfig::default();
-        config.max_display_lines = 2;
+        let config = ToolResultConfig {
+            max_display_lines: 2,
+            ..Default::default()
+        };
         let formatter = ToolResultFormatter::with_config(config);
 
         let files = r#"["file1.txt", "file2.txt", "file3.txt", "file4.txt"]"#;
@@ -673,6 +1004,41 @@ mod tests {
         assert!(result.contains("fn main()"));
     }
 
+    #[test]
+    fn test_is_search_context_line() {
+        // Context lines and chunk separators from --context runs
+        assert!(ToolResultFormatter::is_search_context_line("--"));
+        assert!(ToolResultFormatter::is_search_context_line(
+            "src/main.rs-9-use std::fmt;"
+        ));
+        assert!(ToolResultFormatter::is_search_context_line(
+            "src/tool-result.rs-12-    foo: bar,"
+        ));
+        // Match lines are never context, even with dashes in the text
+        assert!(!ToolResultFormatter::is_search_context_line(
+            "src/main.rs:10:let x = a-1-b;"
+        ));
+        assert!(!ToolResultFormatter::is_search_context_line(
+            "plain text without line numbers"
+        ));
+    }
+
+    #[test]
+    fn test_format_search_results_dims_context_lines() {
+        // Arrange
+        let formatter = ToolResultFormatter::new();
+        let output = "src/main.rs-9-use std::fmt;\nsrc/main.rs:10:fn main()\n--";
+
+        // Act
+        let result = formatter.format_search_results(output);
+
+        // Assert: all lines render; context keeps its dash separators
+        // (no path highlighting splits it apart)
+        assert!(result.contains("src/main.rs-9-use std::fmt;"));
+        assert!(result.contains("fn main()"));
+        assert!(result.contains("--"));
+    }
+
     #[test]
     fn test_detect_language_rust() {
         let formatter = ToolResultFormatter::new();
@@ -723,6 +1089,8 @@ mod tests {
             enable_highlighting: true,
             show_line_numbers: false,
             collapse_threshold: 5,
+            unicode_tables: true,
+            auto_format: true,
         };
 
         assert_eq!(config.max_display_lines, 5);
@@ -735,6 +1103,8 @@ mod tests {
             enable_highlighting: false,
             show_line_numbers: false,
             collapse_threshold: 5,
+            unicode_tables: true,
+            auto_format: true,
         };
 
         assert!(!config.enable_highlighting);
@@ -747,6 +1117,8 @@ mod tests {
             enable_highlighting: false,
             show_line_numbers: true,
             collapse_threshold: 5,
+            unicode_tables: true,
+            auto_format: true,
         };
         let formatter = ToolResultFormatter::with_config(config);
         let content = "fn main() {\n    println!(\"Hello\");\n}";
@@ -766,6 +1138,8 @@ mod tests {
             enable_highlighting: false,
             show_line_numbers: false,
             collapse_threshold: 5,
+            unicode_tables: true,
+            auto_format: true,
         };
         let formatter = ToolResultFormatter::with_config(config);
         let content = "fn main() {\n    println!(\"Hello\");\n}";
@@ -784,6 +1158,8 @@ mod tests {
             enable_highlighting: false,
             show_line_numbers: true,
             collapse_threshold: 5,
+            unicode_tables: true,
+            auto_format: true,
         };
         let formatter = ToolResultFormatter::with_config(config);
 
@@ -898,6 +1274,208 @@ mod tests {
         assert!(result.display.contains("/results"));
     }
 
+    #[test]
+    fn test_format_table_aligns_columns() {
+        let formatter = ToolResultFormatter::new();
+        let headers = ["name", "size"];
+        let rows = vec![
+            vec!["main.rs".to_string(), "1024".to_string()],
+            vec!["a.rs".to_string(), "7".to_string()],
+        ];
+
+        let result = formatter.format_table(&headers, &rows);
+
+        assert!(result.contains("┌"));
+        assert!(result.contains("┬"));
+        assert!(result.contains("┼"));
+        assert!(result.contains("┘"));
+        // Cells pad to the widest entry in their column
+        assert!(result.contains(" main.rs "));
+        assert!(result.contains(" a.rs    "));
+        assert!(result.contains(" 1024 "));
+        assert!(result.contains(" 7    "));
+    }
+
+    #[test]
+    fn test_format_table_ascii_fallback() {
+        let config = ToolResultConfig {
+            unicode_tables: false,
+            ..Default::default()
+        };
+        let formatter = ToolResultFormatter::with_config(config);
+        let headers = ["pid", "command"];
+        let rows = vec![vec!["42".to_string(), "bash".to_string()]];
+
+        let result = formatter.format_table(&headers, &rows);
+
+        assert!(result.contains("+--"));
+        assert!(result.contains("| 42 "));
+        assert!(!result.contains('─'));
+        assert!(!result.contains('│'));
+    }
+
+    #[test]
+    fn test_list_files_objects_render_as_table() {
+        let formatter = ToolResultFormatter::new();
+        let output = r#"[
+            {"name": "main.rs", "size": 1024, "type": "file"},
+            {"name": "src/", "size": 0, "type": "dir"}
+        ]"#;
+
+        let result = formatter.format_result("list_files", output);
+
+        assert!(result.contains("┌"));
+        // Known fields come in the fixed order: name before type and size
+        let name_at = result.find(" name ").expect("name header");
+        let type_at = result.find(" type ").expect("type header");
+        let size_at = result.find(" size ").expect("size header");
+        assert!(name_at < type_at);
+        assert!(type_at < size_at);
+        assert!(result.contains("main.rs"));
+        assert!(result.contains("1024"));
+    }
+
+    #[test]
+    fn test_list_files_string_array_keeps_plain_list() {
+        let formatter = ToolResultFormatter::new();
+        let files = r#"["file1.txt", "dir1/"]"#;
+
+        let result = formatter.format_result("list_files", files);
+
+        assert!(result.contains("2 items"));
+        assert!(!result.contains("┌"));
+    }
+
+    #[test]
+    fn test_list_processes_falls_back_to_generic() {
+        let formatter = ToolResultFormatter::new();
+
+        let result = formatter.format_result("list_processes", "not json at all");
+
+        assert!(result.contains("not json at all"));
+        assert!(!result.contains("┌"));
+    }
+
+    #[test]
+    fn test_auto_detect_format_json() {
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format(r#"{"name": "main.rs", "size": 1024}"#),
+            ContentFormat::Json
+        );
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format(r#"[1, 2, 3]"#),
+            ContentFormat::Json
+        );
+        // Bare scalars are valid JSON but should stay plain text
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format("42"),
+            ContentFormat::PlainText
+        );
+    }
+
+    #[test]
+    fn test_auto_detect_format_yaml() {
+        let content = "name: coding-agent\nversion: 0.1.0\nfeatures:\n  - repl\n  - tools";
+
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format(content),
+            ContentFormat::Yaml
+        );
+    }
+
+    #[test]
+    fn test_auto_detect_format_unified_diff() {
+        let content = "--- a/src/main.rs\n+++ b/src/main.rs\n@@ -1,3 +1,3 @@\n-old line\n+new line";
+
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format(content),
+            ContentFormat::UnifiedDiff
+        );
+    }
+
+    #[test]
+    fn test_auto_detect_format_csv() {
+        let content = "name,size,type\nmain.rs,1024,file\nlib.rs,512,file";
+
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format(content),
+            ContentFormat::Csv
+        );
+    }
+
+    #[test]
+    fn test_auto_detect_format_markdown() {
+        let content = "# Results\n\nAll 42 tests passed.\n\n## Details\n\nSee above.";
+
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format(content),
+            ContentFormat::Markdown
+        );
+    }
+
+    #[test]
+    fn test_auto_detect_format_error_message() {
+        // Compiler-style errors must not read as YAML key/value pairs
+        let content = "error: expected `;`\n --> src/main.rs:3:20";
+
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format(content),
+            ContentFormat::ErrorMessage
+        );
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format("error[E0308]: mismatched types"),
+            ContentFormat::ErrorMessage
+        );
+    }
+
+    #[test]
+    fn test_auto_detect_format_plain_text() {
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format("just some ordinary output"),
+            ContentFormat::PlainText
+        );
+        assert_eq!(
+            ToolResultFormatter::auto_detect_format(""),
+            ContentFormat::PlainText
+        );
+    }
+
+    #[test]
+    fn test_bash_json_output_pretty_printed() {
+        let formatter = ToolResultFormatter::new();
+        let output = r#"{"name":"main.rs","size":1024}"#;
+
+        let result = formatter.format_result("bash", output);
+
+        // Pretty-printed: one key per line, not the single-line original
+        assert!(result.contains("\"name\": \"main.rs\""));
+        assert!(result.contains("\"size\": 1024"));
+        assert!(!result.contains(r#"{"name":"main.rs""#));
+    }
+
+    #[test]
+    fn test_bash_plain_output_keeps_bash_rendering() {
+        let formatter = ToolResultFormatter::new();
+
+        let result = formatter.format_result("bash", "");
+
+        assert!(result.contains("(no output)"));
+    }
+
+    #[test]
+    fn test_auto_format_disabled_keeps_raw_output() {
+        let config = ToolResultConfig {
+            auto_format: false,
+            ..Default::default()
+        };
+        let formatter = ToolResultFormatter::with_config(config);
+        let output = r#"{"name":"main.rs","size":1024}"#;
+
+        let result = formatter.format_result("bash", output);
+
+        assert!(result.contains(r#"{"name":"main.rs""#));
+    }
+
     #[test]
     fn test_read_collapsed_has_content() {
         // Verify collapsed_content contains the full formatted file
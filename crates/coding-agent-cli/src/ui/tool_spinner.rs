@@ -8,6 +8,7 @@
 use indicatif::{ProgressBar as IndicatifBar, ProgressStyle};
 use std::time::{Duration, Instant};
 
+use super::spinner::stdout_is_tty;
 use super::theme::{Color, Theme};
 
 /// Status of a tool execution
@@ -39,22 +40,22 @@ pub struct ToolExecutionSpinner {
     theme: Theme,
     /// Current retry attempt (if any)
     retry_attempt: u32,
+    /// Whether stdout is a TTY; when false the spinner degrades to
+    /// plain single-line progress text (no animation or escape codes)
+    interactive: bool,
 }
 
 impl ToolExecutionSpinner {
     /// Create a new tool execution spinner
     pub fn new(tool_name: impl Into<String>, theme: Theme) -> Self {
         let tool_name = tool_name.into();
-        let bar = IndicatifBar::new_spinner();
-
-        let style = ProgressStyle::default_spinner()
-            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏")
-            .template("{spinner:.yellow} {msg}")
-            .unwrap();
-        bar.set_style(style);
-        bar.enable_steady_tick(Duration::from_millis(80));
+        let interactive = stdout_is_tty();
+        let bar = Self::new_bar(interactive);
 
         let display_name = format_tool_action(&tool_name, None);
+        if !interactive {
+            println!("{}", display_name);
+        }
         bar.set_message(display_name);
 
         Self {
@@ -65,9 +66,29 @@ impl ToolExecutionSpinner {
             status: ToolStatus::Running,
             theme,
             retry_attempt: 0,
+            interactive,
         }
     }
 
+    /// Create the underlying indicatif bar.
+    ///
+    /// When stdout is not a TTY the bar is hidden and the spinner prints
+    /// plain progress lines instead, so piped output contains no escape codes.
+    fn new_bar(interactive: bool) -> IndicatifBar {
+        if !interactive {
+            return IndicatifBar::hidden();
+        }
+
+        let bar = IndicatifBar::new_spinner();
+        let style = ProgressStyle::default_spinner()
+            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏")
+            .template("{spinner:.yellow} {msg}")
+            .unwrap();
+        bar.set_style(style);
+        bar.enable_steady_tick(Duration::from_millis(80));
+        bar
+    }
+
     /// Create a spinner with a target (e.g., file path being read)
     pub fn with_target(
         tool_name: impl Into<String>,
@@ -76,16 +97,13 @@ impl ToolExecutionSpinner {
     ) -> Self {
         let tool_name = tool_name.into();
         let target = target.into();
-        let bar = IndicatifBar::new_spinner();
-
-        let style = ProgressStyle::default_spinner()
-            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏")
-            .template("{spinner:.yellow} {msg}")
-            .unwrap();
-        bar.set_style(style);
-        bar.enable_steady_tick(Duration::from_millis(80));
+        let interactive = stdout_is_tty();
+        let bar = Self::new_bar(interactive);
 
         let display_name = format_tool_action(&tool_name, Some(&target));
+        if !interactive {
+            println!("{}", display_name);
+        }
         bar.set_message(display_name);
 
         Self {
@@ -96,6 +114,7 @@ impl ToolExecutionSpinner {
             status: ToolStatus::Running,
             theme,
             retry_attempt: 0,
+            interactive,
         }
     }
 
@@ -115,13 +134,25 @@ impl ToolExecutionSpinner {
         self.bar.set_message(display);
     }
 
-    /// Mark as retrying with attempt number
-    pub fn set_retrying(&mut self, attempt: u32, max_attempts: u32) {
+    /// Mark as retrying with attempt number and backoff delay
+    pub fn set_retrying(&mut self, attempt: u32, max_attempts: u32, delay: Duration) {
         self.status = ToolStatus::Retrying;
         self.retry_attempt = attempt;
         let display = format_tool_action(&self.tool_name, self.target.as_deref());
-        let retry_msg = format!("{} (retry {}/{})", display, attempt, max_attempts);
-        self.bar.set_message(retry_msg);
+        self.bar
+            .set_message(format_retry_message(&display, attempt, max_attempts, delay));
+    }
+
+    /// Get a clonable handle for updating the retry status from elsewhere
+    ///
+    /// The executor's retry notifier runs while the REPL holds the spinner,
+    /// so it gets this handle (the underlying bar is shared) instead of the
+    /// spinner itself.
+    pub fn retry_handle(&self) -> SpinnerRetryHandle {
+        SpinnerRetryHandle {
+            bar: self.bar.clone(),
+            label: format_tool_action(&self.tool_name, self.target.as_deref()),
+        }
     }
 
     /// Finish with success
@@ -136,8 +167,7 @@ impl ToolExecutionSpinner {
             display
         };
 
-        self.bar
-            .finish_with_message(self.theme.apply(Color::Success, &msg));
+        self.finish_with_line(self.theme.apply(Color::Success, &msg));
     }
 
     /// Finish with success and a custom message
@@ -151,8 +181,7 @@ impl ToolExecutionSpinner {
             format!("✓ {}", message)
         };
 
-        self.bar
-            .finish_with_message(self.theme.apply(Color::Success, &msg));
+        self.finish_with_line(self.theme.apply(Color::Success, &msg));
     }
 
     /// Finish with failure
@@ -170,15 +199,24 @@ impl ToolExecutionSpinner {
             format!("{} ({}): {}", display, duration_str, error)
         };
 
-        self.bar
-            .finish_with_message(self.theme.apply(Color::Error, &msg));
+        self.finish_with_line(self.theme.apply(Color::Error, &msg));
     }
 
     /// Finish with failure and only show the error message
     pub fn finish_failed_simple(&self, error: &str) {
         let msg = format!("✗ {}", error);
-        self.bar
-            .finish_with_message(self.theme.apply(Color::Error, &msg));
+        self.finish_with_line(self.theme.apply(Color::Error, &msg));
+    }
+
+    /// Finish the spinner with a final line, printing it plainly when
+    /// stdout is not a TTY
+    fn finish_with_line(&self, line: String) {
+        if self.interactive {
+            self.bar.finish_with_message(line);
+        } else {
+            self.bar.finish();
+            println!("{}", line);
+        }
     }
 
     /// Finish and clear the spinner (for intermediate steps)
@@ -213,6 +251,28 @@ impl ToolExecutionSpinner {
     }
 }
 
+/// A clonable handle that can update a spinner's retry status
+///
+/// Shares the underlying progress bar with the spinner it came from, so
+/// it stays cheap to clone and safe to hand to executor callbacks.
+#[derive(Clone)]
+pub struct SpinnerRetryHandle {
+    bar: IndicatifBar,
+    label: String,
+}
+
+impl SpinnerRetryHandle {
+    /// Show a retry message like "Executing cargo build (retry 2/3 in 4s)"
+    pub fn show_retry(&self, attempt: u32, max_attempts: u32, delay: Duration) {
+        self.bar.set_message(format_retry_message(
+            &self.label,
+            attempt,
+            max_attempts,
+            delay,
+        ));
+    }
+}
+
 impl Drop for ToolExecutionSpinner {
     fn drop(&mut self) {
         if !self.bar.is_finished() {
@@ -231,6 +291,17 @@ fn format_tool_action(tool_name: &str, target: Option<&str>) -> String {
     }
 }
 
+/// Format a retry status message (e.g., "Executing make (retry 2/3 in 4s)")
+fn format_retry_message(label: &str, attempt: u32, max_attempts: u32, delay: Duration) -> String {
+    format!(
+        "{} (retry {}/{} in {})",
+        label,
+        attempt,
+        max_attempts,
+        format_duration(delay)
+    )
+}
+
 /// Format a tool success message
 fn format_tool_success(tool_name: &str, target: Option<&str>) -> String {
     let verb = tool_name_to_past_tense(tool_name);
@@ -435,6 +506,26 @@ mod tests {
         assert_ne!(ToolStatus::Failed, ToolStatus::Retrying);
     }
 
+    #[test]
+    fn test_format_retry_message() {
+        let msg = format_retry_message("Executing make", 2, 3, Duration::from_secs(4));
+        assert_eq!(msg, "Executing make (retry 2/3 in 4.0s)");
+
+        let msg = format_retry_message("Fetching url", 1, 3, Duration::from_millis(500));
+        assert_eq!(msg, "Fetching url (retry 1/3 in 500ms)");
+    }
+
+    #[test]
+    fn test_retry_handle_outlives_borrow() {
+        let theme = Theme::new(ThemeStyle::Minimal);
+        let spinner = ToolExecutionSpinner::with_target("bash", "cargo build", theme);
+
+        let handle = spinner.retry_handle();
+        // The handle shares the bar, so this must not panic after cloning
+        handle.show_retry(1, 3, Duration::from_secs(2));
+        handle.clone().show_retry(2, 3, Duration::from_secs(4));
+    }
+
     #[test]
     fn test_spinner_cleanup_on_drop() {
         let theme = Theme::new(ThemeStyle::Minimal);
@@ -0,0 +1,159 @@
+//! Record/replay integration tests for the cassette layer.
+//!
+//! A full read_file → edit_file tool-use turn is first recorded against
+//! a local mock server, then replayed from the cassette with the network
+//! pointed at a dead port — proving the whole turn runs offline. The
+//! cassette environment variables and the working directory are
+//! process-global, so both phases run inside one test, in sequence.
+
+use coding_agent_cli::tools::create_tool_definitions;
+use coding_agent_core::cassette::{Cassette, RECORD_ENV, REPLAY_ENV};
+use coding_agent_core::{AgentSession, AnthropicClient, SessionEvent, Tool, ToolDefinition};
+use serde_json::json;
+use std::path::Path;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// The read_file and edit_file subset of the real tool registry.
+fn agent_tools() -> Vec<ToolDefinition> {
+    create_tool_definitions()
+        .into_iter()
+        .filter(|def| def.name == "read_file" || def.name == "edit_file")
+        .collect()
+}
+
+fn api_tools(defs: &[ToolDefinition]) -> Vec<Tool> {
+    defs.iter()
+        .map(|def| Tool {
+            name: def.name.clone(),
+            description: def.description.clone(),
+            input_schema: def.input_schema.clone(),
+        })
+        .collect()
+}
+
+/// Mount the three responses of the recorded turn, served in order.
+async fn mount_turn(server: &MockServer) {
+    let responses = vec![
+        json!({
+            "id": "msg_1",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-sonnet-4-20250514",
+            "content": [{"type": "tool_use", "id": "toolu_1", "name": "read_file",
+                         "input": {"path": "notes.txt"}}],
+            "stop_reason": "tool_use",
+            "usage": {"input_tokens": 30, "output_tokens": 20}
+        }),
+        json!({
+            "id": "msg_2",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-sonnet-4-20250514",
+            "content": [{"type": "tool_use", "id": "toolu_2", "name": "edit_file",
+                         "input": {"path": "notes.txt", "old_str": "hello",
+                                   "new_str": "goodbye"}}],
+            "stop_reason": "tool_use",
+            "usage": {"input_tokens": 45, "output_tokens": 25}
+        }),
+        json!({
+            "id": "msg_3",
+            "type": "message",
+            "role": "assistant",
+            "model": "claude-sonnet-4-20250514",
+            "content": [{"type": "text", "text": "Done."}],
+            "stop_reason": "end_turn",
+            "usage": {"input_tokens": 60, "output_tokens": 5}
+        }),
+    ];
+    for response in responses {
+        Mock::given(method("POST"))
+            .and(path("/v1/messages"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response))
+            .up_to_n_times(1)
+            .mount(server)
+            .await;
+    }
+}
+
+/// Run the turn in a fresh directory holding `notes.txt`, returning the
+/// events; the caller checks the file afterwards via the returned dir.
+fn run_turn(client: AnthropicClient, workdir: &Path) -> Vec<SessionEvent> {
+    std::fs::write(workdir.join("notes.txt"), "hello world\n").unwrap();
+    std::env::set_current_dir(workdir).unwrap();
+
+    let mut session = AgentSession::new(client, agent_tools());
+    session.send("Please update notes.txt").collect()
+}
+
+fn tool_starts(events: &[SessionEvent]) -> Vec<String> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            SessionEvent::ToolCallStarted { tool_name, .. } => Some(tool_name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_record_then_replay_full_tool_use_turn() {
+    let original_dir = std::env::current_dir().unwrap();
+
+    let cassette_dir = tempfile::tempdir().unwrap();
+    let cassette_path = cassette_dir.path().join("turn.json");
+
+    // --- Record phase: real HTTP against the mock server ---
+    let server = MockServer::start().await;
+    mount_turn(&server).await;
+    std::env::set_var(RECORD_ENV, &cassette_path);
+
+    let client = AnthropicClient::builder("test-key")
+        .base_url(server.uri())
+        .tools(api_tools(&agent_tools()))
+        .build();
+    let record_dir = tempfile::tempdir().unwrap();
+    let recorded = run_turn(client, record_dir.path());
+
+    std::env::remove_var(RECORD_ENV);
+
+    // The turn really ran: both tools executed and the edit applied
+    assert_eq!(tool_starts(&recorded), vec!["read_file", "edit_file"]);
+    assert!(matches!(
+        recorded.last(),
+        Some(SessionEvent::Completed { .. })
+    ));
+    assert_eq!(
+        std::fs::read_to_string(record_dir.path().join("notes.txt")).unwrap(),
+        "goodbye world\n"
+    );
+
+    // All three exchanges landed in the cassette
+    let cassette = Cassette::load(&cassette_path).unwrap();
+    assert_eq!(cassette.entries.len(), 3);
+
+    // --- Replay phase: dead base URL, responses come from the cassette ---
+    std::env::set_var(REPLAY_ENV, &cassette_path);
+
+    let client = AnthropicClient::builder("test-key")
+        .base_url("http://127.0.0.1:1")
+        .tools(api_tools(&agent_tools()))
+        .build();
+    let replay_dir = tempfile::tempdir().unwrap();
+    let replayed = run_turn(client, replay_dir.path());
+
+    std::env::remove_var(REPLAY_ENV);
+    std::env::set_current_dir(original_dir).unwrap();
+
+    // The replayed turn behaves identically, entirely offline
+    assert_eq!(tool_starts(&replayed), vec!["read_file", "edit_file"]);
+    assert!(replayed.contains(&SessionEvent::Text("Done.".to_string())));
+    assert!(matches!(
+        replayed.last(),
+        Some(SessionEvent::Completed { .. })
+    ));
+    assert_eq!(
+        std::fs::read_to_string(replay_dir.path().join("notes.txt")).unwrap(),
+        "goodbye world\n"
+    );
+}
@@ -1,7 +1,7 @@
 use expectrl::Session;
 use std::time::Duration;
 
-/// Helper functions for PTY interaction testing
+// Helper functions for PTY interaction testing
 
 /// Wait for a specific pattern with timeout
 pub fn expect_pattern(
@@ -22,7 +22,7 @@ pub fn send_text(session: &mut Session, text: &str) -> Result<(), Box<dyn std::e
 
 /// Send a single key
 pub fn send_key(session: &mut Session, key: char) -> Result<(), Box<dyn std::error::Error>> {
-    session.send(&key.to_string())?;
+    session.send(key.to_string())?;
     Ok(())
 }
 
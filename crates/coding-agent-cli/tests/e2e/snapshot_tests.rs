@@ -1,6 +1,6 @@
 //! Essential E2E snapshot tests
 //!
-//! Focused on true end-to-end CLI behavior rather than duplicating 
+//! Focused on true end-to-end CLI behavior rather than duplicating
 //! UI component tests that are covered in ui_visual_regression_tests.rs
 
 use crate::e2e::harness::CliTestSession;
@@ -49,7 +49,7 @@ fn test_startup_screen_snapshot() {
 }
 
 #[test]
-#[ignore] 
+#[ignore]
 fn test_help_command_snapshot() {
     let mut session = CliTestSession::spawn().expect("Failed to spawn");
     session
@@ -90,7 +90,9 @@ async fn test_full_conversation_flow() {
     let _mock_server = MockClaudeServer::start().await;
 
     let mut session = CliTestSession::spawn().expect("Failed to spawn CLI");
-    session.select_new_session().expect("Failed to select session");
+    session
+        .select_new_session()
+        .expect("Failed to select session");
 
     // Simulate user asking for file read
     let output = session
@@ -98,13 +100,13 @@ async fn test_full_conversation_flow() {
         .expect("Failed to run command");
 
     let clean_output = capture_without_ansi(&output);
-    
+
     // Should contain tool execution and file content
     assert!(clean_output.contains("fizzbuzz") || clean_output.contains("function"));
     assert_snapshot!("full_conversation_flow", clean_output);
 }
 
 // Note: Removed redundant tests that duplicate UI component testing:
-// - context_bar_render_snapshot_* (covered in ui_visual_regression_tests.rs)  
+// - context_bar_render_snapshot_* (covered in ui_visual_regression_tests.rs)
 // - error/success/warning_message_formatting_* (covered in ui_visual_regression_tests.rs)
-// - context_bar_color_transitions (covered in ui_visual_regression_tests.rs)
\ No newline at end of file
+// - context_bar_color_transitions (covered in ui_visual_regression_tests.rs)
@@ -297,20 +297,20 @@ async fn test_workflow_git_commit() {
 
     // Initialize git repo
     std::process::Command::new("git")
-        .args(&["init"])
+        .args(["init"])
         .current_dir(repo_path)
         .output()
         .expect("Failed to init git repo");
 
     // Configure git
     std::process::Command::new("git")
-        .args(&["config", "user.email", "test@example.com"])
+        .args(["config", "user.email", "test@example.com"])
         .current_dir(repo_path)
         .output()
         .expect("Failed to set git email");
 
     std::process::Command::new("git")
-        .args(&["config", "user.name", "Test User"])
+        .args(["config", "user.name", "Test User"])
         .current_dir(repo_path)
         .output()
         .expect("Failed to set git name");
@@ -321,7 +321,7 @@ async fn test_workflow_git_commit() {
         .expect("Failed to create file");
 
     std::process::Command::new("git")
-        .args(&["add", "feature.rs"])
+        .args(["add", "feature.rs"])
         .current_dir(repo_path)
         .output()
         .expect("Failed to stage file");
@@ -0,0 +1,115 @@
+//! Tests that UI formatter paths emit no ANSI escape codes when colors
+//! are disabled (NO_COLOR, `--no-color`, or `theme.color = false`).
+
+use coding_agent_cli::agents::status::{AgentId, AgentState, AgentStatus};
+use coding_agent_cli::ui::theme::{Color, Theme, ThemeStyle};
+use coding_agent_cli::ui::tool_result::ToolResultFormatter;
+use coding_agent_cli::ui::{ContextBar, MarkdownRenderer, StatusBar};
+
+/// Assert that a string contains no ANSI escape bytes
+fn assert_no_escapes(output: &str, context: &str) {
+    assert!(
+        !output.contains('\x1b'),
+        "{} contained escape bytes: {:?}",
+        context,
+        output
+    );
+}
+
+fn plain_theme() -> Theme {
+    Theme::new(ThemeStyle::Monochrome)
+}
+
+#[test]
+fn test_theme_apply_no_escapes_when_disabled() {
+    let theme = plain_theme();
+    assert!(!theme.colors_enabled());
+
+    let colors = [
+        Color::UserInput,
+        Color::Agent,
+        Color::Tool,
+        Color::Success,
+        Color::Error,
+        Color::Warning,
+        Color::Muted,
+        Color::Cost,
+        Color::ContextGreen,
+        Color::ContextYellow,
+        Color::ContextRed,
+    ];
+
+    for color in colors {
+        let styled = theme.apply(color, "sample text");
+        assert_no_escapes(&styled, "Theme::apply");
+        assert_eq!(styled, "sample text");
+    }
+}
+
+#[test]
+fn test_tool_result_formatter_no_escapes_when_disabled() {
+    let formatter = ToolResultFormatter::with_theme(plain_theme());
+
+    let outputs = [
+        ("read_file", "fn main() {\n    println!(\"hello\");\n}\n"),
+        ("bash", "total 4\ndrwxr-xr-x 2 user user 4096 ."),
+        ("list_files", r#"["src/main.rs", "src/lib.rs"]"#),
+    ];
+
+    for (tool, output) in outputs {
+        let formatted = formatter.format_result(tool, output);
+        assert_no_escapes(&formatted, tool);
+    }
+}
+
+#[test]
+fn test_context_bar_no_escapes_when_disabled() {
+    let mut bar = ContextBar::with_theme(200_000, plain_theme());
+
+    // Exercise green, yellow, and red threshold renderings
+    for tokens in [10_000u64, 130_000, 180_000] {
+        bar.set_tokens(tokens);
+        assert_no_escapes(&bar.render(), "ContextBar::render");
+    }
+}
+
+#[test]
+fn test_status_bar_no_escapes_when_disabled() {
+    let status_bar = StatusBar::with_theme(plain_theme());
+
+    let mut running = AgentStatus::new(
+        AgentId(1),
+        "fix-agent".to_string(),
+        "Fixing dependency".to_string(),
+    );
+    running.start();
+    running.update_progress(65);
+
+    let mut failed = AgentStatus::new(
+        AgentId(2),
+        "test-runner".to_string(),
+        "Running tests".to_string(),
+    );
+    failed.start();
+    failed.fail();
+
+    let rendered = status_bar.render_to_string(&[running.clone(), failed]);
+    assert_no_escapes(&rendered, "StatusBar::render_to_string");
+
+    assert_eq!(running.state, AgentState::Running);
+    let line = running.format_line_with_theme(&plain_theme());
+    assert_no_escapes(&line, "AgentStatus::format_line_with_theme");
+}
+
+#[test]
+fn test_plain_markdown_renderer_no_escapes() {
+    let renderer = MarkdownRenderer::plain();
+
+    let markdown =
+        "# Header\n\nSome **bold** text.\n\n```rust\nfn main() {\n    let x = 5;\n}\n```\n";
+    let rendered = renderer.render(markdown);
+
+    assert_no_escapes(&rendered, "MarkdownRenderer::plain render");
+    assert!(rendered.contains("Header"));
+    assert!(rendered.contains("let x = 5;"));
+}
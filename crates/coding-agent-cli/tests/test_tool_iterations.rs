@@ -53,6 +53,7 @@ fn test_behavior_config_serialization() {
         fun_facts: true,
         fun_fact_delay: 10,
         max_tool_iterations: 75,
+        ..Default::default()
     };
 
     // Serialize to TOML
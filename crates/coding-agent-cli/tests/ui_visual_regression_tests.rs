@@ -70,7 +70,7 @@ fn test_message_box_essential_cases() {
     let message_box = MessageBox::new(theme);
 
     let mut result = String::new();
-    
+
     // Basic message
     let basic = message_box.info("Simple information message");
     result.push_str("=== BASIC ===\n");
@@ -110,7 +110,10 @@ fn test_tool_result_formatting() {
     result.push_str("\n\n");
 
     // Search results
-    let search = formatter.format_result("code_search", "src/main.rs:10:fn main()\nsrc/lib.rs:25:pub fn test()");
+    let search = formatter.format_result(
+        "code_search",
+        "src/main.rs:10:fn main()\nsrc/lib.rs:25:pub fn test()",
+    );
     result.push_str("=== SEARCH ===\n");
     result.push_str(&strip_ansi(&search));
 
@@ -120,14 +123,14 @@ fn test_tool_result_formatting() {
 #[test]
 fn test_context_bar_key_thresholds() {
     use coding_agent_cli::ui::context_bar::ContextBar;
-    
+
     let theme = Theme::new(ThemeStyle::Monochrome);
     let mut result = String::new();
 
     // Test color transition thresholds: 60% (yellow) and 85% (red)
     let test_cases = vec![
         (50_000, 200_000, "25% - Green"),
-        (120_000, 200_000, "60% - Yellow"), 
+        (120_000, 200_000, "60% - Yellow"),
         (170_000, 200_000, "85% - Red"),
         (200_000, 200_000, "100% - Red"),
     ];
@@ -197,7 +200,7 @@ fn test_edge_case_handling() {
     let theme = Theme::new(ThemeStyle::Monochrome);
     let message_box = MessageBox::new(theme);
     let formatter = ToolResultFormatter::new();
-    
+
     let mut result = String::new();
 
     // Empty content
@@ -219,4 +222,4 @@ fn test_edge_case_handling() {
     result.push_str(&strip_ansi(&long_msg));
 
     assert_snapshot!("edge_case_handling", normalize_whitespace(&result));
-}
\ No newline at end of file
+}
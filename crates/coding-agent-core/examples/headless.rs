@@ -0,0 +1,53 @@
+//! Headless run through the embeddable AgentSession API - no REPL, no
+//! terminal handling, just events.
+//!
+//! Usage:
+//!   cargo run --example headless -- "What is 2 + 2?"
+//!
+//! Set ANTHROPIC_API_KEY environment variable before running.
+
+use coding_agent_core::{AgentSession, AnthropicClient, SessionEvent};
+use std::env;
+
+fn main() {
+    // Load .env file if present
+    dotenvy::dotenv().ok();
+
+    let prompt = env::args().nth(1).unwrap_or_else(|| {
+        eprintln!("Usage: cargo run --example headless -- \"<prompt>\"");
+        std::process::exit(1);
+    });
+
+    // Get API key from environment
+    let api_key = env::var("ANTHROPIC_API_KEY").unwrap_or_else(|_| {
+        eprintln!("Error: ANTHROPIC_API_KEY environment variable not set");
+        std::process::exit(1);
+    });
+
+    // No tools: a pure-conversation session. Tools would be passed here
+    // and gated through with_permission_handler.
+    let client = AnthropicClient::builder(api_key).build();
+    let mut session = AgentSession::new(client, vec![]);
+
+    for event in session.send(prompt) {
+        match event {
+            SessionEvent::Text(text) => println!("{}", text),
+            SessionEvent::ToolCallStarted { tool_name, .. } => {
+                eprintln!("[tool] {} started", tool_name)
+            }
+            SessionEvent::ToolCallFinished { result, .. } => match result {
+                Ok(_) => eprintln!("[tool] finished"),
+                Err(e) => eprintln!("[tool] failed: {}", e),
+            },
+            SessionEvent::PermissionDenied { tool_name, .. } => {
+                eprintln!("[tool] {} denied", tool_name)
+            }
+            SessionEvent::Warning(warning) => eprintln!("Warning: {}", warning),
+            SessionEvent::Failed(error) => eprintln!("Error: {}", error),
+            SessionEvent::Completed { usage } => eprintln!(
+                "[done] {} input / {} output tokens",
+                usage.input_tokens, usage.output_tokens
+            ),
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Record and replay of API exchanges for deterministic tests.
+//!
+//! The client checks two environment variables on every request:
+//!
+//! - `CODING_AGENT_RECORD=path` — send the request for real, then append
+//!   the request/response pair to the cassette file at `path`.
+//! - `CODING_AGENT_REPLAY=path` — skip the network entirely and serve
+//!   the recorded response whose request hash matches; an unmatched
+//!   request is an error, never a live call.
+//!
+//! Entries are matched by a hash of the canonical (sorted-key) JSON of
+//! the request body, so a replayed conversation must evolve exactly as
+//! it did when recorded. Recorded JSON is scrubbed of anything that
+//! looks like an API key; headers are never written at all.
+//!
+//! The hash is FNV-1a rather than [`DefaultHasher`] because cassettes
+//! are committed to the repository and must hash identically across
+//! compiler releases.
+//!
+//! [`DefaultHasher`]: std::collections::hash_map::DefaultHasher
+
+use crate::types::{MessageRequest, MessageResponse};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+/// Environment variable naming the cassette file to record into.
+pub const RECORD_ENV: &str = "CODING_AGENT_RECORD";
+
+/// Environment variable naming the cassette file to replay from.
+pub const REPLAY_ENV: &str = "CODING_AGENT_REPLAY";
+
+/// One recorded request/response exchange.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CassetteEntry {
+    /// Hash of the canonical JSON of the request body
+    pub request_hash: String,
+    /// The request body as sent (scrubbed, for human inspection)
+    pub request: Value,
+    /// The raw response body
+    pub response: Value,
+}
+
+/// A recorded sequence of API exchanges, stored as one JSON file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cassette {
+    pub entries: Vec<CassetteEntry>,
+}
+
+impl Cassette {
+    /// Load a cassette from disk; a missing file is an error.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read cassette {}: {}", path.display(), e))?;
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse cassette {}: {}", path.display(), e))
+    }
+
+    /// Write the cassette to disk as pretty-printed JSON.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize cassette: {}", e))?;
+        std::fs::write(path, content)
+            .map_err(|e| format!("Failed to write cassette {}: {}", path.display(), e))
+    }
+
+    /// Append an exchange, scrubbing API keys from both bodies.
+    pub fn push(&mut self, mut request: Value, mut response: Value) {
+        let request_hash = hash_json(&request);
+        scrub(&mut request);
+        scrub(&mut response);
+        self.entries.push(CassetteEntry {
+            request_hash,
+            request,
+            response,
+        });
+    }
+
+    /// Find the recorded response for a request hash.
+    pub fn lookup(&self, request_hash: &str) -> Option<&Value> {
+        self.entries
+            .iter()
+            .find(|entry| entry.request_hash == request_hash)
+            .map(|entry| &entry.response)
+    }
+}
+
+/// Hash a request body for cassette matching.
+pub fn request_hash(request: &MessageRequest) -> Result<String, String> {
+    let value =
+        serde_json::to_value(request).map_err(|e| format!("Failed to serialize request: {}", e))?;
+    Ok(hash_json(&value))
+}
+
+/// FNV-1a over the canonical (sorted-key, compact) JSON text.
+///
+/// `serde_json::Value` objects iterate keys in sorted order, so
+/// converting through `Value` before printing canonicalizes field order.
+pub fn hash_json(value: &Value) -> String {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET;
+    for byte in value.to_string().bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    format!("{:016x}", hash)
+}
+
+/// Replace any string that looks like an API key with a placeholder.
+fn scrub(value: &mut Value) {
+    match value {
+        Value::String(s) if s.contains("sk-ant-") => *s = "[redacted]".to_string(),
+        Value::Array(items) => items.iter_mut().for_each(scrub),
+        Value::Object(map) => map.values_mut().for_each(scrub),
+        _ => {}
+    }
+}
+
+/// Append one exchange to the cassette at `path`, creating it if needed.
+pub(crate) fn record(
+    path: &Path,
+    request: &MessageRequest,
+    response: &Value,
+) -> Result<(), String> {
+    let mut cassette = if path.exists() {
+        Cassette::load(path)?
+    } else {
+        Cassette::default()
+    };
+    let request =
+        serde_json::to_value(request).map_err(|e| format!("Failed to serialize request: {}", e))?;
+    cassette.push(request, response.clone());
+    cassette.save(path)
+}
+
+/// Serve a recorded response for `request`, erroring on a miss.
+pub(crate) fn replay(path: &Path, request: &MessageRequest) -> Result<MessageResponse, String> {
+    let cassette = Cassette::load(path)?;
+    let hash = request_hash(request)?;
+    let response = cassette.lookup(&hash).ok_or_else(|| {
+        format!(
+            "No recorded response in {} for this request (hash {}); \
+             re-record the cassette with {}",
+            path.display(),
+            hash,
+            RECORD_ENV
+        )
+    })?;
+    serde_json::from_value(response.clone())
+        .map_err(|e| format!("Failed to parse recorded response: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Message;
+    use serde_json::json;
+
+    fn request(text: &str) -> MessageRequest {
+        MessageRequest {
+            model: "claude-sonnet-4-20250514".to_string(),
+            max_tokens: 1024,
+            messages: vec![Message::user(text)],
+            tools: Vec::new(),
+            system: None,
+        }
+    }
+
+    #[test]
+    fn test_request_hash_is_stable_and_input_sensitive() {
+        // Arrange & Act
+        let first = request_hash(&request("hello")).unwrap();
+        let again = request_hash(&request("hello")).unwrap();
+        let other = request_hash(&request("goodbye")).unwrap();
+
+        // Assert
+        assert_eq!(first, again);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_hash_json_ignores_key_order() {
+        // Arrange: same object, different literal key order
+        let a: Value = serde_json::from_str(r#"{"model": "m", "max_tokens": 5}"#).unwrap();
+        let b: Value = serde_json::from_str(r#"{"max_tokens": 5, "model": "m"}"#).unwrap();
+
+        // Assert
+        assert_eq!(hash_json(&a), hash_json(&b));
+    }
+
+    #[test]
+    fn test_cassette_round_trip_and_lookup() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        let req = serde_json::to_value(request("hi")).unwrap();
+        let hash = hash_json(&req);
+
+        let mut cassette = Cassette::default();
+        cassette.push(req, json!({"content": [], "stop_reason": "end_turn"}));
+        cassette.save(&path).unwrap();
+
+        // Act
+        let loaded = Cassette::load(&path).unwrap();
+
+        // Assert
+        assert_eq!(loaded.entries.len(), 1);
+        let response = loaded.lookup(&hash).expect("recorded response");
+        assert_eq!(response["stop_reason"], "end_turn");
+        assert!(loaded.lookup("no-such-hash").is_none());
+    }
+
+    #[test]
+    fn test_push_scrubs_api_keys_but_hashes_original() {
+        // Arrange
+        let req = json!({"messages": [{"content": "key is sk-ant-secret123"}]});
+        let expected_hash = hash_json(&req);
+
+        // Act
+        let mut cassette = Cassette::default();
+        cassette.push(req, json!({"note": "sk-ant-other"}));
+
+        // Assert: stored bodies are scrubbed, hash matches the original
+        let entry = &cassette.entries[0];
+        assert_eq!(entry.request["messages"][0]["content"], "[redacted]");
+        assert_eq!(entry.response["note"], "[redacted]");
+        assert_eq!(entry.request_hash, expected_hash);
+    }
+
+    #[test]
+    fn test_replay_unmatched_request_errors() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("cassette.json");
+        Cassette::default().save(&path).unwrap();
+
+        // Act
+        let result = replay(&path, &request("never recorded"));
+
+        // Assert
+        let err = result.unwrap_err();
+        assert!(err.contains("No recorded response"));
+        assert!(err.contains(RECORD_ENV));
+    }
+}
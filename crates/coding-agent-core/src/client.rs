@@ -0,0 +1,232 @@
+//! HTTP client for the Anthropic Messages API
+//!
+//! One place for request construction and transport, shared by the legacy
+//! `Agent` and the CLI so the request shape cannot drift between callers.
+
+use crate::types::{Message, MessageRequest, MessageResponse, Tool};
+use std::time::Duration;
+
+/// Default API endpoint, overridable via the builder for proxies and tests.
+pub const DEFAULT_BASE_URL: &str = "https://api.anthropic.com";
+
+/// The `anthropic-version` header sent with every request.
+pub const API_VERSION: &str = "2023-06-01";
+
+/// Client for the Anthropic Messages API.
+///
+/// Holds everything that stays fixed across calls (credentials, model,
+/// system prompt, tool list); each call supplies only the conversation.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use coding_agent_core::{AnthropicClient, Message};
+///
+/// let client = AnthropicClient::builder("sk-ant-...")
+///     .model("claude-sonnet-4-20250514")
+///     .max_tokens(4096)
+///     .system("You are a helpful assistant.")
+///     .build();
+///
+/// let response = client.send(&[Message::user("Hello!")])?;
+/// # Ok::<(), String>(())
+/// ```
+pub struct AnthropicClient {
+    api_key: String,
+    model: String,
+    max_tokens: u32,
+    system: Option<String>,
+    tools: Vec<Tool>,
+    base_url: String,
+    timeout: Option<Duration>,
+}
+
+impl AnthropicClient {
+    /// Start building a client with the given API key.
+    pub fn builder(api_key: impl Into<String>) -> AnthropicClientBuilder {
+        AnthropicClientBuilder {
+            api_key: api_key.into(),
+            model: "claude-sonnet-4-20250514".to_string(),
+            max_tokens: 1024,
+            system: None,
+            tools: Vec::new(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            timeout: None,
+        }
+    }
+
+    /// Build the request body for a conversation without sending it.
+    ///
+    /// Callers that log or inspect requests build once and pass the
+    /// result to [`AnthropicClient::send_request`].
+    pub fn request(&self, messages: &[Message]) -> MessageRequest {
+        MessageRequest {
+            model: self.model.clone(),
+            max_tokens: self.max_tokens,
+            messages: messages.to_vec(),
+            tools: self.tools.clone(),
+            system: self.system.clone(),
+        }
+    }
+
+    /// Send a conversation and parse the response.
+    pub fn send(&self, messages: &[Message]) -> Result<MessageResponse, String> {
+        self.send_request(&self.request(messages))
+    }
+
+    /// Send an already-built request body.
+    ///
+    /// Honors the cassette environment variables: with
+    /// [`crate::cassette::REPLAY_ENV`] set, the response comes from the
+    /// cassette and no network call is made; with
+    /// [`crate::cassette::RECORD_ENV`] set, each exchange is appended to
+    /// the cassette after a real send.
+    pub fn send_request(&self, request: &MessageRequest) -> Result<MessageResponse, String> {
+        if let Ok(path) = std::env::var(crate::cassette::REPLAY_ENV) {
+            return crate::cassette::replay(std::path::Path::new(&path), request);
+        }
+
+        let url = format!("{}/v1/messages", self.base_url);
+        let post = match self.timeout {
+            Some(timeout) => ureq::AgentBuilder::new()
+                .timeout(timeout)
+                .build()
+                .post(&url),
+            None => ureq::post(&url),
+        };
+
+        let response = post
+            .set("Content-Type", "application/json")
+            .set("x-api-key", &self.api_key)
+            .set("anthropic-version", API_VERSION)
+            .send_json(request)
+            .map_err(|e| format!("API request failed: {}", e))?;
+
+        let raw: serde_json::Value = response
+            .into_json()
+            .map_err(|e| format!("Failed to parse response: {}", e))?;
+
+        if let Ok(path) = std::env::var(crate::cassette::RECORD_ENV) {
+            crate::cassette::record(std::path::Path::new(&path), request, &raw)?;
+        }
+
+        serde_json::from_value(raw).map_err(|e| format!("Failed to parse response: {}", e))
+    }
+}
+
+/// Builder for [`AnthropicClient`].
+pub struct AnthropicClientBuilder {
+    api_key: String,
+    model: String,
+    max_tokens: u32,
+    system: Option<String>,
+    tools: Vec<Tool>,
+    base_url: String,
+    timeout: Option<Duration>,
+}
+
+impl AnthropicClientBuilder {
+    /// Set the model to request.
+    pub fn model(mut self, model: impl Into<String>) -> Self {
+        self.model = model.into();
+        self
+    }
+
+    /// Set the maximum number of tokens in the response.
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    /// Set the system prompt.
+    pub fn system(mut self, system: impl Into<String>) -> Self {
+        self.system = Some(system.into());
+        self
+    }
+
+    /// Set the tools offered with every request.
+    pub fn tools(mut self, tools: Vec<Tool>) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// Override the API endpoint (proxies, test servers).
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Set a timeout covering the whole request, including the response
+    /// body. No timeout is applied by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Finish building the client.
+    pub fn build(self) -> AnthropicClient {
+        AnthropicClient {
+            api_key: self.api_key,
+            model: self.model,
+            max_tokens: self.max_tokens,
+            system: self.system,
+            tools: self.tools,
+            base_url: self.base_url,
+            timeout: self.timeout,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_request_serialization_defaults() {
+        let client = AnthropicClient::builder("test-key").build();
+
+        let request = client.request(&[Message::user("Hello")]);
+        let json = serde_json::to_value(&request).expect("Should serialize");
+
+        assert_eq!(json["model"], "claude-sonnet-4-20250514");
+        assert_eq!(json["max_tokens"], 1024);
+        assert_eq!(json["messages"][0]["role"], "user");
+        // Empty tools and absent system are omitted entirely
+        assert!(json.get("tools").is_none());
+        assert!(json.get("system").is_none());
+    }
+
+    #[test]
+    fn test_request_serialization_with_system_and_tools() {
+        let tools = vec![Tool {
+            name: "read_file".to_string(),
+            description: "Read a file".to_string(),
+            input_schema: serde_json::json!({"type": "object"}),
+        }];
+        let client = AnthropicClient::builder("test-key")
+            .model("claude-3-opus")
+            .max_tokens(4096)
+            .system("Be brief.")
+            .tools(tools)
+            .build();
+
+        let request = client.request(&[Message::user("Hello")]);
+        let json = serde_json::to_value(&request).expect("Should serialize");
+
+        assert_eq!(json["model"], "claude-3-opus");
+        assert_eq!(json["max_tokens"], 4096);
+        assert_eq!(json["system"], "Be brief.");
+        assert_eq!(json["tools"][0]["name"], "read_file");
+    }
+
+    #[test]
+    fn test_builder_base_url_and_timeout() {
+        let client = AnthropicClient::builder("test-key")
+            .base_url("http://localhost:8080")
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        assert_eq!(client.base_url, "http://localhost:8080");
+        assert_eq!(client.timeout, Some(Duration::from_secs(30)));
+    }
+}
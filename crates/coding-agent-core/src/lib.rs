@@ -1,14 +1,20 @@
 // State machine modules
+pub mod cassette;
+pub mod client;
 pub mod machine;
+pub mod session;
 pub mod state;
 pub mod types;
 
 // Re-export commonly used types
+pub use cassette::Cassette;
+pub use client::{AnthropicClient, AnthropicClientBuilder};
 pub use machine::StateMachine;
+pub use session::{AgentSession, LlmTransport, PermissionHandler, SessionEvent};
 pub use state::{AgentAction, AgentEvent, AgentState, ToolCall, ToolExecutionStatus};
 pub use types::{
-    generate_schema, ContentBlock, Message, MessageRequest, MessageResponse, Tool, ToolDefinition,
-    ToolFunction,
+    generate_schema, ContentBlock, ImageSource, Message, MessageBuilder, MessageRequest,
+    MessageResponse, Tool, ToolDefinition, ToolFunction, Usage,
 };
 
 use std::io::{self, BufRead, Write};
@@ -113,10 +119,7 @@ impl Agent {
                 }
 
                 // Send tool results back
-                conversation.push(Message {
-                    role: "user".to_string(),
-                    content: tool_results,
-                });
+                conversation.push(MessageBuilder::user().blocks(tool_results).build());
 
                 current_response = self.call_api(&conversation)?;
                 conversation.push(Message::assistant(current_response.content.clone()));
@@ -149,13 +152,10 @@ impl Agent {
             })
             .collect();
 
-        let request = MessageRequest {
-            model: "claude-sonnet-4-20250514".to_string(),
-            max_tokens: 1024,
-            messages: conversation.to_vec(),
-            tools,
-            system: None,
-        };
+        let client = AnthropicClient::builder(self.api_key.clone())
+            .tools(tools)
+            .build();
+        let request = client.request(conversation);
 
         if self.verbose {
             eprintln!(
@@ -164,16 +164,7 @@ impl Agent {
             );
         }
 
-        let response = ureq::post("https://api.anthropic.com/v1/messages")
-            .set("Content-Type", "application/json")
-            .set("x-api-key", &self.api_key)
-            .set("anthropic-version", "2023-06-01")
-            .send_json(&request)
-            .map_err(|e| format!("API request failed: {}", e))?;
-
-        let msg_response: MessageResponse = response
-            .into_json()
-            .map_err(|e| format!("Failed to parse response: {}", e))?;
+        let msg_response = client.send_request(&request)?;
 
         if self.verbose {
             eprintln!("[verbose] API response: {:?}", msg_response.stop_reason);
@@ -149,14 +149,14 @@ impl StateMachine {
                 for exec in &mut execs {
                     match exec {
                         ToolExecutionStatus::Pending { call_id: id, .. }
-                        | ToolExecutionStatus::Running { call_id: id, .. } => {
-                            if id == &call_id {
-                                *exec = ToolExecutionStatus::Completed {
-                                    call_id: call_id.clone(),
-                                    result: result.clone(),
-                                };
-                                break;
-                            }
+                        | ToolExecutionStatus::Running { call_id: id, .. }
+                            if id == &call_id =>
+                        {
+                            *exec = ToolExecutionStatus::Completed {
+                                call_id: call_id.clone(),
+                                result: result.clone(),
+                            };
+                            break;
                         }
                         _ => {}
                     }
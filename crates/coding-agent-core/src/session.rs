@@ -0,0 +1,519 @@
+//! Embeddable, UI-free session API.
+//!
+//! [`AgentSession`] wraps the [`StateMachine`] together with an LLM
+//! transport and a tool set, and drives the same
+//! request → tool-execution → request loop the CLI runs — but instead
+//! of printing, every step comes back to the caller as a
+//! [`SessionEvent`]. Permission decisions go through a
+//! [`PermissionHandler`] so an embedding application can gate tool
+//! calls without a terminal.
+//!
+//! The transport is a trait rather than [`AnthropicClient`] directly so
+//! headless runs and tests can script responses.
+//!
+//! [`AnthropicClient`]: crate::client::AnthropicClient
+
+use crate::machine::StateMachine;
+use crate::state::{AgentAction, AgentEvent, ToolCall};
+use crate::types::{Message, MessageResponse, ToolDefinition, Usage};
+use std::collections::VecDeque;
+
+/// Something that can turn a conversation into an LLM response.
+///
+/// Implemented for [`AnthropicClient`](crate::client::AnthropicClient);
+/// tests and embedders can script their own.
+pub trait LlmTransport {
+    fn send(&mut self, messages: &[Message]) -> Result<MessageResponse, String>;
+}
+
+impl LlmTransport for crate::client::AnthropicClient {
+    fn send(&mut self, messages: &[Message]) -> Result<MessageResponse, String> {
+        crate::client::AnthropicClient::send(self, messages)
+    }
+}
+
+/// Decides whether a tool call may run.
+///
+/// Denied calls are not executed; the model sees a permission error as
+/// the tool result and the caller gets a
+/// [`SessionEvent::PermissionDenied`].
+pub trait PermissionHandler {
+    fn allow(&mut self, call: &ToolCall) -> bool;
+}
+
+/// Permits every tool call; the default handler.
+pub struct AllowAll;
+
+impl PermissionHandler for AllowAll {
+    fn allow(&mut self, _call: &ToolCall) -> bool {
+        true
+    }
+}
+
+/// One step of a conversation turn, as seen by the embedding caller.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SessionEvent {
+    /// Assistant text (one event per response that carries text)
+    Text(String),
+    /// A tool call is about to execute
+    ToolCallStarted {
+        call_id: String,
+        tool_name: String,
+        input: serde_json::Value,
+    },
+    /// A tool call finished (the result also goes back to the model)
+    ToolCallFinished {
+        call_id: String,
+        result: Result<String, String>,
+    },
+    /// The permission handler refused a tool call
+    PermissionDenied { call_id: String, tool_name: String },
+    /// A non-fatal warning surfaced mid-turn
+    Warning(String),
+    /// The turn failed after the machine's bounded retries
+    Failed(String),
+    /// The turn is over; always the final event of a turn
+    Completed { usage: Usage },
+}
+
+/// A UI-free agent session: the CLI's conversation loop as a library.
+///
+/// # Example
+///
+/// A headless run against a scripted transport, with an in-memory
+/// permission handler:
+///
+/// ```
+/// use coding_agent_core::session::{AgentSession, LlmTransport, PermissionHandler, SessionEvent};
+/// use coding_agent_core::{ContentBlock, Message, MessageResponse, ToolCall, Usage};
+///
+/// // Stands in for the API: always answers with plain text
+/// struct Scripted;
+/// impl LlmTransport for Scripted {
+///     fn send(&mut self, _messages: &[Message]) -> Result<MessageResponse, String> {
+///         Ok(MessageResponse {
+///             content: vec![ContentBlock::Text { text: "hello".to_string() }],
+///             stop_reason: Some("end_turn".to_string()),
+///             usage: Usage { input_tokens: 10, output_tokens: 2 },
+///         })
+///     }
+/// }
+///
+/// // Refuses every tool call
+/// struct DenyAll;
+/// impl PermissionHandler for DenyAll {
+///     fn allow(&mut self, _call: &ToolCall) -> bool {
+///         false
+///     }
+/// }
+///
+/// let mut session = AgentSession::new(Scripted, Vec::new()).with_permission_handler(DenyAll);
+/// let events: Vec<SessionEvent> = session.send("hi").collect();
+///
+/// assert_eq!(events[0], SessionEvent::Text("hello".to_string()));
+/// assert!(matches!(events.last(), Some(SessionEvent::Completed { .. })));
+/// ```
+pub struct AgentSession<T: LlmTransport, P: PermissionHandler = AllowAll> {
+    machine: StateMachine,
+    transport: T,
+    tools: Vec<ToolDefinition>,
+    permissions: P,
+}
+
+impl<T: LlmTransport> AgentSession<T, AllowAll> {
+    /// Create a session that permits every tool call.
+    pub fn new(transport: T, tools: Vec<ToolDefinition>) -> Self {
+        Self {
+            machine: StateMachine::new(),
+            transport,
+            tools,
+            permissions: AllowAll,
+        }
+    }
+}
+
+impl<T: LlmTransport, P: PermissionHandler> AgentSession<T, P> {
+    /// Replace the permission handler.
+    pub fn with_permission_handler<Q: PermissionHandler>(
+        self,
+        permissions: Q,
+    ) -> AgentSession<T, Q> {
+        AgentSession {
+            machine: self.machine,
+            transport: self.transport,
+            tools: self.tools,
+            permissions,
+        }
+    }
+
+    /// The conversation so far, including tool results.
+    pub fn conversation(&self) -> &[Message] {
+        self.machine
+            .state()
+            .conversation()
+            .map(|messages| messages.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Send a user message and drive the turn to completion.
+    ///
+    /// The returned iterator is lazy: each `next()` advances the loop
+    /// just far enough to produce the next event, so tool calls execute
+    /// as the caller consumes them. The final event is always
+    /// [`SessionEvent::Completed`] (or nothing further after a
+    /// [`SessionEvent::Failed`]).
+    pub fn send(&mut self, text: impl Into<String>) -> TurnEvents<'_, T, P> {
+        let action = self
+            .machine
+            .handle_event(AgentEvent::UserInput(text.into()));
+        TurnEvents {
+            session: self,
+            pending: VecDeque::from([action]),
+            tool_queue: VecDeque::new(),
+            usage: Usage::default(),
+            done: false,
+        }
+    }
+
+    /// Run one tool call against the registered tool set.
+    fn execute_tool(&mut self, call: &ToolCall) -> Result<String, String> {
+        for tool in &self.tools {
+            if tool.name == call.tool_name {
+                return (tool.function)(call.input.clone());
+            }
+        }
+        Err(format!("tool '{}' not found", call.tool_name))
+    }
+}
+
+/// A pending tool call, split so the started event precedes execution.
+enum ToolPhase {
+    Announce(ToolCall),
+    Execute(ToolCall),
+}
+
+/// Iterator over the events of one conversation turn.
+///
+/// Returned by [`AgentSession::send`]; borrows the session for the
+/// duration of the turn.
+pub struct TurnEvents<'a, T: LlmTransport, P: PermissionHandler> {
+    session: &'a mut AgentSession<T, P>,
+    /// Machine actions not yet acted on
+    pending: VecDeque<AgentAction>,
+    /// Tool calls from the last ExecuteTools action, in order
+    tool_queue: VecDeque<ToolPhase>,
+    /// Token usage accumulated across the turn's LLM calls
+    usage: Usage,
+    done: bool,
+}
+
+impl<T: LlmTransport, P: PermissionHandler> TurnEvents<'_, T, P> {
+    /// Feed a tool result to the machine and queue whatever follows.
+    fn complete_tool(&mut self, call_id: String, result: Result<String, String>) {
+        let action = self
+            .session
+            .machine
+            .handle_event(AgentEvent::ToolCompleted { call_id, result });
+        match action {
+            // More tools from the same response are still running
+            AgentAction::WaitForEvent if !self.tool_queue.is_empty() => {}
+            // The session layer has no post-tool hooks; proceed straight
+            // back to the model with the results
+            AgentAction::RunPostToolsHooks { .. } => {
+                let next = self
+                    .session
+                    .machine
+                    .handle_event(AgentEvent::HooksCompleted {
+                        proceed: true,
+                        warning: None,
+                    });
+                self.pending.push_back(next);
+            }
+            other => self.pending.push_back(other),
+        }
+    }
+
+    /// End the turn with the closing Completed event.
+    fn finish(&mut self) -> Option<SessionEvent> {
+        self.done = true;
+        Some(SessionEvent::Completed { usage: self.usage })
+    }
+}
+
+impl<T: LlmTransport, P: PermissionHandler> Iterator for TurnEvents<'_, T, P> {
+    type Item = SessionEvent;
+
+    fn next(&mut self) -> Option<SessionEvent> {
+        loop {
+            if self.done {
+                return None;
+            }
+
+            // Tool calls drain before further machine actions so their
+            // events interleave in execution order
+            if let Some(phase) = self.tool_queue.pop_front() {
+                match phase {
+                    ToolPhase::Announce(call) => {
+                        if self.session.permissions.allow(&call) {
+                            let event = SessionEvent::ToolCallStarted {
+                                call_id: call.call_id.clone(),
+                                tool_name: call.tool_name.clone(),
+                                input: call.input.clone(),
+                            };
+                            self.tool_queue.push_front(ToolPhase::Execute(call));
+                            return Some(event);
+                        }
+                        let event = SessionEvent::PermissionDenied {
+                            call_id: call.call_id.clone(),
+                            tool_name: call.tool_name.clone(),
+                        };
+                        self.complete_tool(
+                            call.call_id,
+                            Err("permission denied by the embedding application".to_string()),
+                        );
+                        return Some(event);
+                    }
+                    ToolPhase::Execute(call) => {
+                        let result = self.session.execute_tool(&call);
+                        let event = SessionEvent::ToolCallFinished {
+                            call_id: call.call_id.clone(),
+                            result: result.clone(),
+                        };
+                        self.complete_tool(call.call_id, result);
+                        return Some(event);
+                    }
+                }
+            }
+
+            let Some(action) = self.pending.pop_front() else {
+                return self.finish();
+            };
+            match action {
+                AgentAction::SendLlmRequest { messages } => {
+                    match self.session.transport.send(&messages) {
+                        Ok(response) => {
+                            self.usage.input_tokens += response.usage.input_tokens;
+                            self.usage.output_tokens += response.usage.output_tokens;
+                            let next =
+                                self.session.machine.handle_event(AgentEvent::LlmCompleted {
+                                    content: response.content,
+                                    stop_reason: response.stop_reason.unwrap_or_default(),
+                                });
+                            self.pending.push_back(next);
+                        }
+                        Err(error) => {
+                            let next = self
+                                .session
+                                .machine
+                                .handle_event(AgentEvent::LlmError(error));
+                            self.pending.push_back(next);
+                        }
+                    }
+                }
+                AgentAction::ExecuteTools { calls } => {
+                    self.tool_queue
+                        .extend(calls.into_iter().map(ToolPhase::Announce));
+                }
+                AgentAction::RunPostToolsHooks { .. } => {
+                    let next = self
+                        .session
+                        .machine
+                        .handle_event(AgentEvent::HooksCompleted {
+                            proceed: true,
+                            warning: None,
+                        });
+                    self.pending.push_back(next);
+                }
+                AgentAction::ScheduleRetry { delay_ms } => {
+                    // Honor the machine's backoff, then retry
+                    std::thread::sleep(std::time::Duration::from_millis(delay_ms));
+                    let next = self.session.machine.handle_event(AgentEvent::RetryTimeout);
+                    self.pending.push_back(next);
+                }
+                AgentAction::DisplayText(text) => return Some(SessionEvent::Text(text)),
+                AgentAction::DisplayWarning(warning) => {
+                    return Some(SessionEvent::Warning(warning))
+                }
+                AgentAction::DisplayError(error) => {
+                    // Retries are exhausted; the machine is back at
+                    // WaitingForUserInput and the turn is over
+                    self.done = true;
+                    return Some(SessionEvent::Failed(error));
+                }
+                AgentAction::PromptForInput | AgentAction::WaitForEvent => return self.finish(),
+                AgentAction::Shutdown => return self.finish(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ContentBlock;
+    use serde_json::json;
+
+    /// Transport that replays a scripted sequence of responses.
+    struct Scripted {
+        responses: VecDeque<MessageResponse>,
+    }
+
+    impl Scripted {
+        fn new(responses: Vec<MessageResponse>) -> Self {
+            Self {
+                responses: responses.into(),
+            }
+        }
+    }
+
+    impl LlmTransport for Scripted {
+        fn send(&mut self, _messages: &[Message]) -> Result<MessageResponse, String> {
+            self.responses
+                .pop_front()
+                .ok_or_else(|| "no scripted response left".to_string())
+        }
+    }
+
+    fn text_response(text: &str, tokens: u64) -> MessageResponse {
+        MessageResponse {
+            content: vec![ContentBlock::Text {
+                text: text.to_string(),
+            }],
+            stop_reason: Some("end_turn".to_string()),
+            usage: Usage {
+                input_tokens: tokens,
+                output_tokens: tokens,
+            },
+        }
+    }
+
+    fn tool_response(call_id: &str, tool_name: &str) -> MessageResponse {
+        MessageResponse {
+            content: vec![ContentBlock::ToolUse {
+                id: call_id.to_string(),
+                name: tool_name.to_string(),
+                input: json!({"value": 1}),
+            }],
+            stop_reason: Some("tool_use".to_string()),
+            usage: Usage::default(),
+        }
+    }
+
+    fn echo(input: serde_json::Value) -> Result<String, String> {
+        Ok(input.to_string())
+    }
+
+    fn echo_tool() -> ToolDefinition {
+        ToolDefinition {
+            name: "echo".to_string(),
+            description: "Echo the input".to_string(),
+            input_schema: json!({}),
+            function: echo,
+        }
+    }
+
+    #[test]
+    fn test_text_only_turn_yields_text_then_completed() {
+        // Arrange
+        let transport = Scripted::new(vec![text_response("hello", 5)]);
+        let mut session = AgentSession::new(transport, Vec::new());
+
+        // Act
+        let events: Vec<SessionEvent> = session.send("hi").collect();
+
+        // Assert
+        assert_eq!(
+            events,
+            vec![
+                SessionEvent::Text("hello".to_string()),
+                SessionEvent::Completed {
+                    usage: Usage {
+                        input_tokens: 5,
+                        output_tokens: 5
+                    }
+                },
+            ]
+        );
+        // The conversation retains both sides of the exchange
+        assert_eq!(session.conversation().len(), 2);
+    }
+
+    #[test]
+    fn test_tool_turn_interleaves_events_in_order() {
+        // Arrange: a tool call, then a closing text response
+        let transport = Scripted::new(vec![
+            tool_response("call_1", "echo"),
+            text_response("done", 1),
+        ]);
+        let mut session = AgentSession::new(transport, vec![echo_tool()]);
+
+        // Act
+        let events: Vec<SessionEvent> = session.send("run the tool").collect();
+
+        // Assert
+        assert_eq!(events.len(), 4);
+        assert!(matches!(
+            &events[0],
+            SessionEvent::ToolCallStarted { call_id, tool_name, .. }
+                if call_id == "call_1" && tool_name == "echo"
+        ));
+        assert!(matches!(
+            &events[1],
+            SessionEvent::ToolCallFinished { result: Ok(output), .. }
+                if output.contains("\"value\":1")
+        ));
+        assert_eq!(events[2], SessionEvent::Text("done".to_string()));
+        assert!(matches!(events[3], SessionEvent::Completed { .. }));
+    }
+
+    #[test]
+    fn test_denied_tool_reports_permission_error() {
+        // Arrange: a handler that refuses everything
+        struct DenyAll;
+        impl PermissionHandler for DenyAll {
+            fn allow(&mut self, _call: &ToolCall) -> bool {
+                false
+            }
+        }
+        let transport = Scripted::new(vec![
+            tool_response("call_1", "echo"),
+            text_response("understood", 1),
+        ]);
+        let mut session =
+            AgentSession::new(transport, vec![echo_tool()]).with_permission_handler(DenyAll);
+
+        // Act
+        let events: Vec<SessionEvent> = session.send("run the tool").collect();
+
+        // Assert: denied instead of started/finished, and the model saw
+        // the refusal as a tool error
+        assert!(matches!(
+            &events[0],
+            SessionEvent::PermissionDenied { tool_name, .. } if tool_name == "echo"
+        ));
+        assert!(!events
+            .iter()
+            .any(|e| matches!(e, SessionEvent::ToolCallStarted { .. })));
+        let recorded = session
+            .conversation()
+            .iter()
+            .any(|m| format!("{:?}", m).contains("permission denied"));
+        assert!(recorded);
+    }
+
+    #[test]
+    fn test_unknown_tool_surfaces_as_error_result() {
+        let transport = Scripted::new(vec![
+            tool_response("call_1", "missing"),
+            text_response("sorry", 1),
+        ]);
+        let mut session = AgentSession::new(transport, vec![echo_tool()]);
+
+        let events: Vec<SessionEvent> = session.send("go").collect();
+
+        assert!(matches!(
+            &events[1],
+            SessionEvent::ToolCallFinished { result: Err(e), .. } if e.contains("not found")
+        ));
+    }
+}
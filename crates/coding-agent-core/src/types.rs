@@ -82,12 +82,133 @@ pub enum ContentBlock {
         #[serde(skip_serializing_if = "Option::is_none")]
         is_error: Option<bool>,
     },
+    Image {
+        source: ImageSource,
+    },
+}
+
+/// Base64-encoded image payload for an image content block
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ImageSource {
+    #[serde(rename = "type")]
+    pub source_type: String,
+    pub media_type: String,
+    pub data: String,
+}
+
+/// Builder for messages with mixed content blocks
+///
+/// Start with [`MessageBuilder::user`] or [`MessageBuilder::assistant`],
+/// chain content blocks, and finish with [`MessageBuilder::build`]:
+///
+/// ```
+/// use coding_agent_core::types::MessageBuilder;
+///
+/// let message = MessageBuilder::user()
+///     .text("Here are the results:")
+///     .tool_result("toolu_1", "42 tests passed", false)
+///     .build();
+/// assert_eq!(message.role, "user");
+/// ```
+#[derive(Debug)]
+pub struct MessageBuilder {
+    role: Option<String>,
+    content: Vec<ContentBlock>,
+}
+
+impl MessageBuilder {
+    /// Start building a user message
+    pub fn user() -> Self {
+        Self {
+            role: Some("user".to_string()),
+            content: Vec::new(),
+        }
+    }
+
+    /// Start building an assistant message
+    pub fn assistant() -> Self {
+        Self {
+            role: Some("assistant".to_string()),
+            content: Vec::new(),
+        }
+    }
+
+    /// Append a text block
+    pub fn text(mut self, s: &str) -> Self {
+        self.content.push(ContentBlock::Text {
+            text: s.to_string(),
+        });
+        self
+    }
+
+    /// Append a tool use block
+    pub fn tool_use(mut self, id: &str, name: &str, input: Value) -> Self {
+        self.content.push(ContentBlock::ToolUse {
+            id: id.to_string(),
+            name: name.to_string(),
+            input,
+        });
+        self
+    }
+
+    /// Append a tool result block
+    ///
+    /// `is_error: false` omits the flag entirely, matching the API's
+    /// expectation that successful results carry no `is_error` field.
+    pub fn tool_result(mut self, id: &str, content: &str, is_error: bool) -> Self {
+        self.content.push(ContentBlock::ToolResult {
+            tool_use_id: id.to_string(),
+            content: content.to_string(),
+            is_error: if is_error { Some(true) } else { None },
+        });
+        self
+    }
+
+    /// Append a base64-encoded image block
+    pub fn image(mut self, media_type: &str, data: &str) -> Self {
+        self.content.push(ContentBlock::Image {
+            source: ImageSource {
+                source_type: "base64".to_string(),
+                media_type: media_type.to_string(),
+                data: data.to_string(),
+            },
+        });
+        self
+    }
+
+    /// Append already-built content blocks (e.g. accumulated tool results)
+    pub fn blocks(mut self, blocks: Vec<ContentBlock>) -> Self {
+        self.content.extend(blocks);
+        self
+    }
+
+    /// Finish building, validating that a role was set
+    #[must_use]
+    pub fn build(self) -> Message {
+        Message {
+            role: self
+                .role
+                .expect("MessageBuilder requires a role; start with user() or assistant()"),
+            content: self.content,
+        }
+    }
+}
+
+/// Token counts the API reports for one request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct Usage {
+    #[serde(default)]
+    pub input_tokens: u64,
+    #[serde(default)]
+    pub output_tokens: u64,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct MessageResponse {
     pub content: Vec<ContentBlock>,
     pub stop_reason: Option<String>,
+    #[serde(default)]
+    pub usage: Usage,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -118,3 +239,108 @@ pub fn generate_schema<T: JsonSchema>() -> Value {
     let schema = schema_for!(T);
     serde_json::to_value(schema).unwrap_or(Value::Null)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_builder_user_text() {
+        // Arrange & Act
+        let message = MessageBuilder::user().text("hello").build();
+
+        // Assert
+        assert_eq!(message.role, "user");
+        assert_eq!(
+            message.content,
+            vec![ContentBlock::Text {
+                text: "hello".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_message_builder_assistant_tool_use() {
+        // Arrange
+        let input = serde_json::json!({"path": "src/main.rs"});
+
+        // Act
+        let message = MessageBuilder::assistant()
+            .text("Reading the file")
+            .tool_use("toolu_1", "read_file", input.clone())
+            .build();
+
+        // Assert
+        assert_eq!(message.role, "assistant");
+        assert_eq!(message.content.len(), 2);
+        assert_eq!(
+            message.content[1],
+            ContentBlock::ToolUse {
+                id: "toolu_1".to_string(),
+                name: "read_file".to_string(),
+                input,
+            }
+        );
+    }
+
+    #[test]
+    fn test_message_builder_tool_result_error_flag() {
+        // Arrange & Act
+        let message = MessageBuilder::user()
+            .tool_result("toolu_1", "ok", false)
+            .tool_result("toolu_2", "failed", true)
+            .build();
+
+        // Assert: success omits is_error, failure sets Some(true)
+        assert_eq!(
+            message.content[0],
+            ContentBlock::ToolResult {
+                tool_use_id: "toolu_1".to_string(),
+                content: "ok".to_string(),
+                is_error: None,
+            }
+        );
+        assert_eq!(
+            message.content[1],
+            ContentBlock::ToolResult {
+                tool_use_id: "toolu_2".to_string(),
+                content: "failed".to_string(),
+                is_error: Some(true),
+            }
+        );
+    }
+
+    #[test]
+    fn test_message_builder_image_serializes_base64_source() {
+        // Arrange & Act
+        let message = MessageBuilder::user()
+            .image("image/png", "aGVsbG8=")
+            .build();
+        let json = serde_json::to_value(&message.content[0]).unwrap();
+
+        // Assert
+        assert_eq!(json["type"], "image");
+        assert_eq!(json["source"]["type"], "base64");
+        assert_eq!(json["source"]["media_type"], "image/png");
+        assert_eq!(json["source"]["data"], "aGVsbG8=");
+    }
+
+    #[test]
+    fn test_message_builder_blocks_extends_content() {
+        // Arrange
+        let blocks = vec![
+            ContentBlock::Text {
+                text: "a".to_string(),
+            },
+            ContentBlock::Text {
+                text: "b".to_string(),
+            },
+        ];
+
+        // Act
+        let message = MessageBuilder::user().blocks(blocks).build();
+
+        // Assert
+        assert_eq!(message.content.len(), 2);
+    }
+}
@@ -0,0 +1,137 @@
+//! Replay tests against recorded cassette fixtures.
+//!
+//! These exercise the client's `CODING_AGENT_REPLAY` path entirely
+//! offline: the base URL points at a dead port, so any network attempt
+//! fails loudly. Cassette environment variables are process-global, so
+//! every test holds `ENV_LOCK` while one is set.
+
+use coding_agent_core::cassette::REPLAY_ENV;
+use coding_agent_core::{AnthropicClient, ContentBlock, Message, MessageBuilder, Tool};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+/// A base URL no test should ever reach; replay short-circuits before it.
+const DEAD_URL: &str = "http://127.0.0.1:1";
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/cassettes")
+        .join(name)
+}
+
+/// Clears the replay variable when the test ends, pass or fail.
+struct ReplayGuard;
+
+impl ReplayGuard {
+    fn set(name: &str) -> Self {
+        std::env::set_var(REPLAY_ENV, fixture(name));
+        ReplayGuard
+    }
+}
+
+impl Drop for ReplayGuard {
+    fn drop(&mut self) {
+        std::env::remove_var(REPLAY_ENV);
+    }
+}
+
+fn read_file_tool() -> Tool {
+    Tool {
+        name: "read_file".to_string(),
+        description: "Read a file".to_string(),
+        input_schema: serde_json::json!({
+            "type": "object",
+            "properties": {"path": {"type": "string"}},
+            "required": ["path"]
+        }),
+    }
+}
+
+#[test]
+fn test_replay_simple_turn_offline() {
+    // Arrange
+    let _lock = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _guard = ReplayGuard::set("simple_turn.json");
+    let client = AnthropicClient::builder("test-key")
+        .base_url(DEAD_URL)
+        .build();
+
+    // Act
+    let response = client.send(&[Message::user("Hello!")]).expect("replay hit");
+
+    // Assert
+    assert_eq!(response.stop_reason.as_deref(), Some("end_turn"));
+    assert_eq!(
+        response.content,
+        vec![ContentBlock::Text {
+            text: "Hello! How can I help you today?".to_string()
+        }]
+    );
+    assert_eq!(response.usage.input_tokens, 9);
+    assert_eq!(response.usage.output_tokens, 12);
+}
+
+#[test]
+fn test_replay_tool_use_turn_offline() {
+    // Arrange
+    let _lock = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _guard = ReplayGuard::set("tool_use_turn.json");
+    let client = AnthropicClient::builder("test-key")
+        .base_url(DEAD_URL)
+        .tools(vec![read_file_tool()])
+        .build();
+    let mut conversation = vec![Message::user("What is in notes.txt?")];
+
+    // Act: first exchange returns the recorded tool call
+    let first = client.send(&conversation).expect("replay hit");
+
+    // Assert
+    assert_eq!(first.stop_reason.as_deref(), Some("tool_use"));
+    let (id, name, input) = match &first.content[0] {
+        ContentBlock::ToolUse { id, name, input } => (id.clone(), name.clone(), input.clone()),
+        other => panic!("expected tool use, got {:?}", other),
+    };
+    assert_eq!(name, "read_file");
+    assert_eq!(input["path"], "notes.txt");
+
+    // Act: feed the tool result back, exactly as recorded
+    conversation.push(
+        MessageBuilder::assistant()
+            .tool_use(&id, &name, input)
+            .build(),
+    );
+    conversation.push(Message::tool_result(&id, "hello world"));
+    let second = client.send(&conversation).expect("replay hit");
+
+    // Assert
+    assert_eq!(second.stop_reason.as_deref(), Some("end_turn"));
+    assert_eq!(
+        second.content,
+        vec![ContentBlock::Text {
+            text: "notes.txt contains a greeting: \"hello world\".".to_string()
+        }]
+    );
+}
+
+#[test]
+fn test_replay_unmatched_request_errors_instead_of_calling_out() {
+    // Arrange
+    let _lock = ENV_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let _guard = ReplayGuard::set("simple_turn.json");
+    let client = AnthropicClient::builder("test-key")
+        .base_url(DEAD_URL)
+        .build();
+
+    // Act: a conversation that was never recorded
+    let result = client.send(&[Message::user("Something else entirely")]);
+
+    // Assert
+    let err = result.expect_err("unmatched replay must error");
+    assert!(err.contains("No recorded response"), "got: {}", err);
+    assert!(
+        !err.contains("API request failed"),
+        "must not hit the network"
+    );
+}
@@ -1,118 +0,0 @@
-use schemars::{schema_for, JsonSchema};
-use serde::{Deserialize, Serialize};
-use serde_json::Value;
-
-// ============================================================================
-// API Types - Request/Response structures for Anthropic Messages API
-// ============================================================================
-
-#[derive(Debug, Serialize)]
-pub struct MessageRequest {
-    pub model: String,
-    pub max_tokens: u32,
-    pub messages: Vec<Message>,
-    #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub tools: Vec<Tool>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Message {
-    pub role: String,
-    pub content: Vec<ContentBlock>,
-}
-
-impl Message {
-    /// Create a user message with text content
-    pub fn user(text: impl Into<String>) -> Self {
-        Self {
-            role: "user".to_string(),
-            content: vec![ContentBlock::Text { text: text.into() }],
-        }
-    }
-
-    /// Create an assistant message with the given content blocks
-    pub fn assistant(content: Vec<ContentBlock>) -> Self {
-        Self {
-            role: "assistant".to_string(),
-            content,
-        }
-    }
-
-    /// Create a tool result message
-    pub fn tool_result(tool_use_id: impl Into<String>, content: impl Into<String>) -> Self {
-        Self {
-            role: "user".to_string(),
-            content: vec![ContentBlock::ToolResult {
-                tool_use_id: tool_use_id.into(),
-                content: content.into(),
-                is_error: None,
-            }],
-        }
-    }
-
-    /// Create a tool result message with error flag
-    pub fn tool_result_error(tool_use_id: impl Into<String>, content: impl Into<String>) -> Self {
-        Self {
-            role: "user".to_string(),
-            content: vec![ContentBlock::ToolResult {
-                tool_use_id: tool_use_id.into(),
-                content: content.into(),
-                is_error: Some(true),
-            }],
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(tag = "type", rename_all = "snake_case")]
-pub enum ContentBlock {
-    Text {
-        text: String,
-    },
-    ToolUse {
-        id: String,
-        name: String,
-        input: Value,
-    },
-    ToolResult {
-        tool_use_id: String,
-        content: String,
-        #[serde(skip_serializing_if = "Option::is_none")]
-        is_error: Option<bool>,
-    },
-}
-
-#[derive(Debug, Deserialize)]
-pub struct MessageResponse {
-    pub content: Vec<ContentBlock>,
-    pub stop_reason: Option<String>,
-}
-
-#[derive(Debug, Clone, Serialize)]
-pub struct Tool {
-    pub name: String,
-    pub description: String,
-    pub input_schema: Value,
-}
-
-// ============================================================================
-// Agent Types - Tool definitions
-// ============================================================================
-
-pub type ToolFunction = fn(Value) -> Result<String, String>;
-
-pub struct ToolDefinition {
-    pub name: String,
-    pub description: String,
-    pub input_schema: Value,
-    pub function: ToolFunction,
-}
-
-// ============================================================================
-// Helper - Generate JSON Schema from a struct
-// ============================================================================
-
-pub fn generate_schema<T: JsonSchema>() -> Value {
-    let schema = schema_for!(T);
-    serde_json::to_value(schema).unwrap_or(Value::Null)
-}